@@ -0,0 +1,306 @@
+use crate::prelude::*;
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+use crate::object::WeakObjectRef;
+use crate::symbol::WeakSymbol;
+
+/**
+ * Hashable form of a value, equal for values which are the same according to
+ * SameValueZero, i.e. NaN is equal to itself & -0 is equal to 0.
+ */
+#[derive(PartialEq, Eq, Hash)]
+enum CollectionKey {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Number(u64),
+    BigInt(BigInt),
+    String(JsString),
+    Symbol(Symbol),
+    Object(ObjectRef),
+}
+
+impl CollectionKey {
+    fn new(value: &Value) -> Self {
+        match value {
+            Value::Undefined => CollectionKey::Undefined,
+            Value::Null => CollectionKey::Null,
+            Value::Boolean(b) => CollectionKey::Boolean(*b),
+            Value::Number(n) if n.is_nan() => CollectionKey::Number(f64::NAN.to_bits()),
+            Value::Number(n) => CollectionKey::Number((n + 0.0).to_bits()),
+            Value::BigInt(n) => CollectionKey::BigInt(n.clone()),
+            Value::String(s) => CollectionKey::String(s.clone()),
+            Value::Symbol(symbol) => CollectionKey::Symbol(symbol.clone()),
+            Value::Object(object) => CollectionKey::Object(object.clone()),
+        }
+    }
+}
+
+/**
+ * Entries of a Map or a Set, in insertion order. Removed entries leave a hole
+ * so that iterators keep their position while the collection is modified,
+ * e.g. entries added during `forEach` are visited while removed ones are skipped.
+ * Once holes make up half of the entries, they're compacted away & the positions
+ * of the live iterators are moved along.
+ */
+#[derive(Default)]
+pub struct KeyedCollection {
+    entries: Vec<Option<(Value, Value)>>,
+    indices: HashMap<CollectionKey, usize>,
+    cursors: Vec<Weak<Cell<usize>>>,
+}
+
+impl KeyedCollection {
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn get(&self, key: &Value) -> Option<Value> {
+        let index = self.indices.get(&CollectionKey::new(key))?;
+        self.entries[*index].as_ref().map(|(_, value)| value.clone())
+    }
+
+    pub fn has(&self, key: &Value) -> bool {
+        self.indices.contains_key(&CollectionKey::new(key))
+    }
+
+    /**
+     * Updates the value of the key, or appends a new entry. Keys equal to -0 are stored as 0.
+     */
+    pub fn set(&mut self, mut key: Value, value: Value) {
+        if let Value::Number(n) = &mut key {
+            // -0 + 0 is 0, any other number is left as is
+            *n += 0.0;
+        }
+        match self.indices.get(&CollectionKey::new(&key)) {
+            Some(index) => self.entries[*index] = Some((key, value)),
+            None => {
+                self.indices.insert(CollectionKey::new(&key), self.entries.len());
+                self.entries.push(Some((key, value)));
+            },
+        }
+    }
+
+    /**
+     * Removes the entry of the key, returning whether there was one.
+     */
+    pub fn delete(&mut self, key: &Value) -> bool {
+        match self.indices.remove(&CollectionKey::new(key)) {
+            Some(index) => {
+                self.entries[index] = None;
+                if self.entries.len() >= 16 && self.indices.len() * 2 <= self.entries.len() {
+                    self.compact();
+                }
+                true
+            },
+            None => false,
+        }
+    }
+
+    /**
+     * Removes every entry, live iterators moving back to the start to visit the entries added next.
+     */
+    pub fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+        self.move_cursors(|_| 0);
+    }
+
+    /**
+     * Returns the position of a new iterator, to pass to `next_entry`.
+     * It's moved along as holes are compacted, as long as it's alive.
+     */
+    pub fn cursor(&mut self) -> Rc<Cell<usize>> {
+        let cursor = Rc::new(Cell::new(0));
+        self.cursors.push(Rc::downgrade(&cursor));
+        cursor
+    }
+
+    /**
+     * Returns the first entry at or after the position along with its position, skipping holes.
+     */
+    pub fn next_entry(&self, position: usize) -> Option<(usize, Value, Value)> {
+        self.entries.iter().enumerate().skip(position)
+            .find_map(|(index, entry)| entry.as_ref().map(|(key, value)| (index, key.clone(), value.clone())))
+    }
+
+    /**
+     * Removes the holes, moving the cursors before the same entries.
+     */
+    fn compact(&mut self) {
+        // Number of entries before each position once compacted
+        let mut positions = Vec::with_capacity(self.entries.len() + 1);
+        positions.push(0);
+        for entry in &self.entries {
+            positions.push(positions.last().unwrap() + entry.is_some() as usize);
+        }
+        self.move_cursors(|position| positions[position.min(positions.len() - 1)]);
+
+        self.entries.retain(Option::is_some);
+        for (index, (key, _)) in self.entries.iter().flatten().enumerate() {
+            self.indices.insert(CollectionKey::new(key), index);
+        }
+    }
+
+    /**
+     * Moves the live cursors, forgetting the dropped ones.
+     */
+    fn move_cursors(&mut self, position: impl Fn(usize) -> usize) {
+        self.cursors.retain(|cursor| match cursor.upgrade() {
+            Some(cursor) => {
+                cursor.set(position(cursor.get()));
+                true
+            },
+            None => false,
+        });
+    }
+}
+
+/**
+ * Key of a weak collection entry, either an object or a symbol.
+ */
+#[derive(PartialEq, Eq, Hash)]
+enum WeakKey {
+    Object(WeakObjectRef),
+    Symbol(WeakSymbol),
+}
+
+impl WeakKey {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Object(object) => Some(Self::Object(object.downgrade())),
+            Value::Symbol(symbol) => Some(Self::Symbol(symbol.downgrade())),
+            _ => None,
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        match self {
+            Self::Object(object) => object.upgrade().is_some(),
+            Self::Symbol(symbol) => symbol.upgrade().is_some(),
+        }
+    }
+}
+
+/**
+ * Entries of a WeakMap or a WeakSet, which don't keep their object & symbol keys alive.
+ * Entries whose key was dropped are purged as new ones are added.
+ */
+#[derive(Default)]
+pub struct WeakCollection {
+    entries: HashMap<WeakKey, Value>,
+
+    // Number of entries once last purged, the next purge happening when it doubles
+    threshold: usize,
+}
+
+impl WeakCollection {
+    pub fn get(&self, key: &Value) -> Option<Value> {
+        WeakKey::from_value(key).and_then(|key| self.entries.get(&key).cloned())
+    }
+
+    pub fn has(&self, key: &Value) -> bool {
+        WeakKey::from_value(key).is_some_and(|key| self.entries.contains_key(&key))
+    }
+
+    /**
+     * Adds an entry, unless the key is neither an object nor a symbol.
+     */
+    pub fn set(&mut self, key: &Value, value: Value) {
+        let Some(key) = WeakKey::from_value(key) else {
+            return;
+        };
+        self.entries.insert(key, value);
+        if self.entries.len() > self.threshold.max(8) * 2 {
+            self.entries.retain(|key, _| key.is_alive());
+            self.threshold = self.entries.len();
+        }
+    }
+
+    pub fn delete(&mut self, key: &Value) -> bool {
+        WeakKey::from_value(key).is_some_and(|key| self.entries.remove(&key).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use super::{KeyedCollection, WeakCollection};
+
+    #[test]
+    fn test_keyed_collection() {
+        let mut collection = KeyedCollection::default();
+        let object = ObjectRef::new(ObjectKind::Ordinary, None);
+        collection.set(Value::Number(f64::NAN), Value::String("nan".into()));
+        collection.set(Value::Number(-0.0), Value::String("zero".into()));
+        collection.set(Value::Object(object.clone()), Value::Null);
+        collection.set(Value::String("1".into()), Value::Boolean(true));
+
+        assert_eq!(collection.get(&Value::Number(f64::NAN)), Some(Value::String("nan".into())));
+        assert_eq!(collection.get(&Value::Number(0.0)), Some(Value::String("zero".into())));
+        assert!(collection.has(&Value::Object(object)));
+        assert!(!collection.has(&Value::Object(ObjectRef::new(ObjectKind::Ordinary, None))));
+        assert!(!collection.has(&Value::Number(1.0)));
+        assert_eq!(collection.next_entry(1).map(|(_, key, _)| key.same_value(&Value::Number(0.0))), Some(true));
+
+        assert!(collection.delete(&Value::Number(0.0)));
+        assert!(!collection.delete(&Value::Number(0.0)));
+        assert_eq!(collection.len(), 3);
+        assert_eq!(collection.next_entry(1).map(|(index, _, _)| index), Some(2));
+        collection.clear();
+        assert!(collection.is_empty());
+        assert_eq!(collection.next_entry(0), None);
+    }
+
+    #[test]
+    fn test_keyed_collection_compaction() {
+        let mut collection = KeyedCollection::default();
+        for i in 0..20 {
+            collection.set(Value::Number(i as f64), Value::Null);
+        }
+        let cursor = collection.cursor();
+        cursor.set(15);
+        drop(collection.cursor());
+        for i in 0..10 {
+            collection.delete(&Value::Number(i as f64));
+        }
+
+        // The holes are gone while the cursor stays before the 16th entry
+        assert_eq!(collection.entries.len(), 10);
+        assert_eq!(collection.cursors.len(), 1);
+        assert_eq!(cursor.get(), 5);
+        assert_eq!(collection.next_entry(cursor.get()).map(|(_, key, _)| key), Some(Value::Number(15.0)));
+        assert_eq!(collection.get(&Value::Number(19.0)), Some(Value::Null));
+        collection.set(Value::Number(20.0), Value::Null);
+        assert_eq!(collection.next_entry(10).map(|(_, key, _)| key), Some(Value::Number(20.0)));
+    }
+
+    #[test]
+    fn test_weak_collection() {
+        let mut collection = WeakCollection::default();
+        let kept = Value::Object(ObjectRef::new(ObjectKind::Ordinary, None));
+        let symbol = Value::Symbol(Symbol::new(None));
+        collection.set(&kept, Value::Number(1.0));
+        collection.set(&symbol, Value::Number(2.0));
+        for i in 0..100 {
+            collection.set(&Value::Object(ObjectRef::new(ObjectKind::Ordinary, None)), Value::Number(i as f64));
+            collection.set(&Value::Symbol(Symbol::new(None)), Value::Number(i as f64));
+        }
+
+        // Dropped keys are purged while the live ones are kept
+        assert!(collection.entries.len() < 20);
+        assert_eq!(collection.get(&kept), Some(Value::Number(1.0)));
+        assert_eq!(collection.get(&symbol), Some(Value::Number(2.0)));
+        assert!(collection.delete(&kept));
+        assert!(!collection.has(&kept));
+        assert!(!collection.has(&Value::Number(1.0)));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+
+use std::fmt;
+
+/**
+ * Abrupt completion of a statement. It unwinds the evaluation
+ * until it's handled by the enclosing construct, e.g. `return`
+ * is handled by the function call.
+ */
+#[derive(Debug, PartialEq, Clone)]
+pub enum Completion {
+    Return(Value),
+    Break(Option<String>),
+    Continue(Option<String>),
+    Throw(Value),
+}
+
+impl fmt::Display for Completion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Completion::Return(_) => write!(f, "SyntaxError: Illegal return statement"),
+            Completion::Break(_) => write!(f, "SyntaxError: Illegal break statement"),
+            Completion::Continue(_) => write!(f, "SyntaxError: Illegal continue statement"),
+            Completion::Throw(value) => write!(f, "Uncaught {value}"),
+        }
+    }
+}
@@ -1,23 +1,100 @@
 use crate::prelude::*;
 
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 use derive_builder::Builder;
 
+/**
+ * Environment is a cheap handle to a shared variable record, hence
+ * cloning an environment yields the same scope. This lets closures
+ * capture the scope they were defined in.
+ */
 #[derive(Builder, Clone, Debug)]
 pub struct Environment {
-    #[builder(default="HashMap::new()")]
-    record: HashMap<String, Value>,
+    #[builder(default="Rc::new(RefCell::new(HashMap::new()))", setter(custom))]
+    record: Rc<RefCell<HashMap<String, Value>>>,
+
+    // Variables of the record that can't be assigned, i.e. `const`
+    #[builder(default="Rc::new(RefCell::new(HashSet::new()))", setter(skip))]
+    constants: Rc<RefCell<HashSet<String>>>,
 
     #[builder(default="Option::None")]
     parent: Option<Box<Environment>>,
 }
 
+impl EnvironmentBuilder {
+    /**
+     * Sets the initial variables of the environment.
+     */
+    pub fn record(&mut self, record: HashMap<String, Value>) -> &mut Self {
+        self.record = Some(Rc::new(RefCell::new(record)));
+        self
+    }
+}
+
 impl Environment {
+    /**
+     * Creates a new child environment whose parent is this environment.
+     */
+    pub fn extend(&self) -> Environment {
+        Environment {
+            record: Rc::new(RefCell::new(HashMap::new())),
+            constants: Rc::new(RefCell::new(HashSet::new())),
+            parent: Some(Box::new(self.clone())),
+        }
+    }
+
     /**
      * Creates a variable with the given name and value.
      */
     pub fn define(&mut self, name: String, value: Value) {
-        self.record.insert(name, value);
+        self.constants.borrow_mut().remove(&name);
+        self.record.borrow_mut().insert(name, value);
+    }
+
+    /**
+     * Prevents further assignments to a variable defined in this environment.
+     */
+    pub fn mark_constant(&mut self, name: &str) {
+        if self.has_own(name) {
+            self.constants.borrow_mut().insert(name.to_string());
+        }
+    }
+
+    /**
+     * Whether the variable resolves to a constant.
+     */
+    pub fn is_constant(&self, name: &str) -> bool {
+        match self.resolve(name) {
+            None => false,
+            Some(env) => env.constants.borrow().contains(name),
+        }
+    }
+
+    /**
+     * Whether the variable is defined in this very environment,
+     * without looking into the parent environments.
+     */
+    pub fn has_own(&self, name: &str) -> bool {
+        self.record.borrow().contains_key(name)
+    }
+
+    /**
+     * Updates an already defined variable, or returns an error
+     * if the variable is not defined or is a constant.
+     */
+    pub fn assign(&mut self, name: &String, value: Value) -> Result<()> {
+        match self.resolve(name) {
+            None => Err(Error::Reference(format!("Variable '{name}' is not defined!"))),
+            Some(env) if env.constants.borrow().contains(name) => {
+                Err(Error::Type("Assignment to constant variable!".to_string()))
+            },
+            Some(env) => {
+                env.record.borrow_mut().insert(name.clone(), value);
+                Ok(())
+            },
+        }
     }
 
     /**
@@ -25,9 +102,73 @@ impl Environment {
      * an error if the variable is not defined.
      */
     pub fn lookup(&self, name: &String) -> Result<Value> {
-        match self.record.get(name) {
-            None => Err(Error::Runtime(format!("Variable '{name}' is not defined!"))),
-            Some(value) => Ok(value.clone()),
+        match self.resolve(name) {
+            None => Err(Error::Reference(format!("Variable '{name}' is not defined!"))),
+            Some(env) => Ok(env.record.borrow()[name].clone()),
+        }
+    }
+
+    /**
+     * Returns the environment in which the variable is defined.
+     */
+    pub fn resolve(&self, name: &str) -> Option<Environment> {
+        if self.has_own(name) {
+            return Some(self.clone());
+        }
+        match &self.parent {
+            None => None,
+            Some(parent) => parent.resolve(name),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn test_child_environment_lookup() {
+        let global = EnvironmentBuilder::default()
+            .record(HashMap::from([
+                ("x".to_string(), Value::Number(1.0)),
+            ]))
+            .build()
+            .unwrap();
+        let mut child = global.extend();
+        child.define("y".to_string(), Value::Number(2.0));
+
+        assert_eq!(child.lookup(&"x".to_string()), Ok(Value::Number(1.0)));
+        assert_eq!(child.lookup(&"y".to_string()), Ok(Value::Number(2.0)));
+        assert!(global.lookup(&"y".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_child_environment_assign() {
+        let mut global = EnvironmentBuilder::default()
+            .record(HashMap::from([
+                ("x".to_string(), Value::Number(1.0)),
+            ]))
+            .build()
+            .unwrap();
+        let mut child = global.extend();
+
+        assert!(child.assign(&"x".to_string(), Value::Number(5.0)).is_ok());
+        assert_eq!(global.lookup(&"x".to_string()), Ok(Value::Number(5.0)));
+
+        let error = Error::Reference("Variable 'z' is not defined!".to_string());
+        assert_eq!(global.assign(&"z".to_string(), Value::Null), Err(error));
+    }
+
+    #[test]
+    fn test_constant_assign() {
+        let mut global = EnvironmentBuilder::default().build().unwrap();
+        global.define("x".to_string(), Value::Number(1.0));
+        global.mark_constant("x");
+
+        let error = Error::Type("Assignment to constant variable!".to_string());
+        assert_eq!(global.extend().assign(&"x".to_string(), Value::Null), Err(error));
+        assert!(global.is_constant("x"));
+    }
+}
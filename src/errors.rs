@@ -1,3 +1,5 @@
+use crate::prelude::*;
+
 #[derive(thiserror::Error, PartialEq, Debug)]
 pub enum Error {
     #[error("SyntaxError: {}", .0)]
@@ -5,4 +7,16 @@ pub enum Error {
 
     #[error("RuntimeError: {}", .0)]
     Runtime(String),
+
+    #[error("TypeError: {}", .0)]
+    Type(String),
+
+    #[error("ReferenceError: {}", .0)]
+    Reference(String),
+
+    #[error("RangeError: {}", .0)]
+    Range(String),
+
+    #[error("{}", .0)]
+    Abrupt(Completion),
 }
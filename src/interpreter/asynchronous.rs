@@ -0,0 +1,222 @@
+use crate::prelude::*;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use corosensei::CoroutineResult;
+
+use crate::interpreter::builtins::native_closure;
+use crate::interpreter::callable::Callable;
+use crate::interpreter::exceptions::ExceptionEvalable;
+use crate::interpreter::generators::{GeneratorCoroutine, GeneratorResume};
+use crate::interpreter::promises::{PromiseCapability, PromiseOperations};
+
+/**
+ * Running async function, suspended at each `await` until the awaited promise settles.
+ */
+struct AsyncState {
+    coroutine: RefCell<Option<GeneratorCoroutine>>,
+    capability: PromiseCapability,
+}
+
+pub trait AsyncEvalable {
+    /**
+     * Calls an async function, running its body until the first `await`.
+     * Returns the promise settled with the result of the body.
+     */
+    fn call_async(&self, function: Rc<Function>, env: Environment) -> Result<Value>;
+
+    /**
+     * Evaluates an await expression, suspending the async function until the value settles.
+     */
+    fn eval_await(&self, expr: &Tree, env_ref: &EnvRef) -> Result<Value>;
+}
+
+impl <'a> AsyncEvalable for Interpreter<'a> {
+    fn call_async(&self, function: Rc<Function>, env: Environment) -> Result<Value> {
+        let capability = self.new_promise_capability(&self.promise_constructor())?;
+        let state = Rc::new(AsyncState {
+            coroutine: RefCell::new(Some(self.spawn_coroutine(function, env)?)),
+            capability: capability.clone(),
+        });
+
+        self.async_step(&state, GeneratorResume::Next(Value::Undefined))?;
+        Ok(capability.promise)
+    }
+
+    fn eval_await(&self, expr: &Tree, env_ref: &EnvRef) -> Result<Value> {
+        let argument = match expr {
+            Tree::AwaitExpression { argument } => argument,
+            _ => return Err(Error::Runtime(format!("Unimplemented async node: {expr}"))),
+        };
+
+        // The awaited value is handed over to `async_step`, which resumes the body once it settles
+        let value = self.eval_tree(argument, env_ref)?;
+        self.suspend_value(value)
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * The %Promise% intrinsic, used by async functions regardless of the global `Promise`.
+     */
+    pub(crate) fn promise_constructor(&self) -> Value {
+        match self.realm.intrinsic("%Promise%") {
+            Some(constructor) => Value::Object(constructor),
+            None => Value::Undefined,
+        }
+    }
+
+    /**
+     * Resumes the body of an async function until its next `await` or its completion.
+     */
+    fn async_step(&self, state: &Rc<AsyncState>, resume: GeneratorResume) -> Result<()> {
+        let mut coroutine = match state.coroutine.borrow_mut().take() {
+            Some(coroutine) => coroutine,
+            None => return Ok(()),
+        };
+
+        let awaited = match coroutine.resume(resume) {
+            CoroutineResult::Yield(awaited) => awaited,
+            CoroutineResult::Return(result) => {
+                self.release_coroutine(coroutine);
+                return match result {
+                    Ok(value) => self.call(&state.capability.resolve, &Value::Undefined, &[value]).map(|_| ()),
+                    Err(error) => {
+                        let exception = self.to_exception(error)?;
+                        self.call(&state.capability.reject, &Value::Undefined, &[exception]).map(|_| ())
+                    },
+                };
+            },
+        };
+        *state.coroutine.borrow_mut() = Some(coroutine);
+
+        // Values that can't be converted into promises are thrown at the `await`
+        let promise = match self.promise_resolve(&self.promise_constructor(), awaited) {
+            Ok(Value::Object(promise)) => promise,
+            Ok(value) => return Err(Error::Type(format!("{value} is not a promise!"))),
+            Err(error) => {
+                let exception = self.to_exception(error)?;
+                return self.async_step(state, GeneratorResume::Throw(exception));
+            },
+        };
+
+        let target = state.clone();
+        let on_fulfilled = native_closure(&self.realm, "", 1, move |interpreter, _this, args, _new_target| {
+            let value = args.first().cloned().unwrap_or(Value::Undefined);
+            interpreter.async_step(&target, GeneratorResume::Next(value))?;
+            Ok(Value::Undefined)
+        }, false);
+
+        let target = state.clone();
+        let on_rejected = native_closure(&self.realm, "", 1, move |interpreter, _this, args, _new_target| {
+            let reason = args.first().cloned().unwrap_or(Value::Undefined);
+            interpreter.async_step(&target, GeneratorResume::Throw(reason))?;
+            Ok(Value::Undefined)
+        }, false);
+
+        self.perform_promise_then(&promise, &Value::Object(on_fulfilled), &Value::Object(on_rejected), None);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_async_function_returns_promise() {
+        assert_eval("async function f() { return 1; } '' + f();", Value::String("[object Promise]".into()));
+        assert_binding("let x; async function f() { return 1; } f().then(function(v) { x = v; });", "x", "1");
+    }
+
+    #[test]
+    fn test_await_values_and_promises() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            class Thenable { then(resolve) { resolve(3); } }
+            async function f() {
+                log(await 1);
+                log(await Promise.resolve(2));
+                log(await new Thenable());
+                return 4;
+            }
+            f().then(log);
+            log(0);
+        ";
+        assert_binding(content, "out", "0,1,2,3,4");
+    }
+
+    #[test]
+    fn test_await_rejection() {
+        let content = "
+            let x;
+            async function f() {
+                try {
+                    await Promise.reject(new Error('boom'));
+                } catch (e) {
+                    return e.message + '!';
+                } finally {
+                    x = 'done';
+                }
+            }
+            f().then(function(v) { x = x + ' ' + v; });
+        ";
+        assert_binding(content, "x", "done boom!");
+    }
+
+    #[test]
+    fn test_async_function_throws() {
+        let content = "
+            let x;
+            async function f() { await null; null.y; }
+            f().catch(function(e) { x = e.name; });
+        ";
+        assert_binding(content, "x", "TypeError");
+    }
+
+    #[test]
+    fn test_async_ordering() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            async function async1() {
+                log('async1 start');
+                await async2();
+                log('async1 end');
+            }
+            async function async2() {
+                log('async2');
+            }
+            log('script start');
+            async1();
+            new Promise(function(resolve) {
+                log('promise1');
+                resolve();
+            }).then(function() {
+                log('promise2');
+            });
+            log('script end');
+        ";
+        assert_binding(content, "out", "script start,async1 start,async2,promise1,script end,async1 end,promise2");
+    }
+
+    #[test]
+    fn test_async_methods() {
+        let content = "
+            let x;
+            class Api {
+                async load(n) { return await n * 2; }
+            }
+            new Api().load(21).then(function(v) { x = v; });
+        ";
+        assert_binding(content, "x", "42");
+    }
+
+    #[test]
+    fn test_async_function_is_not_constructor() {
+        assert_eval_error("async function f() {} new f();", Error::Type("Object(Function(f)) is not a constructor!".to_string()));
+    }
+}
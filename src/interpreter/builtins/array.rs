@@ -0,0 +1,1143 @@
+use crate::prelude::*;
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::iteration::Iterable;
+use crate::interpreter::properties::{assignment_error, PropertyAccessor};
+use crate::interpreter::realm::Realm;
+
+use super::map::IterationKind;
+use super::{define_global, native_function, native_iterator};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 31] = [
+        ("at", 1, array_at),
+        ("concat", 1, array_concat),
+        ("entries", 0, array_entries),
+        ("every", 1, array_every),
+        ("fill", 1, array_fill),
+        ("filter", 1, array_filter),
+        ("find", 1, array_find),
+        ("findIndex", 1, array_find_index),
+        ("findLast", 1, array_find_last),
+        ("findLastIndex", 1, array_find_last_index),
+        ("flat", 0, array_flat),
+        ("flatMap", 1, array_flat_map),
+        ("forEach", 1, array_for_each),
+        ("includes", 1, array_includes),
+        ("indexOf", 1, array_index_of),
+        ("join", 1, array_join),
+        ("keys", 0, array_keys),
+        ("lastIndexOf", 1, array_last_index_of),
+        ("map", 1, array_map),
+        ("pop", 0, array_pop),
+        ("push", 1, array_push),
+        ("reduce", 1, array_reduce),
+        ("reduceRight", 1, array_reduce_right),
+        ("reverse", 0, array_reverse),
+        ("shift", 0, array_shift),
+        ("slice", 2, array_slice),
+        ("some", 1, array_some),
+        ("sort", 1, array_sort),
+        ("splice", 2, array_splice),
+        ("toString", 0, array_join),
+        ("unshift", 1, array_unshift),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    // Arrays iterate their values, i.e. `arr[Symbol.iterator] === arr.values`
+    let values = native_function(realm, "values", 0, array_values, false);
+    prototype.define_non_enumerable("values".to_string(), Property::Data(Value::Object(values.clone())));
+    prototype.define_non_enumerable(&realm.symbols.iterator, Property::Data(Value::Object(values.clone())));
+    realm.define_intrinsic("%Array.prototype.values%", values);
+
+    let iterator_prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%IteratorPrototype%"));
+    iterator_prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Array Iterator".into())));
+    realm.define_intrinsic("%ArrayIteratorPrototype%", iterator_prototype);
+
+    let constructor = native_function(realm, "Array", 1, construct_array, true);
+    let statics: [(&str, usize, NativeFn); 3] = [
+        ("from", 1, array_from),
+        ("isArray", 1, array_is_array),
+        ("of", 0, array_of),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Array.prototype%", prototype);
+    define_global(global, "Array", Value::Object(constructor));
+}
+
+/**
+ * Whether the object is an array, seeing through proxies, e.g. `Array.isArray(new Proxy([], handler))`.
+ */
+pub fn is_array(object: &ObjectRef) -> Result<bool> {
+    // Walked iteratively, as proxies may be nested arbitrarily deep
+    let mut object = object.clone();
+    while let Some(proxy) = object.proxy() {
+        if proxy.handler.is_none() {
+            return Err(Error::Type("Cannot perform 'IsArray' on a proxy that has been revoked!".to_string()));
+        }
+        object = proxy.target;
+    }
+    Ok(object.is_array())
+}
+
+/**
+ * Creates an array object inheriting the methods of `Array.prototype`.
+ */
+pub fn create_array(interpreter: &Interpreter, elements: Vec<Value>) -> ObjectRef {
+    ObjectRef::new(ObjectKind::Array(elements.into()), interpreter.realm.intrinsic("%Array.prototype%"))
+}
+
+/**
+ * Array(...elements) -> array of the elements, or of the given length if it's the only number.
+ */
+fn construct_array(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    match args {
+        [Value::Number(length)] if length.trunc() != *length || !(0.0..=u32::MAX as f64).contains(length) => {
+            Err(Error::Range("Invalid array length!".to_string()))
+        },
+
+        // The elements of `new Array(n)` are holes
+        [Value::Number(length)] => {
+            let array = create_array(interpreter, vec![]);
+            array.set_own_value("length", Value::Number(*length));
+            Ok(Value::Object(array))
+        },
+        _ => Ok(Value::Object(create_array(interpreter, args.to_vec()))),
+    }
+}
+
+/**
+ * Array.from(items, mapper, thisArg) -> array of the values of an iterable or an array-like object.
+ */
+fn array_from(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let items = args.first().cloned().unwrap_or(Value::Undefined);
+    let mapper = match args.get(1) {
+        None | Some(Value::Undefined) => None,
+        Some(mapper) if mapper.is_callable() => Some(mapper),
+        Some(mapper) => return Err(Error::Type(format!("{mapper} is not a function!"))),
+    };
+    let this_arg = args.get(2).cloned().unwrap_or(Value::Undefined);
+
+    let iterable = match &items {
+        Value::Undefined | Value::Null => return Err(Error::Type(format!("{items} is not iterable!"))),
+        Value::String(_) => true,
+        _ => !matches!(interpreter.get_property(&items, &interpreter.realm.symbols.iterator)?, Value::Undefined | Value::Null),
+    };
+    let values = match iterable {
+        true => interpreter.iterate(&items)?,
+
+        // Array-like objects have a length & indexed properties, e.g. `{ length: 2, 0: 'a', 1: 'b' }`
+        false => {
+            let length = to_length(interpreter.to_number(&interpreter.get_property(&items, "length")?)?);
+            let mut values = Vec::with_capacity(length);
+            for index in 0..length {
+                values.push(interpreter.get_property(&items, index.to_string())?);
+            }
+            values
+        },
+    };
+
+    let values = match mapper {
+        None => values,
+        Some(mapper) => {
+            let mut mapped = Vec::with_capacity(values.len());
+            for (index, value) in values.into_iter().enumerate() {
+                mapped.push(interpreter.call(mapper, &this_arg, &[value, Value::Number(index as f64)])?);
+            }
+            mapped
+        },
+    };
+    Ok(Value::Object(create_array(interpreter, values)))
+}
+
+/**
+ * Array.isArray(value) -> whether the value is an array.
+ */
+fn array_is_array(_interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    match args.first() {
+        Some(Value::Object(object)) => is_array(object).map(Value::Boolean),
+        _ => Ok(Value::Boolean(false)),
+    }
+}
+
+/**
+ * Array.of(...elements) -> array of the elements, even if a single number is given.
+ */
+fn array_of(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Object(create_array(interpreter, args.to_vec())))
+}
+
+/**
+ * Array.prototype.at(index) -> element at the index, negative indices counting from the end.
+ */
+fn array_at(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "at")?;
+    let length = length_of(interpreter, &object)?;
+    let index = integer_argument(interpreter, args, 0)?;
+    let index = if index < 0.0 { length as f64 + index } else { index };
+    match index >= 0.0 && index < length as f64 {
+        true => get(interpreter, &object, index as usize),
+        false => Ok(Value::Undefined),
+    }
+}
+
+/**
+ * Array.prototype.concat(...items) -> new array of the elements followed by the items, arrays being spread.
+ */
+fn array_concat(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = Value::Object(this_object(interpreter, this, "concat")?);
+    let mut result = vec![];
+    for item in [&object].into_iter().chain(args) {
+        match item {
+            Value::Object(array) if is_array(array)? => {
+                let length = length_of(interpreter, array)?;
+                result.extend(elements(interpreter, array, 0..length)?);
+            },
+            _ => result.push(Some(item.clone())),
+        }
+    }
+    Ok(Value::Object(create_array_with_holes(interpreter, result)))
+}
+
+/**
+ * Array.prototype.entries() -> iterator of the [index, element] pairs.
+ */
+fn array_entries(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    array_iterator(interpreter, this, "entries", IterationKind::Entries)
+}
+
+/**
+ * Array.prototype.every(predicate, thisArg) -> whether the predicate holds for every element.
+ */
+fn array_every(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "every")?;
+    let mut every = true;
+    for_each(interpreter, &object, args, |_, _, result| {
+        every = result.to_boolean();
+        Ok(every)
+    })?;
+    Ok(Value::Boolean(every))
+}
+
+/**
+ * Array.prototype.fill(value, start, end) -> the array with the elements between the indices replaced.
+ */
+fn array_fill(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "fill")?;
+    let length = length_of(interpreter, &object)?;
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    let (start, end) = range_arguments(interpreter, args, 1, length)?;
+    if is_plain_array(&object) {
+        rewrite(&object, |elements| {
+            for element in elements.iter_mut().take(end).skip(start) {
+                *element = value.clone();
+            }
+        });
+    } else {
+        for index in start..end {
+            set(interpreter, &object, index.to_string(), value.clone())?;
+        }
+    }
+    Ok(Value::Object(object))
+}
+
+/**
+ * Array.prototype.filter(predicate, thisArg) -> new array of the elements the predicate holds for.
+ */
+fn array_filter(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "filter")?;
+    let mut result = vec![];
+    for_each(interpreter, &object, args, |_, value, selected| {
+        if selected.to_boolean() {
+            result.push(value);
+        }
+        Ok(true)
+    })?;
+    Ok(Value::Object(create_array(interpreter, result)))
+}
+
+/**
+ * Array.prototype.find(predicate, thisArg) -> first element the predicate holds for, or undefined.
+ */
+fn array_find(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "find")?;
+    let found = find(interpreter, &object, args, false)?;
+    Ok(found.map(|(_, value)| value).unwrap_or(Value::Undefined))
+}
+
+/**
+ * Array.prototype.findIndex(predicate, thisArg) -> index of the first element the predicate holds for, or -1.
+ */
+fn array_find_index(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "findIndex")?;
+    let found = find(interpreter, &object, args, false)?;
+    Ok(index_value(found.map(|(index, _)| index)))
+}
+
+/**
+ * Array.prototype.findLast(predicate, thisArg) -> last element the predicate holds for, or undefined.
+ */
+fn array_find_last(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "findLast")?;
+    let found = find(interpreter, &object, args, true)?;
+    Ok(found.map(|(_, value)| value).unwrap_or(Value::Undefined))
+}
+
+/**
+ * Array.prototype.findLastIndex(predicate, thisArg) -> index of the last element the predicate holds for, or -1.
+ */
+fn array_find_last_index(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "findLastIndex")?;
+    let found = find(interpreter, &object, args, true)?;
+    Ok(index_value(found.map(|(index, _)| index)))
+}
+
+/**
+ * Array.prototype.flat(depth) -> new array with the nested arrays spread up to the depth, 1 by default.
+ */
+fn array_flat(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "flat")?;
+    let depth = match args.first() {
+        None | Some(Value::Undefined) => 1.0,
+        Some(_) => integer_argument(interpreter, args, 0)?,
+    };
+    let mut result = vec![];
+    flatten(interpreter, &mut result, &object, depth)?;
+    Ok(Value::Object(create_array(interpreter, result)))
+}
+
+/**
+ * Array.prototype.flatMap(mapper, thisArg) -> new array of the mapped elements, returned arrays being spread.
+ */
+fn array_flat_map(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "flatMap")?;
+    let mut result = vec![];
+    for_each(interpreter, &object, args, |_, _, mapped| {
+        match &mapped {
+            Value::Object(array) if is_array(array)? => flatten(interpreter, &mut result, array, 0.0)?,
+            _ => result.push(mapped),
+        }
+        Ok(true)
+    })?;
+    Ok(Value::Object(create_array(interpreter, result)))
+}
+
+/**
+ * Array.prototype.forEach(callback, thisArg) -> calls back with every element.
+ */
+fn array_for_each(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "forEach")?;
+    for_each(interpreter, &object, args, |_, _, _| Ok(true))?;
+    Ok(Value::Undefined)
+}
+
+/**
+ * Array.prototype.includes(search, fromIndex) -> whether an element is the same value, NaN included.
+ * Holes are looked up like undefined elements.
+ */
+fn array_includes(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "includes")?;
+    let length = length_of(interpreter, &object)?;
+    let search = args.first().cloned().unwrap_or(Value::Undefined);
+    let (start, _) = range_arguments(interpreter, args, 1, length)?;
+    Ok(Value::Boolean(values(interpreter, &object, start..length)?.iter().any(|value| value.same_value_zero(&search))))
+}
+
+/**
+ * Array.prototype.indexOf(search, fromIndex) -> index of the first strictly equal element, or -1.
+ */
+fn array_index_of(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "indexOf")?;
+    let length = length_of(interpreter, &object)?;
+    let search = args.first().cloned().unwrap_or(Value::Undefined);
+    let (start, _) = range_arguments(interpreter, args, 1, length)?;
+    let index = elements(interpreter, &object, start..length)?.iter()
+        .position(|element| element.as_ref().is_some_and(|value| value.strict_equals(&search)));
+    Ok(index_value(index.map(|index| start + index)))
+}
+
+/**
+ * Array.prototype.join(separator) -> the elements converted into strings, separated by commas by default.
+ * Undefined & null elements are converted into empty strings.
+ */
+fn array_join(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "join")?;
+
+    // Arrays containing themselves join as the empty string where they're nested, e.g. `a = [1]; a.push(a)` -> "1,"
+    if interpreter.joining.borrow().contains(&object) {
+        return Ok(Value::String(JsString::default()));
+    }
+    interpreter.joining.borrow_mut().push(object.clone());
+    let joined = join(interpreter, &object, args);
+    interpreter.joining.borrow_mut().pop();
+    joined
+}
+
+/**
+ * Array.prototype.keys() -> iterator of the indices.
+ */
+fn array_keys(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    array_iterator(interpreter, this, "keys", IterationKind::Keys)
+}
+
+/**
+ * Array.prototype.lastIndexOf(search, fromIndex) -> index of the last strictly equal element, or -1.
+ */
+fn array_last_index_of(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "lastIndexOf")?;
+    let length = length_of(interpreter, &object)?;
+    let search = args.first().cloned().unwrap_or(Value::Undefined);
+    let end = match args.get(1) {
+        None => length,
+        Some(_) => match integer_argument(interpreter, args, 1)? {
+            from if from < 0.0 => (length as f64 + from + 1.0).max(0.0) as usize,
+            from => (from + 1.0).min(length as f64) as usize,
+        },
+    };
+    let index = elements(interpreter, &object, 0..end)?.iter()
+        .rposition(|element| element.as_ref().is_some_and(|value| value.strict_equals(&search)));
+    Ok(index_value(index))
+}
+
+/**
+ * Array.prototype.map(mapper, thisArg) -> new array of the mapped elements, holes being kept.
+ */
+fn array_map(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "map")?;
+    let mut result = vec![None; length_of(interpreter, &object)?];
+    for_each(interpreter, &object, args, |index, _, mapped| {
+        result[index] = Some(mapped);
+        Ok(true)
+    })?;
+    Ok(Value::Object(create_array_with_holes(interpreter, result)))
+}
+
+/**
+ * Array.prototype.pop() -> the removed last element, or undefined.
+ */
+fn array_pop(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "pop")?;
+    let length = length_of(interpreter, &object)?;
+    let removed = splice(interpreter, &object, length, length.saturating_sub(1), length.min(1), vec![])?;
+    Ok(removed.into_iter().flatten().next().unwrap_or(Value::Undefined))
+}
+
+/**
+ * Array.prototype.push(...items) -> the new length, once the items are appended.
+ */
+fn array_push(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "push")?;
+    let length = length_of(interpreter, &object)?;
+    splice(interpreter, &object, length, length, 0, args.to_vec())?;
+    Ok(Value::Number((length + args.len()) as f64))
+}
+
+/**
+ * Array.prototype.reduce(reducer, initial) -> the accumulated value, from the first element to the last.
+ */
+fn array_reduce(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "reduce")?;
+    let indices: Vec<usize> = (0..length_of(interpreter, &object)?).collect();
+    reduce(interpreter, &object, args, indices)
+}
+
+/**
+ * Array.prototype.reduceRight(reducer, initial) -> the accumulated value, from the last element to the first.
+ */
+fn array_reduce_right(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "reduceRight")?;
+    let indices: Vec<usize> = (0..length_of(interpreter, &object)?).rev().collect();
+    reduce(interpreter, &object, args, indices)
+}
+
+/**
+ * Array.prototype.reverse() -> the array with its elements reversed in place, holes included.
+ */
+fn array_reverse(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "reverse")?;
+    let length = length_of(interpreter, &object)?;
+    if is_plain_array(&object) {
+        rewrite(&object, |elements| elements.reverse());
+        return Ok(Value::Object(object));
+    }
+    for lower in 0..length / 2 {
+        let upper = length - 1 - lower;
+        let (lower_element, upper_element) = (element(interpreter, &object, lower)?, element(interpreter, &object, upper)?);
+        put(interpreter, &object, lower, upper_element)?;
+        put(interpreter, &object, upper, lower_element)?;
+    }
+    Ok(Value::Object(object))
+}
+
+/**
+ * Array.prototype.shift() -> the removed first element, or undefined.
+ */
+fn array_shift(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "shift")?;
+    let length = length_of(interpreter, &object)?;
+    let removed = splice(interpreter, &object, length, 0, length.min(1), vec![])?;
+    Ok(removed.into_iter().flatten().next().unwrap_or(Value::Undefined))
+}
+
+/**
+ * Array.prototype.slice(start, end) -> new array of the elements between the indices,
+ * negative indices counting from the end.
+ */
+fn array_slice(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "slice")?;
+    let length = length_of(interpreter, &object)?;
+    let (start, end) = range_arguments(interpreter, args, 0, length)?;
+    let sliced = elements(interpreter, &object, start..end)?;
+    Ok(Value::Object(create_array_with_holes(interpreter, sliced)))
+}
+
+/**
+ * Array.prototype.some(predicate, thisArg) -> whether the predicate holds for any element.
+ */
+fn array_some(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "some")?;
+    let mut some = false;
+    for_each(interpreter, &object, args, |_, _, result| {
+        some = result.to_boolean();
+        Ok(!some)
+    })?;
+    Ok(Value::Boolean(some))
+}
+
+/**
+ * Array.prototype.sort(comparator) -> the array sorted in place, keeping the order of equal elements.
+ * Without a comparator, elements are sorted as strings. Undefined elements come last, followed by the holes.
+ */
+fn array_sort(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let comparator = match args.first() {
+        None | Some(Value::Undefined) => None,
+        Some(comparator) if comparator.is_callable() => Some(comparator),
+        Some(comparator) => return Err(Error::Type(
+            format!("The comparison function must be either a function or undefined, got {comparator}!")
+        )),
+    };
+    let object = this_object(interpreter, this, "sort")?;
+    let length = length_of(interpreter, &object)?;
+
+    let (defined, undefined): (Vec<Value>, Vec<Value>) = elements(interpreter, &object, 0..length)?.into_iter()
+        .flatten()
+        .partition(|value| !matches!(value, Value::Undefined));
+    let mut compare = |a: &Value, b: &Value| -> Result<Ordering> {
+        match comparator {
+            Some(comparator) => {
+                let result = interpreter.to_number(&interpreter.call(comparator, &Value::Undefined, &[a.clone(), b.clone()])?)?;
+                Ok(result.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+            },
+
+            // Strings are compared by their UTF-16 code units
+            None => Ok(interpreter.to_string(a)?.cmp(&interpreter.to_string(b)?)),
+        }
+    };
+    let mut sorted = merge_sort(defined, &mut compare)?;
+    sorted.extend(undefined);
+
+    if is_plain_array(&object) {
+        rewrite(&object, |elements| *elements = sorted);
+        return Ok(Value::Object(object));
+    }
+    let count = sorted.len();
+    for (index, value) in sorted.into_iter().enumerate() {
+        set(interpreter, &object, index.to_string(), value)?;
+    }
+    for index in count..length {
+        delete(interpreter, &object, index)?;
+    }
+    Ok(Value::Object(object))
+}
+
+/**
+ * Array.prototype.splice(start, deleteCount, ...items) -> array of the removed elements,
+ * the items being inserted in their place.
+ */
+fn array_splice(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "splice")?;
+    let length = length_of(interpreter, &object)?;
+    let (start, _) = range_arguments(interpreter, args, 0, length)?;
+    let count = match args.len() {
+        0 => 0,
+        1 => length - start,
+        _ => (integer_argument(interpreter, args, 1)?.max(0.0) as usize).min(length - start),
+    };
+    let items = args.iter().skip(2).cloned().collect();
+    let removed = splice(interpreter, &object, length, start, count, items)?;
+    Ok(Value::Object(create_array_with_holes(interpreter, removed)))
+}
+
+/**
+ * Array.prototype.unshift(...items) -> the new length, once the items are prepended.
+ */
+fn array_unshift(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object(interpreter, this, "unshift")?;
+    let length = length_of(interpreter, &object)?;
+    splice(interpreter, &object, length, 0, 0, args.to_vec())?;
+    Ok(Value::Number((length + args.len()) as f64))
+}
+
+/**
+ * Array.prototype.values() -> iterator of the elements, also found under `Symbol.iterator`.
+ */
+fn array_values(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    array_iterator(interpreter, this, "values", IterationKind::Values)
+}
+
+/**
+ * Creates an iterator of an array-like object, which reads its length on each step
+ * & thus also visits the elements appended during the iteration.
+ */
+fn array_iterator(interpreter: &Interpreter, this: &Value, method: &str, kind: IterationKind) -> Result<Value> {
+    let object = this_object(interpreter, this, method)?;
+    let position = Cell::new(0);
+    Ok(native_iterator(interpreter, "%ArrayIteratorPrototype%", move |interpreter| {
+        let index = position.get();
+        if index >= length_of(interpreter, &object)? {
+            return Ok(None);
+        }
+        position.set(index + 1);
+        Ok(Some(match kind {
+            IterationKind::Keys => Value::Number(index as f64),
+            IterationKind::Values => get(interpreter, &object, index)?,
+            IterationKind::Entries => {
+                let entry = vec![Value::Number(index as f64), get(interpreter, &object, index)?];
+                Value::Object(create_array(interpreter, entry))
+            },
+        }))
+    }))
+}
+
+/**
+ * Converts `this` into an object, the methods being generic over array-like objects,
+ * e.g. `Array.prototype.join.call({ length: 2, 0: 'a', 1: 'b' })`.
+ */
+fn this_object(interpreter: &Interpreter, this: &Value, method: &str) -> Result<ObjectRef> {
+    match this {
+        Value::Undefined | Value::Null => Err(Error::Type(format!("Array.prototype.{method} called on null or undefined!"))),
+        _ => interpreter.to_object(this),
+    }
+}
+
+/**
+ * Reads the `length` of an array-like object as an integer, which arrays hold inline.
+ */
+fn length_of(interpreter: &Interpreter, object: &ObjectRef) -> Result<usize> {
+    if let ObjectKind::Array(elements) = &object.borrow().kind {
+        return Ok(elements.length);
+    }
+    let length = interpreter.get_object_property(object, "length", &Value::Object(object.clone()))?;
+    Ok(to_length(interpreter.to_number(&length)?))
+}
+
+fn get(interpreter: &Interpreter, object: &ObjectRef, index: usize) -> Result<Value> {
+    interpreter.get_object_property(object, index.to_string(), &Value::Object(object.clone()))
+}
+
+/**
+ * Returns the element at the index, None for holes.
+ */
+fn element(interpreter: &Interpreter, object: &ObjectRef, index: usize) -> Result<Option<Value>> {
+    match interpreter.has_property(object, &PropertyKey::from(index.to_string()))? {
+        true => get(interpreter, object, index).map(Some),
+        false => Ok(None),
+    }
+}
+
+/**
+ * Returns the elements between the indices, None for holes.
+ */
+fn elements(interpreter: &Interpreter, object: &ObjectRef, indices: Range<usize>) -> Result<Vec<Option<Value>>> {
+    if let Some(values) = inline_values(object, &indices) {
+        return Ok(values.into_iter().map(Some).collect());
+    }
+    indices.map(|index| element(interpreter, object, index)).collect()
+}
+
+/**
+ * Returns the values between the indices, holes being looked up through the prototype chain.
+ */
+fn values(interpreter: &Interpreter, object: &ObjectRef, indices: Range<usize>) -> Result<Vec<Value>> {
+    if let Some(values) = inline_values(object, &indices) {
+        return Ok(values);
+    }
+    indices.map(|index| get(interpreter, object, index)).collect()
+}
+
+/**
+ * Copies the elements between the indices at once if the array holds them all inline.
+ */
+fn inline_values(object: &ObjectRef, indices: &Range<usize>) -> Option<Vec<Value>> {
+    match &object.borrow().kind {
+        ObjectKind::Array(elements) => elements.dense.get(indices.clone()).map(<[Value]>::to_vec),
+        _ => None,
+    }
+}
+
+/**
+ * Sets the property, throwing if it's read-only like in strict mode code.
+ */
+fn set(interpreter: &Interpreter, object: &ObjectRef, key: impl Into<PropertyKey>, value: Value) -> Result<()> {
+    let key = key.into();
+    let receiver = Value::Object(object.clone());
+    match interpreter.set_object_property(object, &key, value, &receiver)? {
+        true => Ok(()),
+        false => Err(assignment_error(&receiver, &key)),
+    }
+}
+
+/**
+ * Deletes the element at the index, throwing if it's non-configurable.
+ */
+fn delete(interpreter: &Interpreter, object: &ObjectRef, index: usize) -> Result<()> {
+    let key = PropertyKey::from(index.to_string());
+    match interpreter.delete_property(object, &key)? {
+        true => Ok(()),
+        false => Err(Error::Type(format!("Cannot delete property '{key}' of {object}!"))),
+    }
+}
+
+/**
+ * Sets the element at the index, or deletes it for holes.
+ */
+fn put(interpreter: &Interpreter, object: &ObjectRef, index: usize, element: Option<Value>) -> Result<()> {
+    match element {
+        Some(value) => set(interpreter, object, index.to_string(), value),
+        None => delete(interpreter, object, index),
+    }
+}
+
+/**
+ * Whether the object is an array holding its elements inline, which can all be changed
+ * along with its length, hence rewritten at once rather than property by property.
+ */
+fn is_plain_array(object: &ObjectRef) -> bool {
+    let object = object.borrow();
+    match &object.kind {
+        ObjectKind::Array(elements) => elements.dense.len() == elements.length
+            && object.extensible
+            && object.non_writable.is_empty()
+            && object.non_configurable.is_empty(),
+        _ => false,
+    }
+}
+
+/**
+ * Rewrites the inline elements of a plain array at once.
+ */
+fn rewrite<R>(array: &ObjectRef, f: impl FnOnce(&mut Vec<Value>) -> R) -> R {
+    match &mut array.borrow_mut().kind {
+        ObjectKind::Array(elements) => {
+            let result = f(&mut elements.dense);
+            elements.length = elements.dense.len();
+            result
+        },
+        _ => f(&mut vec![]),
+    }
+}
+
+/**
+ * Removes the count of elements from the start index, inserting the items in their place,
+ * then updates the length. Returns the removed elements, None for holes. The elements
+ * following them are moved one by one unless the object is a plain array.
+ */
+fn splice(
+    interpreter: &Interpreter,
+    object: &ObjectRef,
+    length: usize,
+    start: usize,
+    count: usize,
+    items: Vec<Value>,
+) -> Result<Vec<Option<Value>>> {
+    if is_plain_array(object) {
+        return Ok(rewrite(object, |elements| elements.splice(start..start + count, items).map(Some).collect()));
+    }
+    let removed = elements(interpreter, object, start..start + count)?;
+    let inserted = items.len();
+    let moved = start + count..length;
+    if inserted < count {
+        for from in moved {
+            put(interpreter, object, from - count + inserted, element(interpreter, object, from)?)?;
+        }
+        for index in (length - count + inserted..length).rev() {
+            delete(interpreter, object, index)?;
+        }
+    } else if inserted > count {
+        for from in moved.rev() {
+            put(interpreter, object, from - count + inserted, element(interpreter, object, from)?)?;
+        }
+    }
+    for (offset, item) in items.into_iter().enumerate() {
+        set(interpreter, object, (start + offset).to_string(), item)?;
+    }
+    set(interpreter, object, "length", Value::Number((length - count + inserted) as f64))?;
+    Ok(removed)
+}
+
+/**
+ * Creates an array of the elements, None being holes, e.g. for `[1, , 3].slice()`.
+ */
+fn create_array_with_holes(interpreter: &Interpreter, elements: Vec<Option<Value>>) -> ObjectRef {
+    let length = elements.len();
+    if elements.iter().all(Option::is_some) {
+        return create_array(interpreter, elements.into_iter().flatten().collect());
+    }
+    let array = create_array(interpreter, vec![]);
+    for (index, element) in elements.into_iter().enumerate() {
+        if let Some(value) = element {
+            array.set_own_value(index.to_string(), value);
+        }
+    }
+    array.set_own_value("length", Value::Number(length as f64));
+    array
+}
+
+/**
+ * Calls back with each element, its index & the object, passing the index, the element & the
+ * result to the visitor until it returns false. Elements appended while iterating aren't visited,
+ * while removed ones are skipped.
+ */
+fn for_each(
+    interpreter: &Interpreter,
+    object: &ObjectRef,
+    args: &[Value],
+    mut visitor: impl FnMut(usize, Value, Value) -> Result<bool>,
+) -> Result<()> {
+    let length = length_of(interpreter, object)?;
+    let callback = callback_argument(args)?;
+    let this_arg = args.get(1).cloned().unwrap_or(Value::Undefined);
+    for index in 0..length {
+        let value = match element(interpreter, object, index)? {
+            Some(value) => value,
+            None => continue,
+        };
+        let args = [value.clone(), Value::Number(index as f64), Value::Object(object.clone())];
+        let result = interpreter.call(callback, &this_arg, &args)?;
+        if !visitor(index, value, result)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/**
+ * Finds the first or last element the predicate holds for, along with its index.
+ */
+fn find(interpreter: &Interpreter, object: &ObjectRef, args: &[Value], last: bool) -> Result<Option<(usize, Value)>> {
+    let length = length_of(interpreter, object)?;
+    let predicate = callback_argument(args)?;
+    let this_arg = args.get(1).cloned().unwrap_or(Value::Undefined);
+    let indices: Vec<usize> = match last {
+        true => (0..length).rev().collect(),
+        false => (0..length).collect(),
+    };
+    for index in indices {
+        let value = get(interpreter, object, index)?;
+        let args = [value.clone(), Value::Number(index as f64), Value::Object(object.clone())];
+        if interpreter.call(predicate, &this_arg, &args)?.to_boolean() {
+            return Ok(Some((index, value)));
+        }
+    }
+    Ok(None)
+}
+
+/**
+ * Accumulates the elements at the indices, starting with the initial value or else the first element.
+ */
+fn reduce(interpreter: &Interpreter, object: &ObjectRef, args: &[Value], indices: Vec<usize>) -> Result<Value> {
+    let reducer = callback_argument(args)?;
+    let mut accumulator = args.get(1).cloned();
+    for index in indices {
+        let value = match element(interpreter, object, index)? {
+            Some(value) => value,
+            None => continue,
+        };
+        accumulator = Some(match accumulator {
+            None => value,
+            Some(accumulator) => {
+                let args = [accumulator, value, Value::Number(index as f64), Value::Object(object.clone())];
+                interpreter.call(reducer, &Value::Undefined, &args)?
+            },
+        });
+    }
+    accumulator.ok_or_else(|| Error::Type("Reduce of empty array with no initial value!".to_string()))
+}
+
+/**
+ * Joins the elements of the array-like object with the separator, "," by default.
+ */
+fn join(interpreter: &Interpreter, object: &ObjectRef, args: &[Value]) -> Result<Value> {
+    let length = length_of(interpreter, object)?;
+    let separator = match args.first() {
+        None | Some(Value::Undefined) => ",".into(),
+        Some(separator) => interpreter.to_string(separator)?,
+    };
+
+    // Joined as code units, which pairs the surrogates split across elements
+    let mut units = vec![];
+    for (index, value) in values(interpreter, object, 0..length)?.into_iter().enumerate() {
+        if index > 0 {
+            units.extend_from_slice(&separator.units());
+        }
+        if !matches!(value, Value::Undefined | Value::Null) {
+            units.extend_from_slice(&interpreter.to_string(&value)?.units());
+        }
+    }
+    Ok(Value::String(JsString::from_units(&units)))
+}
+
+/**
+ * Appends the elements of the array-like object, spreading nested arrays up to the depth.
+ */
+fn flatten(interpreter: &Interpreter, result: &mut Vec<Value>, object: &ObjectRef, depth: f64) -> Result<()> {
+    // Arrays nested as deep as the depth, e.g. containing themselves with a depth of Infinity, exhaust the stack
+    interpreter.check_call_depth()?;
+    let length = length_of(interpreter, object)?;
+    for value in elements(interpreter, object, 0..length)?.into_iter().flatten() {
+        match &value {
+            Value::Object(array) if depth >= 1.0 && is_array(array)? => flatten(interpreter, result, array, depth - 1.0)?,
+            _ => result.push(value),
+        }
+    }
+    Ok(())
+}
+
+/**
+ * Sorts the values with a stable merge sort, stopping at the first error of the comparison.
+ */
+fn merge_sort(mut values: Vec<Value>, compare: &mut impl FnMut(&Value, &Value) -> Result<Ordering>) -> Result<Vec<Value>> {
+    if values.len() <= 1 {
+        return Ok(values);
+    }
+    let right = values.split_off(values.len() / 2);
+    let left = merge_sort(values, compare)?;
+    let right = merge_sort(right, compare)?;
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+        // Taking from the left on ties keeps equal elements in order
+        match compare(a, b)? {
+            Ordering::Greater => merged.extend(right.next()),
+            _ => merged.extend(left.next()),
+        }
+    }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
+}
+
+fn callback_argument(args: &[Value]) -> Result<&Value> {
+    match args.first() {
+        Some(callback) if callback.is_callable() => Ok(callback),
+        callback => Err(Error::Type(format!("{} is not a function!", callback.unwrap_or(&Value::Undefined)))),
+    }
+}
+
+/**
+ * Resolves the start & end arguments at the index into indices clamped to the length,
+ * negative ones counting from the end. The end defaults to the length.
+ */
+fn range_arguments(interpreter: &Interpreter, args: &[Value], index: usize, length: usize) -> Result<(usize, usize)> {
+    let start = relative_index(integer_argument(interpreter, args, index)?, length);
+    let end = match args.get(index + 1) {
+        None | Some(Value::Undefined) => length,
+        Some(_) => relative_index(integer_argument(interpreter, args, index + 1)?, length),
+    };
+    Ok((start, end))
+}
+
+/**
+ * Converts an argument into an integer, NaN & undefined becoming 0, i.e. ToIntegerOrInfinity.
+ */
+fn integer_argument(interpreter: &Interpreter, args: &[Value], index: usize) -> Result<f64> {
+    let x = interpreter.to_number(args.get(index).unwrap_or(&Value::Undefined))?;
+    Ok(if x.is_nan() { 0.0 } else { x.trunc() })
+}
+
+fn relative_index(index: f64, length: usize) -> usize {
+    let index = if index < 0.0 { length as f64 + index } else { index };
+    index.clamp(0.0, length as f64) as usize
+}
+
+/**
+ * Collects the elements of an array-like object, e.g. the arguments passed to `Reflect.apply`.
+ */
+pub fn list_from_array_like(interpreter: &Interpreter, value: &Value) -> Result<Vec<Value>> {
+    if !matches!(value, Value::Object(_)) {
+        return Err(Error::Type(format!("CreateListFromArrayLike called on non-object {value}!")));
+    }
+    let length = to_length(interpreter.to_number(&interpreter.get_property(value, "length")?)?);
+    let mut list = Vec::with_capacity(length);
+    for index in 0..length {
+        list.push(interpreter.get_property(value, index.to_string())?);
+    }
+    Ok(list)
+}
+
+fn to_length(x: f64) -> usize {
+    if x.is_nan() { 0 } else { x.clamp(0.0, u32::MAX as f64) as usize }
+}
+
+fn index_value(index: Option<usize>) -> Value {
+    Value::Number(index.map(|index| index as f64).unwrap_or(-1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_array_mutators() {
+        let content = "
+            let a = [1, 2, 3];
+            let pushed = a.push(4, 5);
+            let popped = a.pop();
+            let shifted = a.shift();
+            let unshifted = a.unshift(0);
+            let removed = a.splice(1, 2, 'x', 'y', 'z');
+            '' + [pushed, popped, shifted, unshifted, removed.join('|'), a.join('|'), a.reverse().join('|'), [1, 2, 3, 4].fill(0, 1, -1)];
+        ";
+        assert_eval(content, Value::String("5,5,1,4,2|3,0|x|y|z|4,4|z|y|x|0,1,0,0,4".into()));
+    }
+
+    #[test]
+    fn test_array_accessors() {
+        let content = "
+            let a = [1, 2, NaN, 2, -0];
+            let u;
+            '' + [
+                a.slice(1, -2).join('|'), a.slice(-1).length, a.concat([6, [7]], 8).length, a.indexOf(2), a.lastIndexOf(2), a.indexOf(NaN),
+                a.includes(NaN), a.includes(0), a.at(-2), a.at(9) === Array.of(1)[1], [null, u, 1].join() === ',,1', [[1, [2]], 3].flat(Infinity).join('')
+            ];
+        ";
+        assert_eval(content, Value::String("2|NaN,1,8,1,3,-1,true,true,2,true,true,123".into()));
+    }
+
+    #[test]
+    fn test_higher_order_functions() {
+        let content = "
+            let a = [1, 2, 3, 4];
+            let visited = [];
+            a.forEach(function(x, i) { visited.push(x * i); });
+            '' + [
+                a.map(function(x) { return x * 2; }).join('|'), a.filter(function(x) { return x > 2; }).join('|'),
+                a.reduce(function(sum, x) { return sum + x; }), a.reduceRight(function(s, x) { return s + x; }, ''),
+                a.find(function(x) { return x > 1; }), a.findIndex(function(x) { return x > 9; }), a.findLast(function(x) { return x < 3; }),
+                a.some(function(x) { return x === 3; }), a.every(function(x) { return x < 4; }), visited.join('|'),
+                a.flatMap(function(x) { return [x, x]; }).length
+            ];
+        ";
+        assert_eval(content, Value::String("2|4|6|8,3|4,10,4321,2,-1,2,true,false,0|2|6|12,8".into()));
+        assert_eval_error("[].reduce(function() {});", Error::Type("Reduce of empty array with no initial value!".to_string()));
+        assert_eval_error("[1].map(3);", Error::Type("3 is not a function!".to_string()));
+    }
+
+    #[test]
+    fn test_array_sort() {
+        let content = "
+            let people = [['b', 2], ['a', 1], ['c', 2], ['d', 1]];
+            let u;
+            people.sort(function(x, y) { return x[1] - y[1]; });
+            let names = people.map(function(p) { return p[0]; }).join('');
+            '' + [names, [10, 9, 1, u, 2].sort().join('|'), ['b', 'a', 'C'].sort().join('')];
+        ";
+        assert_eval(content, Value::String("adbc,1|10|2|9|,Cab".into()));
+    }
+
+    #[test]
+    fn test_array_constructor() {
+        let content = "
+            let generated = Array.from('abc', function(c, i) { return c + i; });
+            '' + [
+                Array(3).length, new Array(1, 2).length, generated.join('|'), Array.isArray([]), Array.isArray('a'),
+                Array.from([1, 2]).length, Array.of(7).length, [1, 2] + '', [].constructor === Array
+            ];
+        ";
+        assert_eval(content, Value::String("3,2,a0|b1|c2,true,false,2,1,1,2,true".into()));
+        assert_eval_error("Array(-1);", Error::Range("Invalid array length!".to_string()));
+    }
+
+    #[test]
+    fn test_array_iterators() {
+        let content = "
+            let array = ['a', 'b'];
+            let values = array.values();
+            let first = values.next().value;
+            array.push('c');
+            let rest = [...values];
+            let tag = Object.getPrototypeOf(values)[Symbol.toStringTag];
+            let arrayLike = Array.prototype.keys.call('xy');
+            '' + [
+                first, rest.join('|'), [...array.keys()].join('|'), [...array.entries()].join('|'), values.next().done,
+                tag, array[Symbol.iterator] === Array.prototype.values, [...arrayLike].join('|')
+            ];
+        ";
+        assert_eval(content, Value::String("a,b|c,0|1|2,0,a|1,b|2,c,true,Array Iterator,true,0|1".into()));
+    }
+
+    #[test]
+    fn test_array_likes() {
+        let content = "
+            let object = new Object();
+            object.length = 2;
+            object[0] = 'a';
+            object[1] = 'b';
+            let joined = Array.prototype.join.call(object);
+            let pushed = Array.prototype.push.call(object, 'c');
+            let proxy = new Proxy([3, 1, 2], new Object());
+            proxy.push(0);
+            proxy.sort();
+            let sparse = [1];
+            sparse[2] = 3;
+            let holes = sparse.slice(0);
+            '' + [
+                joined, pushed, object.length, object[2], proxy.join(''), proxy.length,
+                Array.prototype.map.call('ab', function(c) { return c + c; }).join(''),
+                holes.length, holes.indexOf(undefined), holes.includes(undefined)
+            ];
+        ";
+        assert_eval(content, Value::String("a,b,3,3,c,0123,4,aabb,3,-1,true".into()));
+        assert_eval_error(
+            "Array.prototype.join.call(null);",
+            Error::Type("Array.prototype.join called on null or undefined!".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_cyclic_arrays() {
+        let content = "
+            let a = [1];
+            a.push(a);
+            let b = [2, [a]];
+            '' + [a.join(), String(a), a.toString(), b.join('-'), a.join(';'), a.flat(1).length];
+        ";
+        assert_eval(content, Value::String("1,,1,,1,,2-1,,1;,3".into()));
+        assert_eval_error(
+            "let a = [1]; a.push(a); a.flat(Infinity);",
+            Error::Range("Maximum call stack size exceeded!".to_string()),
+        );
+        assert_eval_error(
+            "let a = [1]; a.push(a); Map.prototype.get.call(a);",
+            Error::Type("Method Map.prototype.get called on incompatible receiver 1,!".to_string()),
+        );
+    }
+}
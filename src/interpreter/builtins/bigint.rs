@@ -0,0 +1,182 @@
+use crate::prelude::*;
+
+use num_traits::{FromPrimitive, One, Zero};
+
+use crate::interpreter::conversions::{PreferredType, TypeConversion};
+use crate::interpreter::realm::Realm;
+
+use super::{define_global, native_function, this_primitive};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 2] = [
+        ("toString", 0, bigint_to_string),
+        ("valueOf", 0, bigint_value_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("BigInt".into())));
+
+    let constructor = native_function(realm, "BigInt", 1, construct_bigint, true);
+    let statics: [(&str, usize, NativeFn); 2] = [
+        ("asIntN", 2, bigint_as_int_n),
+        ("asUintN", 2, bigint_as_uint_n),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%BigInt.prototype%", prototype);
+    define_global(global, "BigInt", Value::Object(constructor));
+}
+
+/**
+ * BigInt(value) -> value converted into a BigInt, which can't be created with `new`.
+ */
+fn construct_bigint(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_some() {
+        return Err(Error::Type("BigInt is not a constructor!".to_string()));
+    }
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    match interpreter.to_primitive(&value, PreferredType::Number)? {
+        Value::Number(n) if n.fract() == 0.0 => Ok(Value::BigInt(BigInt::from_f64(n).unwrap_or_default())),
+        number @ Value::Number(_) => Err(Error::Range(
+            format!("The number {number} cannot be converted to a BigInt because it is not an integer!")
+        )),
+        primitive => to_bigint(&primitive).map(Value::BigInt),
+    }
+}
+
+/**
+ * BigInt.asIntN(bits, bigint) -> bigint wrapped into a signed integer of the width.
+ */
+fn bigint_as_int_n(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let (bits, bigint) = wrapping_args(interpreter, args)?;
+    let modulus = BigInt::one() << bits;
+    let wrapped = ((bigint % &modulus) + &modulus) % &modulus;
+
+    // Values past the sign bit wrap around to negatives
+    if bits > 0 && wrapped >= (BigInt::one() << (bits - 1)) {
+        return Ok(Value::BigInt(wrapped - modulus));
+    }
+    Ok(Value::BigInt(wrapped))
+}
+
+/**
+ * BigInt.asUintN(bits, bigint) -> bigint wrapped into an unsigned integer of the width.
+ */
+fn bigint_as_uint_n(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let (bits, bigint) = wrapping_args(interpreter, args)?;
+    let modulus = BigInt::one() << bits;
+    Ok(Value::BigInt(((bigint % &modulus) + &modulus) % modulus))
+}
+
+fn wrapping_args(interpreter: &Interpreter, args: &[Value]) -> Result<(usize, BigInt)> {
+    let bits = args.first().map(Value::to_number).unwrap_or(0.0);
+    if !(0.0..=f64::from(u32::MAX)).contains(&bits) || bits.fract() != 0.0 {
+        return Err(Error::Range(format!("Invalid value: not (convertible to) a safe integer: {bits}!")));
+    }
+    let bigint = interpreter.to_primitive(args.get(1).unwrap_or(&Value::Undefined), PreferredType::Number)?;
+    Ok((bits as usize, to_bigint(&bigint)?))
+}
+
+/**
+ * BigInt.prototype.toString(radix) -> digits of the BigInt in the radix, 10 by default.
+ */
+fn bigint_to_string(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let bigint = this_bigint(this, "toString")?;
+    let radix = match args.first() {
+        None | Some(Value::Undefined) => 10.0,
+        Some(radix) => radix.to_number(),
+    };
+    if !(2.0..=36.0).contains(&radix) || radix.fract() != 0.0 {
+        return Err(Error::Range("toString() radix must be between 2 and 36!".to_string()));
+    }
+    Ok(Value::String(bigint.to_str_radix(radix as u32).into()))
+}
+
+/**
+ * BigInt.prototype.valueOf() -> the BigInt itself.
+ */
+fn bigint_value_of(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::BigInt(this_bigint(this, "valueOf")?))
+}
+
+fn this_bigint(this: &Value, method: &str) -> Result<BigInt> {
+    match this_primitive(this) {
+        Value::BigInt(bigint) => Ok(bigint),
+        _ => Err(Error::Type(format!("Method BigInt.prototype.{method} called on incompatible receiver {this}!"))),
+    }
+}
+
+/**
+ * Converts a primitive into a BigInt, numbers aside which are only converted by `BigInt()`.
+ */
+fn to_bigint(primitive: &Value) -> Result<BigInt> {
+    match primitive {
+        Value::BigInt(bigint) => Ok(bigint.clone()),
+        Value::Boolean(b) => Ok(if *b { BigInt::one() } else { BigInt::zero() }),
+        Value::String(s) => Value::string_to_bigint(s)
+            .ok_or_else(|| Error::Syntax(format!("Cannot convert {s} to a BigInt!"))),
+        _ => Err(Error::Type(format!("Cannot convert {primitive} to a BigInt!"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    fn bigint(digits: &str) -> Value {
+        Value::BigInt(digits.parse().unwrap())
+    }
+
+    #[test]
+    fn test_bigint_arithmetic() {
+        assert_eval("9007199254740993n + 2n;", bigint("9007199254740995"));
+        assert_eval("2n * 9007199254740993n - 1n;", bigint("18014398509481985"));
+        assert_eval("-7n / 2n;", bigint("-3"));
+        assert_eval("let id = 10n; id += 5n; id;", bigint("15"));
+        assert_eval("1n + '2';", Value::String("12".into()));
+        assert_eval_error("1n + 1;", Error::Type("Cannot mix BigInt and other types, use explicit conversions!".to_string()));
+        assert_eval_error("2 * 1n;", Error::Type("Cannot mix BigInt and other types, use explicit conversions!".to_string()));
+        assert_eval_error("1n / 0n;", Error::Range("Division by zero!".to_string()));
+        assert_eval("-7n % 2n;", bigint("-1"));
+        assert_eval("2n ** 64n;", bigint("18446744073709551616"));
+        assert_eval("let id = 3n; id **= 2n; id %= 5n; id;", bigint("4"));
+        assert_eval_error("1n % 0n;", Error::Range("Division by zero!".to_string()));
+        assert_eval_error("2n ** -1n;", Error::Range("Exponent must be non-negative!".to_string()));
+        assert_eval_error("2n ** 2;", Error::Type("Cannot mix BigInt and other types, use explicit conversions!".to_string()));
+        assert_eval_error("+1n;", Error::Type("Cannot convert a BigInt value to a number!".to_string()));
+    }
+
+    #[test]
+    fn test_bigint_comparison() {
+        let content = "
+            '' + [
+                1n == 1, 1n === 1, 2n === 2n, 1n == '1', 0n == false,
+                2n > 1, 2n < 5 / 2, 9007199254740993n > 9007199254740992, 10n > '9', 1n < 'x'
+            ];
+        ";
+        assert_eval(content, Value::String("true,false,true,true,true,true,true,true,true,false".into()));
+    }
+
+    #[test]
+    fn test_bigint_conversion() {
+        assert_eval("BigInt(42);", bigint("42"));
+        assert_eval("BigInt(' 0xff ');", bigint("255"));
+        assert_eval("BigInt(true) + BigInt('-12');", bigint("-11"));
+        assert_eval("BigInt.asIntN(8, 255n);", bigint("-1"));
+        assert_eval("BigInt.asUintN(8, -1n);", bigint("255"));
+        assert_eval("255n.toString(16) + 10n;", Value::String("ff10".into()));
+        assert_eval_error("BigInt(3 / 2);", Error::Range("The number 1.5 cannot be converted to a BigInt because it is not an integer!".to_string()));
+        assert_eval_error("BigInt('1.5');", Error::Syntax("Cannot convert 1.5 to a BigInt!".to_string()));
+        assert_eval_error("BigInt();", Error::Type("Cannot convert undefined to a BigInt!".to_string()));
+        assert_eval_error("new BigInt(1);", Error::Type("BigInt is not a constructor!".to_string()));
+    }
+}
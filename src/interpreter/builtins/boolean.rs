@@ -0,0 +1,81 @@
+use crate::prelude::*;
+
+use crate::interpreter::realm::Realm;
+
+use super::{construct_wrapper, define_global, native_function, this_primitive};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Primitive(Value::Boolean(false)), realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 2] = [
+        ("toString", 0, boolean_to_string),
+        ("valueOf", 0, boolean_value_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    let constructor = native_function(realm, "Boolean", 1, construct_boolean, true);
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Boolean.prototype%", prototype);
+    define_global(global, "Boolean", Value::Object(constructor));
+}
+
+/**
+ * Boolean(value) -> whether the value is truthy, wrapped into an object with `new`.
+ */
+fn construct_boolean(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = Value::Boolean(args.first().is_some_and(Value::to_boolean));
+    match new_target {
+        None => Ok(value),
+        Some(new_target) => construct_wrapper(interpreter, value, new_target),
+    }
+}
+
+/**
+ * Boolean.prototype.toString() -> 'true' or 'false'.
+ */
+fn boolean_to_string(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::String(this_boolean(this, "toString")?.to_string().into()))
+}
+
+/**
+ * Boolean.prototype.valueOf() -> the boolean itself.
+ */
+fn boolean_value_of(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Boolean(this_boolean(this, "valueOf")?))
+}
+
+fn this_boolean(this: &Value, method: &str) -> Result<bool> {
+    match this_primitive(this) {
+        Value::Boolean(b) => Ok(b),
+        _ => Err(Error::Type(format!("Method Boolean.prototype.{method} called on incompatible receiver {this}!"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_boolean() {
+        let content = "
+            '' + [Boolean(), Boolean(0), Boolean('0'), Boolean(NaN), Boolean([]), true.toString(), Boolean.prototype.valueOf()];
+        ";
+        assert_eval(content, Value::String("false,false,true,false,true,true,false".into()));
+
+        // Wrapper objects are always truthy
+        assert_eval("let b = new Boolean(false); if (b) { b.valueOf(); }", Value::Boolean(false));
+        let content = "
+            class Fake {}
+            Fake.prototype.toString = Boolean.prototype.toString;
+            new Fake().toString();
+        ";
+        assert_eval_error(content, Error::Type(
+            "Method Boolean.prototype.toString called on incompatible receiver [object Object]!".to_string()
+        ));
+    }
+}
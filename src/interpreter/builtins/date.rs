@@ -0,0 +1,732 @@
+use crate::prelude::*;
+
+use regex::Regex;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::{PreferredType, TypeConversion};
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::{define_global, native_closure, native_function, prototype_from_constructor};
+
+const MS_PER_DAY: f64 = 86400000.0;
+
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+// Indices of the components of a time value, in the order of the arguments
+// of `new Date(year, month, date, hours, minutes, seconds, milliseconds)`
+const YEAR: usize = 0;
+const MONTH: usize = 1;
+const DATE: usize = 2;
+const HOURS: usize = 3;
+const MINUTES: usize = 4;
+const SECONDS: usize = 5;
+const MILLISECONDS: usize = 6;
+const WEEKDAY: usize = 7;
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 13] = [
+        ("getTime", 0, date_get_time),
+        ("getTimezoneOffset", 0, date_get_timezone_offset),
+        ("setTime", 1, date_set_time),
+        ("toDateString", 0, date_to_date_string),
+        ("toISOString", 0, date_to_iso_string),
+        ("toJSON", 1, date_to_json),
+        ("toLocaleDateString", 0, date_to_date_string),
+        ("toLocaleString", 0, date_to_string),
+        ("toLocaleTimeString", 0, date_to_time_string),
+        ("toString", 0, date_to_string),
+        ("toTimeString", 0, date_to_time_string),
+        ("toUTCString", 0, date_to_utc_string),
+        ("valueOf", 0, date_value_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    // Each component has a getter in local time & another in UTC, e.g. `getHours` & `getUTCHours`
+    let getters: [(&str, usize); 8] = [
+        ("FullYear", YEAR),
+        ("Month", MONTH),
+        ("Date", DATE),
+        ("Day", WEEKDAY),
+        ("Hours", HOURS),
+        ("Minutes", MINUTES),
+        ("Seconds", SECONDS),
+        ("Milliseconds", MILLISECONDS),
+    ];
+    for (component, index) in getters {
+        for utc in [false, true] {
+            let name = format!("get{}{component}", if utc { "UTC" } else { "" });
+            let method = name.clone();
+            let getter = native_closure(realm, &name, 0, move |interpreter, this, _args, _new_target| {
+                let time = this_time_value(this, &method)?;
+                if time.is_nan() {
+                    return Ok(Value::Number(f64::NAN));
+                }
+                let time = if utc { time } else { local_time(interpreter, time) };
+                Ok(Value::Number(components(time)[index]))
+            }, false);
+            prototype.define_non_enumerable(name, Property::Data(Value::Object(getter)));
+        }
+    }
+
+    // Setters take the component along with the following ones, e.g. `setHours(hours, minutes, seconds, milliseconds)`
+    let setters: [(&str, usize, usize); 7] = [
+        ("FullYear", YEAR, 3),
+        ("Month", MONTH, 2),
+        ("Date", DATE, 1),
+        ("Hours", HOURS, 4),
+        ("Minutes", MINUTES, 3),
+        ("Seconds", SECONDS, 2),
+        ("Milliseconds", MILLISECONDS, 1),
+    ];
+    for (component, index, count) in setters {
+        for utc in [false, true] {
+            let name = format!("set{}{component}", if utc { "UTC" } else { "" });
+            let method = name.clone();
+            let setter = native_closure(realm, &name, count, move |interpreter, this, args, _new_target| {
+                set_components(interpreter, this, &method, args, index..index + count, utc)
+            }, false);
+            prototype.define_non_enumerable(name, Property::Data(Value::Object(setter)));
+        }
+    }
+
+    let to_primitive = native_function(realm, "[Symbol.toPrimitive]", 1, date_to_primitive, false);
+    prototype.define_non_enumerable(&realm.symbols.to_primitive, Property::Data(Value::Object(to_primitive)));
+
+    let constructor = native_function(realm, "Date", 7, construct_date, true);
+    let statics: [(&str, usize, NativeFn); 3] = [
+        ("now", 0, date_now),
+        ("parse", 1, date_parse),
+        ("UTC", 7, date_utc),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Date.prototype%", prototype);
+    define_global(global, "Date", Value::Object(constructor));
+}
+
+/**
+ * Date(...) -> string of the current time, without `new`.
+ * new Date() -> date of the current time.
+ * new Date(value) -> date of the epoch milliseconds, of the parsed string, or a copy of the date.
+ * new Date(year, month, date, hours, minutes, seconds, milliseconds) -> date of the components in local time.
+ */
+fn construct_date(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_none() {
+        return Ok(Value::String(to_date_string(interpreter, time_clip(interpreter.clock.now())).into()));
+    }
+    let time = match args {
+        [] => time_clip(interpreter.clock.now()),
+        [Value::Object(object)] if matches!(object.borrow().kind, ObjectKind::Date(_)) => this_time_value(&args[0], "valueOf")?,
+        [value] => match interpreter.to_primitive(value, PreferredType::Default)? {
+            Value::String(string) => parse_date(interpreter, &string),
+            primitive => time_clip(interpreter.to_number(&primitive)?),
+        },
+        _ => time_clip(utc_time(interpreter, time_from_arguments(interpreter, args)?)),
+    };
+    let prototype = prototype_from_constructor(interpreter, new_target, "%Date.prototype%")?;
+    Ok(Value::Object(ObjectRef::new(ObjectKind::Date(time), prototype)))
+}
+
+/**
+ * Date.now() -> milliseconds elapsed since the epoch, according to the clock of the interpreter.
+ */
+fn date_now(interpreter: &Interpreter, _this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Number(time_clip(interpreter.clock.now())))
+}
+
+/**
+ * Date.parse(string) -> epoch milliseconds of the string, or NaN if it isn't a recognized format.
+ */
+fn date_parse(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    Ok(Value::Number(parse_date(interpreter, &string)))
+}
+
+/**
+ * Date.UTC(year, month, date, hours, minutes, seconds, milliseconds) -> epoch milliseconds of the components in UTC.
+ */
+fn date_utc(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Number(time_clip(time_from_arguments(interpreter, args)?)))
+}
+
+/**
+ * Date.prototype.getTime() -> epoch milliseconds of the date, NaN if invalid.
+ */
+fn date_get_time(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Number(this_time_value(this, "getTime")?))
+}
+
+/**
+ * Date.prototype.valueOf() -> epoch milliseconds of the date, NaN if invalid. Same as `getTime()`.
+ */
+fn date_value_of(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Number(this_time_value(this, "valueOf")?))
+}
+
+/**
+ * Date.prototype.getTimezoneOffset() -> minutes to add to the local time to get UTC, e.g. -60 in Paris in winter.
+ */
+fn date_get_timezone_offset(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let time = this_time_value(this, "getTimezoneOffset")?;
+    if time.is_nan() {
+        return Ok(Value::Number(f64::NAN));
+    }
+    Ok(Value::Number((time - local_time(interpreter, time)) / 60000.0))
+}
+
+/**
+ * Date.prototype.setTime(time) -> the new epoch milliseconds of the date.
+ */
+fn date_set_time(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_time_value(this, "setTime")?;
+    let time = time_clip(interpreter.to_number(args.first().unwrap_or(&Value::Undefined))?);
+    Ok(set_time_value(this, time))
+}
+
+/**
+ * Date.prototype.toString() -> local date & time, e.g. 'Tue Mar 05 2024 11:20:30 GMT+0100'.
+ */
+fn date_to_string(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let time = this_time_value(this, "toString")?;
+    Ok(Value::String(to_date_string(interpreter, time).into()))
+}
+
+/**
+ * Date.prototype.toDateString() -> local date, e.g. 'Tue Mar 05 2024'.
+ */
+fn date_to_date_string(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let time = this_time_value(this, "toDateString")?;
+    if time.is_nan() {
+        return Ok(Value::String("Invalid Date".into()));
+    }
+    Ok(Value::String(format_date(&components(local_time(interpreter, time))).into()))
+}
+
+/**
+ * Date.prototype.toTimeString() -> local time along with the time zone, e.g. '11:20:30 GMT+0100'.
+ */
+fn date_to_time_string(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let time = this_time_value(this, "toTimeString")?;
+    if time.is_nan() {
+        return Ok(Value::String("Invalid Date".into()));
+    }
+    let offset = interpreter.clock.local_offset(time);
+    Ok(Value::String(format!("{} {}", format_time(&components(time + offset)), format_time_zone(offset)).into()))
+}
+
+/**
+ * Date.prototype.toUTCString() -> date & time in UTC, e.g. 'Tue, 05 Mar 2024 10:20:30 GMT'.
+ */
+fn date_to_utc_string(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let time = this_time_value(this, "toUTCString")?;
+    if time.is_nan() {
+        return Ok(Value::String("Invalid Date".into()));
+    }
+    let components = components(time);
+    Ok(Value::String(format!(
+        "{}, {:02} {} {} {} GMT",
+        WEEKDAYS[components[WEEKDAY] as usize],
+        components[DATE],
+        MONTHS[components[MONTH] as usize],
+        format_year(components[YEAR]),
+        format_time(&components),
+    ).into()))
+}
+
+/**
+ * Date.prototype.toISOString() -> date & time in UTC, e.g. '2024-03-05T10:20:30.000Z'.
+ * Years beyond 0 to 9999 have six digits & a sign, e.g. '+010000-01-01T00:00:00.000Z'.
+ */
+fn date_to_iso_string(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let time = this_time_value(this, "toISOString")?;
+    if time.is_nan() {
+        return Err(Error::Range("Invalid time value!".to_string()));
+    }
+    let components = components(time);
+    let year = match components[YEAR] {
+        year if (0.0..=9999.0).contains(&year) => format!("{year:04}"),
+        year => format!("{:+07}", year as i64),
+    };
+    Ok(Value::String(format!(
+        "{year}-{:02}-{:02}T{}.{:03}Z",
+        components[MONTH] + 1.0,
+        components[DATE],
+        format_time(&components),
+        components[MILLISECONDS],
+    ).into()))
+}
+
+/**
+ * Date.prototype.toJSON() -> the result of `toISOString()`, or null for invalid dates.
+ */
+fn date_to_json(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = Value::Object(interpreter.to_object(this)?);
+    if let Value::Number(time) = interpreter.to_primitive(&object, PreferredType::Number)? {
+        if !time.is_finite() {
+            return Ok(Value::Null);
+        }
+    }
+    let to_iso_string = interpreter.get_property(&object, "toISOString")?;
+    interpreter.call(&to_iso_string, &object, &[])
+}
+
+/**
+ * Date.prototype[Symbol.toPrimitive](hint) -> the date converted into a string,
+ * unless a number is asked for, e.g. `'' + date` but `date - 0`.
+ */
+fn date_to_primitive(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = match this {
+        Value::Object(object) => object,
+        _ => return Err(Error::Type(format!("Date.prototype[Symbol.toPrimitive] called on non-object {this}!"))),
+    };
+    let hint = match args.first() {
+        Some(Value::String(hint)) if hint == "string" || hint == "default" => PreferredType::String,
+        Some(Value::String(hint)) if hint == "number" => PreferredType::Number,
+        hint => return Err(Error::Type(format!("Invalid hint: {}!", hint.unwrap_or(&Value::Undefined)))),
+    };
+    interpreter.ordinary_to_primitive(object, hint)
+}
+
+/**
+ * Sets the components of the range to the arguments, in local time or in UTC, e.g. for `setHours(hours, minutes)`.
+ * Returns the new epoch milliseconds of the date.
+ */
+fn set_components(interpreter: &Interpreter, this: &Value, method: &str, args: &[Value], range: std::ops::Range<usize>, utc: bool) -> Result<Value> {
+    let time = this_time_value(this, method)?;
+    let mut values = vec![];
+    for index in 0..range.len().min(args.len().max(1)) {
+        values.push(interpreter.to_number(args.get(index).unwrap_or(&Value::Undefined))?);
+    }
+    let time = match time {
+        // Only setting the year makes an invalid date valid again, i.e. January 1st 1970 of that year
+        time if time.is_nan() && range.start == YEAR => 0.0,
+        time if time.is_nan() => return Ok(Value::Number(f64::NAN)),
+        time if utc => time,
+        time => local_time(interpreter, time),
+    };
+    let mut components = components(time);
+    components[range.start..range.start + values.len()].copy_from_slice(&values);
+    let time = make_date_from(&components);
+    Ok(set_time_value(this, time_clip(if utc { time } else { utc_time(interpreter, time) })))
+}
+
+/**
+ * Returns the epoch milliseconds of `this`, which must be a Date.
+ */
+fn this_time_value(this: &Value, method: &str) -> Result<f64> {
+    if let Value::Object(object) = this {
+        if let ObjectKind::Date(time) = object.borrow().kind {
+            return Ok(time);
+        }
+    }
+    Err(Error::Type(format!("Method Date.prototype.{method} called on incompatible receiver {this}!")))
+}
+
+fn set_time_value(this: &Value, time: f64) -> Value {
+    if let Value::Object(object) = this {
+        object.borrow_mut().kind = ObjectKind::Date(time);
+    }
+    Value::Number(time)
+}
+
+/**
+ * Epoch milliseconds of the year, month, date, hours, minutes, seconds & milliseconds arguments,
+ * missing ones being the first day of the month at midnight. Years 0 to 99 are 1900 to 1999.
+ */
+fn time_from_arguments(interpreter: &Interpreter, args: &[Value]) -> Result<f64> {
+    let mut components = [f64::NAN, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+    for (index, arg) in args.iter().take(7).enumerate() {
+        components[index] = interpreter.to_number(arg)?;
+    }
+    let year = components[YEAR].trunc();
+    if (0.0..=99.0).contains(&year) {
+        components[YEAR] = 1900.0 + year;
+    }
+    Ok(make_date_from(&components))
+}
+
+/**
+ * Epoch milliseconds of the local time, according to the clock of the interpreter.
+ */
+fn utc_time(interpreter: &Interpreter, time: f64) -> f64 {
+    if !time.is_finite() {
+        return f64::NAN;
+    }
+
+    // Offsets are looked up at the UTC time, approximated by the offset of the local time itself
+    time - interpreter.clock.local_offset(time - interpreter.clock.local_offset(time))
+}
+
+/**
+ * Local time of the epoch milliseconds, according to the clock of the interpreter.
+ */
+fn local_time(interpreter: &Interpreter, time: f64) -> f64 {
+    time + interpreter.clock.local_offset(time)
+}
+
+/**
+ * Splits the epoch milliseconds into the year, month, date, hours, minutes, seconds,
+ * milliseconds & day of the week. Months start at 0 & weeks on Sunday.
+ */
+fn components(time: f64) -> [f64; 8] {
+    let days = (time / MS_PER_DAY).floor();
+    let time_within_day = time - days * MS_PER_DAY;
+    let (year, month, date) = civil_from_days(days as i64);
+    [
+        year as f64,
+        (month - 1) as f64,
+        date as f64,
+        (time_within_day / 3600000.0).floor(),
+        (time_within_day / 60000.0).floor() % 60.0,
+        (time_within_day / 1000.0).floor() % 60.0,
+        time_within_day % 1000.0,
+        (days + 4.0).rem_euclid(7.0),
+    ]
+}
+
+/**
+ * Epoch milliseconds of the components, which may overflow into the next one, e.g. month 12 is January of the next year.
+ */
+fn make_date_from(components: &[f64; 8]) -> f64 {
+    let day = make_day(components[YEAR], components[MONTH], components[DATE]);
+    let time = make_time(components[HOURS], components[MINUTES], components[SECONDS], components[MILLISECONDS]);
+    if !day.is_finite() || !time.is_finite() {
+        return f64::NAN;
+    }
+    day * MS_PER_DAY + time
+}
+
+/**
+ * Days from the epoch to the date, months starting at 0.
+ */
+fn make_day(year: f64, month: f64, date: f64) -> f64 {
+    if !year.is_finite() || !month.is_finite() || !date.is_finite() {
+        return f64::NAN;
+    }
+    let year = year.trunc() + (month.trunc() / 12.0).floor();
+
+    // Way beyond the range of valid dates, which is about 275000 years around the epoch
+    if year.abs() > 1e6 {
+        return f64::NAN;
+    }
+    let month = month.trunc().rem_euclid(12.0);
+    days_from_civil(year as i64, month as i64 + 1, 1) as f64 + date.trunc() - 1.0
+}
+
+fn make_time(hours: f64, minutes: f64, seconds: f64, milliseconds: f64) -> f64 {
+    if !hours.is_finite() || !minutes.is_finite() || !seconds.is_finite() || !milliseconds.is_finite() {
+        return f64::NAN;
+    }
+    hours.trunc() * 3600000.0 + minutes.trunc() * 60000.0 + seconds.trunc() * 1000.0 + milliseconds.trunc()
+}
+
+/**
+ * Keeps the epoch milliseconds within 100 million days around the epoch, dropping their fractional part.
+ */
+fn time_clip(time: f64) -> f64 {
+    if !time.is_finite() || time.abs() > 8.64e15 {
+        return f64::NAN;
+    }
+    time.trunc() + 0.0
+}
+
+/**
+ * Days from the epoch to the date of the proleptic Gregorian calendar, months starting at 1.
+ * See http://howardhinnant.github.io/date_algorithms.html
+ */
+fn days_from_civil(year: i64, month: i64, date: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + date - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/**
+ * Year, month & date of the days from the epoch, months starting at 1. Inverse of `days_from_civil`.
+ */
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let date = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, date)
+}
+
+/**
+ * Local date & time of the epoch milliseconds, as returned by `Date.prototype.toString`.
+ */
+fn to_date_string(interpreter: &Interpreter, time: f64) -> String {
+    if time.is_nan() {
+        return "Invalid Date".to_string();
+    }
+    let offset = interpreter.clock.local_offset(time);
+    let components = components(time + offset);
+    format!("{} {} {}", format_date(&components), format_time(&components), format_time_zone(offset))
+}
+
+/**
+ * Formats the date of the components, e.g. 'Tue Mar 05 2024'.
+ */
+fn format_date(components: &[f64; 8]) -> String {
+    format!(
+        "{} {} {:02} {}",
+        WEEKDAYS[components[WEEKDAY] as usize],
+        MONTHS[components[MONTH] as usize],
+        components[DATE],
+        format_year(components[YEAR]),
+    )
+}
+
+/**
+ * Formats the time of the components, e.g. '10:20:30'.
+ */
+fn format_time(components: &[f64; 8]) -> String {
+    format!("{:02}:{:02}:{:02}", components[HOURS], components[MINUTES], components[SECONDS])
+}
+
+/**
+ * Formats the offset from UTC in milliseconds, e.g. 'GMT+0100' or 'GMT-0530'.
+ */
+fn format_time_zone(offset: f64) -> String {
+    let minutes = (offset.abs() / 60000.0).floor();
+    let sign = if offset < 0.0 { '-' } else { '+' };
+    format!("GMT{sign}{:02}{:02}", (minutes / 60.0).floor(), minutes % 60.0)
+}
+
+fn format_year(year: f64) -> String {
+    if year < 0.0 {
+        format!("-{:04}", -year)
+    } else {
+        format!("{year:04}")
+    }
+}
+
+/**
+ * Epoch milliseconds of the string, or NaN if it isn't in the date time string format,
+ * e.g. '2024-03-05T10:20:30.000Z', nor in the one of `toString` or `toUTCString`.
+ * Date-only forms of the former are in UTC, others without an offset are in local time.
+ */
+fn parse_date(interpreter: &Interpreter, string: &str) -> f64 {
+    parse_iso_date(interpreter, string)
+        .or_else(|| parse_date_string(interpreter, string))
+        .map_or(f64::NAN, time_clip)
+}
+
+fn parse_iso_date(interpreter: &Interpreter, string: &str) -> Option<f64> {
+    let format = Regex::new(
+        r"^([+-]\d{6}|\d{4})(?:-(\d{2})(?:-(\d{2}))?)?(?:T(\d{2}):(\d{2})(?::(\d{2})(?:\.(\d+))?)?(Z|[+-]\d{2}:\d{2})?)?$"
+    ).unwrap();
+    let captures = format.captures(string)?;
+
+    // Year zero has no negative counterpart
+    if &captures[1] == "-000000" {
+        return None;
+    }
+    let number = |index: usize, default: f64| captures.get(index).map_or(default, |m| m.as_str().parse().unwrap_or(f64::NAN));
+    let (year, month, date) = (number(1, f64::NAN), number(2, 1.0), number(3, 1.0));
+    let (hours, minutes, seconds) = (number(4, 0.0), number(5, 0.0), number(6, 0.0));
+
+    // Digits beyond milliseconds are ignored, e.g. '.123456'
+    let milliseconds = captures.get(7).map_or(0.0, |m| format!("{:0<3.3}", m.as_str()).parse().unwrap_or(f64::NAN));
+
+    let days_in_month = make_day(year, month, 1.0) - make_day(year, month - 1.0, 1.0);
+    let midnight = hours == 24.0 && minutes == 0.0 && seconds == 0.0 && milliseconds == 0.0;
+    if !(1.0..=12.0).contains(&month) || !(1.0..=days_in_month).contains(&date)
+        || (hours > 23.0 && !midnight) || minutes > 59.0 || seconds > 59.0 {
+        return None;
+    }
+
+    let time = make_day(year, month - 1.0, date) * MS_PER_DAY + make_time(hours, minutes, seconds, milliseconds);
+    match captures.get(8).map(|m| m.as_str()) {
+        Some("Z") => Some(time),
+        Some(offset) => Some(time - parse_offset(&offset.replace(':', ""))),
+        None if captures.get(4).is_some() => Some(utc_time(interpreter, time)),
+        None => Some(time),
+    }
+}
+
+fn parse_date_string(interpreter: &Interpreter, string: &str) -> Option<f64> {
+    let format = Regex::new(
+        r"^(?:[A-Za-z]{3},? )?(?:([A-Za-z]{3}) (\d{1,2})|(\d{1,2}) ([A-Za-z]{3})) (-?\d{1,6})(?: (\d{2}):(\d{2})(?::(\d{2}))?)?( ?(?:GMT|UTC|Z)([+-]\d{4})?)?(?: \(.*\))?$"
+    ).unwrap();
+    let captures = format.captures(string)?;
+    let month = captures.get(1).or(captures.get(4))?.as_str();
+    let month = MONTHS.iter().position(|name| name.eq_ignore_ascii_case(month))? as f64;
+    let number = |index: usize| captures.get(index).map_or(0.0, |m| m.as_str().parse().unwrap_or(f64::NAN));
+    let (date, year) = (number(2) + number(3), number(5));
+    let (hours, minutes, seconds) = (number(6), number(7), number(8));
+    if !(1.0..=31.0).contains(&date) || hours > 23.0 || minutes > 59.0 || seconds > 59.0 {
+        return None;
+    }
+
+    let time = make_day(year, month, date) * MS_PER_DAY + make_time(hours, minutes, seconds, 0.0);
+    match (captures.get(9), captures.get(10)) {
+        (Some(_), Some(offset)) => Some(time - parse_offset(offset.as_str())),
+        (Some(_), None) => Some(time),
+        (None, _) => Some(utc_time(interpreter, time)),
+    }
+}
+
+/**
+ * Parses an offset from UTC into milliseconds, e.g. '+0530'.
+ */
+fn parse_offset(offset: &str) -> f64 {
+    let sign = if offset.starts_with('-') { -1.0 } else { 1.0 };
+    let hours: f64 = offset[1..3].parse().unwrap_or(f64::NAN);
+    let minutes: f64 = offset[3..5].parse().unwrap_or(f64::NAN);
+    sign * (hours * 3600000.0 + minutes * 60000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    use std::rc::Rc;
+
+    /**
+     * Tuesday, March 5th 2024 at 10:20:30.123 UTC.
+     */
+    const NOW: f64 = 1709634030123.0;
+
+    /**
+     * Asserts the script's value on a clock frozen at `NOW`, whose local time zone has the offset in hours.
+     */
+    fn assert_date(content_string: &str, offset: f64, value: &str) {
+        let tree = parse(content_string);
+        let mut env = EnvironmentBuilder::default().build().unwrap();
+        let clock = Rc::new(VirtualClock::new(NOW).with_local_offset(offset * 3600000.0));
+        let interpreter = Interpreter::new(&tree, &mut env).with_clock(clock);
+        assert_eq!(interpreter.eval(), Ok(Value::String(value.into())));
+    }
+
+    #[test]
+    fn test_date_constructor() {
+        let content = "
+            let copy = new Date(new Date(86400000));
+            '' + [Date.now(), new Date().getTime(), new Date(0).valueOf(), copy.getTime(), new Date(8.64e15 + 1).getTime(), new Date('2024-03-05').getTime()];
+        ";
+        assert_date(content, 0.0, "1709634030123,1709634030123,0,86400000,NaN,1709596800000");
+
+        // Components are in local time, years 0 to 99 being in the 20th century
+        let content = "
+            '' + [new Date(2024, 0, 31).getTime(), new Date(2024, 12).getTime(), new Date(99, 0).getTime(), Date.UTC(2024, 0, 31), Date.UTC()];
+        ";
+        assert_date(content, 1.0, "1706655600000,1735686000000,915145200000,1706659200000,NaN");
+        assert_date("Date(0);", 1.0, "Tue Mar 05 2024 11:20:30 GMT+0100");
+    }
+
+    #[test]
+    fn test_date_getters() {
+        let content = "
+            let date = new Date();
+            '' + [date.getFullYear(), date.getMonth(), date.getDate(), date.getDay(), date.getHours(), date.getMinutes(), date.getSeconds(), date.getMilliseconds(), date.getTimezoneOffset()];
+        ";
+        assert_date(content, -11.0, "2024,2,4,1,23,20,30,123,660");
+        let content = "
+            let date = new Date();
+            '' + [date.getUTCFullYear(), date.getUTCMonth(), date.getUTCDate(), date.getUTCDay(), date.getUTCHours(), date.getUTCMinutes(), date.getUTCSeconds(), date.getUTCMilliseconds()];
+        ";
+        assert_date(content, -11.0, "2024,2,5,2,10,20,30,123");
+        assert_date("'' + [new Date(-1).getUTCFullYear(), new Date(-1).getUTCDay(), new Date(NaN).getDate()];", 0.0, "1969,3,NaN");
+    }
+
+    #[test]
+    fn test_date_setters() {
+        let content = "
+            let date = new Date(2024, 0, 31);
+            let out = [date.setMonth(1), date.getDate()];
+            date.setDate(0);
+            out.push(date.getMonth(), date.getDate());
+            date.setHours(25, 61);
+            out.push(date.getDate(), date.getHours(), date.getMinutes());
+            date.setUTCFullYear(2000, 1, 29);
+            out.push(date.toISOString());
+            '' + out;
+        ";
+        assert_date(content, 2.0, "1709330400000,2,1,29,1,2,1,2000-02-29T00:01:00.000Z");
+
+        // Only years make invalid dates valid again
+        let content = "
+            let date = new Date(NaN);
+            let out = [date.setHours(1), date.setTime(), date.setFullYear(2020), date.getHours()];
+            '' + [out, date.setMilliseconds(), date.setTime(5)];
+        ";
+        assert_date(content, 3.0, "NaN,NaN,1577826000000,0,NaN,5");
+    }
+
+    #[test]
+    fn test_date_strings() {
+        let content = "
+            let date = new Date();
+            '' + [date, date.toDateString(), date.toTimeString(), date.toUTCString(), date.toISOString()];
+        ";
+        assert_date(content, -5.5, "Tue Mar 05 2024 04:50:30 GMT-0530,Tue Mar 05 2024,04:50:30 GMT-0530,Tue, 05 Mar 2024 10:20:30 GMT,2024-03-05T10:20:30.123Z");
+
+        let content = "
+            let date = new Date(Date.UTC(-1, 0));
+            date.setUTCFullYear(-1);
+            '' + [date.toISOString(), new Date(Date.UTC(10000, 0)).toISOString(), date.toUTCString(), new Date(NaN), JSON.stringify([new Date(0), new Date(NaN)])];
+        ";
+        assert_date(content, 0.0, "-000001-01-01T00:00:00.000Z,+010000-01-01T00:00:00.000Z,Fri, 01 Jan -0001 00:00:00 GMT,Invalid Date,[\"1970-01-01T00:00:00.000Z\",null]");
+        assert_eval_error("new Date(NaN).toISOString();", Error::Range("Invalid time value!".to_string()));
+    }
+
+    #[test]
+    fn test_date_parse() {
+        let content = "
+            '' + [
+                Date.parse('2024-03-05T10:20:30.123Z'),
+                Date.parse('2024-03-05T11:20:30.123456+01:00'),
+                Date.parse('2024-03-05T12:20'),
+                Date.parse('2024-03'),
+                Date.parse('+002024-03-05'),
+                Date.parse('2024-02-30'),
+                Date.parse('-000000-01-01'),
+                Date.parse('2024-03-05T24:00'),
+                Date.parse('March 5th'),
+            ];
+        ";
+        assert_date(content, 2.0, "1709634030123,1709634030123,1709634000000,1709251200000,1709596800000,NaN,NaN,1709676000000,NaN");
+
+        // Strings returned by `toString` & `toUTCString` are parsed back
+        let content = "
+            let date = new Date(2024, 2, 5, 11, 20, 30);
+            '' + [Date.parse(date.toString()), Date.parse(date.toUTCString()), Date.parse('Tue Mar 05 2024'), Date.parse('5 Mar 2024 10:20 GMT+0100')];
+        ";
+        assert_date(content, 1.0, "1709634030000,1709634030000,1709593200000,1709630400000");
+    }
+
+    #[test]
+    fn test_date_conversions() {
+        let content = "
+            let start = new Date(1000);
+            let end = new Date(4000);
+            '' + [end - start, start + 1, end > start, start == 1000, start[Symbol.toPrimitive]('number')];
+        ";
+        assert_date(content, 0.0, "3000,Thu Jan 01 1970 00:00:01 GMT+00001,true,false,1000");
+        assert_eval_error("new Date()[Symbol.toPrimitive]('boolean');", Error::Type("Invalid hint: boolean!".to_string()));
+        assert_eval_error(
+            "let getTime = new Date().getTime; getTime();",
+            Error::Type("Method Date.prototype.getTime called on incompatible receiver undefined!".to_string()),
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::prelude::*;
+
+use crate::interpreter::iteration::Iterable;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::array::create_array;
+use super::{define_global, native_function};
+
+/**
+ * Names of the native error constructors, the first one
+ * being the parent of all the others.
+ */
+pub const ERROR_TYPES: [&str; 5] = ["Error", "TypeError", "RangeError", "ReferenceError", "SyntaxError"];
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let constructors: [NativeFn; 5] = [
+        construct_error::<0>,
+        construct_error::<1>,
+        construct_error::<2>,
+        construct_error::<3>,
+        construct_error::<4>,
+    ];
+
+    let mut base: Option<(ObjectRef, ObjectRef)> = None;
+    for (name, function) in ERROR_TYPES.into_iter().zip(constructors) {
+        let parent = base.as_ref().map(|(_, prototype)| prototype.clone()).or_else(|| realm.intrinsic("%Object.prototype%"));
+        let prototype = ObjectRef::new(ObjectKind::Ordinary, parent);
+        prototype.define_non_enumerable("name".to_string(), Property::Data(Value::String(name.to_string().into())));
+        prototype.define_non_enumerable("message".to_string(), Property::Data(Value::String(JsString::default())));
+
+        // Subclasses inherit static members of Error, e.g. TypeError.__proto__ === Error
+        let constructor = native_function(realm, name, 1, function, true);
+        if let Some((base_constructor, _)) = &base {
+            constructor.borrow_mut().prototype = Some(base_constructor.clone());
+        }
+        constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+        prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+        if base.is_none() {
+            let to_string = native_function(realm, "toString", 0, error_to_string, false);
+            prototype.define_non_enumerable("toString".to_string(), Property::Data(Value::Object(to_string)));
+            base = Some((constructor.clone(), prototype.clone()));
+        }
+
+        realm.define_intrinsic(&format!("%{name}.prototype%"), prototype);
+        define_global(global, name, Value::Object(constructor));
+    }
+
+    // AggregateError takes the errors it wraps before its message
+    if let Some((base_constructor, base_prototype)) = base {
+        let prototype = ObjectRef::new(ObjectKind::Ordinary, Some(base_prototype));
+        prototype.define_non_enumerable("name".to_string(), Property::Data(Value::String("AggregateError".into())));
+        prototype.define_non_enumerable("message".to_string(), Property::Data(Value::String(JsString::default())));
+
+        let constructor = native_function(realm, "AggregateError", 2, construct_aggregate_error, true);
+        constructor.borrow_mut().prototype = Some(base_constructor);
+        constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+        prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+        realm.define_intrinsic("%AggregateError.prototype%", prototype);
+        define_global(global, "AggregateError", Value::Object(constructor));
+    }
+}
+
+/**
+ * Creates an error object with the given prototype, capturing
+ * the functions running at the moment as its `stack`.
+ */
+pub fn create_error_object(interpreter: &Interpreter, prototype: Option<ObjectRef>, message: Option<String>) -> ObjectRef {
+    let error = ObjectRef::new(ObjectKind::Error, prototype);
+    if let Some(message) = message {
+        error.define_non_enumerable("message".to_string(), Property::Data(Value::String(message.into())));
+    }
+
+    let mut stack = error.to_string();
+    for frame in interpreter.stack_frames() {
+        stack.push_str(&format!("\n    at {frame}"));
+    }
+    error.define_non_enumerable("stack".to_string(), Property::Data(Value::String(stack.into())));
+    error
+}
+
+/**
+ * Creates an AggregateError wrapping the errors, e.g. the reasons passed to `Promise.any`.
+ */
+pub fn create_aggregate_error(interpreter: &Interpreter, errors: Vec<Value>, message: &str) -> Value {
+    let prototype = interpreter.realm.intrinsic("%AggregateError.prototype%");
+    let error = create_error_object(interpreter, prototype, Some(message.to_string()));
+    let errors = create_array(interpreter, errors);
+    error.define_non_enumerable("errors".to_string(), Property::Data(Value::Object(errors)));
+    Value::Object(error)
+}
+
+/**
+ * Error(message), TypeError(message), ... -> callable with or without `new`.
+ */
+fn construct_error<const TYPE: usize>(
+    interpreter: &Interpreter,
+    _this: &Value,
+    args: &[Value],
+    new_target: Option<&ObjectRef>,
+) -> Result<Value> {
+    // Subclasses construct errors with their own prototype
+    let prototype = match new_target {
+        None => None,
+        Some(new_target) => match interpreter.get_property(&Value::Object(new_target.clone()), "prototype")? {
+            Value::Object(prototype) => Some(prototype),
+            _ => None,
+        },
+    };
+    let prototype = prototype.or_else(|| interpreter.realm.intrinsic(&format!("%{}.prototype%", ERROR_TYPES[TYPE])));
+
+    let message = match args.first() {
+        None | Some(Value::Undefined) => None,
+        Some(message) => Some(message.to_string()),
+    };
+    Ok(Value::Object(create_error_object(interpreter, prototype, message)))
+}
+
+/**
+ * AggregateError(errors, message) -> callable with or without `new`.
+ */
+fn construct_aggregate_error(
+    interpreter: &Interpreter,
+    _this: &Value,
+    args: &[Value],
+    new_target: Option<&ObjectRef>,
+) -> Result<Value> {
+    let prototype = match new_target {
+        None => None,
+        Some(new_target) => match interpreter.get_property(&Value::Object(new_target.clone()), "prototype")? {
+            Value::Object(prototype) => Some(prototype),
+            _ => None,
+        },
+    };
+    let prototype = prototype.or_else(|| interpreter.realm.intrinsic("%AggregateError.prototype%"));
+
+    let message = match args.get(1) {
+        None | Some(Value::Undefined) => None,
+        Some(message) => Some(message.to_string()),
+    };
+    let errors = interpreter.iterate(&args.first().cloned().unwrap_or(Value::Undefined))?;
+
+    let error = create_error_object(interpreter, prototype, message);
+    let errors = create_array(interpreter, errors);
+    error.define_non_enumerable("errors".to_string(), Property::Data(Value::Object(errors)));
+    Ok(Value::Object(error))
+}
+
+/**
+ * Error.prototype.toString() -> 'name: message'
+ */
+fn error_to_string(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    if !matches!(this, Value::Object(_)) {
+        return Err(Error::Type("Error.prototype.toString requires that 'this' be an Object!".to_string()));
+    }
+    let name = match interpreter.get_property(this, "name")? {
+        Value::Undefined => "Error".to_string(),
+        name => name.to_string(),
+    };
+    let message = match interpreter.get_property(this, "message")? {
+        Value::Undefined => String::new(),
+        message => message.to_string(),
+    };
+    let string = match (name.is_empty(), message.is_empty()) {
+        (_, true) => name,
+        (true, false) => message,
+        (false, false) => format!("{name}: {message}"),
+    };
+    Ok(Value::String(string.into()))
+}
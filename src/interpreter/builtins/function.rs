@@ -0,0 +1,236 @@
+use crate::prelude::*;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::evalable::Evalable;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::array::list_from_array_like;
+use super::{define_global, native_function};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = realm.intrinsic("%Function.prototype%").expect("Function.prototype is created first!");
+    prototype.define_function_length(0.0);
+    prototype.define_non_enumerable("name".to_string(), Property::Data(Value::String(JsString::default())));
+    let methods: [(&str, usize, NativeFn); 4] = [
+        ("apply", 2, function_apply),
+        ("bind", 1, function_bind),
+        ("call", 1, function_call),
+        ("toString", 0, function_to_string),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    let constructor = native_function(realm, "Function", 1, construct_function, true);
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Function%", constructor.clone());
+    define_global(global, "Function", Value::Object(constructor));
+}
+
+/**
+ * Function(...params, body) -> new function compiled from source, closing over the global scope.
+ */
+fn construct_function(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut sources = vec![];
+    for arg in args {
+        sources.push(interpreter.to_string(arg)?.to_string());
+    }
+    let body = sources.pop().unwrap_or_default();
+    let content_string = format!("(function anonymous({}\n) {{\n{body}\n}});", sources.join(","));
+
+    let tree = Parser::new(content_string).and_then(|mut parser| parser.parse())?;
+    let expression = match &tree {
+        Tree::Program { body, .. } => match body.as_slice() {
+            [Tree::ExpressionStatement { expression }] => expression.as_ref(),
+            _ => return Err(Error::Syntax("Unexpected end of function body!".to_string())),
+        },
+        _ => return Err(Error::Runtime(format!("Invalid program node: {tree}"))),
+    };
+    interpreter.eval_tree(expression, &interpreter.env_ref)
+}
+
+/**
+ * Function.prototype.apply(thisArg, args) -> result of calling the function with the array-like arguments.
+ */
+fn function_apply(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_function(this, "apply")?;
+    let arguments = match args.get(1) {
+        None | Some(Value::Undefined | Value::Null) => vec![],
+        Some(arguments) => list_from_array_like(interpreter, arguments)?,
+    };
+    interpreter.call(this, args.first().unwrap_or(&Value::Undefined), &arguments)
+}
+
+/**
+ * Function.prototype.bind(thisArg, ...args) -> function calling this one with the `this` value &
+ * leading arguments, e.g. `add.bind(null, 1)(2)`. Bound constructors construct the original function.
+ */
+fn function_bind(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = this_function(this, "bind")?;
+    let name = match interpreter.get_property(this, "name")? {
+        Value::String(name) => name,
+        _ => String::new().into(),
+    };
+
+    // The bound arguments are no longer expected, e.g. `f.bind(null, 1).length` is 1 for `function f(a, b) {}`
+    let bound_args: Vec<Value> = args.iter().skip(1).cloned().collect();
+    let length = match interpreter.get_own_property(&target, &PropertyKey::from("length"))? {
+        Some(_) => match interpreter.get_property(this, "length")? {
+            // NaN lengths count as 0, which `max` picks over them
+            Value::Number(length) => (length.trunc() - bound_args.len() as f64).max(0.0),
+            _ => 0.0,
+        },
+        None => 0.0,
+    };
+
+    let prototype = interpreter.get_prototype_of(&target)?;
+    let bound = ObjectRef::new(ObjectKind::BoundFunction(BoundFunction {
+        target,
+        this: args.first().cloned().unwrap_or(Value::Undefined),
+        args: bound_args,
+    }), prototype);
+    bound.define_function_length(length);
+    bound.define_non_enumerable("name".to_string(), Property::Data(Value::String(format!("bound {name}").into())));
+    Ok(Value::Object(bound))
+}
+
+/**
+ * Function.prototype.call(thisArg, ...args) -> result of calling the function with the `this` value & arguments.
+ */
+fn function_call(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_function(this, "call")?;
+    let arguments = args.get(1..).unwrap_or_default();
+    interpreter.call(this, args.first().unwrap_or(&Value::Undefined), arguments)
+}
+
+/**
+ * Function.prototype.toString() -> source text of user-defined functions & classes, e.g. `function f() {}`.
+ * Built-in & bound functions don't have one, e.g. `function max() { [native code] }`.
+ */
+fn function_to_string(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_function(this, "toString")?;
+    match object.function() {
+        Some(function) => Ok(Value::String(function.source.to_string().into())),
+        None => Ok(Value::String(object.to_string().into())),
+    }
+}
+
+/**
+ * Returns `this` for methods of Function.prototype, which must be callable.
+ */
+fn this_function(this: &Value, method: &str) -> Result<ObjectRef> {
+    match this {
+        Value::Object(object) if object.is_callable() => Ok(object.clone()),
+        _ => Err(Error::Type(format!("Function.prototype.{method} was called on {this}, which is not a function!"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_function_constructor() {
+        assert_eval("let add = new Function('a', 'b', 'return a + b;'); add(1, 2);", Value::Number(3.0));
+        assert_eval("let x = 5; Function('return x * 2;')();", Value::Number(10.0));
+        assert_eval("Function('a, b', 'return a - b;')(5, 2);", Value::Number(3.0));
+        assert_eval("Function().name;", Value::String("anonymous".into()));
+        assert_eval_error("Function('return +;');", Error::Syntax("Unexpected token SemiColon, expected Identifier!".to_string()));
+    }
+
+    #[test]
+    fn test_function_prototype() {
+        let content = "
+            function f() {}
+            class C {}
+            '' + [Object.getPrototypeOf(f) === Function.prototype, Object.getPrototypeOf(C) === Function.prototype,
+                Object.getPrototypeOf(Math.max) === Function.prototype, Object.getPrototypeOf(Function.prototype) === Object.prototype,
+                Function.prototype(), f instanceof Function, f instanceof Object, Function.prototype.constructor === Function];
+        ";
+        assert_eval(content, Value::String("true,true,true,true,,true,true,true".into()));
+    }
+
+    #[test]
+    fn test_call_and_apply() {
+        let content = "
+            function describe(greeting, punctuation) { return greeting + ', ' + this.name + punctuation; }
+            let o = new Object();
+            o.name = 'Ada';
+            '' + [describe.call(o, 'Hi', '!'), describe.apply(o, ['Hello', '?']), describe.apply(o), Math.max.apply(null, [1, 3, 2])];
+        ";
+        assert_eval(content, Value::String("Hi, Ada!,Hello, Ada?,undefined, Adaundefined,3".into()));
+        assert_eval_error(
+            "Function.prototype.call.call(1);",
+            Error::Type("Function.prototype.call was called on 1, which is not a function!".to_string()),
+        );
+        assert_eval_error("Math.max.apply(null, 1);", Error::Type("CreateListFromArrayLike called on non-object 1!".to_string()));
+    }
+
+    #[test]
+    fn test_bind() {
+        let content = "
+            function add(a, b, c) { return this.base + a + b + c; }
+            let o = new Object();
+            o.base = 100;
+            let addOne = add.bind(o, 1);
+            let rebound = addOne.bind(null, 2);
+            '' + [addOne(2, 3), rebound(3), addOne.name, rebound.name, Object.getPrototypeOf(addOne) === Function.prototype];
+        ";
+        assert_eval(content, Value::String("106,106,bound add,bound bound add,true".into()));
+
+        let content = "
+            class Point { constructor(x, y) { this.x = x; this.y = y; } }
+            let OnAxis = Point.bind(null, 0);
+            let p = new OnAxis(5);
+            '' + [p.x, p.y, p instanceof Point, p instanceof OnAxis, Reflect.construct(OnAxis, [7]).y];
+        ";
+        assert_eval(content, Value::String("0,5,true,true,7".into()));
+        assert_eval_error("new (Math.max.bind(null))();", Error::Type("Object(BoundFunction(NativeFunction(max))) is not a constructor!".to_string()));
+    }
+
+    #[test]
+    fn test_function_length() {
+        let content = "
+            function add(a, b, c) {}
+            let descriptor = Object.getOwnPropertyDescriptor(add, 'length');
+            add.length = 5;
+            let reassigned = add.length;
+            Reflect.deleteProperty(add, 'length');
+            '' + [
+                descriptor.value, descriptor.writable, descriptor.enumerable, descriptor.configurable, reassigned, add.length,
+                add.bind(null, 1).length, function(a, b, c) {}.bind(null, 1).length, function(a, b) {}.bind(null, 1, 2, 3).length, [].push.length, Math.max.length,
+                Array.prototype.splice.length, Object.defineProperty.length, Reflect.ownKeys(Math.abs).join('|')
+            ];
+        ";
+        assert_eval(content, Value::String("3,false,false,true,3,0,0,2,0,1,2,2,3,length|name".into()));
+    }
+
+    #[test]
+    fn test_to_string() {
+        let content = "
+            function  add(a,b) { return a + b; }
+            let square = async function(x) {
+                return x * x;
+            };
+            class A extends Object { *values() {} }
+            [add.toString(), '' + square, String(A), A.prototype.values.toString(), Function('a', 'return a;').toString()].join('|');
+        ";
+        assert_eval(content, Value::String(
+            "function  add(a,b) { return a + b; }|async function(x) {\n                return x * x;\n            }|class A extends Object { *values() {} }|*values() {}|function anonymous(a\n) {\nreturn a;\n}".into()
+        ));
+        assert_eval("class B { static async m() {} } B.m.toString();", Value::String("async m() {}".into()));
+        assert_eval("'' + [Math.max.toString(), Math.max.bind(null).toString()];", Value::String(
+            "function max() { [native code] },function () { [native code] }".into()
+        ));
+        assert_eval_error(
+            "Function.prototype.toString.call(new Object());",
+            Error::Type("Function.prototype.toString was called on [object Object], which is not a function!".to_string()),
+        );
+    }
+}
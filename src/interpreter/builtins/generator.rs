@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+use crate::interpreter::generators::{GeneratorEvalable, GeneratorResume};
+use crate::interpreter::realm::Realm;
+
+use super::native_function;
+
+pub fn install(realm: &mut Realm, _global: &ObjectRef) {
+    // Iterators are iterable themselves, e.g. `for (const x of gen())`
+    let iterator_prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let iterator = native_function(realm, "[Symbol.iterator]", 0, iterator_self, false);
+    iterator_prototype.define_non_enumerable(&realm.symbols.iterator, Property::Data(Value::Object(iterator)));
+
+    let generator_prototype = ObjectRef::new(ObjectKind::Ordinary, Some(iterator_prototype.clone()));
+    let methods: [(&str, usize, NativeFn); 3] = [
+        ("next", 1, generator_next),
+        ("return", 1, generator_return),
+        ("throw", 1, generator_throw),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        generator_prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    generator_prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Generator".into())));
+
+    realm.define_intrinsic("%IteratorPrototype%", iterator_prototype);
+    realm.define_intrinsic("%GeneratorPrototype%", generator_prototype);
+}
+
+/**
+ * %IteratorPrototype%[Symbol.iterator]() -> this
+ */
+fn iterator_self(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(this.clone())
+}
+
+/**
+ * Generator.prototype.next(value) -> resumes the generator, `yield` evaluating to the value.
+ */
+fn generator_next(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    interpreter.resume_generator(this, GeneratorResume::Next(value), "Generator.prototype.next")
+}
+
+/**
+ * Generator.prototype.return(value) -> finishes the generator, running its `finally` blocks.
+ */
+fn generator_return(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    interpreter.resume_generator(this, GeneratorResume::Return(value), "Generator.prototype.return")
+}
+
+/**
+ * Generator.prototype.throw(exception) -> throws the exception at the suspended `yield`.
+ */
+fn generator_throw(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let exception = args.first().cloned().unwrap_or(Value::Undefined);
+    interpreter.resume_generator(this, GeneratorResume::Throw(exception), "Generator.prototype.throw")
+}
@@ -0,0 +1,707 @@
+use crate::prelude::*;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+use crate::number;
+
+use super::array::{create_array, is_array};
+use super::object::create_object;
+use super::{define_global, native_function};
+
+/**
+ * Deepest nesting of arrays & objects accepted by `JSON.parse`.
+ */
+const MAX_DEPTH: usize = 512;
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let json = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 2] = [
+        ("parse", 2, json_parse),
+        ("stringify", 3, json_stringify),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        json.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    json.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("JSON".into())));
+
+    define_global(global, "JSON", Value::Object(json));
+}
+
+/**
+ * JSON.parse(text, reviver) -> value described by the JSON text. The reviver is called
+ * with every key & value, innermost first, its result replacing the value.
+ */
+fn json_parse(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let text = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let value = parse(interpreter, &text)?;
+    match args.get(1) {
+        Some(reviver) if reviver.is_callable() => {
+            let root = create_object(interpreter);
+            root.set_own_value("", value);
+            internalize(interpreter, reviver, &root, "")
+        },
+        _ => Ok(value),
+    }
+}
+
+/**
+ * JSON.stringify(value, replacer, space) -> JSON text of the value, or undefined if it can't be serialized.
+ * The replacer is either a function transforming the values or an array of the keys to serialize.
+ */
+fn json_stringify(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    let mut serializer = Serializer {
+        interpreter,
+        replacer: None,
+        keys: None,
+        indent: String::new(),
+        gap: gap(interpreter, args.get(2))?,
+        stack: vec![],
+    };
+    match args.get(1) {
+        Some(replacer) if replacer.is_callable() => serializer.replacer = Some(replacer.clone()),
+        Some(Value::Object(replacer)) if is_array(replacer)? => serializer.keys = Some(property_list(interpreter, replacer)?),
+        _ => {},
+    }
+
+    let root = create_object(interpreter);
+    root.set_own_value("", value);
+    match serializer.serialize_property(&root, "")? {
+        Some(json) => Ok(Value::String(json.into())),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/**
+ * Parses a JSON text into interpreter values, objects & arrays inheriting the standard prototypes.
+ */
+fn parse(interpreter: &Interpreter, text: &str) -> Result<Value> {
+    let mut parser = JsonParser { interpreter, chars: text.chars().collect(), position: 0, depth: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespaces();
+    match parser.peek() {
+        None => Ok(value),
+        Some(_) => Err(parser.unexpected()),
+    }
+}
+
+struct JsonParser<'a, 'b> {
+    interpreter: &'a Interpreter<'b>,
+    chars: Vec<char>,
+    position: usize,
+    depth: usize,
+}
+
+impl <'a, 'b> JsonParser<'a, 'b> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<char> {
+        let char = self.peek().ok_or_else(|| self.unexpected())?;
+        self.position += 1;
+        Ok(char)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.peek() {
+            Some(char) if char == expected => {
+                self.position += 1;
+                Ok(())
+            },
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn unexpected(&self) -> Error {
+        match self.peek() {
+            None => Error::Syntax("Unexpected end of JSON input!".to_string()),
+            Some(char) => Error::Syntax(format!("Unexpected token '{char}' in JSON at position {}!", self.position)),
+        }
+    }
+
+    /**
+     * JSON only allows spaces, tabs & line feeds between tokens.
+     */
+    fn skip_whitespaces(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespaces();
+        match self.peek() {
+            Some('{') => self.nested(Self::object),
+            Some('[') => self.nested(Self::array),
+            Some('"') => self.string().map(Value::String),
+            Some('-' | '0'..='9') => self.number(),
+            Some('t') => self.keyword("true", Value::Boolean(true)),
+            Some('f') => self.keyword("false", Value::Boolean(false)),
+            Some('n') => self.keyword("null", Value::Null),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value>) -> Result<Value> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(Error::Syntax("JSON is nested too deeply!".to_string()));
+        }
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<Value> {
+        let object = create_object(self.interpreter);
+        self.expect('{')?;
+        self.skip_whitespaces();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Value::Object(object));
+        }
+        loop {
+            self.skip_whitespaces();
+            if self.peek() != Some('"') {
+                return Err(self.unexpected());
+            }
+            let key = self.string()?;
+            self.skip_whitespaces();
+            self.expect(':')?;
+            let value = self.value()?;
+
+            // Duplicate keys keep the last value, e.g. '{"a":1,"a":2}'
+            object.set_own_value(key.to_string(), value);
+            self.skip_whitespaces();
+            match self.next()? {
+                ',' => continue,
+                '}' => return Ok(Value::Object(object)),
+                _ => {
+                    self.position -= 1;
+                    return Err(self.unexpected());
+                },
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value> {
+        let mut elements = vec![];
+        self.expect('[')?;
+        self.skip_whitespaces();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Value::Object(create_array(self.interpreter, elements)));
+        }
+        loop {
+            elements.push(self.value()?);
+            self.skip_whitespaces();
+            match self.next()? {
+                ',' => continue,
+                ']' => return Ok(Value::Object(create_array(self.interpreter, elements))),
+                _ => {
+                    self.position -= 1;
+                    return Err(self.unexpected());
+                },
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<JsString> {
+        self.expect('"')?;
+        let mut units: Vec<u16> = vec![];
+        loop {
+            let char = match self.peek() {
+                // Control characters must be escaped
+                Some(char) if char < ' ' => return Err(self.unexpected()),
+                _ => self.next()?,
+            };
+            match char {
+                '"' => return Ok(JsString::from_units(&units)),
+                '\\' => {
+                    let escaped = match self.next()? {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+
+                        // Surrogate pairs are escaped as two code units, e.g. '\ud83d\ude00'
+                        'u' => {
+                            let mut unit = 0;
+                            for _ in 0..4 {
+                                let digit = self.peek().and_then(|char| char.to_digit(16)).ok_or_else(|| self.unexpected())?;
+                                unit = unit * 16 + digit as u16;
+                                self.position += 1;
+                            }
+                            units.push(unit);
+                            continue;
+                        },
+                        _ => {
+                            self.position -= 1;
+                            return Err(self.unexpected());
+                        },
+                    };
+                    units.push(escaped as u16);
+                },
+                char => units.extend(char.encode_utf16(&mut [0; 2]).iter()),
+            }
+        }
+    }
+
+    /**
+     * Number without leading zeros, '+' sign or dangling decimal point, e.g. '-0.5e+3'.
+     */
+    fn number(&mut self) -> Result<Value> {
+        let start = self.position;
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+        match self.peek() {
+            Some('0') => self.position += 1,
+            Some('1'..='9') => self.digits(),
+            _ => return Err(self.unexpected()),
+        }
+        if self.peek() == Some('.') {
+            self.position += 1;
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.unexpected());
+            }
+            self.digits();
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.position += 1;
+            }
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.unexpected());
+            }
+            self.digits();
+        }
+        let numeral: String = self.chars[start..self.position].iter().collect();
+        Ok(Value::Number(numeral.parse().unwrap_or(f64::NAN)))
+    }
+
+    fn digits(&mut self) {
+        while matches!(self.peek(), Some('0'..='9')) {
+            self.position += 1;
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Value) -> Result<Value> {
+        for expected in keyword.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+}
+
+/**
+ * Calls the reviver with the holder's property, once its own properties are revived.
+ * Properties the reviver returns undefined for are removed.
+ */
+fn internalize(interpreter: &Interpreter, reviver: &Value, holder: &ObjectRef, key: &str) -> Result<Value> {
+    let value = interpreter.get_object_property(holder, key, &Value::Object(holder.clone()))?;
+    if let Value::Object(object) = &value {
+        let mut keys = vec![];
+        for key in interpreter.own_property_keys(object)? {
+            if key.as_string().is_some() && interpreter.get_own_property(object, &key)?.is_some_and(|descriptor| descriptor.enumerable == Some(true)) {
+                keys.push(key);
+            }
+        }
+        for key in keys {
+            let name = key.to_string();
+            match internalize(interpreter, reviver, object, &name)? {
+                Value::Undefined => interpreter.delete_property(object, &key)?,
+                revived => interpreter.define_own_property(object, &key, PropertyDescriptor::data(revived))?,
+            };
+        }
+    }
+    interpreter.call(reviver, &Value::Object(holder.clone()), &[Value::String(key.to_string().into()), value])
+}
+
+/**
+ * Indentation of the nested values, up to 10 spaces or the first 10 characters of a string.
+ */
+fn gap(interpreter: &Interpreter, space: Option<&Value>) -> Result<String> {
+    let space = match space {
+        Some(Value::Object(object)) => match object.primitive_value() {
+            Some(Value::Number(_)) => Value::Number(interpreter.to_number(&Value::Object(object.clone()))?),
+            Some(Value::String(_)) => Value::String(interpreter.to_string(&Value::Object(object.clone()))?),
+            _ => return Ok(String::new()),
+        },
+        Some(space) => space.clone(),
+        None => return Ok(String::new()),
+    };
+    match space {
+        Value::Number(n) => Ok(" ".repeat(n.clamp(0.0, 10.0) as usize)),
+        Value::String(s) => Ok(s.chars().take(10).collect()),
+        _ => Ok(String::new()),
+    }
+}
+
+/**
+ * Keys listed by a replacer array, numbers being converted into strings & duplicates ignored.
+ */
+fn property_list(interpreter: &Interpreter, replacer: &ObjectRef) -> Result<Vec<String>> {
+    let length = interpreter.get_object_property(replacer, "length", &Value::Object(replacer.clone()))?.to_number();
+    let mut keys: Vec<String> = vec![];
+    for index in 0..length as usize {
+        let item = interpreter.get_object_property(replacer, index.to_string(), &Value::Object(replacer.clone()))?;
+        let key = match &item {
+            Value::String(key) => Some(key.to_string()),
+            Value::Number(_) => Some(item.to_string()),
+            Value::Object(object) if matches!(object.primitive_value(), Some(Value::String(_) | Value::Number(_))) => {
+                Some(interpreter.to_string(&item)?.to_string())
+            },
+            _ => None,
+        };
+        if let Some(key) = key.filter(|key| !keys.contains(key)) {
+            keys.push(key);
+        }
+    }
+    Ok(keys)
+}
+
+struct Serializer<'a, 'b> {
+    interpreter: &'a Interpreter<'b>,
+    replacer: Option<Value>,
+    keys: Option<Vec<String>>,
+    indent: String,
+    gap: String,
+
+    // Objects being serialized, outermost first, to detect cycles
+    stack: Vec<ObjectRef>,
+}
+
+impl <'a, 'b> Serializer<'a, 'b> {
+    /**
+     * Serializes the holder's property, None meaning it's skipped, e.g. functions.
+     */
+    fn serialize_property(&mut self, holder: &ObjectRef, key: &str) -> Result<Option<String>> {
+        let interpreter = self.interpreter;
+        let mut value = interpreter.get_object_property(holder, key, &Value::Object(holder.clone()))?;
+
+        // Objects can customize their serialization, e.g. dates
+        if matches!(value, Value::Object(_) | Value::BigInt(_)) {
+            let to_json = interpreter.get_property(&value, "toJSON")?;
+            if to_json.is_callable() {
+                value = interpreter.call(&to_json, &value, &[Value::String(key.to_string().into())])?;
+            }
+        }
+        if let Some(replacer) = &self.replacer {
+            value = interpreter.call(replacer, &Value::Object(holder.clone()), &[Value::String(key.to_string().into()), value])?;
+        }
+
+        // Wrapper objects are serialized as their primitive value
+        if let Value::Object(object) = &value {
+            value = match object.primitive_value() {
+                Some(Value::Number(_)) => Value::Number(interpreter.to_number(&value)?),
+                Some(Value::String(_)) => Value::String(interpreter.to_string(&value)?),
+                Some(primitive @ (Value::Boolean(_) | Value::BigInt(_))) => primitive,
+                _ => value,
+            };
+        }
+
+        match value {
+            Value::Null => Ok(Some("null".to_string())),
+            Value::Boolean(b) => Ok(Some(b.to_string())),
+            Value::String(s) => Ok(Some(quote(&s))),
+            Value::Number(n) if n.is_finite() => Ok(Some(number::to_string(n))),
+            Value::Number(_) => Ok(Some("null".to_string())),
+            Value::BigInt(_) => Err(Error::Type("Do not know how to serialize a BigInt!".to_string())),
+            Value::Object(object) if object.is_callable() => Ok(None),
+            Value::Object(object) if is_array(&object)? => self.serialize_array(&object).map(Some),
+            Value::Object(object) => self.serialize_object(&object).map(Some),
+            Value::Undefined | Value::Symbol(_) => Ok(None),
+        }
+    }
+
+    fn serialize_object(&mut self, object: &ObjectRef) -> Result<String> {
+        let keys = match &self.keys {
+            Some(keys) => keys.clone(),
+            None => {
+                let mut keys = vec![];
+                for key in self.interpreter.own_property_keys(object)? {
+                    if key.as_string().is_some() && self.interpreter.get_own_property(object, &key)?.is_some_and(|descriptor| descriptor.enumerable == Some(true)) {
+                        keys.push(key.to_string());
+                    }
+                }
+                keys
+            },
+        };
+        let separator = if self.gap.is_empty() { ":" } else { ": " };
+        self.nested(object, '{', '}', |serializer| {
+            let mut members = vec![];
+            for key in keys {
+                if let Some(value) = serializer.serialize_property(object, &key)? {
+                    members.push(format!("{}{separator}{value}", quote(&JsString::from(key.as_str()))));
+                }
+            }
+            Ok(members)
+        })
+    }
+
+    fn serialize_array(&mut self, array: &ObjectRef) -> Result<String> {
+        let length = self.interpreter.get_object_property(array, "length", &Value::Object(array.clone()))?.to_number();
+        self.nested(array, '[', ']', |serializer| {
+            let mut elements = vec![];
+            for index in 0..length as usize {
+                let element = serializer.serialize_property(array, &index.to_string())?;
+                elements.push(element.unwrap_or_else(|| "null".to_string()));
+            }
+            Ok(elements)
+        })
+    }
+
+    /**
+     * Serializes the members of an object or an array between the brackets,
+     * each on its own indented line if a gap is given.
+     */
+    fn nested(
+        &mut self,
+        object: &ObjectRef,
+        open: char,
+        close: char,
+        members: impl FnOnce(&mut Self) -> Result<Vec<String>>,
+    ) -> Result<String> {
+        if self.stack.contains(object) {
+            return Err(Error::Type("Converting circular structure to JSON!".to_string()));
+        }
+        self.stack.push(object.clone());
+        let outer_indent = self.indent.clone();
+        self.indent.push_str(&self.gap.clone());
+
+        let result = members(self);
+        let indent = std::mem::replace(&mut self.indent, outer_indent);
+        self.stack.pop();
+
+        let members = result?;
+        if members.is_empty() {
+            return Ok(format!("{open}{close}"));
+        }
+        match self.gap.is_empty() {
+            true => Ok(format!("{open}{}{close}", members.join(","))),
+            false => {
+                let separator = format!(",\n{indent}");
+                Ok(format!("{open}\n{indent}{}\n{}{close}", members.join(&separator), self.indent))
+            },
+        }
+    }
+}
+
+/**
+ * Wraps the string in double quotes, escaping quotes, backslashes, control characters
+ * & lone surrogates, e.g. '"\ud83d"'.
+ */
+fn quote(s: &JsString) -> String {
+    let mut quoted = String::from('"');
+    for char in char::decode_utf16(s.units().iter().copied()) {
+        let char = match char {
+            Ok(char) => char,
+            Err(error) => {
+                quoted.push_str(&format!("\\u{:04x}", error.unpaired_surrogate()));
+                continue;
+            },
+        };
+        match char {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            char if char < ' ' => quoted.push_str(&format!("\\u{:04x}", char as u32)),
+            char => quoted.push(char),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    use super::{parse, quote};
+
+    /**
+     * Parses the JSON text with a fresh interpreter, describing the result as JSON again.
+     */
+    fn reparse(text: &str) -> Result<String> {
+        let tree = Tree::Program { body: vec![], strict: false };
+        let mut env = EnvironmentBuilder::default().build().unwrap();
+        let interpreter = Interpreter::new(&tree, &mut env);
+        let value = parse(&interpreter, text)?;
+        Ok(describe(&value))
+    }
+
+    fn describe(value: &Value) -> String {
+        match value {
+            Value::String(s) => quote(s),
+            Value::Object(object) if object.is_array() => {
+                let elements: Vec<String> = (0..object.own_keys().len())
+                    .map(|index| describe(&object.get_data_value(index.to_string()).unwrap()))
+                    .collect();
+                format!("[{}]", elements.join(","))
+            },
+            Value::Object(object) => {
+                let members: Vec<String> = object.own_keys().iter()
+                    .map(|key| format!("{}:{}", quote(&key.to_string().into()), describe(&object.get_data_value(key).unwrap())))
+                    .collect();
+                format!("{{{}}}", members.join(","))
+            },
+            value => value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_accepted_texts() {
+        let cases = [
+            (r#" [1, -0.5, 2e3, 1E-2, 0, -0] "#, "[1,-0.5,2000,0.01,0,0]"),
+            (r#"{"a":{"b":[]},"c":{}}"#, r#"{"a":{"b":[]},"c":{}}"#),
+            (r#"{"a":1,"a":2}"#, r#"{"a":2}"#),
+            (r#""\u0041\n\"\\\/\t""#, r#""A\n\"\\/\t""#),
+            (r#""\ud83d\ude00 é""#, r#""😀 é""#),
+            (r#""\ud800""#, r#""\ud800""#),
+            ("[true,false,null]", "[true,false,null]"),
+            ("123456789012345678901234567890", "1.2345678901234568e+29"),
+            ("1e400", "Infinity"),
+            (r#"{"__proto__":1}"#, r#"{"__proto__":1}"#),
+            ("\t\r\n 7 \n", "7"),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(reparse(text), Ok(expected.to_string()), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_rejected_texts() {
+        let cases = [
+            ("", "Unexpected end of JSON input!"),
+            ("[1,]", "Unexpected token ']' in JSON at position 3!"),
+            ("{\"a\":1,}", "Unexpected token '}' in JSON at position 7!"),
+            ("{'a':1}", "Unexpected token ''' in JSON at position 1!"),
+            ("01", "Unexpected token '1' in JSON at position 1!"),
+            ("1.", "Unexpected end of JSON input!"),
+            (".5", "Unexpected token '.' in JSON at position 0!"),
+            ("+1", "Unexpected token '+' in JSON at position 0!"),
+            ("-", "Unexpected end of JSON input!"),
+            ("1e", "Unexpected end of JSON input!"),
+            ("NaN", "Unexpected token 'N' in JSON at position 0!"),
+            ("[1 2]", "Unexpected token '2' in JSON at position 3!"),
+            ("\"a\tb\"", "Unexpected token '\t' in JSON at position 2!"),
+            ("\"\\x\"", "Unexpected token 'x' in JSON at position 2!"),
+            ("\"\\u12\"", "Unexpected token '\"' in JSON at position 5!"),
+            ("\"abc", "Unexpected end of JSON input!"),
+            ("tru", "Unexpected end of JSON input!"),
+            ("nul1", "Unexpected token '1' in JSON at position 3!"),
+            ("[] []", "Unexpected token '[' in JSON at position 3!"),
+            ("\u{a0}1", "Unexpected token '\u{a0}' in JSON at position 0!"),
+        ];
+        for (text, message) in cases {
+            assert_eq!(reparse(text), Err(Error::Syntax(message.to_string())), "{text}");
+        }
+        let nested = "[".repeat(10000) + &"]".repeat(10000);
+        assert_eq!(reparse(&nested), Err(Error::Syntax("JSON is nested too deeply!".to_string())));
+    }
+
+    #[test]
+    fn test_json_parse() {
+        let content = r#"
+            let config = JSON.parse('{"name": "app", "ports": [80, 443], "debug": false, "extra": null}');
+            '' + [config.name, config.ports[1], config.ports.length, config.debug, config.extra === null, JSON.parse('"x"')];
+        "#;
+        assert_eval(content, Value::String("app,443,2,false,true,x".into()));
+
+        let content = r#"
+            let seen = [];
+            let revived = JSON.parse('{"a": [1, 2], "b": {"c": 3}, "d": 4}', function(key, value) {
+                seen.push(key);
+                if (key === 'd') {
+                    return;
+                }
+                if (value === 2) {
+                    return 20;
+                }
+                return value;
+            });
+            '' + [seen.join('|'), revived.a, revived.b.c, JSON.stringify(revived)];
+        "#;
+        assert_eval(content, Value::String(r#"0|1|a|c|b|d|,1,20,3,{"a":[1,20],"b":{"c":3}}"#.into()));
+    }
+
+    #[test]
+    fn test_json_stringify() {
+        let content = r#"
+            let inner = new Object();
+            inner.list = [1, 'two', null, true, NaN, function() {}, Symbol()];
+            let outer = new Object();
+            outer.inner = inner;
+            outer.skipped = function() {};
+            outer.text = 'say "hi"';
+            '' + JSON.stringify(outer);
+        "#;
+        assert_eval(content, Value::String(r#"{"inner":{"list":[1,"two",null,true,null,null,null]},"text":"say \"hi\""}"#.into()));
+
+        let content = "
+            '' + [JSON.stringify(), JSON.stringify(function() {}), JSON.stringify('a'), JSON.stringify(-0), JSON.stringify(new String('s')), JSON.stringify([])];
+        ";
+        assert_eval(content, Value::String(r#",,"a",0,"s",[]"#.into()));
+    }
+
+    #[test]
+    fn test_json_stringify_options() {
+        let content = r#"
+            let value = JSON.parse('{"a": 1, "b": [2, {"c": 3}], "d": {}}');
+            JSON.stringify(value, null, 2);
+        "#;
+        assert_eval(content, Value::String("{\n  \"a\": 1,\n  \"b\": [\n    2,\n    {\n      \"c\": 3\n    }\n  ],\n  \"d\": {}\n}".into()));
+
+        let content = r#"
+            let value = JSON.parse('{"a": 1, "b": 2, "c": {"a": 3, "z": 4}}');
+            '' + [
+                JSON.stringify(value, ['a', 'c', 'a']),
+                JSON.stringify(value, function(key, value) { if (value === 2) { return 'two'; } return value; }),
+                JSON.stringify([1], null, '--')
+            ];
+        "#;
+        assert_eval(content, Value::String("{\"a\":1,\"c\":{\"a\":3}},{\"a\":1,\"b\":\"two\",\"c\":{\"a\":3,\"z\":4}},[\n--1\n]".into()));
+
+        let content = "
+            class Point {
+                constructor(x) { this.x = x; }
+                toJSON(key) { return 'P' + this.x + key; }
+            }
+            let holder = new Object();
+            holder.p = new Point(1);
+            JSON.stringify(holder);
+        ";
+        assert_eval(content, Value::String(r#"{"p":"P1p"}"#.into()));
+    }
+
+    #[test]
+    fn test_json_stringify_errors() {
+        let content = "
+            let a = [];
+            let o = new Object();
+            o.a = a;
+            a.push(o);
+            JSON.stringify(o);
+        ";
+        assert_eval_error(content, Error::Type("Converting circular structure to JSON!".to_string()));
+        assert_eval_error("JSON.stringify([1n]);", Error::Type("Do not know how to serialize a BigInt!".to_string()));
+
+        // Shared objects aren't cycles
+        assert_eval("let o = new Object(); JSON.stringify([o, o]);", Value::String("[{},{}]".into()));
+    }
+}
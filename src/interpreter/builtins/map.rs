@@ -0,0 +1,441 @@
+use crate::prelude::*;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::collections::{KeyedCollection, WeakCollection};
+use crate::interpreter::callable::Callable;
+use crate::interpreter::iteration::Iterable;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::array::create_array;
+use super::{define_global, native_function, native_iterator, prototype_from_constructor};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 8] = [
+        ("clear", 0, map_clear),
+        ("delete", 1, map_delete),
+        ("forEach", 1, map_for_each),
+        ("get", 1, map_get),
+        ("has", 1, map_has),
+        ("keys", 0, map_keys),
+        ("set", 2, map_set),
+        ("values", 0, map_values),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    // Maps iterate their entries, i.e. `map[Symbol.iterator] === map.entries`
+    let entries = native_function(realm, "entries", 0, map_entries, false);
+    prototype.define_non_enumerable("entries".to_string(), Property::Data(Value::Object(entries.clone())));
+    prototype.define_non_enumerable(&realm.symbols.iterator, Property::Data(Value::Object(entries)));
+    let size = native_function(realm, "get size", 0, map_size, false);
+    prototype.define_non_enumerable("size".to_string(), Property::Accessor { get: Some(Value::Object(size)), set: None });
+    prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Map".into())));
+
+    let constructor = native_function(realm, "Map", 0, construct_map, true);
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Map.prototype%", prototype);
+    define_global(global, "Map", Value::Object(constructor));
+
+    let iterator_prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%IteratorPrototype%"));
+    iterator_prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Map Iterator".into())));
+    realm.define_intrinsic("%MapIteratorPrototype%", iterator_prototype);
+
+    let weak_prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 4] = [
+        ("delete", 1, weak_map_delete),
+        ("get", 1, weak_map_get),
+        ("has", 1, weak_map_has),
+        ("set", 2, weak_map_set),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        weak_prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    weak_prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("WeakMap".into())));
+
+    let weak_constructor = native_function(realm, "WeakMap", 0, construct_weak_map, true);
+    weak_constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(weak_prototype.clone())));
+    weak_prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(weak_constructor.clone())));
+
+    realm.define_intrinsic("%WeakMap.prototype%", weak_prototype);
+    define_global(global, "WeakMap", Value::Object(weak_constructor));
+}
+
+/**
+ * Values produced by the iterators of keyed collections.
+ */
+#[derive(Clone, Copy)]
+pub enum IterationKind {
+    Keys,
+    Values,
+    Entries,
+}
+
+/**
+ * Map(entries) -> map of the [key, value] pairs of the iterable, if any.
+ */
+fn construct_map(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_none() {
+        return Err(Error::Type("Map constructor cannot be invoked without 'new'!".to_string()));
+    }
+    let prototype = prototype_from_constructor(interpreter, new_target, "%Map.prototype%")?;
+    let map = Value::Object(ObjectRef::new(ObjectKind::Map(KeyedCollection::default()), prototype));
+    add_entries(interpreter, &map, args.first(), "set", true)?;
+    Ok(map)
+}
+
+/**
+ * Map.prototype.clear() -> removes every entry.
+ */
+fn map_clear(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_map(this, "clear", |entries| entries.clear())?;
+    Ok(Value::Undefined)
+}
+
+/**
+ * Map.prototype.delete(key) -> whether an entry was removed.
+ */
+fn map_delete(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().unwrap_or(&Value::Undefined);
+    Ok(Value::Boolean(this_map(this, "delete", |entries| entries.delete(key))?))
+}
+
+/**
+ * Map.prototype.entries() -> iterator of the [key, value] pairs, in insertion order.
+ */
+fn map_entries(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_map(this, "entries", |_| ())?;
+    Ok(collection_iterator(interpreter, this, "%MapIteratorPrototype%", IterationKind::Entries))
+}
+
+/**
+ * Map.prototype.forEach(callback, thisArg) -> calls the callback with the value, the key & the map of each entry.
+ * Entries added during the iteration are visited too.
+ */
+fn map_for_each(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_map(this, "forEach", |_| ())?;
+    let callback = callback_argument(args)?;
+    let this_arg = args.get(1).cloned().unwrap_or(Value::Undefined);
+    let cursor = this_map(this, "forEach", KeyedCollection::cursor)?;
+    while let Some((index, key, value)) = this_map(this, "forEach", |entries| entries.next_entry(cursor.get()))? {
+        cursor.set(index + 1);
+        interpreter.call(&callback, &this_arg, &[value, key, this.clone()])?;
+    }
+    Ok(Value::Undefined)
+}
+
+/**
+ * Map.prototype.get(key) -> value of the key, or undefined.
+ */
+fn map_get(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().unwrap_or(&Value::Undefined);
+    Ok(this_map(this, "get", |entries| entries.get(key))?.unwrap_or(Value::Undefined))
+}
+
+/**
+ * Map.prototype.has(key) -> whether the map has an entry for the key.
+ */
+fn map_has(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().unwrap_or(&Value::Undefined);
+    Ok(Value::Boolean(this_map(this, "has", |entries| entries.has(key))?))
+}
+
+/**
+ * Map.prototype.keys() -> iterator of the keys, in insertion order.
+ */
+fn map_keys(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_map(this, "keys", |_| ())?;
+    Ok(collection_iterator(interpreter, this, "%MapIteratorPrototype%", IterationKind::Keys))
+}
+
+/**
+ * Map.prototype.set(key, value) -> the map, once the key is given the value.
+ */
+fn map_set(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().cloned().unwrap_or(Value::Undefined);
+    let value = args.get(1).cloned().unwrap_or(Value::Undefined);
+    this_map(this, "set", |entries| entries.set(key, value))?;
+    Ok(this.clone())
+}
+
+/**
+ * get Map.prototype.size -> number of entries.
+ */
+fn map_size(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Number(this_map(this, "size", |entries| entries.len())? as f64))
+}
+
+/**
+ * Map.prototype.values() -> iterator of the values, in insertion order.
+ */
+fn map_values(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_map(this, "values", |_| ())?;
+    Ok(collection_iterator(interpreter, this, "%MapIteratorPrototype%", IterationKind::Values))
+}
+
+/**
+ * WeakMap(entries) -> weak map of the [key, value] pairs of the iterable, if any.
+ */
+fn construct_weak_map(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_none() {
+        return Err(Error::Type("WeakMap constructor cannot be invoked without 'new'!".to_string()));
+    }
+    let prototype = prototype_from_constructor(interpreter, new_target, "%WeakMap.prototype%")?;
+    let map = Value::Object(ObjectRef::new(ObjectKind::WeakMap(WeakCollection::default()), prototype));
+    add_entries(interpreter, &map, args.first(), "set", true)?;
+    Ok(map)
+}
+
+/**
+ * WeakMap.prototype.delete(key) -> whether an entry was removed.
+ */
+fn weak_map_delete(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().unwrap_or(&Value::Undefined);
+    let deleted = this_weak_map(this, "delete", |entries| entries.delete(key))?;
+    Ok(Value::Boolean(deleted))
+}
+
+/**
+ * WeakMap.prototype.get(key) -> value of the key, or undefined.
+ */
+fn weak_map_get(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().unwrap_or(&Value::Undefined);
+    let value = this_weak_map(this, "get", |entries| entries.get(key))?;
+    Ok(value.unwrap_or(Value::Undefined))
+}
+
+/**
+ * WeakMap.prototype.has(key) -> whether the weak map has an entry for the key.
+ */
+fn weak_map_has(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().unwrap_or(&Value::Undefined);
+    let has = this_weak_map(this, "has", |entries| entries.has(key))?;
+    Ok(Value::Boolean(has))
+}
+
+/**
+ * WeakMap.prototype.set(key, value) -> the weak map, once the key is given the value.
+ * Keys must be objects or symbols which aren't registered, i.e. not created by `Symbol.for`.
+ */
+fn weak_map_set(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = args.first().unwrap_or(&Value::Undefined);
+    let value = args.get(1).cloned().unwrap_or(Value::Undefined);
+    if !can_be_held_weakly(interpreter, key) {
+        this_weak_map(this, "set", |_| ())?;
+        return Err(Error::Type(format!("Invalid value used as weak map key: {key}!")));
+    }
+    this_weak_map(this, "set", |entries| entries.set(key, value))?;
+    Ok(this.clone())
+}
+
+/**
+ * Whether the value can be the key of a weak collection, i.e. an object or a symbol which isn't registered.
+ * Registered symbols live as long as the registry & would never be collected.
+ */
+pub fn can_be_held_weakly(interpreter: &Interpreter, value: &Value) -> bool {
+    match value {
+        Value::Object(_) => true,
+        Value::Symbol(symbol) => interpreter.realm.symbol_key(symbol).is_none(),
+        _ => false,
+    }
+}
+
+/**
+ * Adds the values of the iterable to a new collection through its adder method, e.g. `set`.
+ * Maps are given [key, value] pairs, whose key & value are read from their '0' & '1' properties.
+ */
+pub fn add_entries(interpreter: &Interpreter, collection: &Value, iterable: Option<&Value>, adder: &str, pairs: bool) -> Result<()> {
+    let iterable = match iterable {
+        None | Some(Value::Undefined | Value::Null) => return Ok(()),
+        Some(iterable) => iterable,
+    };
+    let adder = match interpreter.get_property(collection, adder)? {
+        method if method.is_callable() => method,
+        method => return Err(Error::Type(format!("{method} is not a function!"))),
+    };
+    for item in interpreter.iterate(iterable)? {
+        let args = match (pairs, &item) {
+            (false, _) => vec![item],
+            (true, Value::Object(_)) => vec![interpreter.get_property(&item, "0")?, interpreter.get_property(&item, "1")?],
+            (true, _) => return Err(Error::Type(format!("Iterator value {item} is not an entry object!"))),
+        };
+        interpreter.call(&adder, collection, &args)?;
+    }
+    Ok(())
+}
+
+/**
+ * Creates an iterator of the entries of a Map or a Set, which also visits the entries
+ * added during the iteration, e.g. `new Set([1]).values()`.
+ */
+pub fn collection_iterator(interpreter: &Interpreter, collection: &Value, prototype: &str, kind: IterationKind) -> Value {
+    let collection = collection.clone();
+    let cursor = match &collection {
+        Value::Object(object) => match &mut object.borrow_mut().kind {
+            ObjectKind::Map(entries) | ObjectKind::Set(entries) => entries.cursor(),
+            _ => Rc::new(Cell::new(0)),
+        },
+        _ => Rc::new(Cell::new(0)),
+    };
+    native_iterator(interpreter, prototype, move |interpreter| {
+        let entry = match &collection {
+            Value::Object(object) => match &object.borrow().kind {
+                ObjectKind::Map(entries) | ObjectKind::Set(entries) => entries.next_entry(cursor.get()),
+                _ => None,
+            },
+            _ => None,
+        };
+        Ok(entry.map(|(index, key, value)| {
+            cursor.set(index + 1);
+            match kind {
+                IterationKind::Keys => key,
+                IterationKind::Values => value,
+                IterationKind::Entries => Value::Object(create_array(interpreter, vec![key, value])),
+            }
+        }))
+    })
+}
+
+/**
+ * Returns the callback argument of `forEach`, which must be a function.
+ */
+pub fn callback_argument(args: &[Value]) -> Result<Value> {
+    match args.first() {
+        Some(callback) if callback.is_callable() => Ok(callback.clone()),
+        callback => Err(Error::Type(format!("{} is not a function!", callback.unwrap_or(&Value::Undefined)))),
+    }
+}
+
+/**
+ * Runs the operation on the entries of `this`, which must be a Map.
+ */
+fn this_map<T>(this: &Value, method: &str, operation: impl FnOnce(&mut KeyedCollection) -> T) -> Result<T> {
+    if let Value::Object(object) = this {
+        if let ObjectKind::Map(entries) = &mut object.borrow_mut().kind {
+            return Ok(operation(entries));
+        }
+    }
+    Err(Error::Type(format!("Method Map.prototype.{method} called on incompatible receiver {this}!")))
+}
+
+/**
+ * Runs the operation on the entries of `this`, which must be a WeakMap.
+ */
+fn this_weak_map<T>(this: &Value, method: &str, operation: impl FnOnce(&mut WeakCollection) -> T) -> Result<T> {
+    if let Value::Object(object) = this {
+        if let ObjectKind::WeakMap(entries) = &mut object.borrow_mut().kind {
+            return Ok(operation(entries));
+        }
+    }
+    Err(Error::Type(format!("Method WeakMap.prototype.{method} called on incompatible receiver {this}!")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_map() {
+        let content = "
+            let key = [];
+            let map = new Map([['a', 1], [NaN, 'nan'], [0, 'zero']]);
+            map.set(key, 'array').set('a', 2);
+            '' + [map.size, map.get('a'), map.get(NaN), map.get(-0), map.get(key), map.get([]), map.has(0), map.has('0'), map.delete('a'), map.delete('a'), map.size];
+        ";
+        assert_eval(content, Value::String("4,2,nan,zero,array,,true,false,true,false,3".into()));
+
+        let content = "
+            let map = new Map();
+            map.set('x', 1).set('y', 2).set('z', 3);
+            map.delete('y');
+            map.set('y', 4);
+            let out = [];
+            for (const entry of map) {
+                out.push(entry[0] + '=' + entry[1]);
+            }
+            '' + [out.join('&'), [...map.keys()], [...map.values()], Array.from(map.entries()).length, map.entries === map[Symbol.iterator]];
+        ";
+        assert_eval(content, Value::String("x=1&z=3&y=4,x,z,y,1,3,4,3,true".into()));
+    }
+
+    #[test]
+    fn test_map_live_iteration() {
+        let content = "
+            let map = new Map([[1, 'a'], [2, 'b'], [3, 'c']]);
+            let visited = [];
+            map.forEach(function(value, key, target) {
+                visited.push(key + value + (target === map));
+                if (key === 1) {
+                    map.delete(2);
+                    map.set(4, 'd');
+                }
+            });
+            let iterator = map.keys();
+            let first = iterator.next().value;
+            map.clear();
+            map.set(5, 'e');
+            '' + [visited.join(' '), first, iterator.next().value, iterator.next().done, map.size];
+        ";
+        assert_eval(content, Value::String("1atrue 3ctrue 4dtrue,1,5,true,1".into()));
+
+        let content = "
+            let map = new Map();
+            for (let i = 0; i < 40; i = i + 1) {
+                map.set(i, i);
+            }
+            let iterator = map.values();
+            iterator.next();
+            let visited = [];
+            map.forEach(function(value) {
+                visited.push(value);
+                map.delete(value + 1);
+            });
+            let prototype = Object.getPrototypeOf(iterator);
+            '' + [
+                visited.length, visited[19], iterator.next().value, map.size,
+                prototype === Object.getPrototypeOf(new Map().keys()), prototype[Symbol.toStringTag],
+                iterator.hasOwnProperty(Symbol.toStringTag), String(new Set().values())
+            ];
+        ";
+        assert_eval(content, Value::String("20,38,2,20,true,Map Iterator,false,[object Set Iterator]".into()));
+
+        assert_eval_error("Map();", Error::Type("Map constructor cannot be invoked without 'new'!".to_string()));
+        assert_eval_error("new Map([1]);", Error::Type("Iterator value 1 is not an entry object!".to_string()));
+        assert_eval_error("let lookup = new Map().get; lookup(1);", Error::Type("Method Map.prototype.get called on incompatible receiver undefined!".to_string()));
+    }
+
+    #[test]
+    fn test_weak_map() {
+        let content = "
+            class Registry extends WeakMap {}
+            let a = new Object();
+            let b = new Object();
+            let registry = new Registry([[a, 1]]);
+            registry.set(b, 2);
+            '' + [registry instanceof WeakMap, registry.get(a), registry.get(b), registry.has(new Object()), registry.get(1), registry.delete(a), registry.has(a), registry.size];
+        ";
+        assert_eval(content, Value::String("true,1,2,false,,true,false,".into()));
+        assert_eval_error("new WeakMap().set('key', 1);", Error::Type("Invalid value used as weak map key: key!".to_string()));
+        assert_eval_error("new WeakMap().set(Symbol.for('key'), 1);", Error::Type("Invalid value used as weak map key: Symbol(key)!".to_string()));
+    }
+
+    #[test]
+    fn test_weak_map_symbol_keys() {
+        let content = "
+            let key = Symbol('key');
+            let cache = new WeakMap([[key, 1]]);
+            cache.set(Symbol.iterator, 2);
+            '' + [cache.get(key), cache.get(Symbol.iterator), cache.has(Symbol('key')), cache.has(Symbol.for('key')), cache.delete(key), cache.has(key)];
+        ";
+        assert_eval(content, Value::String("1,2,false,false,true,false".into()));
+    }
+}
@@ -0,0 +1,259 @@
+use crate::prelude::*;
+
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::realm::Realm;
+
+use super::{define_global, native_closure, native_function};
+
+type UnaryOperation = fn(f64) -> f64;
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let math = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let constants: [(&str, f64); 8] = [
+        ("E", std::f64::consts::E),
+        ("LN10", std::f64::consts::LN_10),
+        ("LN2", std::f64::consts::LN_2),
+        ("LOG10E", std::f64::consts::LOG10_E),
+        ("LOG2E", std::f64::consts::LOG2_E),
+        ("PI", std::f64::consts::PI),
+        ("SQRT1_2", std::f64::consts::FRAC_1_SQRT_2),
+        ("SQRT2", std::f64::consts::SQRT_2),
+    ];
+    for (name, value) in constants {
+        math.define_non_enumerable(name.to_string(), Property::Data(Value::Number(value)));
+    }
+
+    // Functions of a single number, converting their argument first
+    let unary: [(&str, UnaryOperation); 28] = [
+        ("abs", f64::abs),
+        ("acos", f64::acos),
+        ("acosh", f64::acosh),
+        ("asin", f64::asin),
+        ("asinh", f64::asinh),
+        ("atan", f64::atan),
+        ("atanh", f64::atanh),
+        ("cbrt", f64::cbrt),
+        ("ceil", f64::ceil),
+        ("clz32", |x| to_uint32(x).leading_zeros() as f64),
+        ("cos", f64::cos),
+        ("cosh", f64::cosh),
+        ("exp", f64::exp),
+        ("expm1", f64::exp_m1),
+        ("floor", f64::floor),
+        ("fround", |x| x as f32 as f64),
+        ("log", f64::ln),
+        ("log10", f64::log10),
+        ("log1p", f64::ln_1p),
+        ("log2", f64::log2),
+        ("round", round),
+        ("sign", sign),
+        ("sin", f64::sin),
+        ("sinh", f64::sinh),
+        ("sqrt", f64::sqrt),
+        ("tan", f64::tan),
+        ("tanh", f64::tanh),
+        ("trunc", f64::trunc),
+    ];
+    for (name, operation) in unary {
+        let method = native_closure(realm, name, 1, move |interpreter, _this, args, _new_target| {
+            Ok(Value::Number(operation(number_argument(interpreter, args, 0)?)))
+        }, false);
+        math.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    let methods: [(&str, usize, NativeFn); 6] = [
+        ("atan2", 2, math_atan2),
+        ("hypot", 2, math_hypot),
+        ("imul", 2, math_imul),
+        ("max", 2, math_max),
+        ("min", 2, math_min),
+        ("pow", 2, math_pow),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        math.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    math.define_non_enumerable("random".to_string(), Property::Data(Value::Object(random_function(realm))));
+    math.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Math".into())));
+
+    define_global(global, "Math", Value::Object(math));
+}
+
+/**
+ * Math.atan2(y, x) -> angle from the x axis to the point (x, y).
+ */
+fn math_atan2(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let y = number_argument(interpreter, args, 0)?;
+    let x = number_argument(interpreter, args, 1)?;
+    Ok(Value::Number(y.atan2(x)))
+}
+
+/**
+ * Math.hypot(...values) -> square root of the sum of the squared values.
+ */
+fn math_hypot(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut result = 0.0;
+    for arg in args {
+        // Infinity wins over NaN, i.e. `Math.hypot(NaN, Infinity)` is Infinity
+        result = f64::hypot(result, interpreter.to_number(arg)?);
+    }
+    Ok(Value::Number(result))
+}
+
+/**
+ * Math.imul(a, b) -> product of the 32-bit integers, overflowing like C.
+ */
+fn math_imul(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let a = to_uint32(number_argument(interpreter, args, 0)?) as i32;
+    let b = to_uint32(number_argument(interpreter, args, 1)?) as i32;
+    Ok(Value::Number(a.wrapping_mul(b) as f64))
+}
+
+/**
+ * Math.max(...values) -> largest of the values, -Infinity if none.
+ */
+fn math_max(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut result = f64::NEG_INFINITY;
+    for arg in args {
+        let x = interpreter.to_number(arg)?;
+        if result.is_nan() || x.is_nan() {
+            result = f64::NAN;
+        } else if x > result || (x == 0.0 && result == 0.0 && result.is_sign_negative()) {
+            result = x;
+        }
+    }
+    Ok(Value::Number(result))
+}
+
+/**
+ * Math.min(...values) -> smallest of the values, Infinity if none.
+ */
+fn math_min(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut result = f64::INFINITY;
+    for arg in args {
+        let x = interpreter.to_number(arg)?;
+        if result.is_nan() || x.is_nan() {
+            result = f64::NAN;
+        } else if x < result || (x == 0.0 && result == 0.0 && x.is_sign_negative()) {
+            result = x;
+        }
+    }
+    Ok(Value::Number(result))
+}
+
+/**
+ * Math.pow(base, exponent) -> base raised to the exponent.
+ */
+fn math_pow(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let base = number_argument(interpreter, args, 0)?;
+    let exponent = number_argument(interpreter, args, 1)?;
+
+    // Unlike Rust, 1 to the power of NaN or Infinity is NaN
+    if exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+        return Ok(Value::Number(f64::NAN));
+    }
+    Ok(Value::Number(base.powf(exponent)))
+}
+
+/**
+ * Math.random() -> pseudo-random number between 0 inclusive and 1 exclusive.
+ * Uses a xorshift generator seeded from the process' hashing keys.
+ */
+fn random_function(realm: &Realm) -> ObjectRef {
+    let state = Cell::new(RandomState::new().build_hasher().finish() | 1);
+    native_closure(realm, "random", 0, move |_interpreter, _this, _args, _new_target| {
+        let mut x = state.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        state.set(x);
+
+        // The upper 53 bits fill the mantissa of the number
+        Ok(Value::Number((x >> 11) as f64 / (1u64 << 53) as f64))
+    }, false)
+}
+
+/**
+ * Rounds half up, unlike Rust rounding half away from zero, e.g. -2.5 -> -2.
+ * Negative numbers rounding to zero give -0.
+ */
+fn round(x: f64) -> f64 {
+    if !x.is_finite() || x.fract() == 0.0 {
+        return x;
+    }
+    let floor = x.floor();
+    match x - floor >= 0.5 {
+        true => (floor + 1.0).copysign(x),
+        false => floor,
+    }
+}
+
+/**
+ * Sign of the number as -1 or 1, zeros & NaN being returned as they are.
+ */
+fn sign(x: f64) -> f64 {
+    if x == 0.0 || x.is_nan() {
+        return x;
+    }
+    x.signum()
+}
+
+/**
+ * Converts a number into an unsigned 32-bit integer, wrapping around, i.e. ToUint32.
+ */
+fn to_uint32(x: f64) -> u32 {
+    if !x.is_finite() {
+        return 0;
+    }
+    x.trunc().rem_euclid(4294967296.0) as u32
+}
+
+fn number_argument(interpreter: &Interpreter, args: &[Value], index: usize) -> Result<f64> {
+    interpreter.to_number(args.get(index).unwrap_or(&Value::Undefined))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_math_functions() {
+        let content = "
+            '' + [
+                Math.abs(-2), Math.floor(-1.5), Math.ceil('1.2'), Math.trunc(-1.7), Math.sqrt(16), Math.cbrt(-8),
+                Math.pow(2, 10), Math.pow(1, Infinity), Math.sign(-3), Math.log2(8), Math.hypot(3, 4), Math.atan2(0, -1) === Math.PI,
+                Math.imul(4294967295, 5), Math.clz32(1), Math.fround(5.5), Math.max(), Math.min(1, -2, 3), Math.max(1, NaN)
+            ];
+        ";
+        assert_eval(content, Value::String("2,-2,2,-1,4,-2,1024,NaN,-1,3,5,true,-5,31,5.5,-Infinity,-2,NaN".into()));
+    }
+
+    #[test]
+    fn test_math_round() {
+        let content = "
+            '' + [Math.round(2.5), Math.round(-2.5), Math.round(-2.6), Math.round(0.49999999999999994), 1 / Math.round(-0.4), 1 / Math.min(0, -0)];
+        ";
+        assert_eval(content, Value::String("3,-2,-3,0,-Infinity,-Infinity".into()));
+    }
+
+    #[test]
+    fn test_math_random() {
+        let content = "
+            let inRange = true;
+            let i = 0;
+            while (i < 100) {
+                let x = Math.random();
+                inRange = inRange && x >= 0 && x < 1;
+                i = i + 1;
+            }
+            inRange && Math.random() !== Math.random();
+        ";
+        assert_eval(content, Value::Boolean(true));
+        assert_eval_error("Math.abs(1n);", Error::Type("Cannot convert a BigInt value to a number!".to_string()));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::prelude::*;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::iteration::create_iter_result;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+pub mod array;
+pub mod bigint;
+pub mod boolean;
+pub mod date;
+pub mod error;
+pub mod function;
+pub mod generator;
+pub mod json;
+pub mod map;
+pub mod math;
+pub mod number;
+pub mod object;
+pub mod promise;
+pub mod proxy;
+pub mod reflect;
+pub mod regexp;
+pub mod set;
+pub mod string;
+pub mod symbol;
+pub mod timers;
+
+/**
+ * Creates the intrinsics of the realm & the global object holding the
+ * standard built-ins, registered as the '%GlobalObject%' intrinsic.
+ */
+pub fn install(realm: &mut Realm) {
+    // Every other built-in object inherits from these two prototypes
+    let object_prototype = ObjectRef::new(ObjectKind::Ordinary, None);
+    let function_prototype = ObjectRef::new(ObjectKind::NativeFunction(NativeFunction {
+        name: String::new(),
+        function: Rc::new(|_, _, _, _| Ok(Value::Undefined)),
+        constructor: false,
+    }), Some(object_prototype.clone()));
+    realm.define_intrinsic("%Object.prototype%", object_prototype.clone());
+    realm.define_intrinsic("%Function.prototype%", function_prototype);
+    let global = ObjectRef::new(ObjectKind::Ordinary, Some(object_prototype));
+
+    // Symbols come first, other built-ins define well-known symbol keys,
+    // followed by %IteratorPrototype% which the built-in iterators inherit from
+    symbol::install(realm, &global);
+    generator::install(realm, &global);
+    array::install(realm, &global);
+    bigint::install(realm, &global);
+    boolean::install(realm, &global);
+    date::install(realm, &global);
+    error::install(realm, &global);
+    function::install(realm, &global);
+    json::install(realm, &global);
+    map::install(realm, &global);
+    math::install(realm, &global);
+    number::install(realm, &global);
+    object::install(realm, &global);
+    promise::install(realm, &global);
+    proxy::install(realm, &global);
+    reflect::install(realm, &global);
+    regexp::install(realm, &global);
+    set::install(realm, &global);
+    string::install(realm, &global);
+    timers::install(realm, &global);
+
+    // Value properties of the global object can neither be reassigned nor deleted
+    define_global_constant(&global, "Infinity", Value::Number(f64::INFINITY));
+    define_global_constant(&global, "NaN", Value::Number(f64::NAN));
+    define_global_constant(&global, "undefined", Value::Undefined);
+
+    define_global(&global, "globalThis", Value::Object(global.clone()));
+    realm.define_intrinsic("%GlobalObject%", global);
+}
+
+/**
+ * Binds a built-in as a property of the global object, e.g. `globalThis.Math`.
+ */
+pub fn define_global(global: &ObjectRef, name: &str, value: Value) {
+    global.define_non_enumerable(name.to_string(), Property::Data(value));
+}
+
+/**
+ * Binds a read-only, non-configurable value as a property of the global object, e.g. `NaN`.
+ */
+fn define_global_constant(global: &ObjectRef, name: &str, value: Value) {
+    global.define_own_property(name, PropertyDescriptor {
+        value: Some(value),
+        writable: Some(false),
+        enumerable: Some(false),
+        configurable: Some(false),
+        ..PropertyDescriptor::default()
+    });
+}
+
+/**
+ * Unwraps the primitive value of wrapper objects, e.g. `this` of `new Number(1).toFixed()`.
+ * Other values are returned as is.
+ */
+pub fn this_primitive(this: &Value) -> Value {
+    match this {
+        Value::Object(object) => object.primitive_value().unwrap_or_else(|| this.clone()),
+        _ => this.clone(),
+    }
+}
+
+/**
+ * Wraps a primitive into an object for `new Number(1)` & the like,
+ * subclasses giving the wrapper their own prototype.
+ */
+pub fn construct_wrapper(interpreter: &Interpreter, value: Value, new_target: &ObjectRef) -> Result<Value> {
+    let wrapper = interpreter.to_object(&value)?;
+    if let Value::Object(prototype) = interpreter.get_property(&Value::Object(new_target.clone()), "prototype")? {
+        wrapper.borrow_mut().prototype = Some(prototype);
+    }
+    Ok(Value::Object(wrapper))
+}
+
+/**
+ * Resolves the prototype of objects created by built-in constructors, subclasses
+ * passing themselves as the `new.target`, e.g. `class Registry extends Map {}`.
+ */
+pub fn prototype_from_constructor(interpreter: &Interpreter, new_target: Option<&ObjectRef>, fallback: &str) -> Result<Option<ObjectRef>> {
+    if let Some(new_target) = new_target {
+        if let Value::Object(prototype) = interpreter.get_property(&Value::Object(new_target.clone()), "prototype")? {
+            return Ok(Some(prototype));
+        }
+    }
+    Ok(interpreter.realm.intrinsic(fallback))
+}
+
+/**
+ * Creates a built-in function object, inheriting from `Function.prototype`,
+ * whose `length` is the number of arguments it expects.
+ */
+pub fn native_function(realm: &Realm, name: &str, length: usize, function: NativeFn, constructor: bool) -> ObjectRef {
+    native_closure(realm, name, length, function, constructor)
+}
+
+/**
+ * Creates a built-in function object capturing state, e.g. a promise's `resolve`.
+ */
+pub fn native_closure(
+    realm: &Realm,
+    name: &str,
+    length: usize,
+    function: impl Fn(&Interpreter, &Value, &[Value], Option<&ObjectRef>) -> Result<Value> + 'static,
+    constructor: bool,
+) -> ObjectRef {
+    let object = ObjectRef::new(ObjectKind::NativeFunction(NativeFunction {
+        name: name.to_string(),
+        function: Rc::new(function),
+        constructor,
+    }), realm.intrinsic("%Function.prototype%"));
+    object.define_function_length(length as f64);
+    object.define_non_enumerable("name".to_string(), Property::Data(Value::String(name.to_string().into())));
+    object
+}
+
+/**
+ * Creates an iterator inheriting from the intrinsic prototype, whose `next` method returns
+ * the values produced by the closure until it returns None, e.g. `new Map().keys()`.
+ */
+pub fn native_iterator(
+    interpreter: &Interpreter,
+    prototype: &str,
+    next: impl Fn(&Interpreter) -> Result<Option<Value>> + 'static,
+) -> Value {
+    let done = Cell::new(false);
+    let next = native_closure(&interpreter.realm, "next", 0, move |interpreter, _this, _args, _new_target| {
+        if !done.get() {
+            match next(interpreter)? {
+                Some(value) => return Ok(create_iter_result(interpreter, value, false)),
+                None => done.set(true),
+            }
+        }
+        Ok(create_iter_result(interpreter, Value::Undefined, true))
+    }, false);
+
+    let iterator = ObjectRef::new(ObjectKind::Ordinary, interpreter.realm.intrinsic(prototype));
+    iterator.define_non_enumerable("next".to_string(), Property::Data(Value::Object(next)));
+    Value::Object(iterator)
+}
@@ -0,0 +1,335 @@
+use crate::prelude::*;
+
+use num_traits::ToPrimitive;
+use regex::Regex;
+
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::realm::Realm;
+use crate::number;
+
+use super::{construct_wrapper, define_global, native_function, this_primitive};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Primitive(Value::Number(0.0)), realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 5] = [
+        ("toString", 1, number_to_string),
+        ("toFixed", 1, number_to_fixed),
+        ("toExponential", 1, number_to_exponential),
+        ("toPrecision", 1, number_to_precision),
+        ("valueOf", 0, number_value_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    let constructor = native_function(realm, "Number", 1, construct_number, true);
+    let constants: [(&str, f64); 8] = [
+        ("EPSILON", f64::EPSILON),
+        ("MAX_SAFE_INTEGER", MAX_SAFE_INTEGER),
+        ("MAX_VALUE", f64::MAX),
+        ("MIN_SAFE_INTEGER", -MAX_SAFE_INTEGER),
+        ("MIN_VALUE", 5e-324),
+        ("NaN", f64::NAN),
+        ("NEGATIVE_INFINITY", f64::NEG_INFINITY),
+        ("POSITIVE_INFINITY", f64::INFINITY),
+    ];
+    for (name, value) in constants {
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Number(value)));
+    }
+    let statics: [(&str, usize, NativeFn); 4] = [
+        ("isFinite", 1, number_is_finite),
+        ("isInteger", 1, number_is_integer),
+        ("isNaN", 1, number_is_nan),
+        ("isSafeInteger", 1, number_is_safe_integer),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    // Number.parseFloat & Number.parseInt are the very same functions as the global ones
+    let functions: [(&str, usize, NativeFn); 4] = [
+        ("isFinite", 1, is_finite),
+        ("isNaN", 1, is_nan),
+        ("parseFloat", 1, parse_float),
+        ("parseInt", 2, parse_int),
+    ];
+    for (name, length, function) in functions {
+        let function = Value::Object(native_function(realm, name, length, function, false));
+        if name.starts_with("parse") {
+            constructor.define_non_enumerable(name.to_string(), Property::Data(function.clone()));
+        }
+        define_global(global, name, function);
+    }
+
+    realm.define_intrinsic("%Number.prototype%", prototype);
+    define_global(global, "Number", Value::Object(constructor));
+}
+
+/**
+ * Largest integer n such that n & n + 1 are both exactly representable, i.e. 2^53 - 1.
+ */
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+/**
+ * Number(value) -> the value converted into a number, wrapped into an object with `new`.
+ * Unlike implicit conversions, BigInts are converted too.
+ */
+fn construct_number(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = match args.first() {
+        None => Value::Number(0.0),
+        Some(value) => match interpreter.to_numeric(value)? {
+            Value::BigInt(n) => Value::Number(n.to_f64().unwrap_or(f64::NAN)),
+            number => number,
+        },
+    };
+    match new_target {
+        None => Ok(value),
+        Some(new_target) => construct_wrapper(interpreter, value, new_target),
+    }
+}
+
+/**
+ * Number.isFinite(value) -> whether the value is a finite number, without converting it.
+ */
+fn number_is_finite(_interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Boolean(matches!(args.first(), Some(Value::Number(n)) if n.is_finite())))
+}
+
+/**
+ * Number.isInteger(value) -> whether the value is a number without a fraction.
+ */
+fn number_is_integer(_interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Boolean(matches!(args.first(), Some(Value::Number(n)) if n.is_finite() && n.trunc() == *n)))
+}
+
+/**
+ * Number.isNaN(value) -> whether the value is NaN, without converting it.
+ */
+fn number_is_nan(_interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Boolean(matches!(args.first(), Some(Value::Number(n)) if n.is_nan())))
+}
+
+/**
+ * Number.isSafeInteger(value) -> whether the value is an integer exactly representable as a number.
+ */
+fn number_is_safe_integer(_interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Boolean(matches!(args.first(), Some(Value::Number(n)) if n.trunc() == *n && n.abs() <= MAX_SAFE_INTEGER)))
+}
+
+/**
+ * isFinite(value) -> whether the value converted into a number is finite.
+ */
+fn is_finite(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let x = interpreter.to_number(args.first().unwrap_or(&Value::Undefined))?;
+    Ok(Value::Boolean(x.is_finite()))
+}
+
+/**
+ * isNaN(value) -> whether the value converted into a number is NaN, e.g. `isNaN('abc')`.
+ */
+fn is_nan(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let x = interpreter.to_number(args.first().unwrap_or(&Value::Undefined))?;
+    Ok(Value::Boolean(x.is_nan()))
+}
+
+/**
+ * parseFloat(string) -> number the string starts with, ignoring the rest, e.g. '1.5px' -> 1.5.
+ */
+fn parse_float(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let numeral = Regex::new(r"^[+-]?(?:Infinity|(?:\d+\.?\d*|\.\d+)(?:[eE][+-]?\d+)?)").unwrap();
+    match numeral.find(string.trim_start()) {
+        Some(numeral) => Ok(Value::Number(Value::String(numeral.as_str().to_string().into()).to_number())),
+        None => Ok(Value::Number(f64::NAN)),
+    }
+}
+
+/**
+ * parseInt(string, radix) -> integer the string starts with in the radix, ignoring the rest.
+ * The radix defaults to 10, or 16 if the string starts with '0x'.
+ */
+fn parse_int(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let radix = interpreter.to_number(args.get(1).unwrap_or(&Value::Undefined))?;
+    let radix = if radix.is_finite() { radix.trunc() as i64 } else { 0 };
+
+    let string = string.trim_start();
+    let (sign, string) = match string.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, string.strip_prefix('+').unwrap_or(string)),
+    };
+    let (radix, string) = match radix {
+        0 | 16 => match string.get(..2) {
+            Some("0x" | "0X") => (16, &string[2..]),
+            _ => (if radix == 0 { 10 } else { 16 }, string),
+        },
+        2..=36 => (radix as u32, string),
+        _ => return Ok(Value::Number(f64::NAN)),
+    };
+
+    // Only the longest prefix of valid digits is parsed
+    let end = string.find(|c: char| !c.is_digit(radix)).unwrap_or(string.len());
+    let digits = &string[..end];
+    let value = match (digits, radix) {
+        ("", _) => f64::NAN,
+        (_, 10) => digits.parse::<f64>().unwrap_or(f64::NAN),
+        _ => BigInt::parse_bytes(digits.as_bytes(), radix).and_then(|n| n.to_f64()).unwrap_or(f64::NAN),
+    };
+    Ok(Value::Number(sign * value))
+}
+
+/**
+ * Number.prototype.toString(radix) -> digits of the number in the radix, 10 by default.
+ */
+fn number_to_string(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let x = this_number(this, "toString")?;
+    let radix = match args.first() {
+        None | Some(Value::Undefined) => 10.0,
+        Some(radix) => radix.to_number().trunc(),
+    };
+    if !(2.0..=36.0).contains(&radix) {
+        return Err(Error::Range("toString() radix must be between 2 and 36!".to_string()));
+    }
+    Ok(Value::String(number::to_radix_string(x, radix as u32).into()))
+}
+
+/**
+ * Number.prototype.toFixed(digits) -> number with the digits after the decimal point.
+ */
+fn number_to_fixed(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let x = this_number(this, "toFixed")?;
+    let digits = digits_argument(args.first(), 0, "toFixed() digits")?.unwrap_or(0);
+    Ok(Value::String(number::to_fixed(x, digits).into()))
+}
+
+/**
+ * Number.prototype.toExponential(digits) -> number in exponential notation with the digits after the decimal point.
+ */
+fn number_to_exponential(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let x = this_number(this, "toExponential")?;
+    let digits = match x.is_finite() {
+        true => digits_argument(args.first(), 0, "toExponential() argument")?,
+        false => None, // NaN & Infinity ignore the digits
+    };
+    Ok(Value::String(number::to_exponential(x, digits).into()))
+}
+
+/**
+ * Number.prototype.toPrecision(precision) -> number with the significant digits.
+ */
+fn number_to_precision(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let x = this_number(this, "toPrecision")?;
+    if !x.is_finite() {
+        return Ok(Value::String(number::to_string(x).into()));
+    }
+    match digits_argument(args.first(), 1, "toPrecision() argument")? {
+        None => Ok(Value::String(number::to_string(x).into())),
+        Some(precision) => Ok(Value::String(number::to_precision(x, precision).into())),
+    }
+}
+
+/**
+ * Number.prototype.valueOf() -> the number itself.
+ */
+fn number_value_of(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Number(this_number(this, "valueOf")?))
+}
+
+fn this_number(this: &Value, method: &str) -> Result<f64> {
+    match this_primitive(this) {
+        Value::Number(n) => Ok(n),
+        _ => Err(Error::Type(format!("Method Number.prototype.{method} called on incompatible receiver {this}!"))),
+    }
+}
+
+/**
+ * Validates a count of digits between the minimum and 100, undefined meaning none.
+ */
+fn digits_argument(argument: Option<&Value>, min: usize, name: &str) -> Result<Option<usize>> {
+    let digits = match argument {
+        None | Some(Value::Undefined) => return Ok(None),
+        Some(digits) => digits.to_number(),
+    };
+    let digits = if digits.is_nan() { 0.0 } else { digits.trunc() };
+    if !(min as f64..=100.0).contains(&digits) {
+        return Err(Error::Range(format!("{name} must be between {min} and 100!")));
+    }
+    Ok(Some(digits as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_number_edge_cases() {
+        let content = "
+            '' + [
+                NaN === NaN, NaN == NaN, 0 === -0, 1 / -0, -1 / 0, 1 / (0 * -1),
+                Infinity - Infinity, Infinity * 0, -Infinity + 1, 0 / 0, -0, NaN < 1, NaN >= 1
+            ];
+        ";
+        assert_eval(content, Value::String("false,false,true,-Infinity,-Infinity,-Infinity,NaN,NaN,-Infinity,NaN,0,false,false".into()));
+        assert_eval("let x = NaN; if (x) { 1; } else { 2; }", Value::Number(2.0));
+        assert_eval("'' + [-'Infinity' / 'x', -'Infinity' / 2];", Value::String("NaN,-Infinity".into()));
+    }
+
+    #[test]
+    fn test_number_to_string() {
+        let content = "
+            '' + [0.1 + 0.2, 1e21, -0, 123e-20, 0.000001, 1e-7, 9007199254740992, 100 / 3];
+        ";
+        assert_eval(content, Value::String(
+            "0.30000000000000004,1e+21,0,1.23e-18,0.000001,1e-7,9007199254740992,33.333333333333336".into()
+        ));
+        assert_eval("(255).toString(16) + (0.5).toString(2) + (-7).toString(36);", Value::String("ff0.1-7".into()));
+        assert_eval_error("(1).toString(1);", Error::Range("toString() radix must be between 2 and 36!".to_string()));
+    }
+
+    #[test]
+    fn test_number_formatting() {
+        let content = "
+            let x = 123.456;
+            '' + [x.toFixed(2), x.toFixed(), (1.005).toFixed(2), x.toExponential(2), x.toExponential(), x.toPrecision(4), x.toPrecision(2), (0.00001).toPrecision(1), NaN.toFixed(2)];
+        ";
+        assert_eval(content, Value::String("123.46,123,1.00,1.23e+2,1.23456e+2,123.5,1.2e+2,0.00001,NaN".into()));
+        assert_eval_error("(1).toFixed(101);", Error::Range("toFixed() digits must be between 0 and 100!".to_string()));
+        assert_eval_error("(1).toPrecision(0);", Error::Range("toPrecision() argument must be between 1 and 100!".to_string()));
+    }
+
+    #[test]
+    fn test_number_conversion() {
+        let content = "
+            '' + [Number(), Number('  12  '), Number('0x1f'), Number('0b101'), Number('-0x1'), Number(null), Number([5]), Number(10n), Number('1e3')];
+        ";
+        assert_eval(content, Value::String("0,12,31,5,NaN,0,5,10,1000".into()));
+        assert_eval("let n = new Number(1.5); n.toFixed(2) + (n + 1) + (n === 1.5);", Value::String("1.502.5false".into()));
+
+        let content = "
+            '' + [Number.isInteger(5), Number.isInteger(5.5), Number.isSafeInteger(Number.MAX_SAFE_INTEGER + 1), Number.isNaN('x'), isNaN('x'), Number.isFinite('1'), isFinite('1')];
+        ";
+        assert_eval(content, Value::String("true,false,false,false,true,false,true".into()));
+        assert_eval("Number.parseInt === parseInt && Number.EPSILON > 0 && Number.MIN_VALUE > 0;", Value::Boolean(true));
+    }
+
+    #[test]
+    fn test_parse_int_and_float() {
+        let content = "
+            '' + [
+                parseInt('  42px'), parseInt('-0x1F'), parseInt('ff', 16), parseInt('0x10', 16), parseInt('101', 2), parseInt('12', 1),
+                parseInt('z', 36), parseInt(''), parseInt('  -7.9'), parseInt('123456789012345678901234567890')
+            ];
+        ";
+        assert_eval(content, Value::String("42,-31,255,16,5,NaN,35,NaN,-7,1.2345678901234568e+29".into()));
+
+        let content = "
+            '' + [parseFloat('3.14abc'), parseFloat('  -.5'), parseFloat('1e3x'), parseFloat('1e'), parseFloat('-Infinityx'), parseFloat('x1'), parseFloat('1.2.3')];
+        ";
+        assert_eval(content, Value::String("3.14,-0.5,1000,1,-Infinity,NaN,1.2".into()));
+    }
+}
@@ -0,0 +1,809 @@
+use crate::prelude::*;
+
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::array::create_array;
+use super::{define_global, native_function};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = realm.intrinsic("%Object.prototype%").expect("Object.prototype is created first!");
+
+    let constructor = native_function(realm, "Object", 1, construct_object, true);
+    let statics: [(&str, usize, NativeFn); 18] = [
+        ("assign", 2, object_assign),
+        ("create", 2, object_create),
+        ("defineProperties", 2, object_define_properties),
+        ("defineProperty", 3, object_define_property),
+        ("entries", 1, object_entries),
+        ("freeze", 1, object_freeze),
+        ("getOwnPropertyDescriptor", 2, object_get_own_property_descriptor),
+        ("getOwnPropertyDescriptors", 1, object_get_own_property_descriptors),
+        ("getPrototypeOf", 1, object_get_prototype_of),
+        ("is", 2, object_is),
+        ("isExtensible", 1, object_is_extensible),
+        ("isFrozen", 1, object_is_frozen),
+        ("isSealed", 1, object_is_sealed),
+        ("keys", 1, object_keys),
+        ("preventExtensions", 1, object_prevent_extensions),
+        ("seal", 1, object_seal),
+        ("setPrototypeOf", 2, object_set_prototype_of),
+        ("values", 1, object_values),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    let methods: [(&str, usize, NativeFn); 4] = [
+        ("hasOwnProperty", 1, object_has_own_property),
+        ("isPrototypeOf", 1, object_is_prototype_of),
+        ("toString", 0, object_to_string),
+        ("valueOf", 0, object_value_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    let get_proto = native_function(realm, "get __proto__", 0, object_get_proto, false);
+    let set_proto = native_function(realm, "set __proto__", 1, object_set_proto, false);
+    prototype.define_non_enumerable("__proto__".to_string(), Property::Accessor {
+        get: Some(Value::Object(get_proto)),
+        set: Some(Value::Object(set_proto)),
+    });
+
+    realm.define_intrinsic("%Object%", constructor.clone());
+    define_global(global, "Object", Value::Object(constructor));
+}
+
+/**
+ * Creates an ordinary object inheriting from `Object.prototype`.
+ */
+pub fn create_object(interpreter: &Interpreter) -> ObjectRef {
+    ObjectRef::new(ObjectKind::Ordinary, interpreter.realm.intrinsic("%Object.prototype%"))
+}
+
+/**
+ * Object(value) -> the value converted into an object, or a new empty object for undefined & null.
+ */
+fn construct_object(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    // Subclasses construct plain objects with their own prototype
+    if let Some(new_target) = new_target.filter(|new_target| interpreter.realm.intrinsic("%Object%").as_ref() != Some(new_target)) {
+        let prototype = match interpreter.get_property(&Value::Object(new_target.clone()), "prototype")? {
+            Value::Object(prototype) => Some(prototype),
+            _ => interpreter.realm.intrinsic("%Object.prototype%"),
+        };
+        return Ok(Value::Object(ObjectRef::new(ObjectKind::Ordinary, prototype)));
+    }
+    match args.first() {
+        None | Some(Value::Undefined | Value::Null) => Ok(Value::Object(create_object(interpreter))),
+        Some(value) => Ok(Value::Object(interpreter.to_object(value)?)),
+    }
+}
+
+/**
+ * Object.assign(target, ...sources) -> the target, once the enumerable own properties of the sources are copied onto it.
+ */
+fn object_assign(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = Value::Object(interpreter.to_object(args.first().unwrap_or(&Value::Undefined))?);
+    for source in args.iter().skip(1) {
+        if let Value::Undefined | Value::Null = source {
+            continue;
+        }
+        let source = interpreter.to_object(source)?;
+        for key in interpreter.own_property_keys(&source)? {
+            if interpreter.get_own_property(&source, &key)?.is_none_or(|descriptor| descriptor.enumerable != Some(true)) {
+                continue;
+            }
+            let value = interpreter.get_object_property(&source, &key, &Value::Object(source.clone()))?;
+            if !interpreter.set_property(&target, &key, value)? {
+                return Err(Error::Type(format!("Cannot assign to read only property '{key}' of object '{target}'!")));
+            }
+        }
+    }
+    Ok(target)
+}
+
+/**
+ * Object.create(prototype, descriptors) -> new object inheriting from the prototype, which may be null.
+ */
+fn object_create(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let prototype = prototype_argument(args.first().unwrap_or(&Value::Undefined))?;
+    let object = ObjectRef::new(ObjectKind::Ordinary, prototype);
+    match args.get(1) {
+        None | Some(Value::Undefined) => {},
+        Some(descriptors) => define_properties(interpreter, &object, descriptors)?,
+    }
+    Ok(Value::Object(object))
+}
+
+/**
+ * Object.defineProperties(object, descriptors) -> the object, once each of its properties
+ * is defined according to the descriptor of the same key.
+ */
+fn object_define_properties(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object_argument(args, "defineProperties")?;
+    define_properties(interpreter, &object, args.get(1).unwrap_or(&Value::Undefined))?;
+    Ok(Value::Object(object))
+}
+
+/**
+ * Object.defineProperty(object, key, descriptor) -> the object, once its property is defined according to the descriptor.
+ */
+fn object_define_property(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = this_object_argument(args, "defineProperty")?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    let descriptor = to_property_descriptor(interpreter, args.get(2).unwrap_or(&Value::Undefined))?;
+    define_property_or_throw(interpreter, &object, key, descriptor)?;
+    Ok(Value::Object(object))
+}
+
+/**
+ * Object.entries(object) -> array of the [key, value] pairs of the enumerable own string-keyed properties.
+ */
+fn object_entries(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let entries = enumerable_own_properties(interpreter, args.first().unwrap_or(&Value::Undefined))?.into_iter()
+        .map(|(key, value)| Value::Object(create_array(interpreter, vec![Value::String(key.into()), value])))
+        .collect();
+    Ok(Value::Object(create_array(interpreter, entries)))
+}
+
+/**
+ * Object.freeze(object) -> the object, which can no longer be extended & whose properties are read-only & non-configurable.
+ */
+fn object_freeze(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    if let Value::Object(object) = &value {
+        if !set_integrity_level(interpreter, object, true)? {
+            return Err(Error::Type("Cannot freeze!".to_string()));
+        }
+    }
+    Ok(value)
+}
+
+/**
+ * Object.getOwnPropertyDescriptor(object, key) -> descriptor of the own property, or undefined.
+ */
+fn object_get_own_property_descriptor(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = interpreter.to_object(args.first().unwrap_or(&Value::Undefined))?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    match interpreter.get_own_property(&object, &key)? {
+        Some(descriptor) => Ok(from_property_descriptor(interpreter, descriptor)),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/**
+ * Object.getOwnPropertyDescriptors(object) -> object holding the descriptors of every own property.
+ */
+fn object_get_own_property_descriptors(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = interpreter.to_object(args.first().unwrap_or(&Value::Undefined))?;
+    let descriptors = create_object(interpreter);
+    for key in interpreter.own_property_keys(&object)? {
+        if let Some(descriptor) = interpreter.get_own_property(&object, &key)? {
+            descriptors.set_own_value(key, from_property_descriptor(interpreter, descriptor));
+        }
+    }
+    Ok(Value::Object(descriptors))
+}
+
+/**
+ * Object.getPrototypeOf(object) -> the prototype of the object, or null at the end of the chain.
+ */
+fn object_get_prototype_of(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = interpreter.to_object(args.first().unwrap_or(&Value::Undefined))?;
+    Ok(interpreter.get_prototype_of(&object)?.map(Value::Object).unwrap_or(Value::Null))
+}
+
+/**
+ * Object.is(a, b) -> whether both values are the same, unlike `===` telling -0 from 0 & NaN equal to itself.
+ */
+fn object_is(_interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let a = args.first().unwrap_or(&Value::Undefined);
+    let b = args.get(1).unwrap_or(&Value::Undefined);
+    Ok(Value::Boolean(a.same_value(b)))
+}
+
+/**
+ * Object.isExtensible(object) -> whether properties can be added to the object. Primitives never are.
+ */
+fn object_is_extensible(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    match args.first() {
+        Some(Value::Object(object)) => Ok(Value::Boolean(interpreter.is_extensible(object)?)),
+        _ => Ok(Value::Boolean(false)),
+    }
+}
+
+/**
+ * Object.isFrozen(object) -> whether the object is frozen. Primitives always are.
+ */
+fn object_is_frozen(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    match args.first() {
+        Some(Value::Object(object)) => Ok(Value::Boolean(test_integrity_level(interpreter, object, true)?)),
+        _ => Ok(Value::Boolean(true)),
+    }
+}
+
+/**
+ * Object.isSealed(object) -> whether the object is sealed. Primitives always are.
+ */
+fn object_is_sealed(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    match args.first() {
+        Some(Value::Object(object)) => Ok(Value::Boolean(test_integrity_level(interpreter, object, false)?)),
+        _ => Ok(Value::Boolean(true)),
+    }
+}
+
+/**
+ * Object.keys(object) -> array of the keys of the enumerable own string-keyed properties.
+ */
+fn object_keys(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = interpreter.to_object(args.first().unwrap_or(&Value::Undefined))?;
+    let mut keys = vec![];
+    for key in interpreter.own_property_keys(&object)? {
+        if key.as_string().is_some() && interpreter.get_own_property(&object, &key)?.is_some_and(|descriptor| descriptor.enumerable == Some(true)) {
+            keys.push(key.to_value());
+        }
+    }
+    Ok(Value::Object(create_array(interpreter, keys)))
+}
+
+/**
+ * Object.preventExtensions(object) -> the object, to which properties can no longer be added.
+ */
+fn object_prevent_extensions(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    if let Value::Object(object) = &value {
+        if !interpreter.prevent_extensions(object)? {
+            return Err(Error::Type("Cannot prevent extensions!".to_string()));
+        }
+    }
+    Ok(value)
+}
+
+/**
+ * Object.seal(object) -> the object, which can no longer be extended & whose properties are non-configurable.
+ */
+fn object_seal(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    if let Value::Object(object) = &value {
+        if !set_integrity_level(interpreter, object, false)? {
+            return Err(Error::Type("Cannot seal!".to_string()));
+        }
+    }
+    Ok(value)
+}
+
+/**
+ * Object.setPrototypeOf(object, prototype) -> the object, now inheriting from the prototype.
+ * Primitives are returned as is.
+ */
+fn object_set_prototype_of(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    if let Value::Undefined | Value::Null = value {
+        return Err(Error::Type("Object.setPrototypeOf called on null or undefined!".to_string()));
+    }
+    let prototype = prototype_argument(args.get(1).unwrap_or(&Value::Undefined))?;
+    if let Value::Object(object) = &value {
+        set_prototype_or_throw(interpreter, object, prototype)?;
+    }
+    Ok(value)
+}
+
+/**
+ * Object.prototype.isPrototypeOf(value) -> whether the object is in the prototype chain of the value.
+ */
+fn object_is_prototype_of(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut current = match args.first() {
+        Some(Value::Object(object)) => Some(object.clone()),
+        _ => return Ok(Value::Boolean(false)),
+    };
+    let this = interpreter.to_object(this)?;
+    while let Some(object) = current {
+        current = interpreter.get_prototype_of(&object)?;
+        if current.as_ref() == Some(&this) {
+            return Ok(Value::Boolean(true));
+        }
+    }
+    Ok(Value::Boolean(false))
+}
+
+/**
+ * Object.prototype.hasOwnProperty(key) -> whether the object has an own property of the key, inherited ones aside.
+ */
+fn object_has_own_property(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = interpreter.to_property_key(args.first().unwrap_or(&Value::Undefined))?;
+    let object = interpreter.to_object(this)?;
+    Ok(Value::Boolean(interpreter.get_own_property(&object, &key)?.is_some()))
+}
+
+/**
+ * Object.prototype.toString() -> '[object Tag]', the tag being the object's Symbol.toStringTag
+ * or else the kind of built-in it is, e.g. '[object Array]' or '[object Object]'.
+ */
+fn object_to_string(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = match this {
+        Value::Undefined => return Ok(Value::String("[object Undefined]".into())),
+        Value::Null => return Ok(Value::String("[object Null]".into())),
+        _ => interpreter.to_object(this)?,
+    };
+    let builtin_tag = match &object.borrow().kind {
+        ObjectKind::Array(_) => "Array",
+        ObjectKind::Error => "Error",
+        ObjectKind::Date(_) => "Date",
+        ObjectKind::RegExp(_) => "RegExp",
+        ObjectKind::Primitive(Value::Boolean(_)) => "Boolean",
+        ObjectKind::Primitive(Value::Number(_)) => "Number",
+        ObjectKind::Primitive(Value::String(_)) => "String",
+        _ if object.is_callable() => "Function",
+        _ => "Object",
+    };
+    let tag = match interpreter.get_property(&Value::Object(object.clone()), &interpreter.realm.symbols.to_string_tag)? {
+        Value::String(tag) => tag,
+        _ => builtin_tag.to_string().into(),
+    };
+    Ok(Value::String(format!("[object {tag}]").into()))
+}
+
+/**
+ * Object.prototype.valueOf() -> the object itself, primitives being converted into objects.
+ */
+fn object_value_of(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Object(interpreter.to_object(this)?))
+}
+
+/**
+ * get Object.prototype.__proto__ -> the prototype of the object, like `Object.getPrototypeOf`.
+ */
+fn object_get_proto(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let object = interpreter.to_object(this)?;
+    Ok(interpreter.get_prototype_of(&object)?.map(Value::Object).unwrap_or(Value::Null))
+}
+
+/**
+ * set Object.prototype.__proto__ -> replaces the prototype of the object.
+ * Values other than objects & null are ignored, as are primitive receivers.
+ */
+fn object_set_proto(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    if let Value::Undefined | Value::Null = this {
+        return Err(Error::Type("Object.prototype.__proto__ called on null or undefined!".to_string()));
+    }
+    let prototype = match args.first() {
+        Some(Value::Object(prototype)) => Some(prototype.clone()),
+        Some(Value::Null) => None,
+        _ => return Ok(Value::Undefined),
+    };
+    if let Value::Object(object) = this {
+        set_prototype_or_throw(interpreter, object, prototype)?;
+    }
+    Ok(Value::Undefined)
+}
+
+/**
+ * Object.values(object) -> array of the values of the enumerable own string-keyed properties.
+ */
+fn object_values(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let values = enumerable_own_properties(interpreter, args.first().unwrap_or(&Value::Undefined))?.into_iter()
+        .map(|(_, value)| value)
+        .collect();
+    Ok(Value::Object(create_array(interpreter, values)))
+}
+
+/**
+ * Converts an object into a property descriptor, e.g. `{ get() { ... }, enumerable: true }`.
+ */
+pub fn to_property_descriptor(interpreter: &Interpreter, value: &Value) -> Result<PropertyDescriptor> {
+    let object = match value {
+        Value::Object(object) => object,
+        _ => return Err(Error::Type(format!("Property description must be an object: {value}!"))),
+    };
+    let field = |name: &str| -> Result<Option<Value>> {
+        match interpreter.has_property(object, &PropertyKey::from(name))? {
+            true => Ok(Some(interpreter.get_property(value, name)?)),
+            false => Ok(None),
+        }
+    };
+    let descriptor = PropertyDescriptor {
+        enumerable: field("enumerable")?.map(|value| value.to_boolean()),
+        configurable: field("configurable")?.map(|value| value.to_boolean()),
+        value: field("value")?,
+        writable: field("writable")?.map(|value| value.to_boolean()),
+        get: field("get")?,
+        set: field("set")?,
+    };
+    for (name, accessor) in [("Getter", &descriptor.get), ("Setter", &descriptor.set)] {
+        if let Some(accessor) = accessor.as_ref().filter(|accessor| !matches!(accessor, Value::Undefined) && !accessor.is_callable()) {
+            return Err(Error::Type(format!("{name} must be a function: {accessor}!")));
+        }
+    }
+    if descriptor.is_accessor() && descriptor.is_data() {
+        return Err(Error::Type(
+            "Invalid property descriptor. Cannot both specify accessors and a value or writable attribute!".to_string()
+        ));
+    }
+    Ok(descriptor)
+}
+
+/**
+ * Converts a property descriptor into an object, as returned by `Object.getOwnPropertyDescriptor`.
+ */
+pub fn from_property_descriptor(interpreter: &Interpreter, descriptor: PropertyDescriptor) -> Value {
+    let object = create_object(interpreter);
+    let fields = [
+        ("value", descriptor.value),
+        ("writable", descriptor.writable.map(Value::Boolean)),
+        ("get", descriptor.get),
+        ("set", descriptor.set),
+        ("enumerable", descriptor.enumerable.map(Value::Boolean)),
+        ("configurable", descriptor.configurable.map(Value::Boolean)),
+    ];
+    for (name, value) in fields {
+        if let Some(value) = value {
+            object.set_own_value(name, value);
+        }
+    }
+    Value::Object(object)
+}
+
+/**
+ * Defines the properties of the object according to the descriptors held by the enumerable own properties
+ * of the other object, e.g. for `Object.defineProperties`. Every descriptor is validated before any is applied.
+ */
+pub fn define_properties(interpreter: &Interpreter, object: &ObjectRef, descriptors: &Value) -> Result<()> {
+    let descriptors = interpreter.to_object(descriptors)?;
+    let mut definitions = vec![];
+    for key in interpreter.own_property_keys(&descriptors)? {
+        if interpreter.get_own_property(&descriptors, &key)?.is_some_and(|descriptor| descriptor.enumerable == Some(true)) {
+            let descriptor = interpreter.get_object_property(&descriptors, &key, &Value::Object(descriptors.clone()))?;
+            definitions.push((key, to_property_descriptor(interpreter, &descriptor)?));
+        }
+    }
+    for (key, descriptor) in definitions {
+        define_property_or_throw(interpreter, object, key, descriptor)?;
+    }
+    Ok(())
+}
+
+/**
+ * Defines a property through the [[DefineOwnProperty]] internal method, throwing if it's rejected,
+ * e.g. as the existing property is non-configurable or the object isn't extensible.
+ */
+pub fn define_property_or_throw(interpreter: &Interpreter, object: &ObjectRef, key: PropertyKey, descriptor: PropertyDescriptor) -> Result<()> {
+    if interpreter.define_own_property(object, &key, descriptor)? {
+        return Ok(());
+    }
+    Err(Error::Type(match object.proxy() {
+        Some(_) => format!("'defineProperty' on proxy: trap returned falsish for property '{key}'!"),
+        None if object.get_own_property(&key).is_none() && !object.is_extensible() => format!("Cannot define property {key}, object is not extensible!"),
+        None => format!("Cannot redefine property: {key}!"),
+    }))
+}
+
+fn set_prototype_or_throw(interpreter: &Interpreter, object: &ObjectRef, prototype: Option<ObjectRef>) -> Result<()> {
+    if interpreter.set_prototype_of(object, prototype)? {
+        return Ok(());
+    }
+    Err(Error::Type(match object.proxy() {
+        Some(_) => "'setPrototypeOf' on proxy: trap returned falsish!".to_string(),
+        None if !object.is_extensible() => format!("{object} is not extensible!"),
+        None => "Cyclic __proto__ value!".to_string(),
+    }))
+}
+
+/**
+ * Prevents the object from being extended & its properties from being deleted or redefined, e.g. `Object.seal`.
+ * Frozen objects' data properties are read-only too. Returns false if the object couldn't be made non-extensible.
+ */
+fn set_integrity_level(interpreter: &Interpreter, object: &ObjectRef, frozen: bool) -> Result<bool> {
+    if !interpreter.prevent_extensions(object)? {
+        return Ok(false);
+    }
+    for key in interpreter.own_property_keys(object)? {
+        let mut descriptor = PropertyDescriptor { configurable: Some(false), ..PropertyDescriptor::default() };
+        if frozen && interpreter.get_own_property(object, &key)?.is_some_and(|current| !current.is_accessor()) {
+            descriptor.writable = Some(false);
+        }
+        define_property_or_throw(interpreter, object, key, descriptor)?;
+    }
+    Ok(true)
+}
+
+/**
+ * Whether the object is sealed, or frozen, i.e. as if `set_integrity_level` had been called.
+ */
+fn test_integrity_level(interpreter: &Interpreter, object: &ObjectRef, frozen: bool) -> Result<bool> {
+    if interpreter.is_extensible(object)? {
+        return Ok(false);
+    }
+    for key in interpreter.own_property_keys(object)? {
+        if let Some(current) = interpreter.get_own_property(object, &key)? {
+            if current.configurable == Some(true) || (frozen && current.writable == Some(true)) {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/**
+ * Returns the prototype passed to `Object.create` & the like, which must be an object or null.
+ */
+fn prototype_argument(value: &Value) -> Result<Option<ObjectRef>> {
+    match value {
+        Value::Object(prototype) => Ok(Some(prototype.clone())),
+        Value::Null => Ok(None),
+        _ => Err(Error::Type(format!("Object prototype may only be an Object or null: {value}!"))),
+    }
+}
+
+/**
+ * Returns the keys & values of the enumerable own string-keyed properties of the value, e.g. for `Object.entries`.
+ */
+fn enumerable_own_properties(interpreter: &Interpreter, value: &Value) -> Result<Vec<(String, Value)>> {
+    let object = interpreter.to_object(value)?;
+    let mut properties = vec![];
+    for key in interpreter.own_property_keys(&object)? {
+        let name = match key.as_string() {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+
+        // Getters may remove or hide the properties yet to be visited
+        if interpreter.get_own_property(&object, &key)?.is_some_and(|descriptor| descriptor.enumerable == Some(true)) {
+            let value = interpreter.get_object_property(&object, &key, &Value::Object(object.clone()))?;
+            properties.push((name, value));
+        }
+    }
+    Ok(properties)
+}
+
+/**
+ * Returns the first argument of `Object.defineProperty` & the like, which must be an object.
+ */
+fn this_object_argument(args: &[Value], method: &str) -> Result<ObjectRef> {
+    match args.first() {
+        Some(Value::Object(object)) => Ok(object.clone()),
+        value => Err(Error::Type(format!("Object.{method} called on non-object {}!", value.unwrap_or(&Value::Undefined)))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_object_wrappers() {
+        let content = "
+            let n = Object(5);
+            let s = new Object('abc');
+            let o = Object();
+            o.x = 1;
+            '' + [n + 1, n.toFixed(1), s.length, s + 'd', o.x, Object(o) === o, n === 5, n == 5];
+        ";
+        assert_eval(content, Value::String("6,5.0,3,abcd,1,true,false,true".into()));
+        assert_eval("Object(null).constructor === Object;", Value::Boolean(true));
+    }
+
+    #[test]
+    fn test_define_property() {
+        let content = "
+            let o = new Object();
+            o.x = 1;
+            Object.defineProperty(o, 'hidden', JSON.parse('{\"value\":2}'));
+            o.hidden = 3;
+            Object.defineProperty(o, 'x', JSON.parse('{\"enumerable\":false}'));
+            let accessor = new Object();
+            accessor.get = function() { return this.hidden * 10; };
+            Object.defineProperty(o, 'computed', accessor);
+            o.computed = 0;
+            '' + [o.hidden, o.x, Object.keys(o).length, o.computed, JSON.stringify(Object.getOwnPropertyDescriptor(o, 'hidden'))];
+        ";
+        assert_eval(content, Value::String("2,1,0,20,{\"value\":2,\"writable\":false,\"enumerable\":false,\"configurable\":false}".into()));
+
+        let content = "
+            let descriptors = JSON.parse('{\"a\":{\"value\":1,\"enumerable\":true},\"b\":{\"value\":2,\"writable\":true}}');
+            let o = Object.defineProperties(new Object(), descriptors);
+            o.b = 3;
+            let all = Object.getOwnPropertyDescriptors([7]);
+            '' + [Object.keys(o), o.b, all[0].writable, all.length.enumerable, Object.getOwnPropertyDescriptor('ab', 1).value, Object.getOwnPropertyDescriptor(o, 'c')];
+        ";
+        assert_eval(content, Value::String("a,3,true,false,b,".into()));
+
+        let content = "
+            let o = Object.defineProperty(new Object(), 'x', JSON.parse('{\"value\":1}'));
+            Object.defineProperty(o, 'x', JSON.parse('{\"value\":1,\"writable\":false}'));
+            Object.defineProperty(o, 'x', JSON.parse('{\"value\":2}'));
+        ";
+        assert_eval_error(content, Error::Type("Cannot redefine property: x!".to_string()));
+        assert_eval_error("Object.defineProperty(1, 'x', new Object());", Error::Type("Object.defineProperty called on non-object 1!".to_string()));
+        assert_eval_error("Object.defineProperty(new Object(), 'x', 1);", Error::Type("Property description must be an object: 1!".to_string()));
+        assert_eval_error(
+            "Object.defineProperty(new Object(), 'x', JSON.parse('{\"get\":1}'));",
+            Error::Type("Getter must be a function: 1!".to_string()),
+        );
+        assert_eval_error(
+            "let d = JSON.parse('{\"value\":1}'); d.set = function() {}; Object.defineProperty(new Object(), 'x', d);",
+            Error::Type("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute!".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_integrity_levels() {
+        let content = "
+            let frozen = Object.freeze(JSON.parse('{\"x\":1}'));
+            frozen.x = 2;
+            frozen.y = 3;
+            let sealed = Object.seal(JSON.parse('{\"x\":1}'));
+            sealed.x = 2;
+            sealed.y = 3;
+            let closed = Object.preventExtensions(new Object());
+            '' + [frozen.x, frozen.y, sealed.x, sealed.y, Object.isFrozen(frozen), Object.isSealed(frozen), Object.isFrozen(sealed),
+                Object.isSealed(sealed), Object.isExtensible(sealed), Object.isFrozen(closed), Object.isFrozen(1), Object.isExtensible(1)];
+        ";
+        assert_eval(content, Value::String("1,,2,,true,true,false,true,false,true,true,false".into()));
+
+        let content = "
+            let a = Object.freeze([1, 2]);
+            a[0] = 5;
+            a.length = 0;
+            let b = Object.seal([1]);
+            b.reverse();
+            '' + [a, Object.isFrozen(a), b];
+        ";
+        assert_eval(content, Value::String("1,2,true,1".into()));
+        assert_eval_error(
+            "Object.freeze([1]).push(2);",
+            Error::Type("Cannot add property 1, object is not extensible!".to_string()),
+        );
+        assert_eval_error(
+            "Object.seal([1]).pop();",
+            Error::Type("Cannot delete property '0' of 1!".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_strict_mode_failures() {
+        // Failed writes throw in class bodies, which are strict mode code
+        let content = "
+            class Writer {
+                write(target, key) {
+                    target[key] = 1;
+                }
+            }
+            let o = Object.freeze(new Object());
+            new Writer().write(o, 'x');
+        ";
+        assert_eval_error(content, Error::Type("Cannot add property x, object is not extensible!".to_string()));
+        let content = "
+            class Writer {
+                write(target, key) {
+                    target[key] = 1;
+                }
+            }
+            let o = Object.defineProperty(new Object(), 'x', JSON.parse('{\"value\":0}'));
+            new Writer().write(o, 'x');
+        ";
+        assert_eval_error(content, Error::Type("Cannot assign to read only property 'x' of object '[object Object]'!".to_string()));
+        let content = "
+            class Reader {
+                get value() { return 1; }
+                update(value) { this.value = value; }
+            }
+            new Reader().update(2);
+        ";
+        assert_eval_error(content, Error::Type("Cannot assign to read only property 'value' of object '[object Object]'!".to_string()));
+    }
+
+    #[test]
+    fn test_prototype_chain() {
+        let content = "
+            function Animal(name) { this.name = name; }
+            Animal.prototype.speak = function() { return this.name + ' makes a sound'; };
+            function Dog(name) { this.name = name; }
+            Dog.prototype = Object.create(Animal.prototype);
+            Dog.prototype.bark = function() { return this.name + ' barks'; };
+            let d = new Dog('Rex');
+            '' + [d.speak(), d.bark(), d instanceof Animal, Animal.prototype.isPrototypeOf(d), Object.getPrototypeOf(d) === Dog.prototype,
+                Animal.prototype.constructor === Animal, Object.keys(Animal.prototype).length];
+        ";
+        assert_eval(content, Value::String("Rex makes a sound,Rex barks,true,true,true,true,1".into()));
+
+        let content = "
+            let base = new Object();
+            base.greet = function() { return 'hi ' + this.name; };
+            let o = Object.create(base, JSON.parse('{\"name\":{\"value\":\"Ann\",\"enumerable\":true}}'));
+            let bare = Object.create(null);
+            bare.__proto__ = base;
+            let other = new Object();
+            other.__proto__ = base;
+            '' + [o.greet(), Object.keys(o), o.__proto__ === base, Object.getPrototypeOf(bare), bare.__proto__ === base,
+                Object.getPrototypeOf(other) === base, Object.getPrototypeOf(Object.prototype), Object.getPrototypeOf(1) === Number.prototype];
+        ";
+        assert_eval(content, Value::String("hi Ann,name,true,,true,true,,true".into()));
+
+        let content = "
+            class Shape { area() { return 0; } }
+            let square = new Object();
+            square.side = 3;
+            square.area = function() { return this.side * this.side; };
+            let result = Object.setPrototypeOf(square, Shape.prototype);
+            '' + [result === square, square instanceof Shape, Object.setPrototypeOf(1, null), Object.getPrototypeOf(Object.setPrototypeOf(new Object(), null))];
+        ";
+        assert_eval(content, Value::String("true,true,1,".into()));
+
+        assert_eval_error("Object.create(1);", Error::Type("Object prototype may only be an Object or null: 1!".to_string()));
+        assert_eval_error("Object.setPrototypeOf(null, null);", Error::Type("Object.setPrototypeOf called on null or undefined!".to_string()));
+        assert_eval_error(
+            "let a = new Object(); let b = Object.create(a); Object.setPrototypeOf(a, b);",
+            Error::Type("Cyclic __proto__ value!".to_string()),
+        );
+        assert_eval_error("let a = new Object(); a.__proto__ = a;", Error::Type("Cyclic __proto__ value!".to_string()));
+        assert_eval_error(
+            "Object.setPrototypeOf(Object.preventExtensions(new Object()), null);",
+            Error::Type("[object Object] is not extensible!".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_keys_values_entries_assign() {
+        let content = "
+            let o = JSON.parse('{\"b\":1,\"a\":2}');
+            o[Symbol.iterator] = 3;
+            Object.defineProperty(o, 'hidden', JSON.parse('{\"value\":4}'));
+            let target = Object.assign(new Object(), null, o, 'xy');
+            '' + [Object.keys(o), Object.values(o), Object.entries(o).join(';'), Object.keys('ab'), Object.keys(target), target[Symbol.iterator], target.hidden];
+        ";
+        assert_eval(content, Value::String("b,a,1,2,b,1;a,2,0,1,0,1,b,a,3,".into()));
+        assert_eval_error(
+            "Object.assign(Object.freeze(new Object()), JSON.parse('{\"x\":1}'));",
+            Error::Type("Cannot assign to read only property 'x' of object '[object Object]'!".to_string()),
+        );
+        assert_eval_error("Object.keys(null);", Error::Type("Cannot convert undefined or null to object!".to_string()));
+    }
+
+    #[test]
+    fn test_object_prototype_methods() {
+        let content = "
+            let o = new Object();
+            o.a = 1;
+            let tagged = new Object();
+            tagged[Symbol.toStringTag] = 'Custom';
+            let toString = Object.prototype.toString;
+            '' + [
+                o.hasOwnProperty('a'), o.hasOwnProperty('toString'), [5].hasOwnProperty(0), Object.prototype.hasOwnProperty.call('ab', 'length'),
+                o.toString(), String(o), toString.call([]), toString.call(null), toString.call(undefined), toString.call(1),
+                toString.call(function() {}), toString.call(new Error('x')), toString.call(new Map()), toString.call(tagged),
+                o.valueOf() === o, Object.prototype.valueOf.call(1) instanceof Number, Object.prototype.isPrototypeOf(o)
+            ];
+        ";
+        let expected = "true,false,true,true,[object Object],[object Object],[object Array],[object Null],[object Undefined],[object Number],\
+            [object Function],[object Error],[object Map],[object Custom],true,true,true";
+        assert_eval(content, Value::String(expected.into()));
+        assert_eval_error("Object.prototype.valueOf.call(null);", Error::Type("Cannot convert undefined or null to object!".to_string()));
+    }
+
+    #[test]
+    fn test_object_is() {
+        assert_eval("'' + [Object.is(NaN, NaN), Object.is(0, -0), Object.is('a', 'a'), Object.is(new Object(), new Object()), Object.is()];",
+            Value::String("true,false,true,false,true".into()));
+    }
+
+    #[test]
+    fn test_global_value_properties() {
+        let content = "
+            undefined = 1;
+            NaN = 2;
+            let descriptor = Object.getOwnPropertyDescriptor(globalThis, 'undefined');
+            '' + [undefined === globalThis.missing, NaN, Infinity, descriptor.writable, descriptor.configurable, Reflect.deleteProperty(globalThis, 'NaN')];
+        ";
+        assert_eval(content, Value::String("true,NaN,Infinity,false,false,false".into()));
+        assert_eval_error(
+            "'use strict'; undefined = 1;",
+            Error::Type("Cannot assign to read only property 'undefined' of object '[object Object]'!".to_string()),
+        );
+    }
+}
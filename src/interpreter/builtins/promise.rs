@@ -0,0 +1,480 @@
+use crate::prelude::*;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::exceptions::ExceptionEvalable;
+use crate::interpreter::iteration::Iterable;
+use crate::interpreter::promises::{PromiseCapability, PromiseOperations, PromiseState};
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::array::create_array;
+use super::error::create_aggregate_error;
+use super::object::create_object;
+use super::{define_global, native_closure, native_function};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 3] = [
+        ("then", 2, promise_then),
+        ("catch", 1, promise_catch),
+        ("finally", 1, promise_finally),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Promise".into())));
+
+    let constructor = native_function(realm, "Promise", 1, construct_promise, true);
+    let statics: [(&str, usize, NativeFn); 6] = [
+        ("resolve", 1, promise_resolve),
+        ("reject", 1, promise_reject),
+        ("all", 1, promise_all),
+        ("allSettled", 1, promise_all_settled),
+        ("race", 1, promise_race),
+        ("any", 1, promise_any),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Promise%", constructor.clone());
+    realm.define_intrinsic("%Promise.prototype%", prototype);
+    define_global(global, "Promise", Value::Object(constructor));
+}
+
+/**
+ * new Promise(executor) -> calls the executor with the functions settling the promise.
+ */
+fn construct_promise(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    let new_target = match new_target {
+        Some(new_target) => new_target,
+        None => return Err(Error::Type("Promise constructor cannot be invoked without 'new'!".to_string())),
+    };
+    let executor = args.first().cloned().unwrap_or(Value::Undefined);
+    if !executor.is_callable() {
+        return Err(Error::Type(format!("Promise resolver {executor} is not a function!")));
+    }
+
+    // Subclasses construct promises with their own prototype
+    let prototype = match interpreter.get_property(&Value::Object(new_target.clone()), "prototype")? {
+        Value::Object(prototype) => Some(prototype),
+        _ => interpreter.realm.intrinsic("%Promise.prototype%"),
+    };
+    let promise = ObjectRef::new(ObjectKind::Promise(PromiseState::default()), prototype);
+
+    // Exceptions thrown by the executor reject the promise
+    let (resolve, reject) = interpreter.create_resolving_functions(&promise);
+    if let Err(error) = interpreter.call(&executor, &Value::Undefined, &[resolve, reject.clone()]) {
+        let exception = interpreter.to_exception(error)?;
+        interpreter.call(&reject, &Value::Undefined, &[exception])?;
+    }
+    Ok(Value::Object(promise))
+}
+
+/**
+ * Promise.prototype.then(onFulfilled, onRejected) -> promise settled with the result of the handler.
+ */
+fn promise_then(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let promise = match this {
+        Value::Object(object) if object.is_promise() => object,
+        _ => return Err(Error::Type(format!("Method Promise.prototype.then called on incompatible receiver {this}!"))),
+    };
+
+    // Derived promises are created by the constructor of the promise, e.g. a subclass
+    let constructor = match interpreter.get_property(this, "constructor")? {
+        Value::Undefined => interpreter.promise_constructor(),
+        constructor => constructor,
+    };
+    let capability = interpreter.new_promise_capability(&constructor)?;
+
+    let on_fulfilled = args.first().cloned().unwrap_or(Value::Undefined);
+    let on_rejected = args.get(1).cloned().unwrap_or(Value::Undefined);
+    Ok(interpreter.perform_promise_then(promise, &on_fulfilled, &on_rejected, Some(capability)))
+}
+
+/**
+ * Promise.prototype.catch(onRejected) -> this.then(undefined, onRejected)
+ */
+fn promise_catch(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let on_rejected = args.first().cloned().unwrap_or(Value::Undefined);
+    let then = interpreter.get_property(this, "then")?;
+    interpreter.call(&then, this, &[Value::Undefined, on_rejected])
+}
+
+/**
+ * Promise.prototype.finally(onFinally) -> runs the handler once settled, passing the result through.
+ */
+fn promise_finally(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    if !matches!(this, Value::Object(_)) {
+        return Err(Error::Type(format!("Method Promise.prototype.finally called on incompatible receiver {this}!")));
+    }
+    let on_finally = args.first().cloned().unwrap_or(Value::Undefined);
+    let then = interpreter.get_property(this, "then")?;
+    if !on_finally.is_callable() {
+        return interpreter.call(&then, this, &[on_finally.clone(), on_finally]);
+    }
+
+    let constructor = match interpreter.get_property(this, "constructor")? {
+        Value::Undefined => interpreter.promise_constructor(),
+        constructor => constructor,
+    };
+
+    // Both handlers wait for the promise returned by `onFinally` before passing the result on
+    let (handler, target) = (on_finally.clone(), constructor.clone());
+    let then_finally = native_closure(&interpreter.realm, "", 1, move |interpreter, _this, args, _new_target| {
+        let value = args.first().cloned().unwrap_or(Value::Undefined);
+        let result = interpreter.call(&handler, &Value::Undefined, &[])?;
+        let promise = interpreter.promise_resolve(&target, result)?;
+        let value_thunk = native_closure(&interpreter.realm, "", 0, move |_interpreter, _this, _args, _new_target| Ok(value.clone()), false);
+        let then = interpreter.get_property(&promise, "then")?;
+        interpreter.call(&then, &promise, &[Value::Object(value_thunk)])
+    }, false);
+
+    let (handler, target) = (on_finally, constructor);
+    let catch_finally = native_closure(&interpreter.realm, "", 1, move |interpreter, _this, args, _new_target| {
+        let reason = args.first().cloned().unwrap_or(Value::Undefined);
+        let result = interpreter.call(&handler, &Value::Undefined, &[])?;
+        let promise = interpreter.promise_resolve(&target, result)?;
+        let thrower = native_closure(&interpreter.realm, "", 0, move |_interpreter, _this, _args, _new_target| {
+            Err(Error::Abrupt(Completion::Throw(reason.clone())))
+        }, false);
+        let then = interpreter.get_property(&promise, "then")?;
+        interpreter.call(&then, &promise, &[Value::Object(thrower)])
+    }, false);
+
+    interpreter.call(&then, this, &[Value::Object(then_finally), Value::Object(catch_finally)])
+}
+
+/**
+ * Promise.resolve(value) -> promise fulfilled with the value, or the value itself if it's a promise.
+ */
+fn promise_resolve(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    if !matches!(this, Value::Object(_)) {
+        return Err(Error::Type("PromiseResolve called on non-object!".to_string()));
+    }
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    interpreter.promise_resolve(this, value)
+}
+
+/**
+ * Promise.reject(reason) -> promise rejected with the reason.
+ */
+fn promise_reject(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let reason = args.first().cloned().unwrap_or(Value::Undefined);
+    let capability = interpreter.new_promise_capability(this)?;
+    interpreter.call(&capability.reject, &Value::Undefined, &[reason])?;
+    Ok(capability.promise)
+}
+
+/**
+ * Promise.all(iterable) -> promise fulfilled with all the values, or rejected with the first reason.
+ */
+fn promise_all(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    combine(interpreter, this, args, Combinator::All)
+}
+
+/**
+ * Promise.allSettled(iterable) -> promise fulfilled with the outcome of each promise.
+ */
+fn promise_all_settled(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    combine(interpreter, this, args, Combinator::AllSettled)
+}
+
+/**
+ * Promise.race(iterable) -> promise settled like the first promise to settle.
+ */
+fn promise_race(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    combine(interpreter, this, args, Combinator::Race)
+}
+
+/**
+ * Promise.any(iterable) -> promise fulfilled with the first value, or rejected with all the reasons.
+ */
+fn promise_any(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    combine(interpreter, this, args, Combinator::Any)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Combinator {
+    All,
+    AllSettled,
+    Race,
+    Any,
+}
+
+/**
+ * Results collected by a combinator, settling its promise once the last one comes in.
+ */
+struct Aggregate {
+    capability: PromiseCapability,
+    combinator: Combinator,
+    results: RefCell<Vec<Value>>,
+    remaining: Cell<usize>,
+}
+
+impl Aggregate {
+    fn finish(&self, interpreter: &Interpreter) -> Result<()> {
+        let remaining = self.remaining.get() - 1;
+        self.remaining.set(remaining);
+        if remaining > 0 {
+            return Ok(());
+        }
+
+        let results = self.results.borrow().clone();
+        match self.combinator {
+            Combinator::Any => {
+                let error = create_aggregate_error(interpreter, results, "All promises were rejected");
+                interpreter.call(&self.capability.reject, &Value::Undefined, &[error])?;
+            },
+            _ => {
+                let array = Value::Object(create_array(interpreter, results));
+                interpreter.call(&self.capability.resolve, &Value::Undefined, &[array])?;
+            },
+        }
+        Ok(())
+    }
+
+    /**
+     * Creates the handler storing the result of the element at the index, only the first call counting.
+     */
+    fn element_function(interpreter: &Interpreter, aggregate: &Rc<Aggregate>, index: usize, status: Option<&'static str>) -> Value {
+        let (aggregate, already_called) = (aggregate.clone(), Cell::new(false));
+        let function = native_closure(&interpreter.realm, "", 1, move |interpreter, _this, args, _new_target| {
+            if already_called.replace(true) {
+                return Ok(Value::Undefined);
+            }
+            let value = args.first().cloned().unwrap_or(Value::Undefined);
+
+            // Promise.allSettled describes each outcome, e.g. `{ status: 'rejected', reason }`
+            let value = match status {
+                None => value,
+                Some(status) => {
+                    let outcome = create_object(interpreter);
+                    outcome.set_own_value("status".to_string(), Value::String(status.to_string().into()));
+                    let key = if status == "fulfilled" { "value" } else { "reason" };
+                    outcome.set_own_value(key.to_string(), value);
+                    Value::Object(outcome)
+                },
+            };
+            aggregate.results.borrow_mut()[index] = value;
+            aggregate.finish(interpreter)?;
+            Ok(Value::Undefined)
+        }, false);
+        Value::Object(function)
+    }
+}
+
+/**
+ * Resolves each value of the iterable through the constructor's `resolve`,
+ * combining their outcomes into the returned promise.
+ */
+fn combine(interpreter: &Interpreter, constructor: &Value, args: &[Value], combinator: Combinator) -> Result<Value> {
+    let capability = interpreter.new_promise_capability(constructor)?;
+    let iterable = args.first().cloned().unwrap_or(Value::Undefined);
+
+    // Failures while iterating reject the promise instead of throwing
+    if let Err(error) = combine_values(interpreter, constructor, &iterable, &capability, combinator) {
+        let exception = interpreter.to_exception(error)?;
+        interpreter.call(&capability.reject, &Value::Undefined, &[exception])?;
+    }
+    Ok(capability.promise)
+}
+
+fn combine_values(
+    interpreter: &Interpreter,
+    constructor: &Value,
+    iterable: &Value,
+    capability: &PromiseCapability,
+    combinator: Combinator,
+) -> Result<()> {
+    let resolve = interpreter.get_property(constructor, "resolve")?;
+    if !resolve.is_callable() {
+        return Err(Error::Type(format!("{resolve} is not a function!")));
+    }
+    let values = interpreter.iterate(iterable)?;
+
+    // The count starts at one, so that the promise doesn't settle while still iterating
+    let aggregate = Rc::new(Aggregate {
+        capability: capability.clone(),
+        combinator,
+        results: RefCell::new(vec![Value::Undefined; values.len()]),
+        remaining: Cell::new(1),
+    });
+
+    for (index, value) in values.into_iter().enumerate() {
+        let promise = interpreter.call(&resolve, constructor, &[value])?;
+        let (on_fulfilled, on_rejected) = match combinator {
+            Combinator::All => (Aggregate::element_function(interpreter, &aggregate, index, None), capability.reject.clone()),
+            Combinator::AllSettled => (
+                Aggregate::element_function(interpreter, &aggregate, index, Some("fulfilled")),
+                Aggregate::element_function(interpreter, &aggregate, index, Some("rejected")),
+            ),
+            Combinator::Race => (capability.resolve.clone(), capability.reject.clone()),
+            Combinator::Any => (capability.resolve.clone(), Aggregate::element_function(interpreter, &aggregate, index, None)),
+        };
+        aggregate.remaining.set(aggregate.remaining.get() + 1);
+
+        let then = interpreter.get_property(&promise, "then")?;
+        interpreter.call(&then, &promise, &[on_fulfilled, on_rejected])?;
+    }
+
+    // Races never settle by running out of promises
+    match combinator {
+        Combinator::Race => Ok(()),
+        _ => aggregate.finish(interpreter),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_promise_constructor() {
+        assert_eval("'' + new Promise(function() {});", Value::String("[object Promise]".into()));
+        assert_eval_error("Promise(function() {});", Error::Type("Promise constructor cannot be invoked without 'new'!".to_string()));
+        assert_eval_error("new Promise(1);", Error::Type("Promise resolver 1 is not a function!".to_string()));
+        assert_binding("let x; new Promise(function() { throw 'oops'; }).catch(function(e) { x = e; });", "x", "oops");
+    }
+
+    #[test]
+    fn test_promise_settles_once() {
+        let content = "
+            let x;
+            new Promise(function(resolve, reject) {
+                resolve(1);
+                reject(2);
+                resolve(3);
+            }).then(function(v) { x = v; }, function(e) { x = 'rejected'; });
+        ";
+        assert_binding(content, "x", "1");
+    }
+
+    #[test]
+    fn test_then_chaining() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            Promise.resolve(1)
+                .then(function(v) { log(v); return v + 1; })
+                .then(function(v) { log(v); throw v + 1; })
+                .then(function(v) { log('skipped'); })
+                .catch(function(e) { log(e); return Promise.resolve(e + 1); })
+                .finally(function() { log('finally'); return 'ignored'; })
+                .then(function(v) { log(v); });
+        ";
+        assert_binding(content, "out", "1,2,3,finally,4");
+    }
+
+    #[test]
+    fn test_finally_rejections() {
+        let content = "
+            let x;
+            Promise.reject('first')
+                .finally(function() {})
+                .catch(function(e) { x = e; return Promise.resolve(); })
+                .finally(function() { throw x + ' second'; })
+                .catch(function(e) { x = e; });
+        ";
+        assert_binding(content, "x", "first second");
+    }
+
+    #[test]
+    fn test_microtask_ordering() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            Promise.resolve().then(function() { log('a1'); }).then(function() { log('a2'); });
+            Promise.resolve().then(function() { log('b1'); }).then(function() { log('b2'); });
+            log('sync');
+        ";
+        assert_binding(content, "out", "sync,a1,b1,a2,b2");
+    }
+
+    #[test]
+    fn test_thenable_adoption() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            class Thenable { then(resolve) { log('then'); resolve(42); } }
+            Promise.resolve(new Thenable()).then(log);
+            log('sync');
+        ";
+        assert_binding(content, "out", "sync,then,42");
+    }
+
+    #[test]
+    fn test_chaining_cycle() {
+        let content = "
+            let x, p;
+            p = Promise.resolve().then(function() { return p; });
+            p.catch(function(e) { x = e; });
+        ";
+        assert_binding(content, "x", "TypeError: Chaining cycle detected for promise #<Promise>");
+    }
+
+    #[test]
+    fn test_promise_all() {
+        let content = "
+            let x;
+            let slow = new Promise(function(resolve) { resolve(Promise.resolve(1)); });
+            Promise.all([slow, 2, Promise.resolve(3)]).then(function(v) { x = v; });
+        ";
+        assert_binding(content, "x", "1,2,3");
+        assert_binding("let x; Promise.all([]).then(function(v) { x = v.length; });", "x", "0");
+
+        let content = "
+            let x;
+            Promise.all([Promise.resolve(1), Promise.reject('no'), Promise.reject('nope')]).catch(function(e) { x = e; });
+        ";
+        assert_binding(content, "x", "no");
+        assert_binding("let x; Promise.all(1).catch(function(e) { x = e.name; });", "x", "TypeError");
+    }
+
+    #[test]
+    fn test_promise_all_settled() {
+        let content = "
+            let x;
+            Promise.allSettled([Promise.resolve(1), Promise.reject('no')]).then(function(v) {
+                x = v[0].status + ' ' + v[0].value + ' ' + v[1].status + ' ' + v[1].reason;
+            });
+        ";
+        assert_binding(content, "x", "fulfilled 1 rejected no");
+    }
+
+    #[test]
+    fn test_promise_race() {
+        let content = "
+            let x;
+            let later = Promise.resolve().then(function() { return 'later'; });
+            Promise.race([later, Promise.resolve('first')]).then(function(v) { x = v; });
+        ";
+        assert_binding(content, "x", "first");
+        assert_binding("let x; Promise.race([Promise.reject('no'), 1]).catch(function(e) { x = e; });", "x", "no");
+    }
+
+    #[test]
+    fn test_promise_any() {
+        let content = "
+            let x;
+            Promise.any([Promise.reject('no'), Promise.resolve(2), 3]).then(function(v) { x = v; });
+        ";
+        assert_binding(content, "x", "2");
+
+        let content = "
+            let x;
+            Promise.any([Promise.reject('a'), Promise.reject('b')]).catch(function(e) {
+                x = e.name + ': ' + e.message + ' (' + e.errors + ')';
+            });
+        ";
+        assert_binding(content, "x", "AggregateError: All promises were rejected (a,b)");
+    }
+}
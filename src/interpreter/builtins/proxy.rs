@@ -0,0 +1,192 @@
+use crate::prelude::*;
+
+use crate::interpreter::realm::Realm;
+
+use super::object::create_object;
+use super::{define_global, native_closure, native_function};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    // Proxies have no prototype of their own, their instances forwarding to the target's
+    let constructor = native_function(realm, "Proxy", 2, construct_proxy, true);
+    let revocable = native_function(realm, "revocable", 2, proxy_revocable, false);
+    constructor.define_non_enumerable("revocable".to_string(), Property::Data(Value::Object(revocable)));
+
+    define_global(global, "Proxy", Value::Object(constructor));
+}
+
+/**
+ * new Proxy(target, handler) -> object whose operations are intercepted by the traps of the handler.
+ */
+fn construct_proxy(_interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_none() {
+        return Err(Error::Type("Constructor Proxy requires 'new'!".to_string()));
+    }
+    Ok(Value::Object(create_proxy(args)?))
+}
+
+/**
+ * Proxy.revocable(target, handler) -> `{ proxy, revoke }`, calling `revoke()` disabling the proxy for good.
+ */
+fn proxy_revocable(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let proxy = create_proxy(args)?;
+    let revoked = proxy.clone();
+    let revoke = native_closure(&interpreter.realm, "", 0, move |_interpreter, _this, _args, _new_target| {
+        if let ObjectKind::Proxy(state) = &mut revoked.borrow_mut().kind {
+            state.handler = None;
+        }
+        Ok(Value::Undefined)
+    }, false);
+
+    let result = create_object(interpreter);
+    result.set_own_value("proxy", Value::Object(proxy));
+    result.set_own_value("revoke", Value::Object(revoke));
+    Ok(Value::Object(result))
+}
+
+fn create_proxy(args: &[Value]) -> Result<ObjectRef> {
+    match (args.first(), args.get(1)) {
+        (Some(Value::Object(target)), Some(Value::Object(handler))) => Ok(ObjectRef::new(ObjectKind::Proxy(ProxyState {
+            target: target.clone(),
+            handler: Some(handler.clone()),
+        }), None)),
+        _ => Err(Error::Type("Cannot create proxy with a non-object as target or handler!".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_proxy_traps() {
+        let content = "
+            let log = [];
+            let target = new Object();
+            target.a = 1;
+            let handler = new Object();
+            handler.get = function(t, key, receiver) { log.push('get ' + key); return Reflect.get(t, key, receiver); };
+            handler.set = function(t, key, value) { log.push('set ' + key); t[key] = value * 2; return true; };
+            handler.has = function(t, key) { return key === 'hidden' || Reflect.has(t, key); };
+            handler.deleteProperty = function(t, key) { log.push('delete ' + key); return true; };
+            handler.ownKeys = function(t) { return ['a', 'b']; };
+            let p = new Proxy(target, handler);
+            p.b = 2;
+            '' + [p.a, p.b, target.b, Reflect.has(p, 'hidden'), Reflect.has(p, 'c'), Reflect.deleteProperty(p, 'a'), Reflect.ownKeys(p), log];
+        ";
+        assert_eval(content, Value::String("1,4,4,true,false,true,a,b,set b,get a,get b,delete a".into()));
+
+        let content = "
+            let handler = new Object();
+            handler.apply = function(t, self, args) { return t(...args) * 10; };
+            handler.construct = function(t, args) { let o = new Object(); o.args = args; return o; };
+            let add = new Proxy(function(a, b) { return a + b; }, handler);
+            '' + [add(1, 2), new add(3, 4).args, Reflect.apply(add, null, [2, 3])];
+        ";
+        assert_eval(content, Value::String("30,3,4,50".into()));
+
+        // Operations without a trap are forwarded to the target
+        let content = "
+            let target = [1, 2];
+            let p = new Proxy(target, new Object());
+            p[2] = 3;
+            '' + [p.length, target, Object.keys(p), Object.getPrototypeOf(p) === Array.prototype];
+        ";
+        assert_eval(content, Value::String("3,1,2,3,0,1,2,true".into()));
+    }
+
+    #[test]
+    fn test_proxy_invariants() {
+        let content = "
+            let target = Object.freeze(new Object());
+            let handler = new Object();
+            handler.ownKeys = function() { return ['x']; };
+            Reflect.ownKeys(new Proxy(target, handler));
+        ";
+        assert_eval_error(content, Error::Type("'ownKeys' on proxy: trap returned extra keys but proxy target is non-extensible!".to_string()));
+
+        let content = "
+            let target = Object.defineProperty(new Object(), 'x', JSON.parse('{\"value\":1}'));
+            let handler = new Object();
+            handler.get = function() { return 2; };
+            new Proxy(target, handler).x;
+        ";
+        assert_eval_error(content, Error::Type(
+            "'get' on proxy: property 'x' is a read-only and non-configurable data property on the proxy target but the proxy did not return its actual value (expected '1' but got '2')!".to_string()
+        ));
+
+        let content = "
+            let handler = new Object();
+            handler.get = 1;
+            new Proxy(new Object(), handler).x;
+        ";
+        assert_eval_error(content, Error::Type("'get' on proxy: trap 1 is not a function!".to_string()));
+        assert_eval_error("Proxy(new Object(), new Object());", Error::Type("Constructor Proxy requires 'new'!".to_string()));
+        assert_eval_error("new Proxy(1, new Object());", Error::Type("Cannot create proxy with a non-object as target or handler!".to_string()));
+    }
+
+    #[test]
+    fn test_proxy_revocable() {
+        let content = "
+            let revocable = Proxy.revocable(new Object(), new Object());
+            revocable.proxy.x = 1;
+            let before = revocable.proxy.x;
+            revocable.revoke();
+            revocable.revoke();
+            let message;
+            try { revocable.proxy.x; } catch (e) { message = e.message; }
+            '' + [before, message];
+        ";
+        assert_eval(content, Value::String("1,Cannot perform 'get' on a proxy that has been revoked".into()));
+    }
+
+    #[test]
+    fn test_proxied_arrays() {
+        let content = "
+            let log = [];
+            let handler = new Object();
+            handler.get = function(target, key, receiver) {
+                log.push(String(key));
+                return Reflect.get(target, key, receiver);
+            };
+            let proxy = new Proxy([1, 2], handler);
+            let spread = [...proxy];
+            let iterated = [];
+            for (const value of proxy) {
+                iterated.push(value);
+            }
+            '' + [
+                Array.isArray(proxy), Array.isArray(new Proxy(new Object(), handler)), spread.join('|'), iterated.join('|'),
+                log[0], [0].concat(proxy).length, JSON.stringify(new Proxy([1], new Object()))
+            ];
+        ";
+        assert_eval(content, Value::String("true,false,1|2,1|2,Symbol(Symbol.iterator),3,[1]".into()));
+
+        let content = "
+            let revocable = Proxy.revocable([], new Object());
+            revocable.revoke();
+            Array.isArray(revocable.proxy);
+        ";
+        assert_eval_error(content, Error::Type("Cannot perform 'IsArray' on a proxy that has been revoked!".to_string()));
+    }
+
+    #[test]
+    fn test_deeply_nested_proxies() {
+        // Each proxy forwards to the next one, deeper than the stack allows recursing
+        let handler = ObjectRef::new(ObjectKind::Ordinary, None);
+        let mut proxy = ObjectRef::new(ObjectKind::Array(ArrayElements::from(vec![])), None);
+        for _ in 0..200_000 {
+            proxy = ObjectRef::new(ObjectKind::Proxy(ProxyState { target: proxy, handler: Some(handler.clone()) }), None);
+        }
+        let mut env = EnvironmentBuilder::default().build().unwrap();
+        env.define("proxy".to_string(), Value::Object(proxy));
+
+        let content = "
+            let errors = [];
+            try { proxy.x; } catch (error) { errors.push(error.name); }
+            try { proxy.x = 1; } catch (error) { errors.push(error.name); }
+            '' + [errors, Array.isArray(proxy)];
+        ";
+        assert_value_env(parse(content), &mut env, Value::String("RangeError,RangeError,true".into()));
+    }
+}
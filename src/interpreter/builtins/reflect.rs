@@ -0,0 +1,231 @@
+use crate::prelude::*;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::array::{create_array, list_from_array_like};
+use super::object::{from_property_descriptor, to_property_descriptor};
+use super::{define_global, native_function};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let reflect = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 13] = [
+        ("apply", 3, reflect_apply),
+        ("construct", 2, reflect_construct),
+        ("defineProperty", 3, reflect_define_property),
+        ("deleteProperty", 2, reflect_delete_property),
+        ("get", 2, reflect_get),
+        ("getOwnPropertyDescriptor", 2, reflect_get_own_property_descriptor),
+        ("getPrototypeOf", 1, reflect_get_prototype_of),
+        ("has", 2, reflect_has),
+        ("isExtensible", 1, reflect_is_extensible),
+        ("ownKeys", 1, reflect_own_keys),
+        ("preventExtensions", 1, reflect_prevent_extensions),
+        ("set", 3, reflect_set),
+        ("setPrototypeOf", 2, reflect_set_prototype_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        reflect.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    reflect.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Reflect".into())));
+
+    define_global(global, "Reflect", Value::Object(reflect));
+}
+
+/**
+ * Reflect.apply(target, thisArgument, args) -> result of calling the target with the array-like arguments.
+ */
+fn reflect_apply(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = args.first().cloned().unwrap_or(Value::Undefined);
+    if !target.is_callable() {
+        return Err(Error::Type(format!("Function.prototype.apply was called on {target}, which is not a function!")));
+    }
+    let arguments = list_from_array_like(interpreter, args.get(2).unwrap_or(&Value::Undefined))?;
+    interpreter.call(&target, args.get(1).unwrap_or(&Value::Undefined), &arguments)
+}
+
+/**
+ * Reflect.construct(target, args, newTarget) -> `new target(...args)`, instances inheriting from `newTarget.prototype`.
+ */
+fn reflect_construct(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = args.first().cloned().unwrap_or(Value::Undefined);
+    let new_target = match args.get(2).unwrap_or(&target) {
+        Value::Object(new_target) if new_target.is_constructor() => new_target.clone(),
+        value => return Err(Error::Type(format!("{value} is not a constructor!"))),
+    };
+    if !matches!(&target, Value::Object(object) if object.is_constructor()) {
+        return Err(Error::Type(format!("{target} is not a constructor!")));
+    }
+    let arguments = list_from_array_like(interpreter, args.get(1).unwrap_or(&Value::Undefined))?;
+    interpreter.construct(&target, &arguments, &new_target)
+}
+
+/**
+ * Reflect.defineProperty(target, key, descriptor) -> whether the property could be defined, unlike
+ * `Object.defineProperty` which throws otherwise.
+ */
+fn reflect_define_property(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "defineProperty")?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    let descriptor = to_property_descriptor(interpreter, args.get(2).unwrap_or(&Value::Undefined))?;
+    Ok(Value::Boolean(interpreter.define_own_property(&target, &key, descriptor)?))
+}
+
+/**
+ * Reflect.deleteProperty(target, key) -> whether the property is gone, i.e. it wasn't non-configurable.
+ */
+fn reflect_delete_property(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "deleteProperty")?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    Ok(Value::Boolean(interpreter.delete_property(&target, &key)?))
+}
+
+/**
+ * Reflect.get(target, key, receiver) -> value of the property, getters being called with the receiver.
+ */
+fn reflect_get(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "get")?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    let receiver = args.get(2).cloned().unwrap_or_else(|| Value::Object(target.clone()));
+    interpreter.get_object_property(&target, key, &receiver)
+}
+
+/**
+ * Reflect.getOwnPropertyDescriptor(target, key) -> descriptor of the own property, or undefined.
+ */
+fn reflect_get_own_property_descriptor(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "getOwnPropertyDescriptor")?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    match interpreter.get_own_property(&target, &key)? {
+        Some(descriptor) => Ok(from_property_descriptor(interpreter, descriptor)),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/**
+ * Reflect.getPrototypeOf(target) -> the prototype of the target, or null.
+ */
+fn reflect_get_prototype_of(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "getPrototypeOf")?;
+    Ok(interpreter.get_prototype_of(&target)?.map(Value::Object).unwrap_or(Value::Null))
+}
+
+/**
+ * Reflect.has(target, key) -> whether the property exists on the target or its prototype chain.
+ */
+fn reflect_has(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "has")?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    Ok(Value::Boolean(interpreter.has_property(&target, &key)?))
+}
+
+/**
+ * Reflect.isExtensible(target) -> whether properties can be added to the target.
+ */
+fn reflect_is_extensible(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "isExtensible")?;
+    Ok(Value::Boolean(interpreter.is_extensible(&target)?))
+}
+
+/**
+ * Reflect.ownKeys(target) -> array of the own string & symbol keys, including non-enumerable ones.
+ */
+fn reflect_own_keys(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "ownKeys")?;
+    let keys = interpreter.own_property_keys(&target)?.iter().map(PropertyKey::to_value).collect();
+    Ok(Value::Object(create_array(interpreter, keys)))
+}
+
+/**
+ * Reflect.preventExtensions(target) -> whether the target is no longer extensible.
+ */
+fn reflect_prevent_extensions(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "preventExtensions")?;
+    Ok(Value::Boolean(interpreter.prevent_extensions(&target)?))
+}
+
+/**
+ * Reflect.set(target, key, value, receiver) -> whether the property could be written, setters being called with the receiver.
+ */
+fn reflect_set(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "set")?;
+    let key = interpreter.to_property_key(args.get(1).unwrap_or(&Value::Undefined))?;
+    let value = args.get(2).cloned().unwrap_or(Value::Undefined);
+    let receiver = args.get(3).cloned().unwrap_or_else(|| Value::Object(target.clone()));
+    Ok(Value::Boolean(interpreter.set_object_property(&target, key, value, &receiver)?))
+}
+
+/**
+ * Reflect.setPrototypeOf(target, prototype) -> whether the prototype could be replaced.
+ */
+fn reflect_set_prototype_of(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let target = target_argument(args, "setPrototypeOf")?;
+    let prototype = match args.get(1) {
+        Some(Value::Object(prototype)) => Some(prototype.clone()),
+        Some(Value::Null) => None,
+        value => return Err(Error::Type(format!(
+            "Object prototype may only be an Object or null: {}!", value.unwrap_or(&Value::Undefined)
+        ))),
+    };
+    Ok(Value::Boolean(interpreter.set_prototype_of(&target, prototype)?))
+}
+
+/**
+ * Returns the target passed to a `Reflect` method, which must be an object.
+ */
+fn target_argument(args: &[Value], method: &str) -> Result<ObjectRef> {
+    match args.first() {
+        Some(Value::Object(target)) => Ok(target.clone()),
+        _ => Err(Error::Type(format!("Reflect.{method} called on non-object!"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_reflect_properties() {
+        let content = "
+            let o = new Object();
+            o.x = 1;
+            let frozen = Object.freeze(new Object());
+            '' + [Reflect.get(o, 'x'), Reflect.set(o, 'y', 2), o.y, Reflect.set(frozen, 'y', 2), Reflect.has(o, 'isPrototypeOf'),
+                Reflect.defineProperty(frozen, 'y', new Object()), Reflect.deleteProperty(o, 'x'), Reflect.ownKeys(o),
+                Reflect.getOwnPropertyDescriptor(o, 'y').writable, Reflect.getOwnPropertyDescriptor(o, 'z')];
+        ";
+        assert_eval(content, Value::String("1,true,2,false,true,false,true,y,true,".into()));
+
+        let content = "
+            let accessor = new Object();
+            accessor.get = function() { return this.name; };
+            let o = Object.defineProperty(new Object(), 'who', accessor);
+            let receiver = new Object();
+            receiver.name = 'receiver';
+            Reflect.get(o, 'who', receiver);
+        ";
+        assert_eval(content, Value::String("receiver".into()));
+        assert_eval_error("Reflect.get(1, 'x');", Error::Type("Reflect.get called on non-object!".to_string()));
+    }
+
+    #[test]
+    fn test_reflect_objects() {
+        let content = "
+            class A { constructor(x) { this.x = x; } }
+            class B {}
+            let b = Reflect.construct(A, [1], B);
+            let o = new Object();
+            let sealed = Object.seal(new Object());
+            '' + [Reflect.apply(Math.max, null, [1, 3, 2]), b.x, b instanceof B, Reflect.getPrototypeOf(o) === Object.prototype,
+                Reflect.setPrototypeOf(o, null), Reflect.getPrototypeOf(o), Reflect.setPrototypeOf(sealed, null),
+                Reflect.isExtensible(o), Reflect.preventExtensions(o), Reflect.isExtensible(o)];
+        ";
+        assert_eval(content, Value::String("3,1,true,true,true,,false,true,true,false".into()));
+        assert_eval_error("Reflect.apply(1);", Error::Type("Function.prototype.apply was called on 1, which is not a function!".to_string()));
+        assert_eval_error("Reflect.construct(Math.max, []);", Error::Type("function max() { [native code] } is not a constructor!".to_string()));
+        assert_eval_error("Reflect.apply(Math.max, null, 1);", Error::Type("CreateListFromArrayLike called on non-object 1!".to_string()));
+    }
+}
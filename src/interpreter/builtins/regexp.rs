@@ -0,0 +1,809 @@
+use crate::prelude::*;
+
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::rc::Rc;
+
+use fancy_regex::Regex;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+
+use super::array::create_array;
+use super::string::substitute;
+use super::{define_global, native_closure, native_function, native_iterator, prototype_from_constructor};
+
+/**
+ * Flags accepted by regular expressions, in the order of `RegExp.prototype.flags`.
+ */
+const FLAGS: [(char, &str); 6] = [
+    ('g', "global"),
+    ('i', "ignoreCase"),
+    ('m', "multiline"),
+    ('s', "dotAll"),
+    ('u', "unicode"),
+    ('y', "sticky"),
+];
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 3] = [
+        ("exec", 1, regexp_exec),
+        ("test", 1, regexp_test),
+        ("toString", 0, regexp_to_string),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    // String methods delegate to these, e.g. `'a-b'.split(/-/)`
+    let symbol_methods: [(&Symbol, &str, usize, NativeFn); 5] = [
+        (&realm.symbols.r#match, "[Symbol.match]", 1, regexp_match),
+        (&realm.symbols.match_all, "[Symbol.matchAll]", 1, regexp_match_all),
+        (&realm.symbols.replace, "[Symbol.replace]", 2, regexp_replace),
+        (&realm.symbols.search, "[Symbol.search]", 1, regexp_search),
+        (&realm.symbols.split, "[Symbol.split]", 2, regexp_split),
+    ];
+    for (symbol, name, length, function) in symbol_methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(symbol, Property::Data(Value::Object(method)));
+    }
+
+    let getters: [(&str, usize, NativeFn); 2] = [
+        ("flags", 0, regexp_flags),
+        ("source", 0, regexp_source),
+    ];
+    for (name, length, function) in getters {
+        let getter = native_function(realm, &format!("get {name}"), length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Accessor { get: Some(Value::Object(getter)), set: None });
+    }
+    for (flag, name) in FLAGS {
+        let getter = native_closure(realm, &format!("get {name}"), 0, move |_interpreter, this, _args, _new_target| {
+            let regexp = this_regexp(this, name)?;
+            Ok(Value::Boolean(regexp.has_flag(flag)))
+        }, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Accessor { get: Some(Value::Object(getter)), set: None });
+    }
+
+    let constructor = native_function(realm, "RegExp", 2, construct_regexp, true);
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%RegExp.prototype%", prototype);
+    define_global(global, "RegExp", Value::Object(constructor));
+
+    let iterator_prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%IteratorPrototype%"));
+    let tag = Value::String("RegExp String Iterator".into());
+    iterator_prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(tag));
+    realm.define_intrinsic("%RegExpStringIteratorPrototype%", iterator_prototype);
+}
+
+/**
+ * Compiled pattern of a RegExp object, along with the source & flags it was created from.
+ * Patterns are translated into the syntax of the `fancy_regex` crate, which supports
+ * backreferences & lookarounds. Strings are matched by code points with the 'u' flag and by
+ * UTF-16 code units otherwise, their surrogates being mapped to private use characters beforehand
+ * so that the engine matches them one at a time, e.g. `/./.exec('😀')[0].length` is 1.
+ */
+pub struct RegularExpression {
+    pub source: String,
+    pub flags: String,
+    regex: Regex,
+}
+
+/**
+ * Match of a regular expression, its indices counting UTF-16 code units.
+ */
+struct RegExpMatch {
+    index: usize,
+    end: usize,
+
+    // The matched string followed by the captured groups, None if they didn't participate
+    captures: Vec<Option<JsString>>,
+    groups: Vec<(String, Option<JsString>)>,
+}
+
+impl RegularExpression {
+    pub fn new(source: &str, flags: &str) -> Result<Self> {
+        let valid = flags.chars().enumerate()
+            .all(|(index, flag)| FLAGS.iter().any(|(known, _)| *known == flag) && !flags[..index].contains(flag));
+        if !valid {
+            return Err(Error::Syntax(format!("Invalid regular expression flags '{flags}'!")));
+        }
+
+        let mut pattern = String::new();
+        let inline: String = flags.chars().filter(|flag| "ims".contains(*flag)).collect();
+        if !inline.is_empty() {
+            pattern.push_str(&format!("(?{inline})"));
+        }
+        pattern.push_str(&translate(source, flags.contains('u')));
+        match Regex::new(&pattern) {
+            Ok(regex) => Ok(Self { source: source.to_string(), flags: flags.to_string(), regex }),
+            Err(error) => Err(Error::Syntax(format!("Invalid regular expression: /{source}/{flags}: {error}!"))),
+        }
+    }
+
+    pub fn has_flag(&self, flag: char) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /**
+     * Finds the first match starting at or after the code unit index, only at it if sticky.
+     */
+    fn match_at(&self, string: &JsString, index: usize, sticky: bool) -> Result<Option<RegExpMatch>> {
+        let subject = self.subject(string);
+        let start = self.byte_offset(&subject, index);
+        let captures = match self.regex.captures_from_pos(&subject, start) {
+            Ok(Some(captures)) => captures,
+            Ok(None) => return Ok(None),
+            Err(error) => return Err(Error::Range(format!("Regular expression /{}/ failed to match: {error}!", self.source))),
+        };
+        let matched = captures.get(0).unwrap();
+        if sticky && matched.start() != start {
+            return Ok(None);
+        }
+
+        // Groups are sliced out of the code units, keeping their lone surrogates
+        let unit_index = |offset: usize| subject[..offset].chars().map(|char| self.width(char)).sum();
+        let capture = |group: fancy_regex::Match| string.slice(unit_index(group.start()), unit_index(group.end()));
+        let groups = self.regex.capture_names().enumerate()
+            .filter_map(|(index, name)| name.map(|name| (name.to_string(), index)))
+            .map(|(name, index)| (name, captures.get(index).map(capture)))
+            .collect();
+        Ok(Some(RegExpMatch {
+            index: unit_index(matched.start()),
+            end: unit_index(matched.end()),
+            captures: captures.iter().map(|group| group.map(capture)).collect(),
+            groups,
+        }))
+    }
+
+    /**
+     * Returns the string the engine matches, in which every character is a single code unit
+     * unless the 'u' flag is set.
+     */
+    fn subject<'a>(&self, string: &'a JsString) -> Cow<'a, str> {
+        if self.has_flag('u') || string.length() == string.chars().count() {
+            return Cow::Borrowed(string);
+        }
+        let chars = string.units().iter()
+            .map(|unit| match unit {
+                0xD800..=0xDFFF => char::from_u32(SURROGATES + (*unit as u32 - 0xD800)).unwrap(),
+                _ => char::from_u32(*unit as u32).unwrap(),
+            })
+            .collect();
+        Cow::Owned(chars)
+    }
+
+    /**
+     * Counts the code units of a character of the matched string.
+     */
+    fn width(&self, char: char) -> usize {
+        match self.has_flag('u') {
+            true => char.len_utf16(),
+            false => 1,
+        }
+    }
+
+    /**
+     * Converts a code unit index into a byte offset of the matched string,
+     * indices within surrogate pairs moving to the next character.
+     */
+    fn byte_offset(&self, subject: &str, index: usize) -> usize {
+        let mut units = 0;
+        for (offset, char) in subject.char_indices() {
+            if units >= index {
+                return offset;
+            }
+            units += self.width(char);
+        }
+        subject.len()
+    }
+}
+
+/**
+ * First of the private use characters standing for the surrogates when matching code units.
+ */
+const SURROGATES: u32 = 0xF0000;
+
+/**
+ * Escapes the character for the engine, splitting it into surrogates when matching code units.
+ */
+fn push_char(pattern: &mut String, char: char, unicode: bool) {
+    let mut units = [0; 2];
+    match unicode {
+        true => pattern.push_str(&format!("\\x{{{:x}}}", char as u32)),
+        false => for unit in char.encode_utf16(&mut units) {
+            push_unit(pattern, *unit as u32);
+        },
+    }
+}
+
+fn push_unit(pattern: &mut String, unit: u32) {
+    match unit {
+        0xD800..=0xDFFF => pattern.push_str(&format!("\\x{{{:x}}}", SURROGATES + unit - 0xD800)),
+        _ => pattern.push_str(&format!("\\x{{{unit:x}}}")),
+    }
+}
+
+/**
+ * Translates a JS pattern into the syntax of the regex engine, which differs for
+ * escapes & classes, e.g. `\d` only matches ASCII digits & `[^]` matches anything.
+ */
+fn translate(source: &str, unicode: bool) -> String {
+    let mut pattern = String::new();
+    let mut chars = source.chars().peekable();
+    let mut class = false;
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some('d') if class => pattern.push_str("0-9"),
+                Some('d') => pattern.push_str("[0-9]"),
+                Some('D') => pattern.push_str("[^0-9]"),
+                Some('w') if class => pattern.push_str("A-Za-z0-9_"),
+                Some('w') => pattern.push_str("[A-Za-z0-9_]"),
+                Some('W') => pattern.push_str("[^A-Za-z0-9_]"),
+
+                // Backspace within classes, e.g. `[\b]`
+                Some('b') if class => pattern.push_str("\\x{8}"),
+                Some('0') if !chars.peek().is_some_and(char::is_ascii_digit) => pattern.push_str("\\x{0}"),
+                Some('c') if chars.peek().is_some_and(char::is_ascii_alphabetic) => {
+                    let letter = chars.next().unwrap();
+                    pattern.push_str(&format!("\\x{{{:x}}}", letter as u32 % 32));
+                },
+                Some('u') => {
+                    let digits: String = match chars.peek() {
+                        // Code point escapes, e.g. `\u{1F600}`
+                        Some('{') => chars.by_ref().skip(1).take_while(|char| *char != '}').collect(),
+                        _ => (0..4).map_while(|_| chars.next_if(char::is_ascii_hexdigit)).collect(),
+                    };
+                    match u32::from_str_radix(&digits, 16) {
+                        Err(_) => pattern.push('u'),
+                        Ok(code) if !unicode && code <= 0xFFFF => push_unit(&mut pattern, code),
+                        Ok(code) => match char::from_u32(code) {
+                            Some(char) => push_char(&mut pattern, char, unicode),
+                            None => pattern.push_str(&format!("\\x{{{digits}}}")),
+                        },
+                    }
+                },
+                Some(escaped) if escaped.is_ascii_alphanumeric() => {
+                    pattern.push('\\');
+                    pattern.push(escaped);
+                },
+
+                // Escaped punctuation is matched literally, e.g. `\/` or `\.`
+                Some(escaped) => pattern.push_str(&format!("\\x{{{:x}}}", escaped as u32)),
+                None => pattern.push('\\'),
+            },
+
+            '[' if !class => {
+                // Classes matching anything or nothing, i.e. `[^]` & `[]`
+                let next: String = chars.clone().take(2).collect();
+                if next.starts_with(']') {
+                    chars.next();
+                    pattern.push_str("[^\\s\\S]");
+                } else if next == "^]" {
+                    chars.nth(1);
+                    pattern.push_str("[\\s\\S]");
+                } else {
+                    class = true;
+                    pattern.push('[');
+                }
+            },
+            '[' => pattern.push_str("\\["),
+            ']' if class => {
+                class = false;
+                pattern.push(']');
+            },
+            char if !unicode && char.len_utf16() > 1 => push_char(&mut pattern, char, unicode),
+            char => pattern.push(char),
+        }
+    }
+    pattern
+}
+
+/**
+ * Creates a RegExp object, e.g. for regular expression literals.
+ */
+pub fn create_regexp(interpreter: &Interpreter, source: &str, flags: &str) -> Result<ObjectRef> {
+    let prototype = interpreter.realm.intrinsic("%RegExp.prototype%");
+    create_regexp_with_prototype(source, flags, prototype)
+}
+
+fn create_regexp_with_prototype(source: &str, flags: &str, prototype: Option<ObjectRef>) -> Result<ObjectRef> {
+    let regexp = RegularExpression::new(source, flags)?;
+    let object = ObjectRef::new(ObjectKind::RegExp(Rc::new(regexp)), prototype);
+    object.define_non_enumerable("lastIndex".to_string(), Property::Data(Value::Number(0.0)));
+    Ok(object)
+}
+
+/**
+ * RegExp(pattern, flags) -> regular expression of the pattern, copying the source of RegExp patterns.
+ */
+fn construct_regexp(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    let pattern = args.first().cloned().unwrap_or(Value::Undefined);
+    let flags = args.get(1).cloned().unwrap_or(Value::Undefined);
+    let regexp = match &pattern {
+        Value::Object(object) => object.regexp(),
+        _ => None,
+    };
+
+    // Calling without `new` returns RegExp patterns as they are
+    if let (None, Some(_), Value::Undefined) = (new_target, &regexp, &flags) {
+        return Ok(pattern);
+    }
+
+    let source = match (&regexp, &pattern) {
+        (Some(regexp), _) => regexp.source.clone(),
+        (None, Value::Undefined) => String::new(),
+        (None, pattern) => interpreter.to_string(pattern)?.to_string(),
+    };
+    let flags = match (&regexp, &flags) {
+        (Some(regexp), Value::Undefined) => regexp.flags.clone(),
+        (None, Value::Undefined) => String::new(),
+        (_, flags) => interpreter.to_string(flags)?.to_string(),
+    };
+
+    let prototype = prototype_from_constructor(interpreter, new_target, "%RegExp.prototype%")?;
+    Ok(Value::Object(create_regexp_with_prototype(&source, &flags, prototype)?))
+}
+
+/**
+ * RegExp.prototype.exec(string) -> array of the match & its groups, or null.
+ * Global & sticky regular expressions start matching from their `lastIndex`.
+ */
+fn regexp_exec(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "exec")?;
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    match exec(interpreter, this, &regexp, &string)? {
+        Some(found) => Ok(match_array(interpreter, found, &string)),
+        None => Ok(Value::Null),
+    }
+}
+
+/**
+ * RegExp.prototype.test(string) -> whether the string matches.
+ */
+fn regexp_test(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "test")?;
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    Ok(Value::Boolean(exec(interpreter, this, &regexp, &string)?.is_some()))
+}
+
+/**
+ * RegExp.prototype.toString() -> the regular expression as a literal, e.g. '/a+/g'.
+ */
+fn regexp_to_string(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "toString")?;
+    Ok(Value::String(format!("/{}/{}", escape_source(&regexp.source), regexp.flags).into()))
+}
+
+/**
+ * get RegExp.prototype.flags -> the flags of the regular expression, e.g. 'gi'.
+ */
+fn regexp_flags(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "flags")?;
+    let flags: String = FLAGS.iter().map(|(flag, _)| *flag).filter(|flag| regexp.has_flag(*flag)).collect();
+    Ok(Value::String(flags.into()))
+}
+
+/**
+ * get RegExp.prototype.source -> the pattern as written in a literal, e.g. 'a\/b'.
+ */
+fn regexp_source(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "source")?;
+    Ok(Value::String(escape_source(&regexp.source).into()))
+}
+
+/**
+ * RegExp.prototype[Symbol.match](string) -> the first match like `exec`,
+ * or every matched string if global. Returns null if nothing matches.
+ */
+fn regexp_match(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "[Symbol.match]")?;
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    if !regexp.has_flag('g') {
+        return regexp_exec(interpreter, this, &[Value::String(string)], None);
+    }
+
+    let matches: Vec<Value> = exec_all(interpreter, this, &regexp, &string)?.into_iter()
+        .map(|found| found.captures[0].clone().map(Value::String).unwrap_or(Value::Undefined))
+        .collect();
+    match matches.is_empty() {
+        true => Ok(Value::Null),
+        false => Ok(Value::Object(create_array(interpreter, matches))),
+    }
+}
+
+/**
+ * RegExp.prototype[Symbol.matchAll](string) -> iterator of the matches like `exec`.
+ * Iterates a copy of the regular expression, leaving its `lastIndex` untouched.
+ */
+fn regexp_match_all(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "[Symbol.matchAll]")?;
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let matcher = Value::Object(create_regexp(interpreter, &regexp.source, &regexp.flags)?);
+    let last_index = last_index(interpreter, this)?;
+    set_last_index(interpreter, &matcher, last_index)?;
+
+    // Regular expressions which aren't global only match once
+    let done = Cell::new(false);
+    Ok(native_iterator(interpreter, "%RegExpStringIteratorPrototype%", move |interpreter| {
+        if done.get() {
+            return Ok(None);
+        }
+        let found = match exec(interpreter, &matcher, &regexp, &string)? {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        if !regexp.has_flag('g') {
+            done.set(true);
+        } else if found.index == found.end {
+            advance(interpreter, &matcher, &regexp, &string)?;
+        }
+        Ok(Some(match_array(interpreter, found, &string)))
+    }))
+}
+
+/**
+ * RegExp.prototype[Symbol.replace](string, replacement) -> the string with the first match replaced,
+ * or every match if global. Replacement strings may refer to the groups, e.g. '$1' or '$<name>'.
+ */
+fn regexp_replace(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "[Symbol.replace]")?;
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let replacement = args.get(1).cloned().unwrap_or(Value::Undefined);
+    let template = match replacement.is_callable() {
+        true => None,
+        false => Some(interpreter.to_string(&replacement)?),
+    };
+
+    let matches = match regexp.has_flag('g') {
+        true => exec_all(interpreter, this, &regexp, &string)?,
+        false => exec(interpreter, this, &regexp, &string)?.into_iter().collect(),
+    };
+
+    let units = string.units();
+    let mut result = vec![];
+    let mut end = 0;
+    for found in matches {
+        // Matches overlapping the previous replacement are skipped
+        if found.index < end {
+            continue;
+        }
+        result.extend_from_slice(&units[end..found.index]);
+        let replaced = match &template {
+            None => {
+                let mut args: Vec<Value> = found.captures.iter()
+                    .map(|capture| capture.clone().map(Value::String).unwrap_or(Value::Undefined))
+                    .collect();
+                args.push(Value::Number(found.index as f64));
+                args.push(Value::String(string.clone()));
+                if !found.groups.is_empty() {
+                    args.push(groups_object(&found.groups));
+                }
+                interpreter.to_string(&interpreter.call(&replacement, &Value::Undefined, &args)?)?.units().into_owned()
+            },
+            Some(template) => {
+                let groups = (!found.groups.is_empty()).then_some(found.groups.as_slice());
+                substitute(template, &found.captures, groups, &units[..found.index], &units[found.end..])
+            },
+        };
+        result.extend(replaced);
+        end = found.end;
+    }
+    result.extend_from_slice(&units[end..]);
+    Ok(Value::String(JsString::from_units(&result)))
+}
+
+/**
+ * RegExp.prototype[Symbol.search](string) -> index of the first match, or -1.
+ * The `lastIndex` is ignored & left untouched.
+ */
+fn regexp_search(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "[Symbol.search]")?;
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let found = regexp.match_at(&string, 0, regexp.has_flag('y'))?;
+    Ok(Value::Number(found.map(|found| found.index as f64).unwrap_or(-1.0)))
+}
+
+/**
+ * RegExp.prototype[Symbol.split](string, limit) -> array of the parts between the matches,
+ * along with their captured groups. Empty matches split every code point.
+ */
+fn regexp_split(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let regexp = this_regexp(this, "[Symbol.split]")?;
+    let string = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let limit = match args.get(1) {
+        None | Some(Value::Undefined) => u32::MAX as usize,
+        Some(limit) => {
+            let limit = interpreter.to_number(limit)?;
+            if limit.is_finite() { limit.trunc().rem_euclid(4294967296.0) as usize } else { 0 }
+        },
+    };
+    if limit == 0 {
+        return Ok(Value::Object(create_array(interpreter, vec![])));
+    }
+
+    let units = string.units();
+    if units.is_empty() {
+        let parts = match regexp.match_at(&string, 0, true)? {
+            Some(_) => vec![],
+            None => vec![Value::String(string.clone())],
+        };
+        return Ok(Value::Object(create_array(interpreter, parts)));
+    }
+
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut position = 0;
+    while position < units.len() {
+        let found = match regexp.match_at(&string, position, false)? {
+            Some(found) if found.index < units.len() => found,
+            _ => break,
+        };
+
+        // Empty matches at the end of the previous part don't split, e.g. 'ab'.split(/x*/)
+        if found.end == start {
+            position = found.index + 1;
+            continue;
+        }
+        parts.push(Value::String(JsString::from_units(&units[start..found.index])));
+        for capture in found.captures.into_iter().skip(1) {
+            parts.push(capture.map(Value::String).unwrap_or(Value::Undefined));
+        }
+        if parts.len() >= limit {
+            parts.truncate(limit);
+            return Ok(Value::Object(create_array(interpreter, parts)));
+        }
+        start = found.end;
+        position = start;
+    }
+    parts.push(Value::String(JsString::from_units(&units[start..])));
+    parts.truncate(limit);
+    Ok(Value::Object(create_array(interpreter, parts)))
+}
+
+/**
+ * Returns the compiled pattern of `this`, which must be a RegExp object.
+ */
+fn this_regexp(this: &Value, method: &str) -> Result<Rc<RegularExpression>> {
+    match this {
+        Value::Object(object) => object.regexp(),
+        _ => None,
+    }.ok_or_else(|| Error::Type(format!("Method RegExp.prototype.{method} called on incompatible receiver {this}!")))
+}
+
+/**
+ * Matches the string from the `lastIndex` of global & sticky regular expressions,
+ * updating it to the end of the match, or resetting it once nothing matches.
+ */
+fn exec(interpreter: &Interpreter, this: &Value, regexp: &RegularExpression, string: &JsString) -> Result<Option<RegExpMatch>> {
+    let global = regexp.has_flag('g') || regexp.has_flag('y');
+    let index = match global {
+        true => last_index(interpreter, this)?,
+        false => 0,
+    };
+    let found = match index > string.length() {
+        true => None,
+        false => regexp.match_at(string, index, regexp.has_flag('y'))?,
+    };
+    if global {
+        let last_index = found.as_ref().map(|found| found.end).unwrap_or(0);
+        set_last_index(interpreter, this, last_index)?;
+    }
+    Ok(found)
+}
+
+/**
+ * Collects every match of a global regular expression, starting from the beginning.
+ * Empty matches move the `lastIndex` forward to avoid matching the same position forever.
+ */
+fn exec_all(interpreter: &Interpreter, this: &Value, regexp: &RegularExpression, string: &JsString) -> Result<Vec<RegExpMatch>> {
+    set_last_index(interpreter, this, 0)?;
+    let mut matches = vec![];
+    while let Some(found) = exec(interpreter, this, regexp, string)? {
+        if found.index == found.end {
+            advance(interpreter, this, regexp, string)?;
+        }
+        matches.push(found);
+    }
+    Ok(matches)
+}
+
+/**
+ * Moves the `lastIndex` past the next code point with the 'u' flag, else past the next code unit.
+ */
+fn advance(interpreter: &Interpreter, this: &Value, regexp: &RegularExpression, string: &JsString) -> Result<()> {
+    let index = last_index(interpreter, this)?;
+    let units = string.units();
+    let width = match units.get(index..index + 2) {
+        Some([0xD800..=0xDBFF, 0xDC00..=0xDFFF]) if regexp.has_flag('u') => 2,
+        _ => 1,
+    };
+    set_last_index(interpreter, this, index + width)
+}
+
+/**
+ * Updates the `lastIndex`, throwing if it's read-only, e.g. once the regular expression is frozen.
+ */
+fn set_last_index(interpreter: &Interpreter, this: &Value, index: usize) -> Result<()> {
+    match interpreter.set_property(this, "lastIndex", Value::Number(index as f64))? {
+        true => Ok(()),
+        false => Err(Error::Type(format!("Cannot assign to read only property 'lastIndex' of object '{this}'!"))),
+    }
+}
+
+fn last_index(interpreter: &Interpreter, this: &Value) -> Result<usize> {
+    let index = interpreter.to_number(&interpreter.get_property(this, "lastIndex")?)?;
+    Ok(if index.is_nan() || index < 0.0 { 0 } else { index.min(u32::MAX as f64) as usize })
+}
+
+/**
+ * Creates the array returned by `exec`, holding the match & its groups
+ * along with its `index`, the `input` string & the named `groups`.
+ */
+fn match_array(interpreter: &Interpreter, found: RegExpMatch, string: &JsString) -> Value {
+    let elements = found.captures.into_iter()
+        .map(|capture| capture.map(Value::String).unwrap_or(Value::Undefined))
+        .collect();
+    let array = create_array(interpreter, elements);
+    array.set_own_value("index", Value::Number(found.index as f64));
+    array.set_own_value("input", Value::String(string.clone()));
+    let groups = match found.groups.is_empty() {
+        true => Value::Undefined,
+        false => groups_object(&found.groups),
+    };
+    array.set_own_value("groups", groups);
+    Value::Object(array)
+}
+
+/**
+ * Creates the object holding the named groups, without any prototype.
+ */
+fn groups_object(groups: &[(String, Option<JsString>)]) -> Value {
+    let object = ObjectRef::new(ObjectKind::Ordinary, None);
+    for (name, value) in groups {
+        object.set_own_value(name, value.clone().map(Value::String).unwrap_or(Value::Undefined));
+    }
+    Value::Object(object)
+}
+
+/**
+ * Escapes the pattern so that it can be written as a literal, e.g. 'a/b' -> 'a\/b'.
+ */
+fn escape_source(source: &str) -> String {
+    if source.is_empty() {
+        return "(?:)".to_string();
+    }
+    let mut escaped = String::new();
+    let mut chars = source.chars();
+    let mut class = false;
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => {
+                escaped.push(char);
+                if let Some(next) = chars.next() {
+                    escaped.push(next);
+                }
+                continue;
+            },
+            '/' if !class => escaped.push('\\'),
+            '[' => class = true,
+            ']' => class = false,
+            _ => {},
+        }
+        match char {
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_regexp_literals() {
+        let content = r#"
+            let re = /(\d+)-(\d+)/g;
+            let a = 10;
+            let b = a / 2 / 5;
+            '' + [re.source, re.flags, re.global, re.sticky, re, b, /[/]/.test('/'), /a\/b/.test('a/b'), new RegExp('a/b', 'i')];
+        "#;
+        assert_eval(content, Value::String(r"(\d+)-(\d+),g,true,false,/(\d+)-(\d+)/g,1,true,true,/a\/b/i".into()));
+
+        assert_eval_error("new RegExp('a', 'x');", Error::Syntax("Invalid regular expression flags 'x'!".to_string()));
+        assert_eval_error("new RegExp('a', 'gg');", Error::Syntax("Invalid regular expression flags 'gg'!".to_string()));
+        assert_eval_error("new RegExp('(');", Error::Syntax(
+            "Invalid regular expression: /(/: Parsing error at position 1: Opening parenthesis without closing parenthesis!".to_string(),
+        ));
+    }
+
+    #[test]
+    fn test_regexp_exec() {
+        let content = "
+            let re = /(?<year>\\d{4})-(?<month>\\d{2})(x)?/;
+            let m = re.exec('on 2024-05!');
+            '' + [m[0], m[1], m[3] === m.missing, m.index, m.input, m.groups.year, m.groups.month, m.length, re.lastIndex];
+        ";
+        assert_eval(content, Value::String("2024-05,2024,true,3,on 2024-05!,2024,05,4,0".into()));
+
+        let content = "
+            let re = /o/g;
+            let indices = [];
+            while (re.test('foo boo')) {
+                indices.push(re.lastIndex);
+            }
+            let sticky = /b/y;
+            sticky.lastIndex = 4;
+            '' + [indices, re.lastIndex, sticky.test('foo boo'), sticky.lastIndex, sticky.test('foo boo'), sticky.lastIndex];
+        ";
+        assert_eval(content, Value::String("2,3,6,7,0,true,5,false,0".into()));
+
+        let content = "
+            let lines = 'a' + String.fromCharCode(10) + 'b';
+            '' + [/HELLO/i.test('hello'), /^b$/m.test(lines), /^b$/.test(lines), /a.b/s.test(lines), /a.b/.test(lines), /(a)\\1/.test('aa'), /\\u{1F600}/u.test('😀')];
+        ";
+        assert_eval(content, Value::String("true,true,false,true,false,true,true".into()));
+
+        let content = "
+            let smiley = String.fromCodePoint(128512);
+            let global = new RegExp('', 'g');
+            '' + [
+                /./.exec(smiley)[0].length, /./u.exec(smiley)[0].length, /^.$/.test(smiley), /^..$/.test(smiley),
+                /^.$/u.test(smiley), /\\uD83D/.test(smiley), /^😀+$/.test(smiley + String.fromCharCode(56832)),
+                smiley.replace(global, '-').length, (smiley + 'a').split(/(?:)/).length, /a/.exec(smiley + 'a').index
+            ];
+        ";
+        assert_eval(content, Value::String("1,2,false,true,true,true,true,5,3,2".into()));
+    }
+
+    #[test]
+    fn test_string_regexp_methods() {
+        let content = "
+            let text = 'a1b22c333';
+            let all = [];
+            for (const m of text.matchAll(/\\d+/g)) {
+                all.push(m[0] + '@' + m.index);
+            }
+            '' + [text.match(/\\d+/g), text.match(/\\d+/).index, text.match(/x/g), text.search(/b/), text.search(/x/), all];
+        ";
+        assert_eval(content, Value::String("1,22,333,1,,2,-1,1@1,22@3,333@6".into()));
+
+        let content = "
+            '' + [
+                'John Smith'.replace(/(?<first>\\w+) (?<last>\\w+)/, '$<last>, $<first>'),
+                'a-b-c'.replace(/-/g, '+'),
+                'a-b-c'.replace(/-/, '$$'),
+                'x1y2'.replace(/\\d/g, function(d, offset) { return '[' + d * 2 + offset + ']'; }),
+                'aaa'.replace(/a*?/g, '-'),
+                'abc'.replace(/(b)/, \"$2$1$`$'\")
+            ].join('|');
+        ";
+        assert_eval(content, Value::String("Smith, John|a+b+c|a$b-c|x[21]y[43]|-a-a-a-|a$2bacc".into()));
+
+        let content = "
+            '' + [
+                'a, b,c'.split(/\\s*,\\s*/).join('|'),
+                'a1b2c'.split(/(\\d)/).join('|'),
+                'abc'.split(/(?:)/).join('|'),
+                'abc'.split(/b/, 1).join('|'),
+                ''.split(/x/).length,
+                ''.split(/(?:)/).length
+            ];
+        ";
+        assert_eval(content, Value::String("a|b|c,a|1|b|2|c,a|b|c,a,1,0".into()));
+
+        assert_eval_error("'a'.replaceAll(/a/, 'b');", Error::Type("replaceAll must be called with a global RegExp!".to_string()));
+        assert_eval_error("'a'.matchAll(/a/);", Error::Type("String.prototype.matchAll called with a non-global RegExp argument!".to_string()));
+        assert_eval_error("'a'.includes(/a/);", Error::Type("First argument to String.prototype.includes must not be a regular expression!".to_string()));
+    }
+}
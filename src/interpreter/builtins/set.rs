@@ -0,0 +1,264 @@
+use crate::prelude::*;
+
+use crate::collections::{KeyedCollection, WeakCollection};
+use crate::interpreter::callable::Callable;
+use crate::interpreter::realm::Realm;
+
+use super::map::{add_entries, callback_argument, can_be_held_weakly, collection_iterator, IterationKind};
+use super::{define_global, native_function, prototype_from_constructor};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 6] = [
+        ("add", 1, set_add),
+        ("clear", 0, set_clear),
+        ("delete", 1, set_delete),
+        ("entries", 0, set_entries),
+        ("forEach", 1, set_for_each),
+        ("has", 1, set_has),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    // Sets iterate their values, which are their keys as well
+    let values = native_function(realm, "values", 0, set_values, false);
+    for key in [PropertyKey::from("keys"), PropertyKey::from("values"), PropertyKey::from(&realm.symbols.iterator)] {
+        prototype.define_non_enumerable(key, Property::Data(Value::Object(values.clone())));
+    }
+    let size = native_function(realm, "get size", 0, set_size, false);
+    prototype.define_non_enumerable("size".to_string(), Property::Accessor { get: Some(Value::Object(size)), set: None });
+    prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Set".into())));
+
+    let constructor = native_function(realm, "Set", 0, construct_set, true);
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Set.prototype%", prototype);
+
+    let iterator_prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%IteratorPrototype%"));
+    iterator_prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Set Iterator".into())));
+    realm.define_intrinsic("%SetIteratorPrototype%", iterator_prototype);
+    define_global(global, "Set", Value::Object(constructor));
+
+    let weak_prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 3] = [
+        ("add", 1, weak_set_add),
+        ("delete", 1, weak_set_delete),
+        ("has", 1, weak_set_has),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        weak_prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    weak_prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("WeakSet".into())));
+
+    let weak_constructor = native_function(realm, "WeakSet", 0, construct_weak_set, true);
+    weak_constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(weak_prototype.clone())));
+    weak_prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(weak_constructor.clone())));
+
+    realm.define_intrinsic("%WeakSet.prototype%", weak_prototype);
+    define_global(global, "WeakSet", Value::Object(weak_constructor));
+}
+
+/**
+ * Set(values) -> set of the values of the iterable, if any, without duplicates.
+ */
+fn construct_set(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_none() {
+        return Err(Error::Type("Set constructor cannot be invoked without 'new'!".to_string()));
+    }
+    let prototype = prototype_from_constructor(interpreter, new_target, "%Set.prototype%")?;
+    let set = Value::Object(ObjectRef::new(ObjectKind::Set(KeyedCollection::default()), prototype));
+    add_entries(interpreter, &set, args.first(), "add", false)?;
+    Ok(set)
+}
+
+/**
+ * Set.prototype.add(value) -> the set, once it has the value.
+ */
+fn set_add(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().cloned().unwrap_or(Value::Undefined);
+    this_set(this, "add", |entries| entries.set(value.clone(), value))?;
+    Ok(this.clone())
+}
+
+/**
+ * Set.prototype.clear() -> removes every value.
+ */
+fn set_clear(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_set(this, "clear", |entries| entries.clear())?;
+    Ok(Value::Undefined)
+}
+
+/**
+ * Set.prototype.delete(value) -> whether the value was removed.
+ */
+fn set_delete(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().unwrap_or(&Value::Undefined);
+    Ok(Value::Boolean(this_set(this, "delete", |entries| entries.delete(value))?))
+}
+
+/**
+ * Set.prototype.entries() -> iterator of [value, value] pairs, in insertion order.
+ */
+fn set_entries(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_set(this, "entries", |_| ())?;
+    Ok(collection_iterator(interpreter, this, "%SetIteratorPrototype%", IterationKind::Entries))
+}
+
+/**
+ * Set.prototype.forEach(callback, thisArg) -> calls the callback with each value twice & the set.
+ * Values added during the iteration are visited too.
+ */
+fn set_for_each(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_set(this, "forEach", |_| ())?;
+    let callback = callback_argument(args)?;
+    let this_arg = args.get(1).cloned().unwrap_or(Value::Undefined);
+    let cursor = this_set(this, "forEach", KeyedCollection::cursor)?;
+    while let Some((index, value, _)) = this_set(this, "forEach", |entries| entries.next_entry(cursor.get()))? {
+        cursor.set(index + 1);
+        interpreter.call(&callback, &this_arg, &[value.clone(), value, this.clone()])?;
+    }
+    Ok(Value::Undefined)
+}
+
+/**
+ * Set.prototype.has(value) -> whether the set has the value.
+ */
+fn set_has(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().unwrap_or(&Value::Undefined);
+    Ok(Value::Boolean(this_set(this, "has", |entries| entries.has(value))?))
+}
+
+/**
+ * get Set.prototype.size -> number of values.
+ */
+fn set_size(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Number(this_set(this, "size", |entries| entries.len())? as f64))
+}
+
+/**
+ * Set.prototype.values() -> iterator of the values, in insertion order.
+ */
+fn set_values(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    this_set(this, "values", |_| ())?;
+    Ok(collection_iterator(interpreter, this, "%SetIteratorPrototype%", IterationKind::Values))
+}
+
+/**
+ * WeakSet(values) -> weak set of the objects & symbols of the iterable, if any.
+ */
+fn construct_weak_set(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_none() {
+        return Err(Error::Type("WeakSet constructor cannot be invoked without 'new'!".to_string()));
+    }
+    let prototype = prototype_from_constructor(interpreter, new_target, "%WeakSet.prototype%")?;
+    let set = Value::Object(ObjectRef::new(ObjectKind::WeakSet(WeakCollection::default()), prototype));
+    add_entries(interpreter, &set, args.first(), "add", false)?;
+    Ok(set)
+}
+
+/**
+ * WeakSet.prototype.add(value) -> the weak set, once it has the value.
+ * Values must be objects or symbols which aren't registered, i.e. not created by `Symbol.for`.
+ */
+fn weak_set_add(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().unwrap_or(&Value::Undefined);
+    if !can_be_held_weakly(interpreter, value) {
+        this_weak_set(this, "add", |_| ())?;
+        return Err(Error::Type(format!("Invalid value used in weak set: {value}!")));
+    }
+    this_weak_set(this, "add", |entries| entries.set(value, Value::Undefined))?;
+    Ok(this.clone())
+}
+
+/**
+ * WeakSet.prototype.delete(value) -> whether the value was removed.
+ */
+fn weak_set_delete(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().unwrap_or(&Value::Undefined);
+    let deleted = this_weak_set(this, "delete", |entries| entries.delete(value))?;
+    Ok(Value::Boolean(deleted))
+}
+
+/**
+ * WeakSet.prototype.has(value) -> whether the weak set has the value.
+ */
+fn weak_set_has(_interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = args.first().unwrap_or(&Value::Undefined);
+    let has = this_weak_set(this, "has", |entries| entries.has(value))?;
+    Ok(Value::Boolean(has))
+}
+
+/**
+ * Runs the operation on the values of `this`, which must be a Set.
+ */
+fn this_set<T>(this: &Value, method: &str, operation: impl FnOnce(&mut KeyedCollection) -> T) -> Result<T> {
+    if let Value::Object(object) = this {
+        if let ObjectKind::Set(entries) = &mut object.borrow_mut().kind {
+            return Ok(operation(entries));
+        }
+    }
+    Err(Error::Type(format!("Method Set.prototype.{method} called on incompatible receiver {this}!")))
+}
+
+/**
+ * Runs the operation on the values of `this`, which must be a WeakSet.
+ */
+fn this_weak_set<T>(this: &Value, method: &str, operation: impl FnOnce(&mut WeakCollection) -> T) -> Result<T> {
+    if let Value::Object(object) = this {
+        if let ObjectKind::WeakSet(entries) = &mut object.borrow_mut().kind {
+            return Ok(operation(entries));
+        }
+    }
+    Err(Error::Type(format!("Method WeakSet.prototype.{method} called on incompatible receiver {this}!")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_set() {
+        let content = "
+            let letters = new Set('hello');
+            letters.add(NaN).add(NaN).add(0).add(-0);
+            let pairs = [];
+            for (const pair of letters.entries()) {
+                pairs.push(pair[0] === pair[1]);
+            }
+            '' + [letters.size, [...letters], letters.has('l'), letters.has(-0), letters.delete('h'), letters.has('h'), letters.keys === letters.values, pairs.length];
+        ";
+        assert_eval(content, Value::String("6,h,e,l,o,NaN,0,true,true,true,false,true,6".into()));
+
+        let content = "
+            let letters = new Set([1, 2]);
+            let seen = [];
+            letters.forEach(function(value, key, target) {
+                seen.push(value + key);
+                if (value < 4) {
+                    letters.add(value + 2);
+                }
+            });
+            '' + [seen, letters.size];
+        ";
+        assert_eval(content, Value::String("2,4,6,8,10,5".into()));
+    }
+
+    #[test]
+    fn test_weak_set() {
+        let content = "
+            let a = new Object();
+            let letters = new WeakSet([a]);
+            let b = Symbol('b');
+            letters.add(b);
+            '' + [letters.has(a), letters.has(new Object()), letters.has(1), letters.delete(a), letters.has(a), letters.add(a) === letters, letters.has(b)];
+        ";
+        assert_eval(content, Value::String("true,false,false,true,false,true,true".into()));
+        assert_eval_error("new WeakSet([1]);", Error::Type("Invalid value used in weak set: 1!".to_string()));
+        assert_eval_error("new WeakSet([Symbol.for('b')]);", Error::Type("Invalid value used in weak set: Symbol(b)!".to_string()));
+    }
+}
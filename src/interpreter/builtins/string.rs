@@ -0,0 +1,879 @@
+use crate::prelude::*;
+
+use std::cmp::Ordering;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::realm::Realm;
+use crate::utf16;
+
+use super::array::create_array;
+use super::regexp::create_regexp;
+use super::{construct_wrapper, define_global, native_function, this_primitive};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Primitive(Value::String(JsString::default())), realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 32] = [
+        ("at", 1, string_at),
+        ("charAt", 1, string_char_at),
+        ("charCodeAt", 1, string_char_code_at),
+        ("codePointAt", 1, string_code_point_at),
+        ("concat", 1, string_concat),
+        ("endsWith", 1, string_ends_with),
+        ("includes", 1, string_includes),
+        ("indexOf", 1, string_index_of),
+        ("lastIndexOf", 1, string_last_index_of),
+        ("localeCompare", 1, string_locale_compare),
+        ("match", 1, string_match),
+        ("matchAll", 1, string_match_all),
+        ("normalize", 0, string_normalize),
+        ("padEnd", 1, string_pad_end),
+        ("padStart", 1, string_pad_start),
+        ("repeat", 1, string_repeat),
+        ("replace", 2, string_replace),
+        ("replaceAll", 2, string_replace_all),
+        ("search", 1, string_search),
+        ("slice", 2, string_slice),
+        ("split", 2, string_split),
+        ("startsWith", 1, string_starts_with),
+        ("substring", 2, string_substring),
+        ("toLocaleLowerCase", 0, string_to_lower_case),
+        ("toLocaleUpperCase", 0, string_to_upper_case),
+        ("toLowerCase", 0, string_to_lower_case),
+        ("toString", 0, string_value_of),
+        ("toUpperCase", 0, string_to_upper_case),
+        ("trim", 0, string_trim),
+        ("trimEnd", 0, string_trim_end),
+        ("trimStart", 0, string_trim_start),
+        ("valueOf", 0, string_value_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+
+    let constructor = native_function(realm, "String", 1, construct_string, true);
+    let statics: [(&str, usize, NativeFn); 2] = [
+        ("fromCharCode", 1, string_from_char_code),
+        ("fromCodePoint", 1, string_from_code_point),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%String.prototype%", prototype);
+    define_global(global, "String", Value::Object(constructor));
+}
+
+/**
+ * String(value) -> the value converted into a string, wrapped into an object with `new`.
+ * Unlike implicit conversions, symbols are converted into their description.
+ */
+fn construct_string(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    let value = match (args.first(), new_target) {
+        (None, _) => Value::String(JsString::default()),
+        (Some(Value::Symbol(symbol)), None) => Value::String(symbol.to_string().into()),
+        (Some(value), _) => Value::String(interpreter.to_string(value)?),
+    };
+    match new_target {
+        None => Ok(value),
+        Some(new_target) => construct_wrapper(interpreter, value, new_target),
+    }
+}
+
+/**
+ * String.fromCharCode(...codes) -> string of the UTF-16 code units.
+ */
+fn string_from_char_code(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut units = vec![];
+    for arg in args {
+        let code = interpreter.to_number(arg)?;
+        units.push(if code.is_finite() { code.trunc().rem_euclid(65536.0) as u16 } else { 0 });
+    }
+    Ok(Value::String(JsString::from_units(&units)))
+}
+
+/**
+ * String.fromCodePoint(...codes) -> string of the Unicode code points.
+ */
+fn string_from_code_point(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut units = vec![];
+    for arg in args {
+        let code = interpreter.to_number(arg)?;
+        if code.trunc() != code || !(0.0..=1114111.0).contains(&code) {
+            return Err(Error::Range(format!("Invalid code point {}!", Value::Number(code))));
+        }
+
+        // Surrogate code points are kept as lone surrogates
+        match char::from_u32(code as u32) {
+            Some(char) => units.extend(char.encode_utf16(&mut [0; 2]).iter()),
+            None => units.push(code as u16),
+        }
+    }
+    Ok(Value::String(JsString::from_units(&units)))
+}
+
+/**
+ * String.prototype.at(index) -> code unit at the index, negative indices counting from the end.
+ */
+fn string_at(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "at")?;
+    let length = string.length() as f64;
+    let index = integer_argument(interpreter, args, 0)?;
+    let index = if index < 0.0 { length + index } else { index };
+    match index >= 0.0 && index < length {
+        true => Ok(string.unit_at(index as usize).map(Value::String).unwrap_or(Value::Undefined)),
+        false => Ok(Value::Undefined),
+    }
+}
+
+/**
+ * String.prototype.charAt(index) -> code unit at the index, or an empty string.
+ */
+fn string_char_at(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "charAt")?;
+    let unit = match integer_argument(interpreter, args, 0)? {
+        index if index < 0.0 => None,
+        index => string.unit_at(index as usize),
+    };
+    Ok(Value::String(unit.unwrap_or_default()))
+}
+
+/**
+ * String.prototype.charCodeAt(index) -> UTF-16 code unit at the index, or NaN.
+ */
+fn string_char_code_at(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "charCodeAt")?;
+    let unit = match integer_argument(interpreter, args, 0)? {
+        index if index < 0.0 => None,
+        index => string.units().get(index as usize).copied(),
+    };
+    Ok(Value::Number(unit.map(f64::from).unwrap_or(f64::NAN)))
+}
+
+/**
+ * String.prototype.codePointAt(index) -> code point starting at the index, combining surrogate pairs.
+ */
+fn string_code_point_at(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "codePointAt")?;
+    let units = string.units();
+    let index = integer_argument(interpreter, args, 0)?;
+    if index < 0.0 || index >= units.len() as f64 {
+        return Ok(Value::Undefined);
+    }
+    let index = index as usize;
+    let code_point = match char::decode_utf16(units[index..].iter().copied()).next() {
+        Some(Ok(char)) => char as u32,
+        _ => units[index] as u32, // Lone surrogates are returned as they are
+    };
+    Ok(Value::Number(code_point as f64))
+}
+
+/**
+ * String.prototype.concat(...strings) -> the strings appended to this string.
+ */
+fn string_concat(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let mut string = this_string(interpreter, this, "concat")?;
+    for arg in args {
+        string = string.concat(&interpreter.to_string(arg)?);
+    }
+    Ok(Value::String(string))
+}
+
+/**
+ * String.prototype.endsWith(search, end) -> whether the string up to the end index ends with the search string.
+ */
+fn string_ends_with(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "endsWith")?;
+    let units = string.units();
+    let search = search_string(interpreter, args, "endsWith")?;
+    let end = match args.get(1) {
+        None | Some(Value::Undefined) => units.len(),
+        Some(_) => clamp(integer_argument(interpreter, args, 1)?, units.len()),
+    };
+    Ok(Value::Boolean(units[..end].ends_with(&search)))
+}
+
+/**
+ * String.prototype.includes(search, position) -> whether the search string is found from the position.
+ */
+fn string_includes(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "includes")?;
+    let units = string.units();
+    let search = search_string(interpreter, args, "includes")?;
+    let position = clamp(integer_argument(interpreter, args, 1)?, units.len());
+    Ok(Value::Boolean(utf16::index_of(&units, &search, position).is_some()))
+}
+
+/**
+ * String.prototype.indexOf(search, position) -> index of the first occurrence from the position, or -1.
+ */
+fn string_index_of(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "indexOf")?;
+    let units = string.units();
+    let search = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?.units().into_owned();
+    let position = clamp(integer_argument(interpreter, args, 1)?, units.len());
+    Ok(index_value(utf16::index_of(&units, &search, position)))
+}
+
+/**
+ * String.prototype.lastIndexOf(search, position) -> index of the last occurrence up to the position, or -1.
+ */
+fn string_last_index_of(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "lastIndexOf")?;
+    let units = string.units();
+    let search = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?.units().into_owned();
+
+    // NaN positions, e.g. undefined, search the whole string
+    let position = match interpreter.to_number(args.get(1).unwrap_or(&Value::Undefined))? {
+        position if position.is_nan() => units.len(),
+        position => clamp(position.trunc(), units.len()),
+    };
+    Ok(index_value(utf16::last_index_of(&units, &search, position)))
+}
+
+/**
+ * String.prototype.localeCompare(that) -> negative, zero or positive number sorting the strings.
+ * Locales aren't supported: the strings are compared by the code points of their lowercase forms,
+ * ties putting lowercase letters before uppercase ones. Accented letters thus sort after every
+ * ASCII letter, e.g. 'é'.localeCompare('f') is 1 while the default collation would give -1.
+ */
+fn string_locale_compare(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "localeCompare")?;
+    let that = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    let swap_case = |c: char| match c.is_lowercase() {
+        true => c.to_uppercase().next().unwrap_or(c),
+        false => c.to_lowercase().next().unwrap_or(c),
+    };
+    let ordering = string.to_lowercase().cmp(&that.to_lowercase())
+        .then_with(|| string.chars().map(swap_case).cmp(that.chars().map(swap_case)));
+    Ok(Value::Number(match ordering {
+        Ordering::Less => -1.0,
+        Ordering::Equal => 0.0,
+        Ordering::Greater => 1.0,
+    }))
+}
+
+/**
+ * String.prototype.match(regexp) -> the matches of the regular expression, see `RegExp.prototype[Symbol.match]`.
+ * Other patterns are converted into regular expressions, e.g. `'a.c'.match('.')`.
+ */
+fn string_match(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "match")?;
+    let symbol = &interpreter.realm.symbols.r#match;
+    if let Some(matched) = delegate(interpreter, symbol, &string, args)? {
+        return Ok(matched);
+    }
+    let regexp = Value::Object(pattern_regexp(interpreter, args, "")?);
+    interpreter.call(&interpreter.get_property(&regexp, symbol)?, &regexp, &[Value::String(string)])
+}
+
+/**
+ * String.prototype.matchAll(regexp) -> iterator of the matches of a global regular expression.
+ */
+fn string_match_all(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "matchAll")?;
+    let pattern = args.first().cloned().unwrap_or(Value::Undefined);
+    if is_regexp(interpreter, &pattern)? && !flags(interpreter, &pattern)?.contains('g') {
+        return Err(Error::Type("String.prototype.matchAll called with a non-global RegExp argument!".to_string()));
+    }
+    let symbol = &interpreter.realm.symbols.match_all;
+    if let Some(matches) = delegate(interpreter, symbol, &string, args)? {
+        return Ok(matches);
+    }
+    let regexp = Value::Object(pattern_regexp(interpreter, args, "g")?);
+    interpreter.call(&interpreter.get_property(&regexp, symbol)?, &regexp, &[Value::String(string)])
+}
+
+/**
+ * String.prototype.normalize() -> the string itself, Unicode normalization forms aren't supported.
+ */
+fn string_normalize(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "normalize")?;
+    let form = match args.first() {
+        None | Some(Value::Undefined) => "NFC".into(),
+        Some(form) => interpreter.to_string(form)?,
+    };
+    if !["NFC", "NFD", "NFKC", "NFKD"].iter().any(|known| form == *known) {
+        return Err(Error::Range(format!("The normalization form should be one of NFC, NFD, NFKC, NFKD, got {form}!")));
+    }
+    Ok(Value::String(string))
+}
+
+/**
+ * String.prototype.padEnd(length, filler) -> the string padded at its end up to the length.
+ */
+fn string_pad_end(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "padEnd")?;
+    let padding = padding(interpreter, &string, args)?;
+    Ok(Value::String(string.concat(&padding)))
+}
+
+/**
+ * String.prototype.padStart(length, filler) -> the string padded at its start up to the length.
+ */
+fn string_pad_start(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "padStart")?;
+    let padding = padding(interpreter, &string, args)?;
+    Ok(Value::String(padding.concat(&string)))
+}
+
+/**
+ * String.prototype.repeat(count) -> the string repeated the count of times.
+ */
+fn string_repeat(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "repeat")?;
+    let count = integer_argument(interpreter, args, 0)?;
+    if count < 0.0 || count.is_infinite() {
+        return Err(Error::Range(format!("Invalid count value: {}!", Value::Number(count))));
+    }
+    Ok(Value::String(JsString::from_units(&string.units().repeat(count as usize))))
+}
+
+/**
+ * String.prototype.replace(pattern, replacement) -> the string with the first occurrence of the pattern replaced.
+ */
+fn string_replace(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "replace")?;
+    if let Some(replaced) = delegate(interpreter, &interpreter.realm.symbols.replace, &string, args)? {
+        return Ok(replaced);
+    }
+    replace(interpreter, &string, args, false)
+}
+
+/**
+ * String.prototype.replaceAll(pattern, replacement) -> the string with every occurrence of the pattern replaced.
+ */
+fn string_replace_all(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "replaceAll")?;
+    let pattern = args.first().cloned().unwrap_or(Value::Undefined);
+    if is_regexp(interpreter, &pattern)? && !flags(interpreter, &pattern)?.contains('g') {
+        return Err(Error::Type("replaceAll must be called with a global RegExp!".to_string()));
+    }
+    if let Some(replaced) = delegate(interpreter, &interpreter.realm.symbols.replace, &string, args)? {
+        return Ok(replaced);
+    }
+    replace(interpreter, &string, args, true)
+}
+
+/**
+ * String.prototype.search(regexp) -> index of the first match of the regular expression, or -1.
+ */
+fn string_search(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "search")?;
+    let symbol = &interpreter.realm.symbols.search;
+    if let Some(index) = delegate(interpreter, symbol, &string, args)? {
+        return Ok(index);
+    }
+    let regexp = Value::Object(pattern_regexp(interpreter, args, "")?);
+    interpreter.call(&interpreter.get_property(&regexp, symbol)?, &regexp, &[Value::String(string)])
+}
+
+/**
+ * String.prototype.slice(start, end) -> code units between the indices, negative indices counting from the end.
+ */
+fn string_slice(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "slice")?;
+    let length = string.length();
+    let start = relative_index(integer_argument(interpreter, args, 0)?, length);
+    let end = match args.get(1) {
+        None | Some(Value::Undefined) => length,
+        Some(_) => relative_index(integer_argument(interpreter, args, 1)?, length),
+    };
+    Ok(Value::String(string.slice(start, end)))
+}
+
+/**
+ * String.prototype.split(separator, limit) -> array of the parts between the separators.
+ */
+fn string_split(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "split")?;
+    if let Some(parts) = delegate(interpreter, &interpreter.realm.symbols.split, &string, args)? {
+        return Ok(parts);
+    }
+    let limit = match args.get(1) {
+        None | Some(Value::Undefined) => u32::MAX as usize,
+        Some(limit) => {
+            let limit = interpreter.to_number(limit)?;
+            if limit.is_finite() { limit.trunc().rem_euclid(4294967296.0) as usize } else { 0 }
+        },
+    };
+    let separator = match args.first() {
+        None | Some(Value::Undefined) => None,
+        Some(separator) => Some(interpreter.to_string(separator)?.units().into_owned()),
+    };
+
+    let units = string.units();
+    let parts: Vec<Value> = match separator {
+        _ if limit == 0 => vec![],
+        None => vec![Value::String(string)],
+
+        // An empty separator splits every code unit, e.g. 'abc' -> ['a', 'b', 'c']
+        Some(separator) if separator.is_empty() => units.iter()
+            .map(|unit| Value::String(JsString::from_units(&[*unit])))
+            .take(limit)
+            .collect(),
+        Some(separator) => {
+            let mut parts = vec![];
+            let mut start = 0;
+            while let Some(index) = utf16::index_of(&units, &separator, start) {
+                parts.push(Value::String(JsString::from_units(&units[start..index])));
+                start = index + separator.len();
+            }
+            parts.push(Value::String(JsString::from_units(&units[start..])));
+            parts.into_iter().take(limit).collect()
+        },
+    };
+    Ok(Value::Object(create_array(interpreter, parts)))
+}
+
+/**
+ * String.prototype.startsWith(search, position) -> whether the string from the position starts with the search string.
+ */
+fn string_starts_with(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "startsWith")?;
+    let units = string.units();
+    let search = search_string(interpreter, args, "startsWith")?;
+    let position = clamp(integer_argument(interpreter, args, 1)?, units.len());
+    Ok(Value::Boolean(units[position..].starts_with(&search)))
+}
+
+/**
+ * String.prototype.substring(start, end) -> code units between the indices, swapped if needed.
+ */
+fn string_substring(interpreter: &Interpreter, this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "substring")?;
+    let length = string.length();
+    let start = clamp(integer_argument(interpreter, args, 0)?, length);
+    let end = match args.get(1) {
+        None | Some(Value::Undefined) => length,
+        Some(_) => clamp(integer_argument(interpreter, args, 1)?, length),
+    };
+    Ok(Value::String(string.slice(start.min(end), start.max(end))))
+}
+
+/**
+ * String.prototype.toLowerCase() -> the string in lowercase.
+ */
+fn string_to_lower_case(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "toLowerCase")?;
+    Ok(Value::String(map_text(&string, str::to_lowercase)))
+}
+
+/**
+ * String.prototype.toUpperCase() -> the string in uppercase.
+ */
+fn string_to_upper_case(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let string = this_string(interpreter, this, "toUpperCase")?;
+    Ok(Value::String(map_text(&string, str::to_uppercase)))
+}
+
+/**
+ * String.prototype.trim() -> the string without leading & trailing whitespaces.
+ */
+fn string_trim(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::String(trim(this_string(interpreter, this, "trim")?, true, true)))
+}
+
+/**
+ * String.prototype.trimEnd() -> the string without trailing whitespaces.
+ */
+fn string_trim_end(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::String(trim(this_string(interpreter, this, "trimEnd")?, false, true)))
+}
+
+/**
+ * String.prototype.trimStart() -> the string without leading whitespaces.
+ */
+fn string_trim_start(interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::String(trim(this_string(interpreter, this, "trimStart")?, true, false)))
+}
+
+/**
+ * String.prototype.valueOf() -> the string itself, as well as String.prototype.toString().
+ */
+fn string_value_of(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    match this_primitive(this) {
+        string @ Value::String(_) => Ok(string),
+        _ => Err(Error::Type(format!("Method String.prototype.valueOf called on incompatible receiver {this}!"))),
+    }
+}
+
+/**
+ * Converts `this` into a string, which mustn't be undefined or null.
+ */
+fn this_string(interpreter: &Interpreter, this: &Value, method: &str) -> Result<JsString> {
+    match this {
+        Value::Undefined | Value::Null => Err(Error::Type(format!("String.prototype.{method} called on null or undefined!"))),
+        _ => interpreter.to_string(this),
+    }
+}
+
+/**
+ * Calls the method of the pattern under the symbol with the string & the other arguments,
+ * e.g. `RegExp.prototype[Symbol.split]`. Returns None if the pattern doesn't define one.
+ */
+fn delegate(interpreter: &Interpreter, symbol: &Symbol, string: &JsString, args: &[Value]) -> Result<Option<Value>> {
+    let pattern = match args.first() {
+        None | Some(Value::Undefined | Value::Null) => return Ok(None),
+        Some(pattern) => pattern,
+    };
+    let method = interpreter.get_property(pattern, symbol)?;
+    if matches!(method, Value::Undefined | Value::Null) {
+        return Ok(None);
+    }
+    let mut args = args.to_vec();
+    args[0] = Value::String(string.clone());
+    interpreter.call(&method, pattern, &args).map(Some)
+}
+
+/**
+ * Creates a regular expression of the pattern argument, undefined matching the empty string.
+ */
+fn pattern_regexp(interpreter: &Interpreter, args: &[Value], flags: &str) -> Result<ObjectRef> {
+    let source = match args.first() {
+        None | Some(Value::Undefined) => JsString::default(),
+        Some(pattern) => interpreter.to_string(pattern)?,
+    };
+    create_regexp(interpreter, &source, flags)
+}
+
+/**
+ * Whether the value is a regular expression, or an object opting in with `Symbol.match`.
+ */
+fn is_regexp(interpreter: &Interpreter, value: &Value) -> Result<bool> {
+    let object = match value {
+        Value::Object(object) => object,
+        _ => return Ok(false),
+    };
+    match interpreter.get_property(value, &interpreter.realm.symbols.r#match)? {
+        Value::Undefined => Ok(object.regexp().is_some()),
+        matcher => Ok(matcher.to_boolean()),
+    }
+}
+
+fn flags(interpreter: &Interpreter, regexp: &Value) -> Result<JsString> {
+    interpreter.to_string(&interpreter.get_property(regexp, "flags")?)
+}
+
+/**
+ * Converts the search string argument, which mustn't be a regular expression.
+ */
+fn search_string(interpreter: &Interpreter, args: &[Value], method: &str) -> Result<Vec<u16>> {
+    let search = args.first().unwrap_or(&Value::Undefined);
+    if is_regexp(interpreter, search)? {
+        return Err(Error::Type(format!("First argument to String.prototype.{method} must not be a regular expression!")));
+    }
+    Ok(interpreter.to_string(search)?.units().into_owned())
+}
+
+/**
+ * Replaces the first or every occurrence of the pattern, either with the result of
+ * calling the replacement function or with the replacement string, where '$&' is
+ * the matched string, '$`' the string before it, '$'' the string after it & '$$' a '$'.
+ */
+fn replace(interpreter: &Interpreter, string: &JsString, args: &[Value], all: bool) -> Result<Value> {
+    let units = string.units();
+    let pattern = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?.units().into_owned();
+    let replacement = args.get(1).cloned().unwrap_or(Value::Undefined);
+    let template = match replacement.is_callable() {
+        true => None,
+        false => Some(interpreter.to_string(&replacement)?),
+    };
+
+    // Empty patterns match before every code unit & at the end, e.g. 'ab' -> '_a_b_'
+    let mut positions = vec![];
+    let mut start = 0;
+    while let Some(index) = utf16::index_of(&units, &pattern, start) {
+        positions.push(index);
+        if !all {
+            break;
+        }
+        start = index + pattern.len().max(1);
+    }
+
+    let mut result = vec![];
+    let mut end = 0;
+    for position in positions {
+        result.extend_from_slice(&units[end..position]);
+        end = position + pattern.len();
+        let matched = JsString::from_units(&units[position..end]);
+        let replaced = match &template {
+            None => {
+                let args = [Value::String(matched), Value::Number(position as f64), Value::String(string.clone())];
+                interpreter.to_string(&interpreter.call(&replacement, &Value::Undefined, &args)?)?.units().into_owned()
+            },
+            Some(template) => substitute(template, &[Some(matched)], None, &units[..position], &units[end..]),
+        };
+        result.extend(replaced);
+    }
+    result.extend_from_slice(&units[end..]);
+    Ok(Value::String(JsString::from_units(&result)))
+}
+
+/**
+ * Expands the '$' patterns of a replacement string into code units, unknown ones being kept as
+ * they are. The captures hold the matched string followed by the groups, e.g. '$1', & the named
+ * groups are only given for regular expressions having some, e.g. '$<year>'.
+ */
+pub fn substitute(
+    template: &JsString,
+    captures: &[Option<JsString>],
+    groups: Option<&[(String, Option<JsString>)]>,
+    before: &[u16],
+    after: &[u16],
+) -> Vec<u16> {
+    let template = template.units();
+    let char_at = |index: usize| template.get(index).and_then(|unit| char::from_u32(u32::from(*unit)));
+    let units = |capture: Option<&JsString>| capture.map(|capture| capture.units().into_owned()).unwrap_or_default();
+    let mut result = vec![];
+    let mut index = 0;
+    while index < template.len() {
+        let unit = template[index];
+        index += 1;
+        if unit != u16::from(b'$') {
+            result.push(unit);
+            continue;
+        }
+        match char_at(index) {
+            Some('$') => result.push(unit),
+            Some('&') => result.extend(units(captures[0].as_ref())),
+            Some('`') => result.extend_from_slice(before),
+            Some('\'') => result.extend_from_slice(after),
+
+            // Two digits refer to a group only if it exists, e.g. '$10' may be '$1' followed by '0'
+            Some(digit) if digit.is_ascii_digit() => {
+                let rest: String = (index..index + 2).map_while(char_at).collect();
+                let group = [rest.as_str(), &rest[..1]].into_iter()
+                    .find_map(|digits| digits.parse::<usize>().ok()
+                        .filter(|group| *group > 0 && *group < captures.len())
+                        .map(|group| (group, digits.len())));
+                match group {
+                    Some((group, length)) => {
+                        result.extend(units(captures[group].as_ref()));
+                        index += length - 1;
+                    },
+                    None => {
+                        result.push(unit);
+                        continue;
+                    },
+                }
+            },
+            Some('<') if groups.is_some() => {
+                match template[index..].iter().position(|unit| *unit == u16::from(b'>')) {
+                    Some(end) => {
+                        let name = String::from_utf16_lossy(&template[index + 1..index + end]);
+                        let value = groups.unwrap().iter().find(|(group, _)| *group == name).and_then(|(_, value)| value.as_ref());
+                        result.extend(units(value));
+                        index += end;
+                    },
+                    None => {
+                        result.push(unit);
+                        continue;
+                    },
+                }
+            },
+            _ => {
+                result.push(unit);
+                continue;
+            },
+        }
+        index += 1;
+    }
+    result
+}
+
+/**
+ * Repeats the filler, a space by default, up to the missing length of the string.
+ */
+fn padding(interpreter: &Interpreter, string: &JsString, args: &[Value]) -> Result<JsString> {
+    let length = interpreter.to_number(args.first().unwrap_or(&Value::Undefined))?;
+    let filler = match args.get(1) {
+        None | Some(Value::Undefined) => vec![b' ' as u16],
+        Some(filler) => interpreter.to_string(filler)?.units().into_owned(),
+    };
+    let missing = match length > string.length() as f64 {
+        true => length as usize - string.length(),
+        false => 0,
+    };
+    if filler.is_empty() {
+        return Ok(JsString::default());
+    }
+    let padding: Vec<u16> = filler.iter().copied().cycle().take(missing).collect();
+    Ok(JsString::from_units(&padding))
+}
+
+/**
+ * Removes the leading and/or trailing whitespaces, which are all single code units.
+ */
+fn trim(string: JsString, start: bool, end: bool) -> JsString {
+    let units = string.units();
+    let is_whitespace = |unit: &u16| char::from_u32(u32::from(*unit)).is_some_and(is_whitespace);
+    let first = match start {
+        true => units.iter().position(|unit| !is_whitespace(unit)).unwrap_or(units.len()),
+        false => 0,
+    };
+    let last = match end {
+        true => units.iter().rposition(|unit| !is_whitespace(unit)).map(|last| last + 1).unwrap_or(0),
+        false => units.len(),
+    };
+    string.slice(first, last.max(first))
+}
+
+/**
+ * Maps the text of the string, e.g. into lowercase, lone surrogates being kept as they are.
+ */
+fn map_text(string: &JsString, map: fn(&str) -> String) -> JsString {
+    if string.is_well_formed() {
+        return map(string).into();
+    }
+    let mut units = vec![];
+    for char in char::decode_utf16(string.units().iter().copied()) {
+        match char {
+            Ok(char) => units.extend(map(char.encode_utf8(&mut [0; 4])).encode_utf16()),
+            Err(error) => units.push(error.unpaired_surrogate()),
+        }
+    }
+    JsString::from_units(&units)
+}
+
+/**
+ * Whitespaces & line terminators trimmed by JS, including the byte order mark.
+ */
+fn is_whitespace(c: char) -> bool {
+    c == '\u{feff}' || (c.is_whitespace() && c != '\u{85}')
+}
+
+/**
+ * Converts an argument into an integer, NaN & undefined becoming 0, i.e. ToIntegerOrInfinity.
+ */
+fn integer_argument(interpreter: &Interpreter, args: &[Value], index: usize) -> Result<f64> {
+    let x = interpreter.to_number(args.get(index).unwrap_or(&Value::Undefined))?;
+    Ok(if x.is_nan() { 0.0 } else { x.trunc() })
+}
+
+/**
+ * Clamps an integer between 0 and the length.
+ */
+fn clamp(index: f64, length: usize) -> usize {
+    index.clamp(0.0, length as f64) as usize
+}
+
+/**
+ * Resolves an integer counting from the end if negative, clamped between 0 and the length.
+ */
+fn relative_index(index: f64, length: usize) -> usize {
+    match index < 0.0 {
+        true => clamp(length as f64 + index, length),
+        false => clamp(index, length),
+    }
+}
+
+fn index_value(index: Option<usize>) -> Value {
+    Value::Number(index.map(|index| index as f64).unwrap_or(-1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_string_conversion() {
+        let content = "
+            '' + [String(), String(1.5), String(null), String(10n), String([1, 2]), String(Symbol('s')), String(true)];
+        ";
+        assert_eval(content, Value::String(",1.5,null,10,1,2,Symbol(s),true".into()));
+
+        let content = "
+            let s = new String('abc');
+            '' + [s.length, s[1], s.toString(), s.valueOf() === 'abc', s === 'abc', 'x'.valueOf(), s.constructor === String];
+        ";
+        assert_eval(content, Value::String("3,b,abc,true,false,x,true".into()));
+        assert_eval_error("new String(Symbol());", Error::Type("Cannot convert a Symbol value to a string!".to_string()));
+    }
+
+    #[test]
+    fn test_utf16_indices() {
+        let content = "
+            let s = 'a😀b';
+            '' + [s.length, s.charCodeAt(1), s.codePointAt(1), s.codePointAt(2), s.indexOf('b'), s.slice(1, 3) === '😀', s.at(-1), s.charAt(9) === ''];
+        ";
+        assert_eval(content, Value::String("4,55357,128512,56832,3,true,b,true".into()));
+        assert_eval("String.fromCharCode(72, 105, 65536 + 33) + String.fromCodePoint(128512).length;", Value::String("Hi!2".into()));
+        assert_eval_error("String.fromCodePoint(-1);", Error::Range("Invalid code point -1!".to_string()));
+    }
+
+    #[test]
+    fn test_lone_surrogates() {
+        let content = "
+            let s = '😀';
+            let units = s.split('');
+            '' + [
+                units.length, units[0].length, units.join('') === s, units[0] + units[1] === s, s[0].concat(s[1]) === s,
+                s[0] === s[1], String.fromCharCode(55357, 56832) === s, s.slice(0, 1).charCodeAt(0), JSON.stringify(s[1]),
+                (s[1] + s[0]).length, s[0].padEnd(2, s[1]) === s, s[0].repeat(2).charCodeAt(1), s[0].toUpperCase() === s[0]
+            ];
+        ";
+        assert_eval(content, Value::String("2,1,true,true,true,false,true,55357,\"\\ude00\",2,true,55357,true".into()));
+    }
+
+    #[test]
+    fn test_string_search() {
+        let content = "
+            let s = 'abcabc';
+            '' + [
+                s.indexOf('c', 3), s.lastIndexOf('a'), s.lastIndexOf('a', 2), s.indexOf('x'), s.includes('ca'), s.includes('a', 4),
+                s.startsWith('bc', 1), s.endsWith('ca', 4), s.endsWith('c'), 'Apple'.localeCompare('apple'), 'a'.localeCompare('B'), 'b'.localeCompare('b'),
+                'é'.localeCompare('f'), 'É'.localeCompare('é')
+            ];
+        ";
+        assert_eval(content, Value::String("5,3,0,-1,true,false,true,true,true,1,-1,0,1,1".into()));
+    }
+
+    #[test]
+    fn test_string_slicing() {
+        let content = "
+            let s = 'hello world';
+            '' + [s.slice(-5), s.slice(2, -6), s.substring(5, 2), s.substring(-3, 2), s.slice(3, 1) === '', 'ab'.concat(1, null)];
+        ";
+        assert_eval(content, Value::String("world,llo,llo,he,true,ab1null".into()));
+
+        let content = "
+            let parts = 'a,b,,c'.split(',');
+            '' + [parts.length, parts[2] === '', 'abc'.split('').length, 'a,b,c'.split(',', 2), 'abc'.split().length, ''.split(',').length, ''.split('').length];
+        ";
+        assert_eval(content, Value::String("4,true,3,a,b,1,1,0".into()));
+    }
+
+    #[test]
+    fn test_string_replace() {
+        let content = "
+            let s = 'a-b-c';
+            '' + [
+                s.replace('-', '+'), s.replaceAll('-', '+'), s.replace('b', \"[$&|$`|$'|$$|$1]\"), 'ab'.replaceAll('', '_'),
+                s.replaceAll('-', function(matched, position) { return position; })
+            ];
+        ";
+        assert_eval(content, Value::String("a+b-c,a+b+c,a-[b|a-|-c|$|$1]-c,_a_b_,a1b3c".into()));
+    }
+
+    #[test]
+    fn test_string_formatting() {
+        let content = "
+            '' + [
+                '5'.padStart(3, '0'), 'ab'.padEnd(7, 'xyz'), 'abc'.padStart(2), 'ab'.repeat(3), '  hi  '.trim() + '|',
+                '  hi'.trimStart(), ('hi  '.trimEnd() + '|'), 'Straße'.toUpperCase(), 'ÀB'.toLowerCase()
+            ];
+        ";
+        assert_eval(content, Value::String("005,abxyzxy,abc,ababab,hi|,hi,hi|,STRASSE,àb".into()));
+        assert_eval_error("'a'.repeat(-1);", Error::Range("Invalid count value: -1!".to_string()));
+        assert_eval_error("let trim = String.prototype.trim; trim();", Error::Type(
+            "String.prototype.trim called on null or undefined!".to_string()
+        ));
+    }
+}
@@ -0,0 +1,184 @@
+use crate::prelude::*;
+
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::realm::Realm;
+
+use super::{define_global, native_function, this_primitive};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let prototype = ObjectRef::new(ObjectKind::Ordinary, realm.intrinsic("%Object.prototype%"));
+    let methods: [(&str, usize, NativeFn); 2] = [
+        ("toString", 0, symbol_to_string),
+        ("valueOf", 0, symbol_value_of),
+    ];
+    for (name, length, function) in methods {
+        let method = native_function(realm, name, length, function, false);
+        prototype.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    let description = native_function(realm, "get description", 0, symbol_description, false);
+    prototype.define_non_enumerable("description".to_string(), Property::Accessor {
+        get: Some(Value::Object(description)),
+        set: None,
+    });
+    let to_primitive = native_function(realm, "[Symbol.toPrimitive]", 1, symbol_value_of, false);
+    prototype.define_non_enumerable(&realm.symbols.to_primitive, Property::Data(Value::Object(to_primitive)));
+    prototype.define_non_enumerable(&realm.symbols.to_string_tag, Property::Data(Value::String("Symbol".into())));
+
+    let constructor = native_function(realm, "Symbol", 0, construct_symbol, true);
+    let statics: [(&str, usize, NativeFn); 2] = [
+        ("for", 1, symbol_for),
+        ("keyFor", 1, symbol_key_for),
+    ];
+    for (name, length, function) in statics {
+        let method = native_function(realm, name, length, function, false);
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Object(method)));
+    }
+    for (name, symbol) in realm.symbols.entries() {
+        constructor.define_non_enumerable(name.to_string(), Property::Data(Value::Symbol(symbol.clone())));
+    }
+    constructor.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+    prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(constructor.clone())));
+
+    realm.define_intrinsic("%Symbol.prototype%", prototype);
+    define_global(global, "Symbol", Value::Object(constructor));
+}
+
+/**
+ * Symbol(description) -> new unique symbol, which can't be created with `new`.
+ */
+fn construct_symbol(interpreter: &Interpreter, _this: &Value, args: &[Value], new_target: Option<&ObjectRef>) -> Result<Value> {
+    if new_target.is_some() {
+        return Err(Error::Type("Symbol is not a constructor!".to_string()));
+    }
+    let description = match args.first() {
+        None | Some(Value::Undefined) => None,
+        Some(description) => Some(interpreter.to_string(description)?.to_string()),
+    };
+    Ok(Value::Symbol(Symbol::new(description)))
+}
+
+/**
+ * Symbol.for(key) -> symbol shared by all the code under the key.
+ */
+fn symbol_for(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let key = interpreter.to_string(args.first().unwrap_or(&Value::Undefined))?;
+    Ok(Value::Symbol(interpreter.realm.registered_symbol(&key)))
+}
+
+/**
+ * Symbol.keyFor(symbol) -> key of the shared symbol, or undefined.
+ */
+fn symbol_key_for(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    match args.first() {
+        Some(Value::Symbol(symbol)) => Ok(match interpreter.realm.symbol_key(symbol) {
+            Some(key) => Value::String(key.into()),
+            None => Value::Undefined,
+        }),
+        argument => Err(Error::Type(format!("{} is not a symbol!", argument.unwrap_or(&Value::Undefined)))),
+    }
+}
+
+/**
+ * Symbol.prototype.toString() -> 'Symbol(description)'
+ */
+fn symbol_to_string(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::String(this_symbol(this, "toString")?.to_string().into()))
+}
+
+/**
+ * Symbol.prototype.valueOf(), Symbol.prototype[Symbol.toPrimitive]() -> the symbol itself.
+ */
+fn symbol_value_of(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(Value::Symbol(this_symbol(this, "valueOf")?))
+}
+
+/**
+ * get Symbol.prototype.description -> description of the symbol, or undefined.
+ */
+fn symbol_description(_interpreter: &Interpreter, this: &Value, _args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    Ok(match this_symbol(this, "description")?.description() {
+        Some(description) => Value::String(description.into()),
+        None => Value::Undefined,
+    })
+}
+
+fn this_symbol(this: &Value, method: &str) -> Result<Symbol> {
+    match this_primitive(this) {
+        Value::Symbol(symbol) => Ok(symbol),
+        _ => Err(Error::Type(format!("Method Symbol.prototype.{method} called on incompatible receiver {this}!"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_symbol_identity() {
+        assert_eval("Symbol('id') === Symbol('id');", Value::Boolean(false));
+        assert_eval("let id = Symbol('id'); id === id;", Value::Boolean(true));
+        assert_eval("Symbol.for('app') === Symbol.for('app');", Value::Boolean(true));
+        assert_eval("Symbol.keyFor(Symbol.for('app'));", Value::String("app".into()));
+        assert_eval("Symbol.keyFor(Symbol('app'));", Value::Undefined);
+        assert_eval("Symbol.keyFor(Symbol.iterator);", Value::Undefined);
+        assert_eval_error("Symbol.keyFor('app');", Error::Type("app is not a symbol!".to_string()));
+        assert_eval_error("new Symbol();", Error::Type("Symbol is not a constructor!".to_string()));
+    }
+
+    #[test]
+    fn test_symbol_prototype() {
+        assert_eval("Symbol('id').toString();", Value::String("Symbol(id)".into()));
+        assert_eval("Symbol('id').description;", Value::String("id".into()));
+        assert_eval("Symbol().description;", Value::Undefined);
+        assert_eval("Symbol.iterator.description;", Value::String("Symbol.iterator".into()));
+        assert_eval("Symbol('id')[Symbol.toStringTag];", Value::String("Symbol".into()));
+        assert_eval_error("Symbol('id') + '';", Error::Type("Cannot convert a Symbol value to a string!".to_string()));
+        assert_eval_error("Symbol('id') * 2;", Error::Type("Cannot convert a Symbol value to a number!".to_string()));
+    }
+
+    #[test]
+    fn test_symbol_keyed_properties() {
+        let content = "
+            class Box {
+                constructor() { this.visible = 1; }
+            }
+            let hidden = Symbol('hidden');
+            let box = new Box();
+            box[hidden] = 2;
+            let keys = [];
+            for (let key in box) {
+                keys[keys.length] = key;
+            }
+            '' + [keys, box[hidden], box[Symbol('hidden')]];
+        ";
+        assert_eval(content, Value::String("visible,2,".into()));
+    }
+
+    #[test]
+    fn test_well_known_symbols() {
+        let content = "
+            class Range {
+                constructor(end) { this.end = end; }
+                *[Symbol.iterator]() {
+                    for (let i = 0; i < this.end; i = i + 1) {
+                        yield i;
+                    }
+                }
+            }
+            let out = [];
+            for (const i of new Range(3)) {
+                out[out.length] = i;
+            }
+            '' + [out, ...new Range(2)];
+        ";
+        assert_eval(content, Value::String("0,1,2,0,1".into()));
+
+        let content = "
+            class Positive {}
+            Positive[Symbol.hasInstance] = function(value) { return value > 0; };
+            '' + [2 instanceof Positive, -3 instanceof Positive];
+        ";
+        assert_eval(content, Value::String("true,false".into()));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::prelude::*;
+
+use crate::interpreter::event_loop::{EventLoop, Job};
+use crate::interpreter::realm::Realm;
+
+use super::{define_global, native_function};
+
+pub fn install(realm: &mut Realm, global: &ObjectRef) {
+    let functions: [(&str, usize, NativeFn); 5] = [
+        ("setTimeout", 1, set_timeout),
+        ("setInterval", 1, set_interval),
+        ("clearTimeout", 0, clear_timer),
+        ("clearInterval", 0, clear_timer),
+        ("queueMicrotask", 1, queue_microtask),
+    ];
+    for (name, length, function) in functions {
+        define_global(global, name, Value::Object(native_function(realm, name, length, function, false)));
+    }
+}
+
+/**
+ * setTimeout(callback, delay, ...args) -> id of the timer calling back once after the delay.
+ */
+fn set_timeout(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    schedule(interpreter, args, false)
+}
+
+/**
+ * setInterval(callback, delay, ...args) -> id of the timer calling back every time the delay elapses.
+ */
+fn set_interval(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    schedule(interpreter, args, true)
+}
+
+fn schedule(interpreter: &Interpreter, args: &[Value], repeat: bool) -> Result<Value> {
+    let callback = args.first().cloned().unwrap_or(Value::Undefined);
+    if !callback.is_callable() {
+        return Err(Error::Type(format!("Timer callback {callback} is not a function!")));
+    }
+    let delay = args.get(1).map(Value::to_number).unwrap_or(0.0);
+    let arguments = args.iter().skip(2).cloned().collect();
+
+    let id = interpreter.set_timer(callback, arguments, delay, repeat);
+    Ok(Value::Number(id as f64))
+}
+
+/**
+ * clearTimeout(id), clearInterval(id) -> cancels the timer, ignoring unknown ids.
+ */
+fn clear_timer(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let id = args.first().map(Value::to_number).unwrap_or(0.0);
+    if id >= 1.0 && id.fract() == 0.0 {
+        interpreter.clear_timer(id as usize);
+    }
+    Ok(Value::Undefined)
+}
+
+/**
+ * queueMicrotask(callback) -> calls back once the running code & the jobs queued before completed.
+ */
+fn queue_microtask(interpreter: &Interpreter, _this: &Value, args: &[Value], _new_target: Option<&ObjectRef>) -> Result<Value> {
+    let callback = args.first().cloned().unwrap_or(Value::Undefined);
+    if !callback.is_callable() {
+        return Err(Error::Type(format!("Microtask callback {callback} is not a function!")));
+    }
+    interpreter.enqueue_job(Job::Microtask { callback });
+    Ok(Value::Undefined)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_set_timeout() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            setTimeout(log, 200, 'c');
+            setTimeout(log, 100, 'a');
+            setTimeout(log, 100, 'b');
+            setTimeout(log, -5, 'now');
+            log('sync');
+        ";
+        let clock = assert_binding(content, "out", "sync,now,a,b,c");
+        assert_eq!(clock.now(), 200.0);
+    }
+
+    #[test]
+    fn test_macrotasks_after_microtasks() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            setTimeout(function() {
+                log('timeout');
+                Promise.resolve().then(function() { log('timeout promise'); });
+                queueMicrotask(function() { log('timeout microtask'); });
+            }, 0);
+            setTimeout(function() { log('second timeout'); }, 0);
+            Promise.resolve().then(function() { log('promise'); });
+            queueMicrotask(function() { log('microtask'); });
+            log('sync');
+        ";
+        assert_binding(content, "out", "sync,promise,microtask,timeout,timeout promise,timeout microtask,second timeout");
+    }
+
+    #[test]
+    fn test_clear_timeout() {
+        let content = "
+            let x = 'kept';
+            let id = setTimeout(function() { x = 'cleared'; }, 10);
+            clearTimeout(id);
+            clearTimeout(42);
+            clearTimeout();
+        ";
+        let clock = assert_binding(content, "x", "kept");
+        assert_eq!(clock.now(), 0.0);
+    }
+
+    #[test]
+    fn test_set_interval() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            let count = 0;
+            let id = setInterval(function() {
+                count = count + 1;
+                log('tick ' + count);
+                if (count === 3) {
+                    clearInterval(id);
+                }
+            }, 1000);
+            setTimeout(function() { log('timeout'); }, 1500);
+        ";
+        let clock = assert_binding(content, "out", "tick 1,timeout,tick 2,tick 3");
+        assert_eq!(clock.now(), 3000.0);
+    }
+
+    #[test]
+    fn test_timers_with_async_functions() {
+        let content = "
+            let out = [];
+            function log(x) { out[out.length] = x; }
+            function sleep(ms) {
+                return new Promise(function(resolve) { setTimeout(resolve, ms); });
+            }
+            async function run(name, ms) {
+                await sleep(ms);
+                log(name);
+                await sleep(ms);
+                log(name);
+            }
+            run('slow', 300);
+            run('fast', 100);
+        ";
+        let clock = assert_binding(content, "out", "fast,fast,slow,slow");
+        assert_eq!(clock.now(), 600.0);
+    }
+
+    #[test]
+    fn test_invalid_callbacks() {
+        assert_eval_error("setTimeout(1, 10);", Error::Type("Timer callback 1 is not a function!".to_string()));
+        assert_eval_error("queueMicrotask();", Error::Type("Microtask callback undefined is not a function!".to_string()));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::prelude::*;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::interpreter::asynchronous::AsyncEvalable;
+use crate::interpreter::builtins::object::define_property_or_throw;
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::generators::GeneratorEvalable;
+use crate::interpreter::properties::PropertyAccessor;
+use crate::interpreter::proxies::ProxyOperations;
+use crate::interpreter::statements::StatementEvalable;
+
+pub trait Callable {
+    /**
+     * Creates a function object out of a user-defined function.
+     */
+    fn create_function(&self, function: Function) -> ObjectRef;
+
+    /**
+     * Invokes a function value with the given `this` value and arguments.
+     */
+    fn call(&self, callee: &Value, this: &Value, args: &[Value]) -> Result<Value>;
+
+    /**
+     * Invokes a function value as a constructor, i.e. `new F(...)`.
+     * The `new_target` is the constructor `new` was initially applied
+     * to, which differs from the callee while running `super(...)`.
+     */
+    fn construct(&self, callee: &Value, args: &[Value], new_target: &ObjectRef) -> Result<Value>;
+
+    /**
+     * Defines the class fields of the function on a freshly constructed object.
+     */
+    fn initialize_fields(&self, function: &Function, this: &Value) -> Result<()>;
+}
+
+impl <'a> Callable for Interpreter<'a> {
+    fn create_function(&self, function: Function) -> ObjectRef {
+        let name = function.name.clone();
+        let generator = function.generator;
+        let normal = function.kind == FunctionKind::Normal && !function.is_async;
+
+        // Parameters following one with a default value or a rest parameter aren't counted
+        let length = function.params.iter().take_while(|param| matches!(param, Tree::Identifier { .. })).count();
+        let object = ObjectRef::new(ObjectKind::Function(Rc::new(function)), self.realm.intrinsic("%Function.prototype%"));
+        object.define_function_length(length as f64);
+        object.define_non_enumerable("name".to_string(), Property::Data(Value::String(name.into())));
+
+        // Generator objects inherit from their function's prototype, instances
+        // of plain constructor functions from a fresh object pointing back at it
+        if generator {
+            let prototype = ObjectRef::new(ObjectKind::Ordinary, self.realm.intrinsic("%GeneratorPrototype%"));
+            object.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype)));
+        } else if normal {
+            let prototype = ObjectRef::new(ObjectKind::Ordinary, self.realm.intrinsic("%Object.prototype%"));
+            prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(object.clone())));
+            object.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype)));
+        }
+        object
+    }
+
+    fn call(&self, callee: &Value, this: &Value, args: &[Value]) -> Result<Value> {
+        // Built-ins & proxies may call back into each other as deep as the objects are nested
+        self.check_call_depth()?;
+
+        // Bound functions call their target, e.g. `f.bind(o, 1)(2)` -> `f.call(o, 1, 2)`
+        if let Value::Object(object) = callee {
+            if let Some(bound) = object.bound_function() {
+                return self.call(&Value::Object(bound.target), &bound.this, &[bound.args, args.to_vec()].concat());
+            }
+        }
+
+        let (object, function) = match callee {
+            Value::Object(object) => match (object.function(), object.native_function(), object.proxy()) {
+                (Some(function), _, _) => (object, function),
+                (None, Some(native), _) => return (native.function)(self, this, args, None),
+                (None, None, Some(proxy)) if object.is_callable() => return self.proxy_call(&proxy, this, args),
+                _ => return Err(Error::Type(format!("{callee:?} is not a function!"))),
+            },
+            _ => return Err(Error::Type(format!("{callee} is not a function!"))),
+        };
+
+        if let FunctionKind::ClassConstructor { .. } = function.kind {
+            return Err(Error::Type(
+                format!("Class constructor {} cannot be invoked without 'new'!", function.name)
+            ));
+        }
+
+        if function.generator {
+            let env = self.function_environment(object, &function, Some(this.clone()), args, Value::Undefined);
+            return self.create_generator(object, function, env);
+        }
+
+        if function.is_async {
+            let env = self.function_environment(object, &function, Some(this.clone()), args, Value::Undefined);
+            return self.call_async(function, env);
+        }
+
+        let (value, _) = self.invoke(object, &function, Some(this.clone()), args, Value::Undefined)?;
+        Ok(value)
+    }
+
+    fn construct(&self, callee: &Value, args: &[Value], new_target: &ObjectRef) -> Result<Value> {
+        self.check_call_depth()?;
+
+        // Bound functions construct their target, e.g. `new (F.bind(null, 1))(2)` -> `new F(1, 2)`
+        if let Value::Object(object) = callee {
+            if let Some(bound) = object.bound_function().filter(|bound| bound.target.is_constructor()) {
+                let new_target = if object == new_target { &bound.target } else { new_target };
+                return self.construct(&Value::Object(bound.target.clone()), &[bound.args, args.to_vec()].concat(), new_target);
+            }
+        }
+
+        let (object, function) = match callee {
+            Value::Object(object) if object.is_constructor() => match (object.function(), object.native_function(), object.proxy()) {
+                (Some(function), _, _) => (object, function),
+                (None, Some(native), _) => return (native.function)(self, &Value::Undefined, args, Some(new_target)),
+                (None, None, Some(proxy)) => return self.proxy_construct(&proxy, args, new_target),
+                _ => return Err(Error::Type(format!("{callee:?} is not a constructor!"))),
+            },
+            _ => return Err(Error::Type(format!("{callee:?} is not a constructor!"))),
+        };
+
+        // Derived classes receive their `this` value from the parent constructor
+        if let FunctionKind::ClassConstructor { derived: true, implicit } = function.kind {
+            let parent = match object.prototype() {
+                Some(parent) => Value::Object(parent),
+                None => return Err(Error::Type("Super constructor is not a constructor!".to_string())),
+            };
+
+            if implicit {
+                let this = self.construct(&parent, args, new_target)?;
+                self.initialize_fields(&function, &this)?;
+                return Ok(this);
+            }
+
+            let (value, env) = self.invoke(object, &function, None, args, Value::Object(new_target.clone()))?;
+            if let Value::Object(_) = value {
+                return Ok(value);
+            }
+            return env.lookup(&"this".to_string()).map_err(|_| Error::Reference(
+                "Must call super constructor in derived class before accessing 'this' or returning from derived constructor!".to_string()
+            ));
+        }
+
+        // Base constructors allocate the object using `new_target`'s prototype
+        let prototype = match self.get_property(&Value::Object(new_target.clone()), "prototype")? {
+            Value::Object(prototype) => Some(prototype),
+            _ => self.realm.intrinsic("%Object.prototype%"),
+        };
+        let this = Value::Object(ObjectRef::new(ObjectKind::Ordinary, prototype));
+        self.initialize_fields(&function, &this)?;
+
+        let (value, _) = self.invoke(object, &function, Some(this.clone()), args, Value::Object(new_target.clone()))?;
+        match value {
+            Value::Object(_) => Ok(value),
+            _ => Ok(this),
+        }
+    }
+
+    fn initialize_fields(&self, function: &Function, this: &Value) -> Result<()> {
+        // Private methods are installed before any field initializer runs, as those may call them
+        if let Value::Object(object) = this {
+            for (key, property) in &function.private_methods {
+                if object.get_own_property(key).is_some() {
+                    return Err(Error::Type(format!("Cannot initialize private methods of class {} twice on the same object!", function.name)));
+                }
+                object.define_private_method(key.clone(), property.clone());
+            }
+        }
+
+        for field in &function.fields {
+            self.define_field(field, &function.env, this)?;
+        }
+        Ok(())
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Defines a field of a class on an instance, or on the class itself for static fields.
+     */
+    pub(crate) fn define_field(&self, field: &FieldDefinition, env: &Environment, this: &Value) -> Result<()> {
+        // Field initializers see the object as `this`
+        let mut env = env.extend();
+        env.define("this".to_string(), this.clone());
+        let value = match &field.value {
+            None => Value::Undefined,
+            Some(value) => self.eval_tree(value, &RefCell::new(&mut env))?,
+        };
+
+        let object = match this {
+            Value::Object(object) => object,
+            _ => return Ok(()),
+        };
+
+        // Private fields are added even to non-extensible objects, but only once
+        if let PropertyKey::Private(_) = field.key {
+            if object.get_own_property(&field.key).is_some() {
+                return Err(Error::Type(format!("Cannot initialize {} twice on the same object!", field.key)));
+            }
+            object.define_property(&field.key, Property::Data(value));
+            return Ok(());
+        }
+
+        // Fields are defined on the object, regardless of inherited setters or read-only properties
+        define_property_or_throw(self, object, field.key.clone(), PropertyDescriptor::data(value))
+    }
+
+    /**
+     * Creates the environment a function's body runs in,
+     * binding `this`, `new.target` & the arguments.
+     */
+    pub(crate) fn function_environment(
+        &self,
+        object: &ObjectRef,
+        function: &Function,
+        this: Option<Value>,
+        args: &[Value],
+        new_target: Value,
+    ) -> Environment {
+        let mut env = function.env.extend();
+
+        // Sloppy mode functions see the global object in place of a missing `this`, & primitives as objects
+        if let Some(this) = this {
+            let this = match &this {
+                _ if function.strict => this,
+                Value::Object(_) => this,
+                Value::Undefined | Value::Null => match self.realm.intrinsic("%GlobalObject%") {
+                    Some(global) => Value::Object(global),
+                    None => this,
+                },
+                _ => self.to_object(&this).map(Value::Object).unwrap_or(this),
+            };
+            env.define("this".to_string(), this);
+        }
+        env.define("new.target".to_string(), new_target);
+        env.define("%function".to_string(), Value::Object(object.clone()));
+
+        for (index, param) in function.params.iter().enumerate() {
+            if let Tree::Identifier { name } = param {
+                let value = args.get(index).cloned().unwrap_or(Value::Undefined);
+                env.define(name.clone(), value);
+            }
+        }
+        env
+    }
+
+    /**
+     * Runs the body of a user-defined function, turning `return` into its result.
+     */
+    pub(crate) fn eval_function_body(&self, function: &Function, env_ref: &EnvRef) -> Result<Value> {
+        let body = match function.body.as_ref() {
+            Tree::BlockStatement { body } => body,
+            _ => return Err(Error::Runtime(format!("Invalid function body: {}", function.body))),
+        };
+
+        match self.run_in_mode(function.strict, || self.eval_statement_list(body, env_ref)) {
+            Ok(_) => Ok(Value::Undefined),
+            Err(Error::Abrupt(Completion::Return(value))) => Ok(value),
+            Err(error) => Err(error),
+        }
+    }
+
+    /**
+     * Runs the body of a user-defined function in a fresh environment,
+     * returning the result along with the environment it ran in.
+     */
+    fn invoke(
+        &self,
+        object: &ObjectRef,
+        function: &Function,
+        this: Option<Value>,
+        args: &[Value],
+        new_target: Value,
+    ) -> Result<(Value, Environment)> {
+        self.check_call_depth()?;
+        let mut env = self.function_environment(object, function, this, args, new_target);
+
+        self.call_stack.borrow_mut().push(frame_name(function));
+        let result = self.eval_function_body(function, &RefCell::new(&mut env));
+        self.call_stack.borrow_mut().pop();
+
+        result.map(|value| (value, env))
+    }
+}
+
+/**
+ * Describes a function in stack traces.
+ */
+pub(crate) fn frame_name(function: &Function) -> String {
+    match function.name.as_str() {
+        "" => "<anonymous>".to_string(),
+        name => name.to_string(),
+    }
+}
@@ -0,0 +1,451 @@
+use crate::prelude::*;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::interpreter::builtins::object::define_property_or_throw;
+use crate::interpreter::callable::Callable;
+use crate::interpreter::properties::PropertyAccessor;
+
+pub trait ClassEvalable {
+    /**
+     * Evaluates class AST Tree nodes.
+     * + ClassDeclaration
+     */
+    fn eval_class(&self, class: &Tree, env_ref: &EnvRef) -> Result<Value>;
+}
+
+impl <'a> ClassEvalable for Interpreter<'a> {
+    fn eval_class(&self, class: &Tree, env_ref: &EnvRef) -> Result<Value> {
+        let (name, body, super_class, source) = match class {
+            Tree::ClassDeclaration { idenifier, body, super_class, source } => match (idenifier.as_ref(), body.as_ref()) {
+                (Tree::Identifier { name }, Tree::ClassBody { body }) => (name, body, super_class, source),
+                _ => return Err(Error::Runtime(format!("Invalid class declaration node: {class}"))),
+            },
+            _ => return Err(Error::Runtime(format!("Unimplemented class node: {class}"))),
+        };
+
+        // Resolving parent constructor & the prototype of instances
+        let parent = match super_class.as_ref() {
+            None => None,
+            Some(super_class) => match self.eval_tree(super_class, env_ref)? {
+                Value::Object(parent) if parent.is_constructor() => Some(parent),
+                value => return Err(Error::Type(
+                    format!("Class extends value {value} is not a constructor!")
+                )),
+            },
+        };
+        let parent_prototype = match &parent {
+            None => self.realm.intrinsic("%Object.prototype%"),
+            Some(parent) => match self.get_property(&Value::Object(parent.clone()), "prototype")? {
+                Value::Object(prototype) => Some(prototype),
+                _ => None,
+            },
+        };
+        let prototype = ObjectRef::new(ObjectKind::Ordinary, parent_prototype);
+
+        let mut env = env_ref.borrow().extend();
+
+        // Each private name is a fresh symbol, shared by the getter & setter of an accessor
+        for statement in body {
+            if let Some(Tree::PrivateIdentifier { name }) = element_name(statement) {
+                if !env.has_own(name) {
+                    env.define(name.clone(), Value::Symbol(Symbol::new(Some(name.clone()))));
+                }
+            }
+        }
+
+        let mut scope = env.clone();
+        let scope_ref = RefCell::new(&mut scope);
+        let method = |value: &Tree, key: &PropertyKey, home_object: &ObjectRef| -> Result<Value> {
+            match value {
+                Tree::FunctionExpression { params, body, generator, is_async, strict, source, .. } => Ok(Value::Object(self.create_function(Function {
+                    name: key.function_name(),
+                    params: params.clone(),
+                    body: body.clone(),
+                    env: env.clone(),
+                    kind: FunctionKind::Method,
+                    generator: *generator,
+                    is_async: *is_async,
+                    strict: *strict,
+                    home_object: Some(home_object.clone()),
+                    fields: vec![],
+                    private_methods: vec![],
+                    source: source.clone(),
+                }))),
+                _ => Err(Error::Runtime(format!("Invalid method node: {value}"))),
+            }
+        };
+
+        // Keys are computed in order, while static elements wait for the class to exist
+        let mut constructor = None;
+        let mut fields = vec![];
+        let private_methods = ObjectRef::new(ObjectKind::Ordinary, None);
+        let mut statics = vec![];
+        for statement in body {
+            let (key, computed, is_static) = match statement {
+                Tree::ConstructorDefinition { value } => {
+                    constructor = Some(value.as_ref());
+                    continue;
+                },
+                Tree::StaticBlock { .. } => {
+                    statics.push((None, statement));
+                    continue;
+                },
+                Tree::MethodDefinition { key, computed, is_static, .. }
+                | Tree::GetterDefinition { key, computed, is_static, .. }
+                | Tree::SetterDefinition { key, computed, is_static, .. }
+                | Tree::PropertyDefinition { key, computed, is_static, .. } => (key, *computed, *is_static),
+                _ => return Err(Error::Runtime(format!("Unimplemented class element: {statement}"))),
+            };
+
+            let key = self.eval_property_key(key, computed, &scope_ref)?;
+            match statement {
+                _ if is_static => statics.push((Some(key), statement)),
+                Tree::PropertyDefinition { value, .. } => fields.push(FieldDefinition {
+                    key,
+                    value: value.as_ref().clone(),
+                }),
+                _ => {
+                    // Private methods are added to each instance rather than inherited
+                    let target = match key {
+                        PropertyKey::Private(_) => &private_methods,
+                        _ => &prototype,
+                    };
+                    let function = method(method_value(statement), &key, &prototype)?;
+                    define_method(self, target, key, statement, function)?;
+                },
+            }
+        }
+
+        // Classes without constructor get an implicit one
+        let (params, body) = match constructor {
+            Some(Tree::FunctionExpression { params, body, .. }) => (params.clone(), body.clone()),
+            _ => (Rc::from([]), Rc::new(Tree::BlockStatement { body: vec![] })),
+        };
+        let private_methods = private_methods.borrow().properties.iter()
+            .map(|(key, property)| (key.clone(), property.clone()))
+            .collect();
+        let class = self.create_function(Function {
+            name: name.clone(),
+            params,
+            body,
+            env: env.clone(),
+            kind: FunctionKind::ClassConstructor {
+                derived: parent.is_some(),
+                implicit: constructor.is_none(),
+            },
+            generator: false,
+            is_async: false,
+            strict: true,
+            home_object: Some(prototype.clone()),
+            fields,
+            private_methods,
+            source: source.clone(),
+        });
+
+        // Static members are inherited through the constructor's prototype
+        if let Some(parent) = parent {
+            class.borrow_mut().prototype = Some(parent);
+        }
+        // The prototype of a class is read-only, e.g. static elements can't replace it
+        class.define_non_enumerable("prototype".to_string(), Property::Data(Value::Object(prototype.clone())));
+        class.borrow_mut().non_writable.insert("prototype".into());
+        class.borrow_mut().non_configurable.insert("prototype".into());
+        prototype.define_non_enumerable("constructor".to_string(), Property::Data(Value::Object(class.clone())));
+
+        // Static methods are defined first, as static fields & blocks may call them
+        for (key, statement) in &statics {
+            if let (Some(key), Tree::MethodDefinition { .. } | Tree::GetterDefinition { .. } | Tree::SetterDefinition { .. }) = (key, statement) {
+                let function = method(method_value(statement), key, &class)?;
+                define_method(self, &class, key.clone(), statement, function)?;
+            }
+        }
+
+        // Static fields & blocks run in order, with the class as `this` & its name already bound
+        let this = Value::Object(class.clone());
+        scope_ref.borrow_mut().define(name.clone(), this.clone());
+        for (key, statement) in &statics {
+            match (key, statement) {
+                (Some(key), Tree::PropertyDefinition { value, .. }) => {
+                    let field = FieldDefinition { key: key.clone(), value: value.as_ref().clone() };
+                    self.define_field(&field, &env, &this)?;
+                },
+                (None, Tree::StaticBlock { body }) => {
+                    // Blocks run like a static method, so that `super` refers to the parent class
+                    let block = Tree::FunctionExpression {
+                        identifier: Box::new(None),
+                        params: Rc::from([]),
+                        body: body.clone(),
+                        generator: false,
+                        is_async: false,
+                        strict: true,
+                        source: Rc::from(""),
+                    };
+                    let block = method(&block, &PropertyKey::from(""), &class)?;
+                    self.call(&block, &this, &[])?;
+                },
+                _ => {},
+            }
+        }
+
+        env_ref.borrow_mut().define(name.clone(), this);
+        Ok(Value::Undefined)
+    }
+}
+
+/**
+ * Returns the name of a class element, unless it's computed.
+ */
+fn element_name(element: &Tree) -> Option<&Tree> {
+    match element {
+        Tree::MethodDefinition { key, computed: false, .. }
+        | Tree::GetterDefinition { key, computed: false, .. }
+        | Tree::SetterDefinition { key, computed: false, .. }
+        | Tree::PropertyDefinition { key, computed: false, .. } => Some(key.as_ref()),
+        _ => None,
+    }
+}
+
+/**
+ * Returns the function expression of a method, getter or setter.
+ */
+fn method_value(element: &Tree) -> &Tree {
+    match element {
+        Tree::MethodDefinition { value, .. }
+        | Tree::GetterDefinition { value, .. }
+        | Tree::SetterDefinition { value, .. } => value,
+        _ => element,
+    }
+}
+
+/**
+ * Defines a method on the prototype, the class itself or the holder of private methods.
+ * A getter & setter of the same key are merged into one accessor property. Public methods
+ * are defined like by `Object.defineProperty`, which throws if the property can't be redefined.
+ */
+fn define_method(interpreter: &Interpreter, target: &ObjectRef, key: PropertyKey, element: &Tree, function: Value) -> Result<()> {
+    if let PropertyKey::Private(_) = key {
+        let (get, set) = match target.get_own_property(&key) {
+            Some(Property::Accessor { get, set }) => (get, set),
+            _ => (None, None),
+        };
+        let property = match element {
+            Tree::GetterDefinition { .. } => Property::Accessor { get: Some(function), set },
+            Tree::SetterDefinition { .. } => Property::Accessor { get, set: Some(function) },
+            _ => Property::Data(function),
+        };
+        target.define_private_method(key, property);
+        return Ok(());
+    }
+
+    // Accessor descriptors leave the other half of an existing accessor as it is
+    let descriptor = match element {
+        Tree::GetterDefinition { .. } => PropertyDescriptor { get: Some(function), ..PropertyDescriptor::default() },
+        Tree::SetterDefinition { .. } => PropertyDescriptor { set: Some(function), ..PropertyDescriptor::default() },
+        _ => PropertyDescriptor { value: Some(function), writable: Some(true), ..PropertyDescriptor::default() },
+    };
+    let descriptor = PropertyDescriptor { enumerable: Some(false), configurable: Some(true), ..descriptor };
+    define_property_or_throw(interpreter, target, key, descriptor)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_class_instance_fields_and_methods() {
+        let content_string = "
+            class Point {
+                z = 3;
+                constructor(x, y) {
+                    this.x = x;
+                    this.y = y;
+                }
+                sum() {
+                    return this.x + this.y + this.z;
+                }
+            }
+            let p = new Point(1, 2);
+            p.sum();
+        ";
+        assert_eval(content_string, Value::Number(6.0));
+    }
+
+    #[test]
+    fn test_class_getter_and_setter() {
+        let content_string = "
+            class Temperature {
+                constructor() {
+                    this.celsius = 0;
+                }
+                get fahrenheit() {
+                    return this.celsius * 9 / 5 + 32;
+                }
+                set fahrenheit(value) {
+                    this.celsius = (value - 32) * 5 / 9;
+                }
+            }
+            let t = new Temperature();
+            t.fahrenheit = 212;
+            t.celsius + t.fahrenheit;
+        ";
+        assert_eval(content_string, Value::Number(312.0));
+    }
+
+    #[test]
+    fn test_derived_class() {
+        let content_string = "
+            class Point {
+                constructor(x, y) {
+                    this.x = x;
+                    this.y = y;
+                }
+                sum() {
+                    return this.x + this.y;
+                }
+            }
+            class Point3D extends Point {
+                constructor(x, y, z) {
+                    super(x, y);
+                    this.z = z;
+                }
+                sum() {
+                    return super.sum() + this.z;
+                }
+            }
+            class Origin extends Point3D {}
+            let p = new Point3D(1, 2, 3);
+            let o = new Origin(0, 0, 1);
+            p.sum() * 10 + o.sum();
+        ";
+        assert_eval(content_string, Value::Number(61.0));
+    }
+
+    #[test]
+    fn test_class_constructor_without_new() {
+        let error = Error::Type("Class constructor Point cannot be invoked without 'new'!".to_string());
+        assert_eval_error("class Point {} Point();", error);
+    }
+
+    #[test]
+    fn test_this_before_super_call() {
+        let content_string = "
+            class A {}
+            class B extends A {
+                constructor() {
+                    this.x = 1;
+                    super();
+                }
+            }
+            new B();
+        ";
+        let error = Error::Reference("Must call super constructor in derived class before accessing 'this'!".to_string());
+        assert_eval_error(content_string, error);
+    }
+    #[test]
+    fn test_private_fields_and_methods() {
+        let content_string = "
+            class Counter {
+                #count = 10;
+                #step() {
+                    return 2;
+                }
+                get #double() {
+                    return this.#count * 2;
+                }
+                set #double(value) {
+                    this.#count = value / 2;
+                }
+                increment() {
+                    this.#count += this.#step();
+                    return this.#count;
+                }
+                reset() {
+                    this.#double = 8;
+                    return this.#double;
+                }
+            }
+            let c = new Counter();
+            let keys = Reflect.ownKeys(c);
+            c.increment() * 100 + c.reset() * 10 + keys.length;
+        ";
+        assert_eval(content_string, Value::Number(1280.0));
+        assert_eval("class A { #x; static has(o) { return #x in o; } } A.has(new A());", Value::Boolean(true));
+        assert_eval("class A { #x; static has(o) { return #x in o; } } A.has(new Object());", Value::Boolean(false));
+    }
+
+    #[test]
+    fn test_private_member_of_other_object() {
+        let content_string = "
+            class Point {
+                #x = 1;
+                static read(point) {
+                    return point.#x;
+                }
+            }
+            Point.read(new Object());
+        ";
+        let error = Error::Type("Cannot read private member #x from an object whose class did not declare it!".to_string());
+        assert_eval_error(content_string, error);
+
+        let content_string = "
+            class Point {
+                #x() {}
+                constructor() {
+                    this.#x = 1;
+                }
+            }
+            new Point();
+        ";
+        let error = Error::Type("Private method '#x' is not writable!".to_string());
+        assert_eval_error(content_string, error);
+
+        let error = Error::Type("Cannot use 'in' operator to search for '#x' in 1!".to_string());
+        assert_eval_error("class Point { #x; static has() { return #x in 1; } } Point.has();", error);
+    }
+
+    #[test]
+    fn test_static_members_and_blocks() {
+        let content_string = "
+            class Base {
+                static #instances = 0;
+                static created = 'base';
+                static count() {
+                    Base.#instances += 1;
+                    return Base.#instances;
+                }
+            }
+            class Config extends Base {
+                static ['computed' + 'Key'] = 3;
+                static total = this.computedKey * 2;
+                static {
+                    this.total += Config.count() + super.count();
+                }
+                static get doubled() {
+                    return this.total * 2;
+                }
+            }
+            Config.doubled + Config.created.length;
+        ";
+        assert_eval(content_string, Value::Number(22.0));
+    }
+
+    #[test]
+    fn test_class_prototype_is_read_only() {
+        let content_string = "
+            class Point {}
+            let prototype = Point.prototype;
+            let descriptor = Object.getOwnPropertyDescriptor(Point, 'prototype');
+            Point.prototype = null;
+            '' + [Point.prototype === prototype, descriptor.writable, descriptor.enumerable, descriptor.configurable];
+        ";
+        assert_eval(content_string, Value::String("true,false,false,false".into()));
+
+        let expected = || Error::Type("Cannot redefine property: prototype!".to_string());
+        assert_eval_error("class Point { static ['proto' + 'type'] = 1; }", expected());
+        assert_eval_error("class Point { static ['prototype']() {} }", expected());
+        assert_eval_error("class Point { static get ['prototype']() {} }", expected());
+    }
+}
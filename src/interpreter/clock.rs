@@ -0,0 +1,119 @@
+use std::cell::Cell;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Local, Offset, TimeZone};
+
+/**
+ * Source of the current time for the host, e.g. to service timers.
+ * Times are milliseconds elapsed since the Unix epoch.
+ */
+pub trait Clock {
+    fn now(&self) -> f64;
+
+    /**
+     * Waits until the given time, returning right away if it already passed.
+     */
+    fn sleep_until(&self, time: f64);
+
+    /**
+     * Offset of the local time zone from UTC at the given time, in milliseconds,
+     * e.g. 3600000 for Paris in winter. Local times of `Date` objects derive from it.
+     */
+    fn local_offset(&self, time: f64) -> f64;
+}
+
+/**
+ * Wall clock of the system, actually waiting for timers to be due.
+ */
+#[derive(Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> f64 {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs_f64() * 1000.0,
+            Err(_) => 0.0,
+        }
+    }
+
+    fn sleep_until(&self, time: f64) {
+        let remaining = time - self.now();
+        if remaining > 0.0 {
+            thread::sleep(Duration::from_secs_f64(remaining / 1000.0));
+        }
+    }
+
+    fn local_offset(&self, time: f64) -> f64 {
+        match DateTime::from_timestamp_millis(time as i64) {
+            Some(utc) => Local.offset_from_utc_datetime(&utc.naive_utc()).fix().local_minus_utc() as f64 * 1000.0,
+            None => 0.0,
+        }
+    }
+}
+
+/**
+ * Clock jumping straight to the time it's asked to wait for,
+ * so that timer-heavy scripts run instantly & deterministically.
+ * Its local time zone is UTC unless given a fixed offset.
+ */
+#[derive(Default)]
+pub struct VirtualClock {
+    time: Cell<f64>,
+    offset: f64,
+}
+
+impl VirtualClock {
+    pub fn new(time: f64) -> Self {
+        Self { time: Cell::new(time), offset: 0.0 }
+    }
+
+    /**
+     * Sets the offset of the local time zone from UTC, in milliseconds.
+     */
+    pub fn with_local_offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /**
+     * Moves the clock forward by the given milliseconds.
+     */
+    pub fn advance(&self, duration: f64) {
+        self.time.set(self.time.get() + duration);
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> f64 {
+        self.time.get()
+    }
+
+    fn sleep_until(&self, time: f64) {
+        if time > self.time.get() {
+            self.time.set(time);
+        }
+    }
+
+    fn local_offset(&self, _time: f64) -> f64 {
+        self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_clock() {
+        let clock = VirtualClock::new(10.0);
+        clock.sleep_until(25.0);
+        assert_eq!(clock.now(), 25.0);
+        clock.sleep_until(5.0);
+        assert_eq!(clock.now(), 25.0);
+        clock.advance(5.0);
+        assert_eq!(clock.now(), 30.0);
+        assert_eq!(clock.local_offset(30.0), 0.0);
+        assert_eq!(VirtualClock::new(0.0).with_local_offset(-18000000.0).local_offset(0.0), -18000000.0);
+    }
+}
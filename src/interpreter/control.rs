@@ -0,0 +1,600 @@
+use crate::prelude::*;
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use crate::interpreter::iteration::Iterable;
+use crate::interpreter::properties::PropertyAccessor;
+
+pub trait ControlFlowEvalable {
+    /**
+     * Evaluates control flow AST Tree nodes.
+     * + IfStatement
+     * + SwitchStatement
+     * + WhileStatement
+     * + DoWhileStatement
+     * + ForStatement
+     * + ForOfStatement
+     * + ForInStatement
+     * + BreakStatement
+     * + ContinueStatement
+     * + LabeledStatement
+     */
+    fn eval_control(&self, statement: &Tree, env_ref: &EnvRef) -> Result<Value>;
+
+    /**
+     * Evaluates a loop, which handles `break` & `continue` completions
+     * that are either unlabeled or target one of its `labels`.
+     */
+    fn eval_iteration(&self, statement: &Tree, env_ref: &EnvRef, labels: &[String]) -> Result<Value>;
+}
+
+impl <'a> ControlFlowEvalable for Interpreter<'a> {
+    fn eval_control(&self, statement: &Tree, env_ref: &EnvRef) -> Result<Value> {
+        match statement {
+            Tree::IfStatement { test, consequent, alternate } => {
+                if self.eval_tree(test, env_ref)?.to_boolean() {
+                    return self.eval_tree(consequent, env_ref);
+                }
+                match alternate.as_ref() {
+                    None => Ok(Value::Undefined),
+                    Some(alternate) => self.eval_tree(alternate, env_ref),
+                }
+            },
+            Tree::SwitchStatement { discriminant, cases } => {
+                let discriminant = self.eval_tree(discriminant, env_ref)?;
+
+                // Cases share a single lexical scope
+                let mut block_env = env_ref.borrow().extend();
+                let block_ref = RefCell::new(&mut block_env);
+                for case in cases {
+                    if let Tree::SwitchCase { consequent, .. } = case {
+                        for statement in consequent.iter().filter(|statement| matches!(statement, Tree::FunctionDeclaration { .. })) {
+                            self.eval_tree(statement, &block_ref)?;
+                        }
+                    }
+                }
+
+                // Finding the first strictly equal case, falling back to the default one
+                let mut start = None;
+                let mut default = None;
+                for (index, case) in cases.iter().enumerate() {
+                    match case {
+                        Tree::SwitchCase { test, .. } => match test.as_ref() {
+                            None => default = Some(index),
+                            Some(test) => if self.eval_tree(test, &block_ref)?.strict_equals(&discriminant) {
+                                start = Some(index);
+                                break;
+                            },
+                        },
+                        _ => return Err(Error::Runtime(format!("Invalid switch case node: {case}"))),
+                    }
+                }
+
+                // Falling through the remaining cases until a `break`
+                let mut value = Value::Undefined;
+                let start = match start.or(default) {
+                    None => return Ok(value),
+                    Some(start) => start,
+                };
+                for case in &cases[start..] {
+                    if let Tree::SwitchCase { consequent, .. } = case {
+                        for statement in consequent {
+                            if let Tree::FunctionDeclaration { .. } = statement {
+                                continue;
+                            }
+                            match self.eval_tree(statement, &block_ref) {
+                                Ok(result) => value = result,
+                                Err(Error::Abrupt(Completion::Break(None))) => return Ok(value),
+                                Err(error) => return Err(error),
+                            }
+                        }
+                    }
+                }
+                Ok(value)
+            },
+            Tree::WhileStatement { .. }
+            | Tree::DoWhileStatement { .. }
+            | Tree::ForStatement { .. }
+            | Tree::ForOfStatement { .. }
+            | Tree::ForInStatement { .. } => self.eval_iteration(statement, env_ref, &[]),
+            Tree::BreakStatement { label } => Err(Error::Abrupt(Completion::Break(label_name(label)))),
+            Tree::ContinueStatement { label } => Err(Error::Abrupt(Completion::Continue(label_name(label)))),
+            Tree::LabeledStatement { .. } => {
+                // Collecting the label set, e.g. `a: b: while (...)`
+                let mut labels = vec![];
+                let mut body = statement;
+                while let Tree::LabeledStatement { label, body: inner } = body {
+                    if let Tree::Identifier { name } = label.as_ref() {
+                        labels.push(name.clone());
+                    }
+                    body = inner;
+                }
+
+                match body {
+                    Tree::WhileStatement { .. }
+                    | Tree::DoWhileStatement { .. }
+                    | Tree::ForStatement { .. }
+                    | Tree::ForOfStatement { .. }
+                    | Tree::ForInStatement { .. } => self.eval_iteration(body, env_ref, &labels),
+                    _ => match self.eval_tree(body, env_ref) {
+                        Err(Error::Abrupt(Completion::Break(Some(label)))) if labels.contains(&label) => {
+                            Ok(Value::Undefined)
+                        },
+                        result => result,
+                    },
+                }
+            },
+            _ => Err(Error::Runtime(format!("Unimplemented control flow node: {statement}"))),
+        }
+    }
+
+    fn eval_iteration(&self, statement: &Tree, env_ref: &EnvRef, labels: &[String]) -> Result<Value> {
+        match statement {
+            Tree::WhileStatement { test, body } => {
+                while self.eval_tree(test, env_ref)?.to_boolean() {
+                    if !self.eval_loop_body(body, env_ref, labels)? {
+                        break;
+                    }
+                }
+                Ok(Value::Undefined)
+            },
+            Tree::DoWhileStatement { body, test } => {
+                while self.eval_loop_body(body, env_ref, labels)? {
+                    if !self.eval_tree(test, env_ref)?.to_boolean() {
+                        break;
+                    }
+                }
+                Ok(Value::Undefined)
+            },
+            Tree::ForStatement { init, test, update, body } => {
+                // Variables declared in the initializer are scoped to the loop
+                let mut loop_env = env_ref.borrow().extend();
+                let names = match init.as_ref() {
+                    Some(Tree::VariableStatement { declarations, .. }) => declarations.iter()
+                        .filter_map(|declaration| match declaration {
+                            Tree::VariableDeclaration { identifier, .. } => match identifier.as_ref() {
+                                Tree::Identifier { name } => Some(name.clone()),
+                                _ => None,
+                            },
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => vec![],
+                };
+                if let Some(init) = init.as_ref() {
+                    self.eval_tree(init, &RefCell::new(&mut loop_env))?;
+                }
+
+                // Each iteration gets its own copy of the loop variables,
+                // so closures created in the body capture that iteration's values
+                let next_iteration = |loop_env: &Environment| -> Result<Environment> {
+                    let mut iteration_env = env_ref.borrow().extend();
+                    for name in &names {
+                        iteration_env.define(name.clone(), loop_env.lookup(name)?);
+                        if loop_env.is_constant(name) {
+                            iteration_env.mark_constant(name);
+                        }
+                    }
+                    Ok(iteration_env)
+                };
+
+                let mut iteration_env = next_iteration(&loop_env)?;
+                loop {
+                    let iteration_ref = RefCell::new(&mut iteration_env);
+                    if let Some(test) = test.as_ref() {
+                        if !self.eval_tree(test, &iteration_ref)?.to_boolean() {
+                            break;
+                        }
+                    }
+                    if !self.eval_loop_body(body, &iteration_ref, labels)? {
+                        break;
+                    }
+
+                    iteration_env = next_iteration(&iteration_env)?;
+                    if let Some(update) = update.as_ref() {
+                        self.eval_tree(update, &RefCell::new(&mut iteration_env))?;
+                    }
+                }
+                Ok(Value::Undefined)
+            },
+            Tree::ForOfStatement { left, right, body } => {
+                let iterable = self.eval_tree(right, env_ref)?;
+                let mut record = self.get_iterator(&iterable)?;
+                while let Some(value) = self.iterator_step(&mut record)? {
+                    let mut iteration_env = env_ref.borrow().extend();
+                    let iteration_ref = RefCell::new(&mut iteration_env);
+                    let result = self.bind_for_declaration(left, value, &iteration_ref)
+                        .and_then(|_| self.eval_loop_body(body, &iteration_ref, labels));
+
+                    // Leaving the loop early closes the iterator
+                    match result {
+                        Ok(true) => (),
+                        Ok(false) => return self.iterator_close(&record, Ok(Value::Undefined)),
+                        Err(error) => return self.iterator_close(&record, Err(error)),
+                    }
+                }
+                Ok(Value::Undefined)
+            },
+            Tree::ForInStatement { left, right, body } => {
+                let object = self.eval_tree(right, env_ref)?;
+                for key in self.enumerable_keys(&object)? {
+                    // Skipping properties deleted while iterating
+                    if let Value::Object(object) = &object {
+                        if !self.has_property(object, &PropertyKey::from(&key))? {
+                            continue;
+                        }
+                    }
+
+                    let mut iteration_env = env_ref.borrow().extend();
+                    let iteration_ref = RefCell::new(&mut iteration_env);
+                    self.bind_for_declaration(left, Value::String(key.into()), &iteration_ref)?;
+                    if !self.eval_loop_body(body, &iteration_ref, labels)? {
+                        break;
+                    }
+                }
+                Ok(Value::Undefined)
+            },
+            _ => Err(Error::Runtime(format!("Unimplemented iteration node: {statement}"))),
+        }
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Runs one iteration of a loop body, returning whether the loop should go on.
+     */
+    fn eval_loop_body(&self, body: &Tree, env_ref: &EnvRef, labels: &[String]) -> Result<bool> {
+        let targets = |label: &Option<String>| match label {
+            None => true,
+            Some(label) => labels.contains(label),
+        };
+        match self.eval_tree(body, env_ref) {
+            Ok(_) => Ok(true),
+            Err(Error::Abrupt(Completion::Break(label))) if targets(&label) => Ok(false),
+            Err(Error::Abrupt(Completion::Continue(label))) if targets(&label) => Ok(true),
+            Err(error) => Err(error),
+        }
+    }
+
+    /**
+     * Binds the value of an iteration to the head of a for-in/of loop,
+     * either declaring a variable or assigning to an existing reference.
+     */
+    fn bind_for_declaration(&self, left: &Tree, value: Value, env_ref: &EnvRef) -> Result<()> {
+        match left {
+            Tree::VariableStatement { kind, declarations } => match declarations.as_slice() {
+                [Tree::VariableDeclaration { identifier, .. }] => match identifier.as_ref() {
+                    Tree::Identifier { name } => {
+                        let mut env = env_ref.borrow_mut();
+                        env.define(name.clone(), value);
+                        if kind == "const" {
+                            env.mark_constant(name);
+                        }
+                        Ok(())
+                    },
+                    _ => Err(Error::Runtime(format!("Invalid for-in/of declaration: {identifier}"))),
+                },
+                _ => Err(Error::Runtime(format!("Invalid for-in/of declaration: {left}"))),
+            },
+            Tree::Identifier { name } => self.assign_variable(name, value, env_ref),
+            Tree::MemberExpression { object, property, computed } => {
+                let object = self.eval_tree(object, env_ref)?;
+                let key = self.eval_property_key(property, *computed, env_ref)?;
+                self.assign_property(&object, &key, value)
+            },
+            _ => Err(Error::Runtime(format!("Invalid for-in/of declaration: {left}"))),
+        }
+    }
+
+    /**
+     * Collects the enumerable string keys of a value & its prototype chain, as
+     * visited by for-in loops. Shadowed keys are only visited once.
+     */
+    fn enumerable_keys(&self, value: &Value) -> Result<Vec<String>> {
+        let mut current = match value {
+            Value::Object(object) => Some(object.clone()),
+            Value::String(string) => return Ok((0..string.length()).map(|index| index.to_string()).collect()),
+            _ => None,
+        };
+
+        let mut visited = HashSet::new();
+        let mut keys = vec![];
+        while let Some(object) = current {
+            for key in self.own_property_keys(&object)? {
+                let name = match &key {
+                    PropertyKey::String(name) => name,
+                    PropertyKey::Symbol(_) | PropertyKey::Private(_) => continue,
+                };
+                if !visited.insert(name.clone()) {
+                    continue;
+                }
+                if self.get_own_property(&object, &key)?.is_some_and(|descriptor| descriptor.enumerable == Some(true)) {
+                    keys.push(name.clone());
+                }
+            }
+            current = self.get_prototype_of(&object)?;
+        }
+        Ok(keys)
+    }
+}
+
+/**
+ * Extracts the name of an optional `break` or `continue` label.
+ */
+fn label_name(label: &Option<Tree>) -> Option<String> {
+    match label {
+        Some(Tree::Identifier { name }) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_if_statement() {
+        assert_eval("let x = 5; if (x > 3) { x = 1; } else { x = 2; } x;", Value::Number(1.0));
+        assert_eval("let x = 0; if (x) x = 1; else if (!x) x = 2; x;", Value::Number(2.0));
+    }
+
+    #[test]
+    fn test_switch_statement() {
+        let content_string = "
+            function name(x) {
+                let result = '';
+                switch (x) {
+                    case 1:
+                        result = 'one';
+                        break;
+                    case 2:
+                    case 3:
+                        result = 'few';
+                        break;
+                    default:
+                        result = 'many';
+                }
+                return result;
+            }
+            name(1) + ' ' + name(3) + ' ' + name(7) + ' ' + name('1');
+        ";
+        assert_eval(content_string, Value::String("one few many many".into()));
+    }
+
+    #[test]
+    fn test_switch_fall_through() {
+        let content_string = "
+            let s = '';
+            switch (2) {
+                case 1: s += 'a';
+                default: s += 'd';
+                case 2: s += 'b';
+                case 3: s += 'c';
+            }
+            s;
+        ";
+        assert_eval(content_string, Value::String("bc".into()));
+
+        let content_string = "
+            let s = '';
+            switch (9) {
+                case 1: s += 'a';
+                default: s += 'd';
+                case 2: s += 'b'; break;
+                case 3: s += 'c';
+            }
+            s;
+        ";
+        assert_eval(content_string, Value::String("db".into()));
+    }
+
+    #[test]
+    fn test_switch_scope_and_loops() {
+        let content_string = "
+            let x = 'outer';
+            let n = 0;
+            for (let i = 0; i < 4; i += 1) {
+                switch (i) {
+                    case 1: let x = 'inner'; continue;
+                    case 3: break;
+                    default: n += 1;
+                }
+                n += 10;
+            }
+            x + n;
+        ";
+        assert_eval(content_string, Value::String("outer32".into()));
+    }
+
+    #[test]
+    fn test_loops() {
+        assert_eval("let x = 0; while (x < 5) x += 1; x;", Value::Number(5.0));
+        assert_eval("let x = 10; do x += 1; while (x < 5); x;", Value::Number(11.0));
+        assert_eval("let s = 0; for (let i = 0; i < 5; i += 1) s += i; s;", Value::Number(10.0));
+    }
+
+    #[test]
+    fn test_break_and_continue() {
+        let content_string = "
+            let s = 0;
+            for (let i = 0; i < 10; i += 1) {
+                if (i === 2) continue;
+                if (i === 5) break;
+                s += i;
+            }
+            s;
+        ";
+        assert_eval(content_string, Value::Number(8.0));
+
+        assert_eval("let x = 0; do { x += 1; continue; } while (x < 3); x;", Value::Number(3.0));
+    }
+
+    #[test]
+    fn test_labeled_break_and_continue() {
+        let content_string = "
+            let s = '';
+            outer: for (let i = 0; i < 3; i += 1) {
+                inner: for (let j = 0; j < 3; j += 1) {
+                    if (j === 1) continue outer;
+                    if (i === 2) break outer;
+                    s += i + '' + j + ' ';
+                }
+            }
+            s;
+        ";
+        assert_eval(content_string, Value::String("00 10 ".into()));
+
+        assert_eval("let x = 1; block: { x = 2; break block; x = 3; } x;", Value::Number(2.0));
+        assert_eval("let x = 0; a: b: while (true) { x += 1; if (x < 3) continue a; break b; } x;", Value::Number(3.0));
+    }
+
+    #[test]
+    fn test_break_inside_function_inside_loop() {
+        let content_string = "
+            let found;
+            function find(n) {
+                for (let i = 0;; i += 1) {
+                    if (i * i >= n) return i;
+                }
+            }
+            while (true) {
+                found = find(50);
+                break;
+            }
+            found;
+        ";
+        assert_eval(content_string, Value::Number(8.0));
+    }
+
+    #[test]
+    fn test_for_loop_closures_capture_iteration() {
+        let content_string = "
+            let fs = [];
+            for (let i = 0; i < 3; i += 1) {
+                fs[i] = function() { return i; };
+            }
+            fs[0]() + fs[1]() * 10 + fs[2]() * 100;
+        ";
+        assert_eval(content_string, Value::Number(210.0));
+    }
+
+    #[test]
+    fn test_logical_and_equality_operators() {
+        assert_eval("0 || 'a';", Value::String("a".into()));
+        assert_eval("1 && 0;", Value::Number(0.0));
+        assert_eval("let u; null == u;", Value::Boolean(true));
+        assert_eval("'1' == 1;", Value::Boolean(true));
+        assert_eval("'1' === 1;", Value::Boolean(false));
+        assert_eval("'b' > 'a';", Value::Boolean(true));
+        assert_eval("-'3';", Value::Number(-3.0));
+    }
+
+    #[test]
+    fn test_for_of_loop() {
+        assert_eval("let s = 0; for (const x of [1, 2, 3]) s += x; s;", Value::Number(6.0));
+        assert_eval("let s = ''; for (let c of 'abc') s = c + s; s;", Value::String("cba".into()));
+        assert_eval("let x; for (x of [1, 2]) ; x;", Value::Number(2.0));
+
+        let content_string = "
+            let a = [1];
+            let n = 0;
+            for (const x of a) {
+                if (x < 3) a[a.length] = x + 1;
+                n += x;
+            }
+            n;
+        ";
+        assert_eval(content_string, Value::Number(6.0));
+    }
+
+    #[test]
+    fn test_for_of_iteration_protocol() {
+        let content_string = "
+            class Range {
+                constructor(end) {
+                    this.current = 0;
+                    this.end = end;
+                    this.closed = false;
+                }
+                next() {
+                    let result = new Result(this.current, this.current >= this.end);
+                    this.current += 1;
+                    return result;
+                }
+                return() {
+                    this.closed = true;
+                    return new Result(this.current, true);
+                }
+            }
+            class Result {
+                constructor(value, done) {
+                    this.value = value;
+                    this.done = done;
+                }
+            }
+            Range.prototype[Symbol.iterator] = function() { return this; };
+
+            let s = 0;
+            let complete = new Range(4);
+            for (const x of complete) s += x;
+
+            let broken = new Range(4);
+            for (const x of broken) if (x === 1) break;
+
+            let thrown = new Range(4);
+            try {
+                for (const x of thrown) throw 'stop';
+            } catch {}
+
+            s + ' ' + complete.closed + ' ' + broken.closed + ' ' + thrown.closed;
+        ";
+        assert_eval(content_string, Value::String("6 false true true".into()));
+    }
+
+    #[test]
+    fn test_for_of_not_iterable() {
+        let error = Error::Type("42 is not iterable!".to_string());
+        assert_eval_error("for (const x of 42) ;", error);
+    }
+
+    #[test]
+    fn test_for_of_const_binding() {
+        let error = Error::Type("Assignment to constant variable!".to_string());
+        assert_eval_error("for (const x of [1]) x = 2;", error);
+
+        let content_string = "
+            let fs = [];
+            for (const x of [1, 2]) fs[fs.length] = function() { return x; };
+            fs[0]() + fs[1]() * 10;
+        ";
+        assert_eval(content_string, Value::Number(21.0));
+    }
+
+    #[test]
+    fn test_for_in_loop() {
+        let content_string = "
+            class Base {
+                constructor() {
+                    this.a = 1;
+                }
+                method() {}
+            }
+            class Derived extends Base {
+                constructor() {
+                    super();
+                    this.b = 2;
+                }
+            }
+            Base.prototype.inherited = 3;
+            let keys = '';
+            for (const key in new Derived()) keys += key;
+            keys;
+        ";
+        assert_eval(content_string, Value::String("abinherited".into()));
+
+        assert_eval("let keys = ''; for (let k in ['x', 'y']) keys += k; keys;", Value::String("01".into()));
+        assert_eval("let n = 0; let u; for (let k in u) n += 1; n;", Value::Number(0.0));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::prelude::*;
+
+use std::fmt;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::properties::PropertyAccessor;
+
+/**
+ * Type an object is preferably converted into, passed as the
+ * hint to its `[Symbol.toPrimitive]` method.
+ */
+#[derive(Clone, Copy, PartialEq)]
+pub enum PreferredType {
+    Default,
+    Number,
+    String,
+}
+
+impl fmt::Display for PreferredType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreferredType::Default => write!(f, "default"),
+            PreferredType::Number => write!(f, "number"),
+            PreferredType::String => write!(f, "string"),
+        }
+    }
+}
+
+pub trait TypeConversion {
+    /**
+     * Converts an object into a primitive value through its `[Symbol.toPrimitive]`
+     * method, or else its `valueOf` & `toString` methods. Primitives are returned as is.
+     */
+    fn to_primitive(&self, value: &Value, hint: PreferredType) -> Result<Value>;
+
+    /**
+     * Converts an object into a primitive value through its `valueOf` & `toString` methods,
+     * in the order given by the hint, e.g. for `Date.prototype[Symbol.toPrimitive]`.
+     */
+    fn ordinary_to_primitive(&self, object: &ObjectRef, hint: PreferredType) -> Result<Value>;
+
+    /**
+     * Converts a value into a number or a BigInt, e.g. for arithmetic operators.
+     */
+    fn to_numeric(&self, value: &Value) -> Result<Value>;
+
+    /**
+     * Converts a value into a number, e.g. for `Math` functions. BigInts are only converted explicitly.
+     */
+    fn to_number(&self, value: &Value) -> Result<f64>;
+
+    /**
+     * Converts a value into a string, e.g. for `String.prototype` methods. Symbols are only converted explicitly.
+     */
+    fn to_string(&self, value: &Value) -> Result<JsString>;
+
+    /**
+     * Converts a value into an object, wrapping primitives, e.g. `Object(1)`.
+     */
+    fn to_object(&self, value: &Value) -> Result<ObjectRef>;
+}
+
+impl <'a> TypeConversion for Interpreter<'a> {
+    fn to_primitive(&self, value: &Value, hint: PreferredType) -> Result<Value> {
+        let object = match value {
+            Value::Object(object) => object,
+            _ => return Ok(value.clone()),
+        };
+
+        let exotic = self.get_property(value, &self.realm.symbols.to_primitive)?;
+        if !matches!(exotic, Value::Undefined | Value::Null) {
+            if !exotic.is_callable() {
+                return Err(Error::Type(format!("{exotic} is not a function!")));
+            }
+            return match self.call(&exotic, value, &[Value::String(hint.to_string().into())])? {
+                Value::Object(_) => Err(Error::Type("Cannot convert object to primitive value!".to_string())),
+                result => Ok(result),
+            };
+        }
+        self.ordinary_to_primitive(object, hint)
+    }
+
+    fn ordinary_to_primitive(&self, object: &ObjectRef, hint: PreferredType) -> Result<Value> {
+        let value = &Value::Object(object.clone());
+        let methods = match hint {
+            PreferredType::String => ["toString", "valueOf"],
+            _ => ["valueOf", "toString"],
+        };
+        let mut callable = false;
+        for name in methods {
+            let method = self.get_property(value, name)?;
+            if method.is_callable() {
+                callable = true;
+                match self.call(&method, value, &[])? {
+                    Value::Object(_) => continue,
+                    result => return Ok(result),
+                }
+            }
+        }
+        if callable {
+            return Err(Error::Type("Cannot convert object to primitive value!".to_string()));
+        }
+
+        // Objects without any conversion method are converted natively, e.g. arrays
+        Ok(Value::String(object.to_string().into()))
+    }
+
+    fn to_numeric(&self, value: &Value) -> Result<Value> {
+        match self.to_primitive(value, PreferredType::Number)? {
+            Value::Symbol(_) => Err(Error::Type("Cannot convert a Symbol value to a number!".to_string())),
+            primitive @ Value::BigInt(_) => Ok(primitive),
+            primitive => Ok(Value::Number(primitive.to_number())),
+        }
+    }
+
+    fn to_number(&self, value: &Value) -> Result<f64> {
+        match self.to_numeric(value)? {
+            Value::BigInt(_) => Err(Error::Type("Cannot convert a BigInt value to a number!".to_string())),
+            number => Ok(number.to_number()),
+        }
+    }
+
+    fn to_string(&self, value: &Value) -> Result<JsString> {
+        match self.to_primitive(value, PreferredType::String)? {
+            Value::Symbol(_) => Err(Error::Type("Cannot convert a Symbol value to a string!".to_string())),
+            Value::String(string) => Ok(string),
+            primitive => Ok(JsString::from(primitive.to_string())),
+        }
+    }
+
+    fn to_object(&self, value: &Value) -> Result<ObjectRef> {
+        match value {
+            Value::Object(object) => Ok(object.clone()),
+            Value::Undefined | Value::Null => Err(Error::Type("Cannot convert undefined or null to object!".to_string())),
+            _ => Ok(ObjectRef::new(ObjectKind::Primitive(value.clone()), self.primitive_prototype(value))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_to_primitive_methods() {
+        let content = "
+            class Money {
+                constructor(amount) { this.amount = amount; }
+                valueOf() { return this.amount; }
+                toString() { return 'usd' + this.amount; }
+            }
+            let m = new Money(5);
+            let prices = new Money(0);
+            prices[m] = 'five';
+            '' + [m + 1, m * 2, m > 4, m == 5, m - '1', prices.usd5];
+        ";
+        assert_eval(content, Value::String("6,10,true,true,4,five".into()));
+    }
+
+    #[test]
+    fn test_symbol_to_primitive() {
+        let content = "
+            class Temperature {
+                [Symbol.toPrimitive](hint) {
+                    if (hint === 'number') {
+                        return 21;
+                    }
+                    return hint;
+                }
+            }
+            let t = new Temperature();
+            let hints = new Temperature();
+            hints[t] = 'string key';
+            '' + [t + '', t * 2, -t, t < 22, hints.string];
+        ";
+        assert_eval(content, Value::String("default,42,-21,true,string key".into()));
+    }
+
+    #[test]
+    fn test_to_primitive_errors() {
+        let content = "
+            class Broken {
+                valueOf() { return this; }
+                toString() { return this; }
+            }
+            new Broken() + 1;
+        ";
+        assert_eval_error(content, Error::Type("Cannot convert object to primitive value!".to_string()));
+
+        let content = "
+            class Broken {
+                [Symbol.toPrimitive]() { return this; }
+            }
+            new Broken() + 1;
+        ";
+        assert_eval_error(content, Error::Type("Cannot convert object to primitive value!".to_string()));
+    }
+}
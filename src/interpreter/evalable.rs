@@ -1,13 +1,20 @@
 use crate::prelude::*;
 
+use crate::interpreter::asynchronous::AsyncEvalable;
+use crate::interpreter::classes::ClassEvalable;
+use crate::interpreter::control::ControlFlowEvalable;
+use crate::interpreter::event_loop::EventLoop;
+use crate::interpreter::exceptions::ExceptionEvalable;
 use crate::interpreter::expressions::ExpressionEvalable;
+use crate::interpreter::generators::GeneratorEvalable;
 use crate::interpreter::identifier::IdentifierEvalable;
 use crate::interpreter::literals::LiteralEvalable;
 use crate::interpreter::statements::StatementEvalable;
 
 pub trait Evalable {
     /**
-     * Evaluates AST Tree set while constructing Interpreter instance.
+     * Evaluates AST Tree set while constructing Interpreter instance,
+     * then runs the jobs it scheduled, e.g. promise reactions.
      */
     fn eval(&self) -> Result<Value>;
 
@@ -19,7 +26,11 @@ pub trait Evalable {
 
 impl <'a> Evalable for Interpreter<'a> {
     fn eval(&self) -> Result<Value> {
-        self.eval_tree(self.tree, &self.env_ref)
+        self.on_call_stack(|| {
+            let value = self.eval_tree(self.tree, &self.env_ref)?;
+            self.run_jobs()?;
+            Ok(value)
+        })
     }
 
     fn eval_tree(&self, tree: &Tree, env_ref: &EnvRef) -> Result<Value> {
@@ -28,36 +39,74 @@ impl <'a> Evalable for Interpreter<'a> {
 
         let value = match tree {
             // ----- PROGRAM -----
-            Tree::Program { body } => {
-                for statement in body {
-                    self.eval_tree(statement, env_ref)?;
-                }
-                Ok(Value::Undefined)
-            },
+            Tree::Program { body, strict } => self.run_in_mode(*strict, || self.eval_statement_list(body, env_ref)),
 
             // ----- EXPRESSIONS -----
-            Tree::BinaryExpression { .. } => self.eval_expression(tree, env_ref),
+            Tree::BinaryExpression { .. }
+            | Tree::LogicalExpression { .. }
+            | Tree::UnaryExpression { .. }
+            | Tree::AssignmentExpression { .. }
+            | Tree::MemberExpression { .. }
+            | Tree::CallExpression { .. }
+            | Tree::NewExpression { .. }
+            | Tree::ArrayExpression { .. }
+            | Tree::FunctionExpression { .. } => self.eval_expression(tree, env_ref),
 
             // ----- STATEMENTS -----
             Tree::ExpressionStatement { .. } 
             | Tree::VariableStatement { .. } 
-            | Tree::VariableDeclaration { .. } => self.eval_statement(tree, env_ref),
+            | Tree::VariableDeclaration { .. }
+            | Tree::EmptyStatement
+            | Tree::BlockStatement { .. }
+            | Tree::FunctionDeclaration { .. }
+            | Tree::ReturnStatement { .. } => self.eval_statement(tree, env_ref),
+
+            // ----- CONTROL FLOW -----
+            Tree::IfStatement { .. }
+            | Tree::SwitchStatement { .. }
+            | Tree::WhileStatement { .. }
+            | Tree::DoWhileStatement { .. }
+            | Tree::ForStatement { .. }
+            | Tree::ForOfStatement { .. }
+            | Tree::ForInStatement { .. }
+            | Tree::BreakStatement { .. }
+            | Tree::ContinueStatement { .. }
+            | Tree::LabeledStatement { .. } => self.eval_control(tree, env_ref),
+
+            // ----- EXCEPTIONS -----
+            Tree::ThrowStatement { .. }
+            | Tree::TryStatement { .. } => self.eval_exception(tree, env_ref),
+
+            // ----- GENERATORS -----
+            Tree::YieldExpression { .. } => self.eval_yield(tree, env_ref),
+
+            // ----- ASYNC FUNCTIONS -----
+            Tree::AwaitExpression { .. } => self.eval_await(tree, env_ref),
+
+            // ----- CLASSES -----
+            // Class bodies are strict mode code, as is any function nested in them
+            Tree::ClassDeclaration { .. } => self.run_in_mode(true, || self.eval_class(tree, env_ref)),
 
             // ----- LITERALS -----
             Tree::NumericLiteral { .. } 
+            | Tree::BigIntLiteral { .. }
             | Tree::StringLiteral { .. }
+            | Tree::RegExpLiteral { .. }
             | Tree::BooleanLiteral { .. } 
             | Tree::NullLiteral => self.eval_literal(tree),
 
             // ----- IDENTIFIER ------
-            Tree::Identifier { .. } => self.eval_identifier(tree, env_ref),
+            Tree::Identifier { .. }
+            | Tree::ThisLiteral => self.eval_identifier(tree, env_ref),
 
             // ----- UNIMPLEMENTED -----
             _ => Err(Error::Runtime(format!("Unimplemented tree node: {tree}")))
-        }?;
+        };
 
-        println!("{}< {value:?}", " ".repeat(depth - 1));
+        // Restoring the depth before propagating errors
         self.decrement_depth();
+        let value = value?;
+        println!("{}< {value:?}", " ".repeat(depth - 1));
 
         Ok(value)
     }
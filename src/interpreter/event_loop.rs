@@ -0,0 +1,190 @@
+use crate::prelude::*;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::promises::{PromiseOperations, PromiseReaction};
+
+/**
+ * Work scheduled to run once the running code completes, i.e. a microtask.
+ */
+pub enum Job {
+    // Runs the handler registered with `then` once the promise settled
+    PromiseReaction { reaction: PromiseReaction, argument: Value },
+
+    // Adopts the state of the thenable a promise got resolved with
+    PromiseResolveThenable { promise: ObjectRef, thenable: Value, then: Value },
+
+    // Calls the function passed to `queueMicrotask`
+    Microtask { callback: Value },
+}
+
+/**
+ * Shared handle to the queue of pending jobs. Interpreters running
+ * generator & async function bodies share the queue of their creator.
+ */
+#[derive(Clone, Default)]
+pub struct JobQueue(Rc<RefCell<VecDeque<Job>>>);
+
+/**
+ * Callback scheduled with `setTimeout` or `setInterval`, i.e. a macrotask.
+ */
+struct Timer {
+    callback: Value,
+    args: Vec<Value>,
+    due: f64,
+
+    // Delay between the runs of intervals
+    interval: Option<f64>,
+
+    // Order of scheduling, breaking ties between timers due at the same time
+    sequence: usize,
+}
+
+#[derive(Default)]
+struct Timers {
+    last_id: usize,
+    last_sequence: usize,
+    timers: HashMap<usize, Timer>,
+}
+
+impl Timers {
+    fn next_sequence(&mut self) -> usize {
+        self.last_sequence += 1;
+        self.last_sequence
+    }
+}
+
+/**
+ * Shared handle to the pending timers, keyed by their ids.
+ */
+#[derive(Clone, Default)]
+pub struct TimerQueue(Rc<RefCell<Timers>>);
+
+pub trait EventLoop {
+    /**
+     * Schedules a job to run after the current one.
+     */
+    fn enqueue_job(&self, job: Job);
+
+    /**
+     * Runs the pending jobs in the order they were scheduled,
+     * including the ones they schedule, until none is left.
+     */
+    fn run_jobs(&self) -> Result<()>;
+
+    /**
+     * Schedules the callback to run after the delay in milliseconds,
+     * repeatedly if it's an interval. Returns the id of the timer.
+     */
+    fn set_timer(&self, callback: Value, args: Vec<Value>, delay: f64, repeat: bool) -> usize;
+
+    /**
+     * Cancels the timer, if it's still pending.
+     */
+    fn clear_timer(&self, id: usize);
+
+    /**
+     * Waits for the next timer to be due & runs it, followed by the jobs it scheduled.
+     * Returns false if no timer is pending.
+     */
+    fn run_timer(&self) -> Result<bool>;
+}
+
+impl <'a> EventLoop for Interpreter<'a> {
+    fn enqueue_job(&self, job: Job) {
+        self.jobs.0.borrow_mut().push_back(job);
+    }
+
+    fn run_jobs(&self) -> Result<()> {
+        loop {
+            let job = self.jobs.0.borrow_mut().pop_front();
+            match job {
+                None => return Ok(()),
+                Some(Job::PromiseReaction { reaction, argument }) => self.promise_reaction_job(reaction, argument)?,
+                Some(Job::PromiseResolveThenable { promise, thenable, then }) => {
+                    self.promise_resolve_thenable_job(&promise, thenable, then)?;
+                },
+                Some(Job::Microtask { callback }) => {
+                    self.call(&callback, &Value::Undefined, &[])?;
+                },
+            }
+        }
+    }
+
+    fn set_timer(&self, callback: Value, args: Vec<Value>, delay: f64, repeat: bool) -> usize {
+        // Negative & invalid delays mean running as soon as possible
+        let delay = if delay.is_finite() && delay > 0.0 { delay } else { 0.0 };
+
+        let mut timers = self.timers.0.borrow_mut();
+        timers.last_id += 1;
+        let (id, sequence) = (timers.last_id, timers.next_sequence());
+        timers.timers.insert(id, Timer {
+            callback,
+            args,
+            due: self.clock.now() + delay,
+            interval: if repeat { Some(delay) } else { None },
+            sequence,
+        });
+        id
+    }
+
+    fn clear_timer(&self, id: usize) {
+        self.timers.0.borrow_mut().timers.remove(&id);
+    }
+
+    fn run_timer(&self) -> Result<bool> {
+        let next = self.timers.0.borrow().timers.iter()
+            .min_by(|(_, a), (_, b)| a.due.total_cmp(&b.due).then(a.sequence.cmp(&b.sequence)))
+            .map(|(id, timer)| (*id, timer.due));
+        let (id, due) = match next {
+            Some(next) => next,
+            None => return Ok(false),
+        };
+        self.clock.sleep_until(due);
+
+        // Intervals stay registered while running, so that they can clear themselves
+        let (callback, args, interval) = {
+            let mut timers = self.timers.0.borrow_mut();
+            let timer = match timers.timers.get(&id) {
+                Some(timer) => timer,
+                None => return Ok(true),
+            };
+            let scheduled = (timer.callback.clone(), timer.args.clone(), timer.interval);
+            if timer.interval.is_none() {
+                timers.timers.remove(&id);
+            }
+            scheduled
+        };
+
+        let result = self.call(&callback, &Value::Undefined, &args);
+        if let Some(delay) = interval {
+            let mut timers = self.timers.0.borrow_mut();
+            let sequence = timers.next_sequence();
+            if let Some(timer) = timers.timers.get_mut(&id) {
+                timer.due = self.clock.now() + delay;
+                timer.sequence = sequence;
+            }
+        }
+        result?;
+
+        self.run_jobs()?;
+        Ok(true)
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Drives the event loop to completion, running the pending jobs
+     * & timers until none is left. Meant to be called after `eval`.
+     */
+    pub fn run_event_loop(&self) -> Result<()> {
+        self.on_call_stack(|| {
+            self.run_jobs()?;
+            while self.run_timer()? {}
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,211 @@
+use crate::prelude::*;
+
+use std::cell::RefCell;
+
+use crate::interpreter::builtins::error::create_error_object;
+
+pub trait ExceptionEvalable {
+    /**
+     * Evaluates exception AST Tree nodes.
+     * + ThrowStatement
+     * + TryStatement
+     */
+    fn eval_exception(&self, statement: &Tree, env_ref: &EnvRef) -> Result<Value>;
+
+    /**
+     * Converts an error into the value received by `catch`, e.g. internal
+     * type errors become TypeError objects. Completions like `return`
+     * aren't exceptions, hence they are given back as is.
+     */
+    fn to_exception(&self, error: Error) -> core::result::Result<Value, Error>;
+
+    /**
+     * Creates an error object of a built-in type, e.g. `TypeError`.
+     */
+    fn create_error(&self, name: &str, message: &str) -> Value;
+}
+
+impl <'a> ExceptionEvalable for Interpreter<'a> {
+    fn eval_exception(&self, statement: &Tree, env_ref: &EnvRef) -> Result<Value> {
+        match statement {
+            Tree::ThrowStatement { argument } => {
+                let value = self.eval_tree(argument, env_ref)?;
+                Err(Error::Abrupt(Completion::Throw(value)))
+            },
+            Tree::TryStatement { block, handler, finalizer } => {
+                let result = match (self.eval_tree(block, env_ref), handler.as_ref()) {
+                    (Err(error), Some(Tree::CatchClause { param, body })) => match self.to_exception(error) {
+                        Err(error) => Err(error),
+                        Ok(exception) => {
+                            // The exception is bound in a scope of its own
+                            let mut catch_env = env_ref.borrow().extend();
+                            if let Some(Tree::Identifier { name }) = param.as_ref() {
+                                catch_env.define(name.clone(), exception);
+                            }
+                            self.eval_tree(body, &RefCell::new(&mut catch_env))
+                        },
+                    },
+                    (result, _) => result,
+                };
+
+                // Abrupt completions of the finalizer override the ones of try & catch
+                if let Some(finalizer) = finalizer.as_ref() {
+                    self.eval_tree(finalizer, env_ref)?;
+                }
+                result
+            },
+            _ => Err(Error::Runtime(format!("Unimplemented exception node: {statement}"))),
+        }
+    }
+
+    fn to_exception(&self, error: Error) -> core::result::Result<Value, Error> {
+        let (name, message) = match &error {
+            Error::Abrupt(Completion::Throw(value)) => return Ok(value.clone()),
+            Error::Abrupt(_) => return Err(error),
+            Error::Runtime(message) => ("Error", message),
+            Error::Type(message) => ("TypeError", message),
+            Error::Reference(message) => ("ReferenceError", message),
+            Error::Range(message) => ("RangeError", message),
+            Error::Syntax(message) => ("SyntaxError", message),
+        };
+
+        // Internal messages end with '!', unlike the ones of JS errors
+        let message = message.strip_suffix('!').unwrap_or(message);
+        Ok(self.create_error(name, message))
+    }
+
+    fn create_error(&self, name: &str, message: &str) -> Value {
+        let prototype = self.realm.intrinsic(&format!("%{name}.prototype%"));
+        Value::Object(create_error_object(self, prototype, Some(message.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_throw_and_catch_values() {
+        assert_eval("let x; try { throw 42; } catch (e) { x = e; } x;", Value::Number(42.0));
+        assert_eval("let x = 1; try { throw 'oops'; } catch { x = 2; } x;", Value::Number(2.0));
+    }
+
+    #[test]
+    fn test_uncaught_throw() {
+        let error = Error::Abrupt(Completion::Throw(Value::String("oops".into())));
+        assert_eval_error("try { throw 'oops'; } finally { }", error);
+    }
+
+    #[test]
+    fn test_finally() {
+        let content_string = "
+            let log = '';
+            function f() {
+                try {
+                    log += 't';
+                    return 'try';
+                } finally {
+                    log += 'f';
+                }
+            }
+            f() + ' ' + log;
+        ";
+        assert_eval(content_string, Value::String("try tf".into()));
+
+        let content_string = "
+            function f() {
+                try {
+                    throw 1;
+                } catch (e) {
+                    return 'catch';
+                } finally {
+                    return 'finally';
+                }
+            }
+            f();
+        ";
+        assert_eval(content_string, Value::String("finally".into()));
+
+        let content_string = "
+            let n = 0;
+            for (let i = 0; i < 5; i += 1) {
+                try {
+                    if (i === 3) break;
+                    continue;
+                } finally {
+                    n += 1;
+                }
+            }
+            n;
+        ";
+        assert_eval(content_string, Value::Number(4.0));
+    }
+
+    #[test]
+    fn test_error_constructors() {
+        let content_string = "
+            let e = new TypeError('bad type');
+            e.name + '|' + e.message + '|' + e.toString();
+        ";
+        assert_eval(content_string, Value::String("TypeError|bad type|TypeError: bad type".into()));
+
+        assert_eval("let e = Error('plain'); e instanceof Error;", Value::Boolean(true));
+        assert_eval("new RangeError('r') instanceof Error;", Value::Boolean(true));
+        assert_eval("new RangeError('r') instanceof TypeError;", Value::Boolean(false));
+        assert_eval("new Error().message;", Value::String("".into()));
+    }
+
+    #[test]
+    fn test_error_stack() {
+        let content_string = "
+            function inner() { return new Error('deep'); }
+            function outer() { return inner(); }
+            outer().stack;
+        ";
+        let stack = "Error: deep\n    at inner\n    at outer\n    at <anonymous>";
+        assert_eval(content_string, Value::String(stack.into()));
+    }
+
+    #[test]
+    fn test_catch_internal_errors() {
+        let content_string = "
+            let result;
+            try {
+                let o;
+                o.x;
+            } catch (e) {
+                result = (e instanceof TypeError) + ' ' + e.message;
+            }
+            result;
+        ";
+        assert_eval(content_string, Value::String("true Cannot read properties of undefined (reading 'x')".into()));
+
+        let content_string = "
+            let result;
+            try { missing; } catch (e) { result = e.name; }
+            result;
+        ";
+        assert_eval(content_string, Value::String("ReferenceError".into()));
+    }
+
+    #[test]
+    fn test_error_subclass() {
+        let content_string = "
+            class ValidationError extends Error {
+                constructor(message) {
+                    super(message);
+                    this.name = 'ValidationError';
+                }
+            }
+            let result;
+            try {
+                throw new ValidationError('invalid');
+            } catch (e) {
+                result = (e instanceof ValidationError) + ' ' + (e instanceof Error) + ' ' + e;
+            }
+            result;
+        ";
+        assert_eval(content_string, Value::String("true true ValidationError: invalid".into()));
+    }
+}
@@ -1,31 +1,361 @@
 use crate::prelude::*;
 
+use crate::interpreter::builtins::array::create_array;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::{PreferredType, TypeConversion};
+use crate::interpreter::iteration::Iterable;
+use crate::interpreter::properties::PropertyAccessor;
+
 pub trait ExpressionEvalable {
     /**
      * Evaluate expression AST Tree nodes.
      * + BinaryExpression
+     * + LogicalExpression
+     * + UnaryExpression
+     * + AssignmentExpression
+     * + MemberExpression
+     * + CallExpression
+     * + NewExpression
+     * + ArrayExpression
+     * + FunctionExpression
      */
     fn eval_expression(&self, expr: &Tree, env_ref: &EnvRef) -> Result<Value>;
+
+    /**
+     * Evaluates a list of arguments or array elements,
+     * expanding any SpreadElement into the iterated values.
+     */
+    fn eval_list(&self, list: &[Tree], env_ref: &EnvRef) -> Result<Vec<Value>>;
+
+    /**
+     * Applies a binary operator on the evaluated operands.
+     */
+    fn eval_binary_operator(&self, operator: &str, lvalue: Value, rvalue: Value) -> Result<Value>;
 }
 
 impl <'a> ExpressionEvalable for Interpreter<'a> {
     fn eval_expression(&self, expr: &Tree, env_ref: &EnvRef) -> Result<Value> {
         match expr {
+            Tree::BinaryExpression { operator, left, right } if matches!(left.as_ref(), Tree::PrivateIdentifier { .. }) => {
+                // Checking whether an object has a private member -> #x in obj
+                let key = self.eval_property_key(left, false, env_ref)?;
+                match self.eval_tree(right, env_ref)? {
+                    Value::Object(object) => Ok(Value::Boolean(object.get_own_property(&key).is_some())),
+                    value => Err(Error::Type(format!("Cannot use '{operator}' operator to search for '{key}' in {value}!"))),
+                }
+            },
             Tree::BinaryExpression { operator, left, right } => {
                 // Reducing left and right operands
                 let lvalue = self.eval_tree(left, env_ref)?;
                 let rvalue = self.eval_tree(right, env_ref)?;
+                self.eval_binary_operator(operator, lvalue, rvalue)
+            },
+            Tree::LogicalExpression { operator, left, right } => {
+                // Short-circuiting, i.e. the right operand is only evaluated if needed
+                let lvalue = self.eval_tree(left, env_ref)?;
+                match (operator.as_str(), lvalue.to_boolean()) {
+                    ("&&", true) | ("||", false) => self.eval_tree(right, env_ref),
+                    ("&&", false) | ("||", true) => Ok(lvalue),
+                    _ => Err(Error::Runtime(format!("Unimplemented operator: {operator}"))),
+                }
+            },
+            Tree::UnaryExpression { operator, argument } => {
+                let value = self.eval_tree(argument, env_ref)?;
                 match operator.as_str() {
-                    "+" => Ok(lvalue + rvalue),
-                    "-" => Ok(lvalue - rvalue),
-                    "*" => Ok(lvalue * rvalue),
-                    "/" => Ok(lvalue / rvalue),
+                    "+" => Ok(Value::Number(self.to_number(&value)?)),
+                    "-" => match self.to_numeric(&value)? {
+                        Value::BigInt(n) => Ok(Value::BigInt(-n)),
+                        number => Ok(Value::Number(-number.to_number())),
+                    },
+                    "!" => Ok(Value::Boolean(!value.to_boolean())),
                     _ => Err(Error::Runtime(format!("Unimplemented operator: {operator}"))),
                 }
             },
+            Tree::AssignmentExpression { operator, left, right } => {
+                // Operator without '=', e.g. '+=' -> '+'
+                let binary_operator = &operator[..operator.len() - 1];
+                match left.as_ref() {
+                    Tree::Identifier { name } => {
+                        let mut value = self.eval_tree(right, env_ref)?;
+                        if !binary_operator.is_empty() {
+                            let current = self.eval_tree(left, env_ref)?;
+                            value = self.eval_binary_operator(binary_operator, current, value)?;
+                        }
+                        self.assign_variable(name, value.clone(), env_ref)?;
+                        Ok(value)
+                    },
+                    Tree::MemberExpression { object, property, computed } => {
+                        let object = self.eval_tree(object, env_ref)?;
+                        let key = self.eval_property_key(property, *computed, env_ref)?;
+                        let mut value = self.eval_tree(right, env_ref)?;
+                        if !binary_operator.is_empty() {
+                            let current = self.get_property(&object, &key)?;
+                            value = self.eval_binary_operator(binary_operator, current, value)?;
+                        }
+                        self.assign_property(&object, &key, value.clone())?;
+                        Ok(value)
+                    },
+                    _ => Err(Error::Runtime(format!("Invalid left-hand side in assignment: {left}"))),
+                }
+            },
+            Tree::MemberExpression { object, property, computed } => {
+                let key = self.eval_property_key(property, *computed, env_ref)?;
+                if let Tree::SuperLiteral = object.as_ref() {
+                    return self.eval_super_property(&key, env_ref);
+                }
+                let object = self.eval_tree(object, env_ref)?;
+                self.get_property(&object, &key)
+            },
+            Tree::CallExpression { callee, arguments } => {
+                // Calling parent class constructor -> super(...)
+                if let Tree::SuperLiteral = callee.as_ref() {
+                    let args = self.eval_list(arguments, env_ref)?;
+                    return self.eval_super_call(&args, env_ref);
+                }
+
+                // Methods are called with their object as `this`
+                let (function, this) = match callee.as_ref() {
+                    Tree::MemberExpression { object, property, computed } => {
+                        let key = self.eval_property_key(property, *computed, env_ref)?;
+                        match object.as_ref() {
+                            Tree::SuperLiteral => (
+                                self.eval_super_property(&key, env_ref)?,
+                                self.eval_tree(&Tree::ThisLiteral, env_ref)?,
+                            ),
+                            _ => {
+                                let object = self.eval_tree(object, env_ref)?;
+                                (self.get_property(&object, &key)?, object)
+                            },
+                        }
+                    },
+                    _ => (self.eval_tree(callee, env_ref)?, Value::Undefined),
+                };
+
+                let args = self.eval_list(arguments, env_ref)?;
+                self.call(&function, &this, &args)
+            },
+            Tree::NewExpression { callee, arguments } => {
+                let constructor = self.eval_tree(callee, env_ref)?;
+                let args = self.eval_list(arguments, env_ref)?;
+                match &constructor {
+                    Value::Object(new_target) => self.construct(&constructor, &args, new_target),
+                    _ => Err(Error::Type(format!("{constructor} is not a constructor!"))),
+                }
+            },
+            Tree::ArrayExpression { elements } => {
+                let elements = self.eval_list(elements, env_ref)?;
+                Ok(Value::Object(create_array(self, elements)))
+            },
+            Tree::FunctionExpression { identifier, params, body, generator, is_async, strict, source } => {
+                let name = match identifier.as_ref() {
+                    Some(Tree::Identifier { name }) => name.clone(),
+                    _ => String::new(),
+                };
+                let function = self.create_function(Function {
+                    name,
+                    params: params.clone(),
+                    body: body.clone(),
+                    env: env_ref.borrow().clone(),
+                    kind: FunctionKind::Normal,
+                    generator: *generator,
+                    is_async: *is_async,
+                    strict: *strict,
+                    home_object: None,
+                    fields: vec![],
+                    private_methods: vec![],
+                    source: source.clone(),
+                });
+                Ok(Value::Object(function))
+            },
             _ => Err(Error::Runtime(format!("Unimplemented expression node: {expr}"))),
         }
     }
+
+    fn eval_list(&self, list: &[Tree], env_ref: &EnvRef) -> Result<Vec<Value>> {
+        let mut values = vec![];
+        for item in list {
+            match item {
+                Tree::SpreadElement { argument } => {
+                    let iterable = self.eval_tree(argument, env_ref)?;
+                    values.extend(self.iterate(&iterable)?);
+                },
+                _ => values.push(self.eval_tree(item, env_ref)?),
+            }
+        }
+        Ok(values)
+    }
+
+    fn eval_binary_operator(&self, operator: &str, lvalue: Value, rvalue: Value) -> Result<Value> {
+        match operator {
+            "+" => {
+                // Concatenating if either primitive is a string, adding numbers otherwise
+                let lvalue = self.to_primitive(&lvalue, PreferredType::Default)?;
+                let rvalue = self.to_primitive(&rvalue, PreferredType::Default)?;
+                match (&lvalue, &rvalue) {
+                    (Value::Symbol(_), Value::String(_)) | (Value::String(_), Value::Symbol(_)) => {
+                        Err(Error::Type("Cannot convert a Symbol value to a string!".to_string()))
+                    },
+                    (Value::String(_), _) | (_, Value::String(_)) => lvalue + rvalue,
+                    _ => self.to_numeric(&lvalue)? + self.to_numeric(&rvalue)?,
+                }
+            },
+            "-" => self.to_numeric(&lvalue)? - self.to_numeric(&rvalue)?,
+            "*" => self.to_numeric(&lvalue)? * self.to_numeric(&rvalue)?,
+            "/" => self.to_numeric(&lvalue)? / self.to_numeric(&rvalue)?,
+            "%" => self.to_numeric(&lvalue)? % self.to_numeric(&rvalue)?,
+            "**" => self.to_numeric(&lvalue)?.pow(self.to_numeric(&rvalue)?),
+            "==" => Ok(Value::Boolean(self.loose_equals(&lvalue, &rvalue)?)),
+            "!=" => Ok(Value::Boolean(!self.loose_equals(&lvalue, &rvalue)?)),
+            "===" => Ok(Value::Boolean(lvalue.strict_equals(&rvalue))),
+            "!==" => Ok(Value::Boolean(!lvalue.strict_equals(&rvalue))),
+            "instanceof" => self.instance_of(&lvalue, &rvalue).map(Value::Boolean),
+            "<" | ">" | "<=" | ">=" => {
+                let lvalue = self.to_primitive(&lvalue, PreferredType::Number)?;
+                let rvalue = self.to_primitive(&rvalue, PreferredType::Number)?;
+
+                // Strings are compared lexicographically, anything else numerically
+                let ordering = match (&lvalue, &rvalue) {
+                    (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+                    (Value::BigInt(a), Value::String(b)) => Value::string_to_bigint(b).map(|b| a.cmp(&b)),
+                    (Value::String(a), Value::BigInt(b)) => Value::string_to_bigint(a).map(|a| a.cmp(b)),
+                    _ => self.to_numeric(&lvalue)?.compare_numeric(&self.to_numeric(&rvalue)?),
+                };
+                let result = match ordering {
+                    None => false, // Comparisons with NaN
+                    Some(ordering) => match operator {
+                        "<" => ordering.is_lt(),
+                        ">" => ordering.is_gt(),
+                        "<=" => ordering.is_le(),
+                        _ => ordering.is_ge(),
+                    },
+                };
+                Ok(Value::Boolean(result))
+            },
+            _ => Err(Error::Runtime(format!("Unimplemented operator: {operator}"))),
+        }
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Evaluates the key of a member expression, i.e. `x.y` -> 'y' and `x[y]` -> value of y.
+     */
+    pub(crate) fn eval_property_key(&self, property: &Tree, computed: bool, env_ref: &EnvRef) -> Result<PropertyKey> {
+        match (property, computed) {
+            (Tree::Identifier { name }, false) => Ok(PropertyKey::String(name.clone())),
+            (Tree::PrivateIdentifier { name }, false) => match env_ref.borrow().lookup(name)? {
+                Value::Symbol(symbol) => Ok(PropertyKey::Private(symbol)),
+                _ => Err(Error::Runtime(format!("Invalid private name: {name}"))),
+            },
+            _ => {
+                let key = self.eval_tree(property, env_ref)?;
+                self.to_property_key(&key)
+            },
+        }
+    }
+
+    /**
+     * Loose equality comparison, converting objects compared with primitives -> a == b
+     */
+    fn loose_equals(&self, lvalue: &Value, rvalue: &Value) -> Result<bool> {
+        match (lvalue, rvalue) {
+            (Value::Object(_), Value::Object(_) | Value::Undefined | Value::Null)
+            | (Value::Undefined | Value::Null, Value::Object(_)) => Ok(lvalue.loose_equals(rvalue)),
+            (Value::Object(_), _) => Ok(self.to_primitive(lvalue, PreferredType::Default)?.loose_equals(rvalue)),
+            (_, Value::Object(_)) => Ok(lvalue.loose_equals(&self.to_primitive(rvalue, PreferredType::Default)?)),
+            _ => Ok(lvalue.loose_equals(rvalue)),
+        }
+    }
+
+    /**
+     * Whether the value is an instance of the constructor -> value instanceof constructor
+     * The constructor may customize the check with its `[Symbol.hasInstance]` method.
+     */
+    fn instance_of(&self, value: &Value, constructor: &Value) -> Result<bool> {
+        if let Value::Object(_) = constructor {
+            let handler = self.get_property(constructor, &self.realm.symbols.has_instance)?;
+            if !matches!(handler, Value::Undefined | Value::Null) {
+                return Ok(self.call(&handler, constructor, std::slice::from_ref(value))?.to_boolean());
+            }
+        }
+        if !constructor.is_callable() {
+            return Err(Error::Type("Right-hand side of 'instanceof' is not callable!".to_string()));
+        }
+
+        // Bound functions don't have a prototype, their target's being used instead
+        if let Value::Object(object) = constructor {
+            if let Some(bound) = object.bound_function() {
+                return self.instance_of(value, &Value::Object(bound.target));
+            }
+        }
+
+        // Looking for the constructor's prototype in the object's prototype chain
+        let prototype = self.get_property(constructor, "prototype")?;
+        let mut current = match value {
+            Value::Object(object) => self.get_prototype_of(object)?,
+            _ => None,
+        };
+        while let Some(object) = current {
+            if prototype == Value::Object(object.clone()) {
+                return Ok(true);
+            }
+            current = self.get_prototype_of(&object)?;
+        }
+        Ok(false)
+    }
+
+    /**
+     * Reads a property from the prototype of the running method's home object -> super.x
+     */
+    fn eval_super_property(&self, key: &PropertyKey, env_ref: &EnvRef) -> Result<Value> {
+        let this = self.eval_tree(&Tree::ThisLiteral, env_ref)?;
+        let home_object = match env_ref.borrow().lookup(&"%function".to_string()) {
+            Ok(Value::Object(function)) => function.function().and_then(|function| function.home_object.clone()),
+            _ => None,
+        };
+        match home_object.and_then(|home_object| home_object.prototype()) {
+            None => Ok(Value::Undefined),
+            Some(prototype) => self.get_object_property(&prototype, key, &this),
+        }
+    }
+
+    /**
+     * Constructs `this` using the parent class constructor -> super(...)
+     */
+    fn eval_super_call(&self, args: &[Value], env_ref: &EnvRef) -> Result<Value> {
+        let (object, function) = match env_ref.borrow().lookup(&"%function".to_string()) {
+            Ok(Value::Object(object)) => match object.function() {
+                Some(function) if matches!(function.kind, FunctionKind::ClassConstructor { derived: true, .. }) => {
+                    (object, function)
+                },
+                _ => return Err(Error::Runtime("'super' keyword unexpected here!".to_string())),
+            },
+            _ => return Err(Error::Runtime("'super' keyword unexpected here!".to_string())),
+        };
+
+        let mut function_env = match env_ref.borrow().resolve("new.target") {
+            Some(function_env) => function_env,
+            None => return Err(Error::Runtime("'super' keyword unexpected here!".to_string())),
+        };
+        if function_env.has_own("this") {
+            return Err(Error::Reference("Super constructor may only be called once!".to_string()));
+        }
+
+        let new_target = match function_env.lookup(&"new.target".to_string())? {
+            Value::Object(new_target) => new_target,
+            _ => return Err(Error::Runtime("'super' keyword unexpected here!".to_string())),
+        };
+        let parent = match object.prototype() {
+            Some(parent) => Value::Object(parent),
+            None => return Err(Error::Type("Super constructor is not a constructor!".to_string())),
+        };
+
+        let this = self.construct(&parent, args, &new_target)?;
+        function_env.define("this".to_string(), this.clone());
+        self.initialize_fields(&function, &this)?;
+        Ok(this)
+    }
 }
 
 #[cfg(test)]
@@ -65,6 +395,14 @@ mod tests {
         assert_value(tree, Value::Number(45.0));
     }
 
+    #[test]
+    fn test_remainder_and_exponentiation() {
+        assert_eval("-7 % 3;", Value::Number(-1.0));
+        assert_eval("2 ** 3 ** 2;", Value::Number(512.0));
+        assert_eval("(-2) ** 3 % 5;", Value::Number(-3.0));
+        assert_eval("let x = 3; x **= 2; x %= 4; x;", Value::Number(1.0));
+    }
+
     #[test]
     fn test_unimplemented_operator_binary_expression() {
         let tree = Tree::BinaryExpression {
@@ -75,4 +413,131 @@ mod tests {
         let error = Error::Runtime("Unimplemented operator: ^".to_string());
         assert_runtime_error(tree, error);
     }
+
+    #[test]
+    fn test_function_call_and_closure() {
+        let content_string = "
+            function counter(start) {
+                let count = start;
+                return function(step) {
+                    count += step;
+                    return count;
+                };
+            }
+            let next = counter(10);
+            next(1);
+            next(2);
+        ";
+        assert_eval(content_string, Value::Number(13.0));
+    }
+
+    #[test]
+    fn test_hoisted_function_declaration() {
+        assert_eval("square(4); function square(x) { return x * x; }", Value::Number(16.0));
+    }
+
+    #[test]
+    fn test_spread_array_expression() {
+        let content_string = "
+            let a = [1, 2];
+            let b = [...a, 3, ...a, ...'hi'];
+            b.length * 100 + b[2] * 10 + b[4];
+        ";
+        assert_eval(content_string, Value::Number(732.0));
+        assert_eval("[...'hi'][1];", Value::String("i".into()));
+    }
+
+    #[test]
+    fn test_spread_call_expression() {
+        let content_string = "
+            function sum(a, b, c, d) {
+                return a + b * 10 + c * 100 + d * 1000;
+            }
+            let args = [2, 3];
+            sum(1, ...args, ...[4, 5]);
+        ";
+        assert_eval(content_string, Value::Number(4321.0));
+    }
+
+    #[test]
+    fn test_spread_method_call_expression() {
+        let content_string = "
+            class Calculator {
+                constructor(base) {
+                    this.base = base;
+                }
+                add(x, y) {
+                    return this.base + x + y;
+                }
+            }
+            let calculator = new Calculator(100);
+            calculator.add(...[20, 3]);
+        ";
+        assert_eval(content_string, Value::Number(123.0));
+    }
+
+    #[test]
+    fn test_spread_new_expression() {
+        let content_string = "
+            class Point {
+                constructor(x, y) {
+                    this.x = x;
+                    this.y = y;
+                }
+            }
+            let xs = [4, 2];
+            let p = new Point(...xs);
+            p.x - p.y;
+        ";
+        assert_eval(content_string, Value::Number(2.0));
+    }
+
+    #[test]
+    fn test_spread_non_iterable() {
+        let error = Error::Type("42 is not iterable!".to_string());
+        assert_eval_error("let x = 42; [...x];", error);
+    }
+
+    #[test]
+    fn test_call_non_function() {
+        let error = Error::Type("undefined is not a function!".to_string());
+        assert_eval_error("let f; f();", error);
+    }
+
+    #[test]
+    fn test_maximum_call_stack_size() {
+        let content_string = "
+            function r(n) {
+                if (n == 0) {
+                    return 0;
+                }
+                return r(n - 1) + 1;
+            }
+            let result = r(500);
+            try {
+                r(100000);
+            } catch (e) {
+                result += ' ' + e.name + ': ' + e.message;
+            }
+            result;
+        ";
+        assert_eval(content_string, Value::String("500 RangeError: Maximum call stack size exceeded".into()));
+
+        // Getters reading themselves through a proxy recurse without end
+        let content_string = "
+            let handler = new Object();
+            handler.get = function (target, key, receiver) {
+                return receiver[key];
+            };
+            let proxy = new Proxy(new Object(), handler);
+            let result;
+            try {
+                proxy.x;
+            } catch (e) {
+                result = e instanceof RangeError;
+            }
+            result;
+        ";
+        assert_eval(content_string, Value::Boolean(true));
+    }
 }
@@ -0,0 +1,557 @@
+use crate::prelude::*;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use corosensei::stack::{DefaultStack, Stack};
+use corosensei::{Coroutine, CoroutineResult, Yielder};
+
+use crate::interpreter::callable::{frame_name, Callable};
+use crate::interpreter::iteration::{create_iter_result, Iterable, IteratorRecord};
+use crate::interpreter::properties::PropertyAccessor;
+
+/**
+ * Size of the stack each generator body runs on, same as the main thread's one.
+ * Calls nested deeper than it allows throw a RangeError, as on the interpreter's stack.
+ */
+const GENERATOR_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/**
+ * Number of stacks kept for reuse once the coroutines running on them complete.
+ */
+const STACK_POOL_SIZE: usize = 16;
+
+/**
+ * Request to resume a suspended generator, i.e. a call of
+ * `next(value)`, `return(value)` or `throw(exception)`.
+ */
+pub enum GeneratorResume {
+    Next(Value),
+    Return(Value),
+    Throw(Value),
+}
+
+pub type GeneratorYielder = Yielder<GeneratorResume, Value>;
+
+/**
+ * Suspendable run of a function body, shared by generators & async functions.
+ */
+pub type GeneratorCoroutine = Coroutine<GeneratorResume, Value, Result<Value>>;
+
+/**
+ * Execution state of a generator object. The body runs on a coroutine
+ * of its own, hence it can be suspended at a `yield` and resumed later
+ * with its state intact. The coroutine & its stack are only created once
+ * the body starts, i.e. on the first call of `next()`.
+ */
+pub enum GeneratorState {
+    SuspendedStart(Rc<Function>, Environment),
+    SuspendedYield(GeneratorCoroutine),
+    Executing,
+    Completed,
+}
+
+pub trait GeneratorEvalable {
+    /**
+     * Creates the generator object returned by calling a generator function.
+     * The body doesn't run until the first call of `next()`.
+     */
+    fn create_generator(&self, object: &ObjectRef, function: Rc<Function>, env: Environment) -> Result<Value>;
+
+    /**
+     * Evaluates YieldExpression AST Tree node.
+     */
+    fn eval_yield(&self, expr: &Tree, env_ref: &EnvRef) -> Result<Value>;
+
+    /**
+     * Resumes a generator, returning the next iterator result.
+     * The method name is used to describe invalid receivers.
+     */
+    fn resume_generator(&self, generator: &Value, resume: GeneratorResume, method: &str) -> Result<Value>;
+}
+
+impl <'a> GeneratorEvalable for Interpreter<'a> {
+    fn create_generator(&self, object: &ObjectRef, function: Rc<Function>, env: Environment) -> Result<Value> {
+        let prototype = match self.get_property(&Value::Object(object.clone()), "prototype")? {
+            Value::Object(prototype) => Some(prototype),
+            _ => self.realm.intrinsic("%GeneratorPrototype%"),
+        };
+
+        let state = GeneratorState::SuspendedStart(function, env);
+        Ok(Value::Object(ObjectRef::new(ObjectKind::Generator(state), prototype)))
+    }
+
+    fn eval_yield(&self, expr: &Tree, env_ref: &EnvRef) -> Result<Value> {
+        let (argument, delegate) = match expr {
+            Tree::YieldExpression { argument, delegate } => (argument, *delegate),
+            _ => return Err(Error::Runtime(format!("Unimplemented generator node: {expr}"))),
+        };
+
+        let value = match argument.as_ref() {
+            None => Value::Undefined,
+            Some(argument) => self.eval_tree(argument, env_ref)?,
+        };
+        if delegate {
+            return self.yield_delegate(&value);
+        }
+
+        self.suspend_value(value)
+    }
+
+    fn resume_generator(&self, generator: &Value, resume: GeneratorResume, method: &str) -> Result<Value> {
+        let (object, state) = match generator {
+            Value::Object(object) => match &mut object.borrow_mut().kind {
+                ObjectKind::Generator(state) => (object.clone(), std::mem::replace(state, GeneratorState::Executing)),
+                _ => return Err(Error::Type(format!("{method} method called on incompatible receiver {generator}!"))),
+            },
+            _ => return Err(Error::Type(format!("{method} method called on incompatible receiver {generator}!"))),
+        };
+
+        let (mut coroutine, resume) = match (state, resume) {
+            (GeneratorState::Executing, _) => return Err(Error::Type("Generator is already running!".to_string())),
+            (GeneratorState::SuspendedYield(coroutine), resume) => (coroutine, resume),
+            (GeneratorState::SuspendedStart(function, env), resume @ GeneratorResume::Next(_)) => {
+                match self.spawn_coroutine(function.clone(), env.clone()) {
+                    Ok(coroutine) => (coroutine, resume),
+                    Err(error) => {
+                        // The generator can be started again, e.g. once other ones complete
+                        set_generator_state(&object, GeneratorState::SuspendedStart(function, env));
+                        return Err(error);
+                    },
+                }
+            },
+
+            // Generators that haven't started or are done complete right away
+            (_, resume) => {
+                set_generator_state(&object, GeneratorState::Completed);
+                return match resume {
+                    GeneratorResume::Next(_) => Ok(create_iter_result(self, Value::Undefined, true)),
+                    GeneratorResume::Return(value) => Ok(create_iter_result(self, value, true)),
+                    GeneratorResume::Throw(exception) => Err(Error::Abrupt(Completion::Throw(exception))),
+                };
+            },
+        };
+
+        match coroutine.resume(resume) {
+            CoroutineResult::Yield(value) => {
+                set_generator_state(&object, GeneratorState::SuspendedYield(coroutine));
+                Ok(create_iter_result(self, value, false))
+            },
+            CoroutineResult::Return(result) => {
+                set_generator_state(&object, GeneratorState::Completed);
+                self.release_coroutine(coroutine);
+                result.map(|value| create_iter_result(self, value, true))
+            },
+        }
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Creates a coroutine running the body of a function in the given environment.
+     * The body is run by an interpreter living on the coroutine's stack, which
+     * shares the realm & the event loop of this one.
+     */
+    pub(crate) fn spawn_coroutine(&self, function: Rc<Function>, env: Environment) -> Result<GeneratorCoroutine> {
+        // Stacks are reused if possible, failing to allocate one as calls nested too deeply would
+        let pooled = self.stacks.borrow_mut().pop();
+        let stack = match pooled {
+            Some(stack) => stack,
+            None => DefaultStack::new(GENERATOR_STACK_SIZE)
+                .map_err(|error| Error::Range(format!("Unable to allocate generator stack: {error}!")))?,
+        };
+        let stack_limit = stack.limit().get();
+
+        let (realm, jobs, timers, clock) = (self.realm.clone(), self.jobs.clone(), self.timers.clone(), self.clock.clone());
+        let (joining, stacks) = (self.joining.clone(), self.stacks.clone());
+        Ok(Coroutine::with_stack(stack, move |yielder: &GeneratorYielder, _: GeneratorResume| {
+            let mut env = env;
+            let interpreter = Interpreter {
+                tree: &function.body,
+                env_ref: RefCell::new(&mut env),
+                depth: Cell::new(0),
+                realm,
+                call_stack: RefCell::new(vec![frame_name(&function)]),
+                stack_limit: Cell::new(stack_limit),
+                strict: Cell::new(function.strict),
+                joining,
+                stacks,
+                yielder: Some(yielder),
+                jobs,
+                timers,
+                clock,
+            };
+            interpreter.eval_function_body(&function, &interpreter.env_ref)
+        }))
+    }
+
+    /**
+     * Keeps the stack of a completed coroutine for the next one, unless enough stacks are pooled already.
+     */
+    pub(crate) fn release_coroutine(&self, coroutine: GeneratorCoroutine) {
+        let mut stacks = self.stacks.borrow_mut();
+        if coroutine.done() && stacks.len() < STACK_POOL_SIZE {
+            stacks.push(coroutine.into_stack());
+        }
+    }
+
+    /**
+     * Suspends the running generator or async function, handing the value over to its caller.
+     */
+    fn suspend(&self, value: Value) -> Result<GeneratorResume> {
+        match self.yielder {
+            Some(yielder) => Ok(yielder.suspend(value)),
+            None => Err(Error::Runtime("Unable to suspend outside of a generator or an async function!".to_string())),
+        }
+    }
+
+    /**
+     * Suspends the running body, evaluating to the value it is resumed with.
+     * Returning & throwing into the body resumes it with an abrupt completion.
+     */
+    pub(crate) fn suspend_value(&self, value: Value) -> Result<Value> {
+        match self.suspend(value)? {
+            GeneratorResume::Next(value) => Ok(value),
+            GeneratorResume::Return(value) => Err(Error::Abrupt(Completion::Return(value))),
+            GeneratorResume::Throw(exception) => Err(Error::Abrupt(Completion::Throw(exception))),
+        }
+    }
+
+    /**
+     * yield* iterable -> yields all the values of the iterable, forwarding
+     * `next`, `return` & `throw` requests to its iterator. Evaluates to
+     * the value the iterator completes with.
+     */
+    fn yield_delegate(&self, iterable: &Value) -> Result<Value> {
+        let mut record = self.get_iterator(iterable)?;
+        let mut resume = GeneratorResume::Next(Value::Undefined);
+        loop {
+            // Arrays & strings are iterated natively, hence they only support `next`
+            let (iterator, next) = match &record {
+                IteratorRecord::Object { iterator, next } => (iterator.clone(), next.clone()),
+                _ => match resume {
+                    GeneratorResume::Next(_) => match self.iterator_step(&mut record)? {
+                        Some(value) => {
+                            resume = self.suspend(value)?;
+                            continue;
+                        },
+                        None => return Ok(Value::Undefined),
+                    },
+                    GeneratorResume::Return(value) => return Err(Error::Abrupt(Completion::Return(value))),
+                    GeneratorResume::Throw(_) => {
+                        return Err(Error::Type("The iterator does not provide a 'throw' method!".to_string()));
+                    },
+                },
+            };
+
+            let (result, returning) = match resume {
+                GeneratorResume::Next(value) => (self.call(&next, &iterator, &[value])?, false),
+                GeneratorResume::Return(value) => match self.get_property(&iterator, "return")? {
+                    Value::Undefined | Value::Null => return Err(Error::Abrupt(Completion::Return(value))),
+                    method => (self.call(&method, &iterator, &[value])?, true),
+                },
+                GeneratorResume::Throw(exception) => match self.get_property(&iterator, "throw")? {
+                    Value::Undefined | Value::Null => {
+                        self.iterator_close(&record, Ok(Value::Undefined))?;
+                        return Err(Error::Type("The iterator does not provide a 'throw' method!".to_string()));
+                    },
+                    method => (self.call(&method, &iterator, &[exception])?, false),
+                },
+            };
+
+            if !matches!(result, Value::Object(_)) {
+                return Err(Error::Type(format!("Iterator result {result} is not an object!")));
+            }
+            let value = self.get_property(&result, "value")?;
+            if self.get_property(&result, "done")?.to_boolean() {
+                return match returning {
+                    true => Err(Error::Abrupt(Completion::Return(value))),
+                    false => Ok(value),
+                };
+            }
+            resume = self.suspend(value)?;
+        }
+    }
+}
+
+fn set_generator_state(object: &ObjectRef, state: GeneratorState) {
+    if let ObjectKind::Generator(current) = &mut object.borrow_mut().kind {
+        *current = state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_generator_next() {
+        let content_string = "
+            function* gen() {
+                yield 1;
+                yield 2;
+                return 3;
+            }
+            let it = gen();
+            let log = '';
+            for (let i = 0; i < 4; i += 1) {
+                let result = it.next();
+                log += result.value + ':' + result.done + ' ';
+            }
+            log;
+        ";
+        assert_eval(content_string, Value::String("1:false 2:false 3:true undefined:true ".into()));
+    }
+
+    #[test]
+    fn test_generator_is_lazy() {
+        let content_string = "
+            let log = '';
+            function* gen() {
+                log += 'start ';
+                yield 1;
+                log += 'end ';
+            }
+            let it = gen();
+            log += 'created ';
+            it.next();
+            it.next();
+            log;
+        ";
+        assert_eval(content_string, Value::String("created start end ".into()));
+    }
+
+    #[test]
+    fn test_generator_receives_values() {
+        let content_string = "
+            function* accumulate() {
+                let total = 0;
+                while (true) {
+                    total += yield total;
+                }
+            }
+            let it = accumulate();
+            it.next('ignored');
+            it.next(5);
+            it.next(10).value;
+        ";
+        assert_eval(content_string, Value::Number(15.0));
+    }
+
+    #[test]
+    fn test_generator_iteration() {
+        let content_string = "
+            function* naturals() {
+                let n = 0;
+                while (true) {
+                    n += 1;
+                    yield n;
+                }
+            }
+            let sum = 0;
+            for (const n of naturals()) {
+                if (n > 4) break;
+                sum += n;
+            }
+            sum;
+        ";
+        assert_eval(content_string, Value::Number(10.0));
+
+        let content_string = "
+            class Range {
+                constructor(start, end) {
+                    this.start = start;
+                    this.end = end;
+                }
+                *values() {
+                    for (let i = this.start; i < this.end; i += 1) yield i;
+                }
+            }
+            '' + [...new Range(2, 5).values()];
+        ";
+        assert_eval(content_string, Value::String("2,3,4".into()));
+    }
+
+    #[test]
+    fn test_generator_return() {
+        let content_string = "
+            let log = '';
+            function* gen() {
+                try {
+                    yield 1;
+                    yield 2;
+                } finally {
+                    log += 'cleanup ';
+                }
+            }
+            for (const x of gen()) {
+                log += x + ' ';
+                break;
+            }
+            log;
+        ";
+        assert_eval(content_string, Value::String("1 cleanup ".into()));
+
+        let content_string = "
+            function* gen() {
+                try {
+                    yield 1;
+                } finally {
+                    yield 'finally';
+                }
+            }
+            let it = gen();
+            it.next();
+            let a = it.return(42);
+            let b = it.next();
+            a.value + ':' + a.done + ' ' + b.value + ':' + b.done;
+        ";
+        assert_eval(content_string, Value::String("finally:false 42:true".into()));
+
+        let content_string = "
+            function* gen() { yield 1; }
+            let it = gen();
+            let a = it.return(7);
+            let b = it.next();
+            a.value + ':' + a.done + ' ' + b.done;
+        ";
+        assert_eval(content_string, Value::String("7:true true".into()));
+    }
+
+    #[test]
+    fn test_generator_throw() {
+        let content_string = "
+            function* gen() {
+                while (true) {
+                    try {
+                        yield 'waiting';
+                    } catch (e) {
+                        yield 'caught ' + e;
+                    }
+                }
+            }
+            let it = gen();
+            it.next();
+            it.throw('oops').value;
+        ";
+        assert_eval(content_string, Value::String("caught oops".into()));
+
+        let content_string = "
+            function* gen() { yield 1; }
+            let it = gen();
+            let result;
+            try { it.throw('early'); } catch (e) { result = e + ' ' + it.next().done; }
+            result;
+        ";
+        assert_eval(content_string, Value::String("early true".into()));
+    }
+
+    #[test]
+    fn test_delegating_yield() {
+        let content_string = "
+            function* inner() {
+                let x = yield 'a';
+                yield x;
+                return 'inner done';
+            }
+            function* outer() {
+                let result = yield* inner();
+                yield* [result, 'b'];
+                yield* 'cd';
+            }
+            let it = outer();
+            let log = it.next().value;
+            let result = it.next('sent');
+            while (!result.done) {
+                log += ' ' + result.value;
+                result = it.next();
+            }
+            log;
+        ";
+        assert_eval(content_string, Value::String("a sent inner done b c d".into()));
+    }
+
+    #[test]
+    fn test_recursive_generators() {
+        let content_string = "
+            function* countdown(n) {
+                if (n === 0) return;
+                yield n;
+                yield* countdown(n - 1);
+            }
+            let total = 0;
+            for (const n of countdown(20)) total += n;
+            total;
+        ";
+        assert_eval(content_string, Value::Number(210.0));
+    }
+
+    #[test]
+    fn test_deep_recursion_in_generator() {
+        let content_string = "
+            function r(n) {
+                if (n == 0) return 0;
+                return r(n - 1) + 1;
+            }
+            function* gen() {
+                yield r(10);
+                try {
+                    r(100000);
+                } catch (e) {
+                    yield e.name + ': ' + e.message;
+                }
+            }
+            let it = gen();
+            it.next().value + ' ' + it.next().value;
+        ";
+        assert_eval(content_string, Value::String("10 RangeError: Maximum call stack size exceeded".into()));
+    }
+
+    #[test]
+    fn test_generator_errors() {
+        let content_string = "
+            let it;
+            function* gen() { it.next(); yield 1; }
+            it = gen();
+            let result;
+            try { it.next(); } catch (e) { result = e.name + ': ' + e.message; }
+            result;
+        ";
+        assert_eval(content_string, Value::String("TypeError: Generator is already running".into()));
+
+        let content_string = "
+            function* gen() { throw 'failure'; }
+            let it = gen();
+            let result;
+            try { it.next(); } catch (e) { result = e + ' ' + it.next().done; }
+            result;
+        ";
+        assert_eval(content_string, Value::String("failure true".into()));
+
+        let content_string = "
+            function* gen() {}
+            new gen();
+        ";
+        assert_eval_error(content_string, Error::Type("Object(Function(gen)) is not a constructor!".to_string()));
+    }
+
+    #[test]
+    fn test_many_generators() {
+        // More generators than stacks can be mapped at once, which only get one while running
+        let content_string = "
+            function* count() { yield 1; }
+            let generators = [];
+            for (let i = 0; i < 33000; i += 1) {
+                generators.push(count());
+            }
+            let total = 0;
+            for (const generator of generators) {
+                total += generator.next().value;
+                generator.next();
+            }
+            total;
+        ";
+        assert_eval(content_string, Value::Number(33000.0));
+    }
+}
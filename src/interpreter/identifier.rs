@@ -1,9 +1,12 @@
 use crate::prelude::*;
 
+use crate::interpreter::properties::PropertyAccessor;
+
 pub trait IdentifierEvalable {
     /**
      * Evaluate identifier AST Tree nodes.
      * + Identifier
+     * + ThisLiteral
      */
     fn eval_identifier(&self, identifier: &Tree, env_ref: &EnvRef) -> Result<Value>;
 }
@@ -11,14 +14,57 @@ pub trait IdentifierEvalable {
 impl <'a> IdentifierEvalable for Interpreter<'a> {
     fn eval_identifier(&self, identifier: &Tree, env_ref: &EnvRef) -> Result<Value> {
         let depth = self.depth.get();
-        if let Tree::Identifier { name } = identifier {
-            // Extracting variable from environment
-            let env = env_ref.borrow_mut();
-            let value = env.lookup(name)?;
-            println!("{}* {name} = {value:?}", " ".repeat(depth));
-            return Ok(value);
+        match identifier {
+            Tree::Identifier { name } => {
+                // Extracting variable from environment
+                let value = self.lookup_variable(name, env_ref)?;
+                println!("{}* {name} = {value:?}", " ".repeat(depth));
+                Ok(value)
+            },
+            Tree::ThisLiteral => {
+                let env = env_ref.borrow();
+
+                // Derived class constructors bind `this` only once super(...) is called
+                if let Some(function_env) = env.resolve("new.target") {
+                    if !function_env.has_own("this") {
+                        return Err(Error::Reference(
+                            "Must call super constructor in derived class before accessing 'this'!".to_string()
+                        ));
+                    }
+                }
+                Ok(env.lookup(&"this".to_string()).unwrap_or(Value::Undefined))
+            },
+            _ => Err(Error::Runtime(format!("Invalid identifier node: {identifier}"))),
+        }
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Returns the value of a variable, falling back on the properties of the global object.
+     */
+    pub(crate) fn lookup_variable(&self, name: &String, env_ref: &EnvRef) -> Result<Value> {
+        let result = env_ref.borrow().lookup(name);
+        match (result, self.realm.intrinsic("%GlobalObject%")) {
+            (Err(Error::Reference(_)), Some(global)) if global.has_property(name) => {
+                self.get_object_property(&global, name, &Value::Object(global.clone()))
+            },
+            (result, _) => result,
+        }
+    }
+
+    /**
+     * Updates a variable, falling back on the properties of the global object, e.g. `NaN = 1`.
+     * Assigning an undeclared variable creates a global property, unless in strict mode code.
+     */
+    pub(crate) fn assign_variable(&self, name: &String, value: Value, env_ref: &EnvRef) -> Result<()> {
+        let defined = env_ref.borrow().resolve(name).is_some();
+        match self.realm.intrinsic("%GlobalObject%") {
+            Some(global) if !defined && (global.has_property(name) || !self.is_strict()) => {
+                self.assign_property(&Value::Object(global), name, value)
+            },
+            _ => env_ref.borrow_mut().assign(name, value),
         }
-        Err(Error::Runtime(format!("Invalid identifier node: {identifier}")))
     }
 }
 
@@ -48,7 +94,53 @@ mod tests {
             ]))
             .build()
             .unwrap();
-        let error = Error::Runtime("Variable 'xyz' is not defined!".to_string());
+        let error = Error::Reference("Variable 'xyz' is not defined!".to_string());
         assert_runtime_error_env(Tree::Identifier { name: "xyz".to_string() }, &mut env, error);
     }
+
+    #[test]
+    fn test_global_object() {
+        let content = "
+            let before = Math.PI === globalThis.Math.PI;
+            globalThis.answer = 42;
+            answer = answer + 1;
+            let Infinity = 'shadowed';
+            '' + [before, globalThis.answer, Infinity, globalThis.Infinity, globalThis.globalThis === globalThis];
+        ";
+        assert_eval(content, Value::String("true,43,shadowed,Infinity,true".into()));
+        assert_eval_error("answer;", Error::Reference("Variable 'answer' is not defined!".to_string()));
+    }
+
+    #[test]
+    fn test_bare_interpreter() {
+        let tree = parse("Math;");
+        let mut env = EnvironmentBuilder::default().build().unwrap();
+        let result = Interpreter::bare(&tree, &mut env).eval();
+        assert_eq!(result, Err(Error::Reference("Variable 'Math' is not defined!".to_string())));
+
+        // Errors thrown by the interpreter itself are still proper error objects
+        let tree = parse("let message; try { null.x; } catch (e) { message = e.message; } message;");
+        let result = Interpreter::bare(&tree, &mut env).eval();
+        assert_eq!(result, Ok(Value::String("Cannot read properties of null (reading 'x')".into())));
+    }
+
+    #[test]
+    fn test_strict_mode() {
+        assert_eval("answer = 42; globalThis.answer;", Value::Number(42.0));
+        let error = Error::Reference("Variable 'answer' is not defined!".to_string());
+        assert_eval_error("'use strict'; answer = 42;", error);
+        let error = Error::Reference("Variable 'answer' is not defined!".to_string());
+        assert_eval_error("class A { static { answer = 42; } }", error);
+
+        // Strictness is a property of the code, not of the code calling it
+        assert_eval("(\"use strict\"); answer = 42; answer;", Value::Number(42.0));
+        assert_eval("function f() { 'use strict'; } f(); answer = 42; answer;", Value::Number(42.0));
+        assert_eval("'use strict'; Function('answer = 42;')(); answer;", Value::Number(42.0));
+
+        // Sloppy mode functions see the global object as `this` in plain calls
+        assert_eval("function f() { return this; } f() === globalThis;", Value::Boolean(true));
+        assert_eval("function f() { 'use strict'; return this; } f();", Value::Undefined);
+        assert_eval("'use strict'; function f() { return function() { return this; }; } f()();", Value::Undefined);
+        assert_eval("function f() { return this.length; } f.call('abc');", Value::Number(3.0));
+    }
 }
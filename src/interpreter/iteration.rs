@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::TypeConversion;
+use crate::interpreter::properties::PropertyAccessor;
+
+/**
+ * Creates the object returned by an iterator's `next()`, i.e. `{ value, done }`.
+ */
+pub fn create_iter_result(interpreter: &Interpreter, value: Value, done: bool) -> Value {
+    let result = ObjectRef::new(ObjectKind::Ordinary, interpreter.realm.intrinsic("%Object.prototype%"));
+    result.set_own_value("value".to_string(), value);
+    result.set_own_value("done".to_string(), Value::Boolean(done));
+    Value::Object(result)
+}
+
+/**
+ * State of an ongoing iteration. Objects using the built-in array iterator & strings
+ * are iterated natively, any other object through its `next()` method.
+ */
+pub enum IteratorRecord {
+    Array { array: ObjectRef, index: usize },
+    String { chars: Vec<char>, index: usize },
+    Object { iterator: Value, next: Value },
+}
+
+pub trait Iterable {
+    /**
+     * Obtains an iterator from a value, i.e. calls its `[Symbol.iterator]()` method.
+     */
+    fn get_iterator(&self, iterable: &Value) -> Result<IteratorRecord>;
+
+    /**
+     * Advances the iterator, returning None once it's done.
+     */
+    fn iterator_step(&self, record: &mut IteratorRecord) -> Result<Option<Value>>;
+
+    /**
+     * Notifies the iterator that the iteration ended early, e.g. by `break`,
+     * calling its `return()` method. An error caused by the early exit wins
+     * over the ones raised while closing.
+     */
+    fn iterator_close(&self, record: &IteratorRecord, completion: Result<Value>) -> Result<Value>;
+
+    /**
+     * Collects the values produced by iterating over a value, e.g.
+     * the elements of an array or the characters of a string.
+     */
+    fn iterate(&self, iterable: &Value) -> Result<Vec<Value>>;
+}
+
+impl <'a> Iterable for Interpreter<'a> {
+    fn get_iterator(&self, iterable: &Value) -> Result<IteratorRecord> {
+        let method = match iterable {
+            Value::Undefined | Value::Null => Value::Undefined,
+            _ => self.get_property(iterable, &self.realm.symbols.iterator)?,
+        };
+
+        match (iterable, method) {
+            // Iterating with the built-in array iterator is done natively, e.g. for arrays & their proxies
+            (Value::Object(object), Value::Object(method)) if self.realm.intrinsic("%Array.prototype.values%").as_ref() == Some(&method) => {
+                Ok(IteratorRecord::Array { array: object.clone(), index: 0 })
+            },
+            (_, method @ Value::Object(_)) => {
+                let iterator = self.call(&method, iterable, &[])?;
+                if !matches!(iterator, Value::Object(_)) {
+                    return Err(Error::Type("Result of the Symbol.iterator method is not an object!".to_string()));
+                }
+                let next = self.get_property(&iterator, "next")?;
+                Ok(IteratorRecord::Object { iterator, next })
+            },
+            (Value::String(string), _) => Ok(IteratorRecord::String { chars: string.chars().collect(), index: 0 }),
+            (Value::Object(_), _) => Err(Error::Type(format!("{iterable:?} is not iterable!"))),
+            _ => Err(Error::Type(format!("{iterable} is not iterable!"))),
+        }
+    }
+
+    fn iterator_step(&self, record: &mut IteratorRecord) -> Result<Option<Value>> {
+        match record {
+            IteratorRecord::Array { array, index } => {
+                // Arrays are read live, hence elements pushed while iterating are visited too
+                let length = self.get_property(&Value::Object(array.clone()), "length")?;
+                if *index as f64 >= self.to_number(&length)? {
+                    return Ok(None);
+                }
+                let value = self.get_property(&Value::Object(array.clone()), index.to_string())?;
+                *index += 1;
+                Ok(Some(value))
+            },
+            IteratorRecord::String { chars, index } => {
+                let value = chars.get(*index).map(|char| Value::String(char.to_string().into()));
+                *index += 1;
+                Ok(value)
+            },
+            IteratorRecord::Object { iterator, next } => {
+                let result = self.call(next, iterator, &[])?;
+                if !matches!(result, Value::Object(_)) {
+                    return Err(Error::Type(format!("Iterator result {result} is not an object!")));
+                }
+                if self.get_property(&result, "done")?.to_boolean() {
+                    return Ok(None);
+                }
+                self.get_property(&result, "value").map(Some)
+            },
+        }
+    }
+
+    fn iterator_close(&self, record: &IteratorRecord, completion: Result<Value>) -> Result<Value> {
+        let iterator = match record {
+            IteratorRecord::Object { iterator, .. } => iterator,
+            _ => return completion,
+        };
+
+        let result = match self.get_property(iterator, "return") {
+            Ok(Value::Undefined | Value::Null) => return completion,
+            Ok(method) => self.call(&method, iterator, &[]),
+            Err(error) => Err(error),
+        };
+
+        // Errors thrown while iterating take precedence
+        let is_throw = matches!(
+            &completion,
+            Err(error) if !matches!(error, Error::Abrupt(Completion::Return(_) | Completion::Break(_) | Completion::Continue(_)))
+        );
+        if is_throw {
+            return completion;
+        }
+        match result? {
+            Value::Object(_) => completion,
+            result => Err(Error::Type(format!("Iterator result {result} is not an object!"))),
+        }
+    }
+
+    fn iterate(&self, iterable: &Value) -> Result<Vec<Value>> {
+        let mut record = self.get_iterator(iterable)?;
+        let mut values = vec![];
+        while let Some(value) = self.iterator_step(&mut record)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
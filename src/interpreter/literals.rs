@@ -1,10 +1,14 @@
 use crate::prelude::*;
 
+use crate::interpreter::builtins::regexp::create_regexp;
+
 pub trait LiteralEvalable {
     /**
      * Evaluates literal AST Tree nodes.
      * + NumericLiteral
+     * + BigIntLiteral
      * + StringLiteral
+     * + RegExpLiteral
      * + NullLiteral
      * + BooleanLiteral
      */
@@ -15,7 +19,14 @@ impl <'a> LiteralEvalable for Interpreter<'a> {
     fn eval_literal(&self, literal: &Tree) -> Result<Value> {
         match literal {
             Tree::NumericLiteral { value } => Ok(Value::Number(*value)),
-            Tree::StringLiteral { value } => Ok(Value::String((*value).clone())),
+            Tree::BigIntLiteral { value } => match BigInt::parse_bytes(value.as_bytes(), 10) {
+                Some(value) => Ok(Value::BigInt(value)),
+                None => Err(Error::Syntax(format!("Invalid BigInt literal: {value}n"))),
+            },
+            Tree::StringLiteral { value } => Ok(Value::String((*value).clone().into())),
+
+            // Every evaluation creates a new object, e.g. in loops
+            Tree::RegExpLiteral { pattern, flags } => Ok(Value::Object(create_regexp(self, pattern, flags)?)),
             Tree::NullLiteral => Ok(Value::Null),
             Tree::BooleanLiteral { value } => Ok(Value::Boolean(*value)),
             _ => Err(Error::Runtime(
@@ -35,9 +46,15 @@ mod tests {
         assert_value(Tree::NumericLiteral { value: 10.0 }, Value::Number(10.0));
     }
 
+    #[test]
+    fn test_bigint_literal() {
+        let value = "123456789012345678901234567890".to_string();
+        assert_value(Tree::BigIntLiteral { value: value.clone() }, Value::BigInt(value.parse().unwrap()));
+    }
+
     #[test]
     fn test_string_literal() {
-        assert_value(Tree::StringLiteral { value: "Hello!".to_string() }, Value::String("Hello!".to_string()));
+        assert_value(Tree::StringLiteral { value: "Hello!".to_string() }, Value::String("Hello!".into()));
     }
 
     #[test]
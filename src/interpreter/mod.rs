@@ -1,15 +1,57 @@
 use crate::prelude::*;
 
 use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
+use corosensei::stack::{DefaultStack, Stack};
+
+pub use builtins::regexp::RegularExpression;
+pub use clock::{Clock, SystemClock, VirtualClock};
 pub use evalable::Evalable;
+pub use generators::GeneratorState;
+pub use promises::PromiseState;
+
+use event_loop::{JobQueue, TimerQueue};
+use generators::GeneratorYielder;
+use realm::Realm;
 
+mod asynchronous;
+mod builtins;
+mod callable;
+mod classes;
+mod clock;
+mod control;
+mod conversions;
+mod exceptions;
 mod expressions;
+mod generators;
 mod identifier;
 mod evalable;
+mod event_loop;
+mod iteration;
 mod literals;
+mod promises;
+mod properties;
+mod proxies;
+mod realm;
 mod statements;
 
+/**
+ * Nesting of function calls after which a RangeError is thrown, instead of overflowing the host stack.
+ */
+const MAX_CALL_DEPTH: usize = 1000;
+
+/**
+ * Host stack reserved for each nested function call, generous enough for unoptimized builds.
+ */
+const CALL_FRAME_SIZE: usize = 64 * 1024;
+
+/**
+ * Host stack left free when entering a function, as statements & expressions nested
+ * in its body may need more than `CALL_FRAME_SIZE` before the next call is checked.
+ */
+const STACK_RESERVE: usize = 256 * 1024;
+
 /**
  * AST Tree-walking interpreter implementation.
  */
@@ -17,20 +59,85 @@ pub struct Interpreter<'a> {
     tree: &'a Tree,
     env_ref: RefCell<&'a mut Environment>,
     depth: Cell<usize>,
+    realm: Realm,
+
+    // Names of the user-defined functions being run, innermost last
+    call_stack: RefCell<Vec<String>>,
+
+    // Lowest usable address of the stack the interpreter runs on, 0 if unknown
+    stack_limit: Cell<usize>,
+
+    // Whether the code being run is strict mode code
+    strict: Cell<bool>,
+
+    // Arrays being joined, innermost last, which join as the empty string when they contain themselves
+    joining: Rc<RefCell<Vec<ObjectRef>>>,
+
+    // Stacks of completed generators & async functions, reused by the next ones to run
+    stacks: Rc<RefCell<Vec<DefaultStack>>>,
+
+    // Suspends the generator or async function whose body is being run, if any
+    yielder: Option<&'a GeneratorYielder>,
+
+    // Microtasks run once the script completes, e.g. promise reactions
+    jobs: JobQueue,
+
+    // Macrotasks serviced by the event loop, e.g. `setTimeout` callbacks
+    timers: TimerQueue,
+    clock: Rc<dyn Clock>,
 }
 
 impl <'a> Interpreter<'a> {
     /**
      * Creates a new interpreter with AST Tree.
+     * Variables missing from the supplied environment are looked up
+     * on the global object holding the standard built-ins, e.g. `Math`.
      */
     pub fn new(tree: &'a Tree, env: &'a mut Environment) -> Self {
+        let mut realm = Realm::default();
+        builtins::install(&mut realm);
+        Self::with_realm(tree, env, realm)
+    }
+
+    /**
+     * Creates a new interpreter with AST Tree whose global object is empty,
+     * i.e. scripts only see the variables of the supplied environment.
+     */
+    pub fn bare(tree: &'a Tree, env: &'a mut Environment) -> Self {
+        let mut realm = Realm::default();
+        builtins::install(&mut realm);
+
+        // Intrinsics are kept, e.g. for errors thrown by the interpreter itself
+        realm.define_intrinsic("%GlobalObject%", ObjectRef::new(ObjectKind::Ordinary, None));
+        Self::with_realm(tree, env, realm)
+    }
+
+    fn with_realm(tree: &'a Tree, env: &'a mut Environment, realm: Realm) -> Self {
         Self {
             tree,
             env_ref: RefCell::new(env),
             depth: Cell::new(0),
+            realm,
+            call_stack: RefCell::new(vec![]),
+            stack_limit: Cell::new(0),
+            strict: Cell::new(false),
+            joining: Rc::default(),
+            stacks: Rc::default(),
+            yielder: None,
+            jobs: JobQueue::default(),
+            timers: TimerQueue::default(),
+            clock: Rc::new(SystemClock),
         }
     }
 
+    /**
+     * Replaces the clock servicing timers, e.g. with a `VirtualClock` in tests.
+     */
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     fn increment_depth(&self) -> usize {
         let depth = self.depth.get() + 1;
         self.depth.set(depth);
@@ -42,12 +149,54 @@ impl <'a> Interpreter<'a> {
         self.depth.set(depth);
         depth
     }
+
+    /**
+     * Runs the closure on a stack with room for `MAX_CALL_DEPTH` nested calls, as the
+     * one of the calling thread may be much smaller, e.g. 2MB for spawned threads.
+     */
+    fn on_call_stack<R>(&self, f: impl FnOnce() -> R) -> R {
+        if self.stack_limit.get() != 0 {
+            return f();
+        }
+        let stack = match DefaultStack::new(MAX_CALL_DEPTH * CALL_FRAME_SIZE + STACK_RESERVE) {
+            Ok(stack) => stack,
+            Err(_) => return f(),
+        };
+        self.stack_limit.set(stack.limit().get());
+        let result = corosensei::on_stack(stack, f);
+        self.stack_limit.set(0);
+        result
+    }
+
+    /**
+     * Throws a RangeError once calls are nested too deeply, or the stack is about to be exhausted.
+     */
+    fn check_call_depth(&self) -> Result<()> {
+        let marker = 0u8;
+        let stack_limit = self.stack_limit.get();
+        let exhausted = stack_limit != 0 && (std::ptr::addr_of!(marker) as usize).saturating_sub(stack_limit) < STACK_RESERVE;
+        if exhausted || self.call_stack.borrow().len() >= MAX_CALL_DEPTH {
+            return Err(Error::Range("Maximum call stack size exceeded!".to_string()));
+        }
+        Ok(())
+    }
+
+    /**
+     * Describes the functions being run, innermost first, e.g. for `Error.prototype.stack`.
+     */
+    fn stack_frames(&self) -> Vec<String> {
+        let mut frames: Vec<String> = self.call_stack.borrow().iter().rev().cloned().collect();
+        frames.push("<anonymous>".to_string());
+        frames
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
 
+    use std::rc::Rc;
+
     pub fn assert_value(tree: Tree, value: Value) {
         let mut env = EnvironmentBuilder::default().build().unwrap();
         assert_value_env(tree, &mut env, value);
@@ -71,4 +220,33 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), error);
     }
+
+    pub fn parse(content_string: &str) -> Tree {
+        let mut parser = Parser::new(content_string.to_owned()).unwrap();
+        parser.parse().unwrap()
+    }
+
+    pub fn assert_eval(content_string: &str, value: Value) {
+        assert_value(parse(content_string), value);
+    }
+
+    pub fn assert_eval_error(content_string: &str, error: Error) {
+        assert_runtime_error(parse(content_string), error);
+    }
+
+    /**
+     * Asserts the binding's value once the event loop completed, e.g. `'1,2'` for `[1, 2]`.
+     * Timers are run on a virtual clock, returned to check the time spent.
+     */
+    pub fn assert_binding(content_string: &str, name: &str, value: &str) -> Rc<VirtualClock> {
+        let tree = parse(content_string);
+        let mut env = EnvironmentBuilder::default().build().unwrap();
+        let clock = Rc::new(VirtualClock::default());
+        let interpreter = Interpreter::new(&tree, &mut env).with_clock(clock.clone());
+        assert!(interpreter.eval().is_ok());
+        assert!(interpreter.run_event_loop().is_ok());
+        drop(interpreter);
+        assert_eq!(env.lookup(&name.to_string()).unwrap().to_string(), value);
+        clock
+    }
 }
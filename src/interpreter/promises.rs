@@ -0,0 +1,296 @@
+use crate::prelude::*;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use crate::interpreter::builtins::native_closure;
+use crate::interpreter::callable::Callable;
+use crate::interpreter::event_loop::{EventLoop, Job};
+use crate::interpreter::exceptions::ExceptionEvalable;
+use crate::interpreter::properties::PropertyAccessor;
+
+/**
+ * Whether a promise is still waiting for its result.
+ */
+#[derive(Default)]
+pub enum PromiseStatus {
+    #[default]
+    Pending,
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/**
+ * Internal state of a promise object, holding the reactions
+ * registered with `then` until the promise settles.
+ */
+#[derive(Default)]
+pub struct PromiseState {
+    pub status: PromiseStatus,
+    fulfill_reactions: Vec<PromiseReaction>,
+    reject_reactions: Vec<PromiseReaction>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ReactionKind {
+    Fulfill,
+    Reject,
+}
+
+/**
+ * Handler to run once a promise settles, settling the derived promise
+ * of its capability with the handler's result. Reactions of `await`
+ * don't derive any promise.
+ */
+#[derive(Clone)]
+pub struct PromiseReaction {
+    pub capability: Option<PromiseCapability>,
+    pub kind: ReactionKind,
+    pub handler: Option<Value>,
+}
+
+/**
+ * Promise along with the functions resolving & rejecting it.
+ */
+#[derive(Clone)]
+pub struct PromiseCapability {
+    pub promise: Value,
+    pub resolve: Value,
+    pub reject: Value,
+}
+
+pub trait PromiseOperations {
+    /**
+     * Creates the `resolve` & `reject` functions of a promise,
+     * only the first call of either having any effect.
+     */
+    fn create_resolving_functions(&self, promise: &ObjectRef) -> (Value, Value);
+
+    /**
+     * Resolves a promise, adopting the state of thenables & fulfilling it with any other value.
+     */
+    fn resolve_promise(&self, promise: &ObjectRef, resolution: Value) -> Result<()>;
+
+    /**
+     * Fulfills or rejects a pending promise, scheduling its reactions.
+     */
+    fn settle_promise(&self, promise: &ObjectRef, kind: ReactionKind, value: Value);
+
+    /**
+     * Creates a promise through a constructor, e.g. `Promise` or a subclass
+     * of it, capturing the resolving functions passed to its executor.
+     */
+    fn new_promise_capability(&self, constructor: &Value) -> Result<PromiseCapability>;
+
+    /**
+     * Converts a value into a promise of the constructor, e.g. `Promise.resolve(value)`.
+     * Promises created by the same constructor are returned as is.
+     */
+    fn promise_resolve(&self, constructor: &Value, value: Value) -> Result<Value>;
+
+    /**
+     * Registers the handlers to run once the promise settles, returning the derived promise.
+     */
+    fn perform_promise_then(
+        &self,
+        promise: &ObjectRef,
+        on_fulfilled: &Value,
+        on_rejected: &Value,
+        capability: Option<PromiseCapability>,
+    ) -> Value;
+
+    /**
+     * Runs the handler of a reaction, settling the derived promise with its result.
+     */
+    fn promise_reaction_job(&self, reaction: PromiseReaction, argument: Value) -> Result<()>;
+
+    /**
+     * Calls the `then` method of a thenable with the resolving functions of a promise.
+     */
+    fn promise_resolve_thenable_job(&self, promise: &ObjectRef, thenable: Value, then: Value) -> Result<()>;
+}
+
+impl <'a> PromiseOperations for Interpreter<'a> {
+    fn create_resolving_functions(&self, promise: &ObjectRef) -> (Value, Value) {
+        let already_resolved = Rc::new(Cell::new(false));
+
+        let (target, resolved) = (promise.clone(), already_resolved.clone());
+        let resolve = native_closure(&self.realm, "", 1, move |interpreter, _this, args, _new_target| {
+            if !resolved.replace(true) {
+                let resolution = args.first().cloned().unwrap_or(Value::Undefined);
+                interpreter.resolve_promise(&target, resolution)?;
+            }
+            Ok(Value::Undefined)
+        }, false);
+
+        let (target, resolved) = (promise.clone(), already_resolved);
+        let reject = native_closure(&self.realm, "", 1, move |interpreter, _this, args, _new_target| {
+            if !resolved.replace(true) {
+                let reason = args.first().cloned().unwrap_or(Value::Undefined);
+                interpreter.settle_promise(&target, ReactionKind::Reject, reason);
+            }
+            Ok(Value::Undefined)
+        }, false);
+
+        (Value::Object(resolve), Value::Object(reject))
+    }
+
+    fn resolve_promise(&self, promise: &ObjectRef, resolution: Value) -> Result<()> {
+        let then = match &resolution {
+            Value::Object(object) if object == promise => {
+                let error = self.create_error("TypeError", "Chaining cycle detected for promise #<Promise>");
+                self.settle_promise(promise, ReactionKind::Reject, error);
+                return Ok(());
+            },
+            Value::Object(_) => match self.get_property(&resolution, "then") {
+                Ok(then) => then,
+                Err(error) => {
+                    let exception = self.to_exception(error)?;
+                    self.settle_promise(promise, ReactionKind::Reject, exception);
+                    return Ok(());
+                },
+            },
+            _ => Value::Undefined,
+        };
+
+        // Thenables are adopted asynchronously, so that `then` never runs synchronously
+        match then.is_callable() {
+            true => self.enqueue_job(Job::PromiseResolveThenable { promise: promise.clone(), thenable: resolution, then }),
+            false => self.settle_promise(promise, ReactionKind::Fulfill, resolution),
+        }
+        Ok(())
+    }
+
+    fn settle_promise(&self, promise: &ObjectRef, kind: ReactionKind, value: Value) {
+        let reactions = match &mut promise.borrow_mut().kind {
+            ObjectKind::Promise(state) if matches!(state.status, PromiseStatus::Pending) => {
+                state.status = match kind {
+                    ReactionKind::Fulfill => PromiseStatus::Fulfilled(value.clone()),
+                    ReactionKind::Reject => PromiseStatus::Rejected(value.clone()),
+                };
+                let fulfill_reactions = std::mem::take(&mut state.fulfill_reactions);
+                let reject_reactions = std::mem::take(&mut state.reject_reactions);
+                match kind {
+                    ReactionKind::Fulfill => fulfill_reactions,
+                    ReactionKind::Reject => reject_reactions,
+                }
+            },
+            _ => vec![],
+        };
+
+        for reaction in reactions {
+            self.enqueue_job(Job::PromiseReaction { reaction, argument: value.clone() });
+        }
+    }
+
+    fn new_promise_capability(&self, constructor: &Value) -> Result<PromiseCapability> {
+        let new_target = match constructor {
+            Value::Object(object) if object.is_constructor() => object,
+            _ => return Err(Error::Type(format!("{constructor} is not a constructor!"))),
+        };
+
+        // The executor captures the resolving functions created by the constructor
+        let slots = Rc::new(RefCell::new((Value::Undefined, Value::Undefined)));
+        let captured = slots.clone();
+        let executor = native_closure(&self.realm, "", 2, move |_interpreter, _this, args, _new_target| {
+            let mut slots = captured.borrow_mut();
+            if slots.0 != Value::Undefined || slots.1 != Value::Undefined {
+                return Err(Error::Type("Promise executor has already been invoked with non-undefined arguments!".to_string()));
+            }
+            slots.0 = args.first().cloned().unwrap_or(Value::Undefined);
+            slots.1 = args.get(1).cloned().unwrap_or(Value::Undefined);
+            Ok(Value::Undefined)
+        }, false);
+
+        let promise = self.construct(constructor, &[Value::Object(executor)], new_target)?;
+        let (resolve, reject) = slots.borrow().clone();
+        if !resolve.is_callable() || !reject.is_callable() {
+            return Err(Error::Type("Promise resolve or reject function is not callable!".to_string()));
+        }
+        Ok(PromiseCapability { promise, resolve, reject })
+    }
+
+    fn promise_resolve(&self, constructor: &Value, value: Value) -> Result<Value> {
+        if let Value::Object(object) = &value {
+            if object.is_promise() && self.get_property(&value, "constructor")? == *constructor {
+                return Ok(value);
+            }
+        }
+
+        let capability = self.new_promise_capability(constructor)?;
+        self.call(&capability.resolve, &Value::Undefined, &[value])?;
+        Ok(capability.promise)
+    }
+
+    fn perform_promise_then(
+        &self,
+        promise: &ObjectRef,
+        on_fulfilled: &Value,
+        on_rejected: &Value,
+        capability: Option<PromiseCapability>,
+    ) -> Value {
+        let reaction = |kind: ReactionKind, handler: &Value| PromiseReaction {
+            capability: capability.clone(),
+            kind,
+            handler: match handler.is_callable() {
+                true => Some(handler.clone()),
+                false => None,
+            },
+        };
+        let fulfill_reaction = reaction(ReactionKind::Fulfill, on_fulfilled);
+        let reject_reaction = reaction(ReactionKind::Reject, on_rejected);
+
+        // Handlers of settled promises are scheduled right away
+        let job = match &mut promise.borrow_mut().kind {
+            ObjectKind::Promise(state) => match &state.status {
+                PromiseStatus::Pending => {
+                    state.fulfill_reactions.push(fulfill_reaction);
+                    state.reject_reactions.push(reject_reaction);
+                    None
+                },
+                PromiseStatus::Fulfilled(value) => Some(Job::PromiseReaction { reaction: fulfill_reaction, argument: value.clone() }),
+                PromiseStatus::Rejected(reason) => Some(Job::PromiseReaction { reaction: reject_reaction, argument: reason.clone() }),
+            },
+            _ => None,
+        };
+        if let Some(job) = job {
+            self.enqueue_job(job);
+        }
+
+        match capability {
+            Some(capability) => capability.promise,
+            None => Value::Undefined,
+        }
+    }
+
+    fn promise_reaction_job(&self, reaction: PromiseReaction, argument: Value) -> Result<()> {
+        // Missing handlers pass the value or the reason on to the derived promise
+        let result = match (&reaction.handler, reaction.kind) {
+            (Some(handler), _) => self.call(handler, &Value::Undefined, &[argument]),
+            (None, ReactionKind::Fulfill) => Ok(argument),
+            (None, ReactionKind::Reject) => Err(Error::Abrupt(Completion::Throw(argument))),
+        };
+
+        let capability = match reaction.capability {
+            Some(capability) => capability,
+            None => return result.map(|_| ()),
+        };
+        match result {
+            Ok(value) => self.call(&capability.resolve, &Value::Undefined, &[value])?,
+            Err(error) => {
+                let exception = self.to_exception(error)?;
+                self.call(&capability.reject, &Value::Undefined, &[exception])?
+            },
+        };
+        Ok(())
+    }
+
+    fn promise_resolve_thenable_job(&self, promise: &ObjectRef, thenable: Value, then: Value) -> Result<()> {
+        let (resolve, reject) = self.create_resolving_functions(promise);
+        if let Err(error) = self.call(&then, &thenable, &[resolve, reject.clone()]) {
+            let exception = self.to_exception(error)?;
+            self.call(&reject, &Value::Undefined, &[exception])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,463 @@
+use crate::prelude::*;
+
+use crate::interpreter::callable::Callable;
+use crate::interpreter::conversions::{PreferredType, TypeConversion};
+use crate::interpreter::proxies::ProxyOperations;
+
+pub trait PropertyAccessor {
+    /**
+     * Reads a property of any value, e.g. `obj.x` or `'abc'.length`.
+     */
+    fn get_property(&self, object: &Value, key: impl Into<PropertyKey>) -> Result<Value>;
+
+    /**
+     * Reads a property of an object, walking up its prototype chain.
+     * Getters are invoked with `receiver` as their `this` value.
+     */
+    fn get_object_property(&self, object: &ObjectRef, key: impl Into<PropertyKey>, receiver: &Value) -> Result<Value>;
+
+    /**
+     * Writes a property of any value, e.g. `obj.x = 1`. Setters found
+     * on the prototype chain are invoked instead of creating a property.
+     * Returns false if the write failed, e.g. as the property is read-only.
+     */
+    fn set_property(&self, object: &Value, key: impl Into<PropertyKey>, value: Value) -> Result<bool>;
+
+    /**
+     * Writes a property of an object, walking up its prototype chain. Setters are invoked
+     * with `receiver` as their `this` value, which otherwise gets the property.
+     */
+    fn set_object_property(&self, object: &ObjectRef, key: impl Into<PropertyKey>, value: Value, receiver: &Value) -> Result<bool>;
+
+    /**
+     * Writes a property for an assignment, failed writes throwing in strict mode code
+     * & being silently ignored otherwise.
+     */
+    fn assign_property(&self, object: &Value, key: impl Into<PropertyKey>, value: Value) -> Result<()>;
+
+    /**
+     * Converts a value into a property key, e.g. `arr[0]` reads key '0'.
+     * Symbols are used as they are.
+     */
+    fn to_property_key(&self, value: &Value) -> Result<PropertyKey>;
+
+    /*
+     * The internal methods of objects, which proxies forward to the traps of their handler.
+     * Reading & writing properties goes through `get_object_property` & `set_object_property`.
+     */
+
+    fn get_prototype_of(&self, object: &ObjectRef) -> Result<Option<ObjectRef>>;
+
+    /**
+     * Returns false if the object isn't extensible or if the chain would become cyclic.
+     */
+    fn set_prototype_of(&self, object: &ObjectRef, prototype: Option<ObjectRef>) -> Result<bool>;
+
+    fn is_extensible(&self, object: &ObjectRef) -> Result<bool>;
+
+    /**
+     * Returns whether the object is no longer extensible.
+     */
+    fn prevent_extensions(&self, object: &ObjectRef) -> Result<bool>;
+
+    fn get_own_property(&self, object: &ObjectRef, key: &PropertyKey) -> Result<Option<PropertyDescriptor>>;
+
+    /**
+     * Returns false if the property can't be defined, e.g. as it's non-configurable.
+     */
+    fn define_own_property(&self, object: &ObjectRef, key: &PropertyKey, descriptor: PropertyDescriptor) -> Result<bool>;
+
+    /**
+     * Whether the property exists on the object or its prototype chain.
+     */
+    fn has_property(&self, object: &ObjectRef, key: &PropertyKey) -> Result<bool>;
+
+    /**
+     * Returns false if the property can't be deleted, i.e. as it's non-configurable.
+     */
+    fn delete_property(&self, object: &ObjectRef, key: &PropertyKey) -> Result<bool>;
+
+    fn own_property_keys(&self, object: &ObjectRef) -> Result<Vec<PropertyKey>>;
+}
+
+impl <'a> PropertyAccessor for Interpreter<'a> {
+    fn get_property(&self, object: &Value, key: impl Into<PropertyKey>) -> Result<Value> {
+        let key = key.into();
+        if let PropertyKey::Private(_) = key {
+            return self.get_private_member(object, &key);
+        }
+        if let (Value::String(string), Some(key)) = (object, key.as_string()) {
+            if key == "length" {
+                return Ok(Value::Number(string.length() as f64));
+            }
+            if let Some(unit) = key.parse::<usize>().ok().and_then(|index| string.unit_at(index)) {
+                return Ok(Value::String(unit));
+            }
+        }
+        match (object, key.as_string()) {
+            (Value::Object(target), _) => self.get_object_property(target, key, object),
+            (Value::Undefined | Value::Null, _) => Err(Error::Type(
+                format!("Cannot read properties of {object} (reading '{key}')!")
+            )),
+
+            // Primitives inherit the methods of their wrapper's prototype, e.g. `sym.description`
+            _ => match self.primitive_prototype(object) {
+                Some(prototype) => self.get_object_property(&prototype, key, object),
+                None => Ok(Value::Undefined),
+            },
+        }
+    }
+
+    fn get_object_property(&self, object: &ObjectRef, key: impl Into<PropertyKey>, receiver: &Value) -> Result<Value> {
+        let key = key.into();
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            if let Some(proxy) = object.proxy() {
+                return self.proxy_get(&proxy, &key, receiver);
+            }
+            match object.get_own_property(&key) {
+                Some(Property::Data(value)) => return Ok(value),
+                Some(Property::Accessor { get, .. }) => return match get {
+                    None => Ok(Value::Undefined),
+                    Some(getter) => self.call(&getter, receiver, &[]),
+                },
+                None => current = object.prototype(),
+            }
+        }
+        Ok(Value::Undefined)
+    }
+
+    fn set_property(&self, object: &Value, key: impl Into<PropertyKey>, value: Value) -> Result<bool> {
+        let key = key.into();
+        if let PropertyKey::Private(_) = key {
+            return self.set_private_member(object, &key, value).map(|_| true);
+        }
+        match object {
+            Value::Object(target) => self.set_object_property(target, key, value, object),
+            Value::Undefined | Value::Null => Err(Error::Type(
+                format!("Cannot set properties of {object} (setting '{key}')!")
+            )),
+
+            // Primitives can't hold properties of their own
+            _ => Ok(false),
+        }
+    }
+
+    fn set_object_property(&self, object: &ObjectRef, key: impl Into<PropertyKey>, value: Value, receiver: &Value) -> Result<bool> {
+        let key = key.into();
+
+        // Looking for a setter or a read-only property up the prototype chain
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            if let Some(proxy) = object.proxy() {
+                return self.proxy_set(&proxy, &key, value, receiver);
+            }
+            match object.get_own_property(&key) {
+                Some(Property::Data(_)) if !object.is_writable(&key) => return Ok(false),
+                Some(Property::Data(_)) => break,
+                Some(Property::Accessor { set, .. }) => return match set {
+                    None => Ok(false),
+                    Some(setter) => self.call(&setter, receiver, &[value]).map(|_| true),
+                },
+                None => current = object.prototype(),
+            }
+        }
+
+        // The receiver's own property is updated, or created if it doesn't exist yet
+        let receiver = match receiver {
+            Value::Object(receiver) => receiver,
+            _ => return Ok(false),
+        };
+        let descriptor = match self.get_own_property(receiver, &key)? {
+            Some(existing) if existing.is_accessor() || existing.writable == Some(false) => return Ok(false),
+            Some(_) => PropertyDescriptor { value: Some(value), ..PropertyDescriptor::default() },
+            None => PropertyDescriptor::data(value),
+        };
+        self.define_own_property(receiver, &key, descriptor)
+    }
+
+    fn assign_property(&self, object: &Value, key: impl Into<PropertyKey>, value: Value) -> Result<()> {
+        let key = key.into();
+        if self.set_property(object, &key, value)? || !self.is_strict() {
+            return Ok(());
+        }
+        Err(assignment_error(object, &key))
+    }
+
+    fn to_property_key(&self, value: &Value) -> Result<PropertyKey> {
+        match self.to_primitive(value, PreferredType::String)? {
+            Value::Symbol(symbol) => Ok(PropertyKey::Symbol(symbol)),
+            key => Ok(PropertyKey::String(key.to_string())),
+        }
+    }
+
+    fn get_prototype_of(&self, object: &ObjectRef) -> Result<Option<ObjectRef>> {
+        match object.proxy() {
+            Some(proxy) => self.proxy_get_prototype_of(&proxy),
+            None => Ok(object.prototype()),
+        }
+    }
+
+    fn set_prototype_of(&self, object: &ObjectRef, prototype: Option<ObjectRef>) -> Result<bool> {
+        match object.proxy() {
+            Some(proxy) => self.proxy_set_prototype_of(&proxy, prototype),
+            None => Ok(object.set_prototype(prototype)),
+        }
+    }
+
+    fn is_extensible(&self, object: &ObjectRef) -> Result<bool> {
+        match object.proxy() {
+            Some(proxy) => self.proxy_is_extensible(&proxy),
+            None => Ok(object.is_extensible()),
+        }
+    }
+
+    fn prevent_extensions(&self, object: &ObjectRef) -> Result<bool> {
+        match object.proxy() {
+            Some(proxy) => self.proxy_prevent_extensions(&proxy),
+            None => {
+                object.prevent_extensions();
+                Ok(true)
+            },
+        }
+    }
+
+    fn get_own_property(&self, object: &ObjectRef, key: &PropertyKey) -> Result<Option<PropertyDescriptor>> {
+        match object.proxy() {
+            Some(proxy) => self.proxy_get_own_property(&proxy, key),
+            None => Ok(object.get_own_property_descriptor(key)),
+        }
+    }
+
+    fn define_own_property(&self, object: &ObjectRef, key: &PropertyKey, mut descriptor: PropertyDescriptor) -> Result<bool> {
+        if let Some(proxy) = object.proxy() {
+            return self.proxy_define_own_property(&proxy, key, descriptor);
+        }
+
+        // Array lengths are unsigned 32-bit integers, e.g. `a.length = -1` throws
+        if let (true, Some("length"), Some(value)) = (object.is_array(), key.as_string(), &descriptor.value) {
+            let length = self.to_number(value)?;
+            if length.trunc() != length || !(0.0..=u32::MAX as f64).contains(&length) {
+                return Err(Error::Range("Invalid array length!".to_string()));
+            }
+            descriptor.value = Some(Value::Number(length));
+        }
+        Ok(object.define_own_property(key, descriptor))
+    }
+
+    fn has_property(&self, object: &ObjectRef, key: &PropertyKey) -> Result<bool> {
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            if let Some(proxy) = object.proxy() {
+                return self.proxy_has(&proxy, key);
+            }
+            if object.get_own_property(key).is_some() {
+                return Ok(true);
+            }
+            current = object.prototype();
+        }
+        Ok(false)
+    }
+
+    fn delete_property(&self, object: &ObjectRef, key: &PropertyKey) -> Result<bool> {
+        match object.proxy() {
+            Some(proxy) => self.proxy_delete_property(&proxy, key),
+            None => Ok(object.delete_property(key)),
+        }
+    }
+
+    fn own_property_keys(&self, object: &ObjectRef) -> Result<Vec<PropertyKey>> {
+        match object.proxy() {
+            Some(proxy) => self.proxy_own_keys(&proxy),
+            None => Ok(object.own_property_keys()),
+        }
+    }
+}
+
+/**
+ * Error thrown when setting the property failed where it must not, e.g. in strict mode code.
+ */
+pub fn assignment_error(object: &Value, key: &PropertyKey) -> Error {
+    Error::Type(match object {
+        Value::Object(target) => match target.get_own_property(key) {
+            Some(Property::Accessor { .. }) => format!("Cannot set property {key} of {object} which has only a getter!"),
+            None if !target.is_extensible() => format!("Cannot add property {key}, object is not extensible!"),
+            _ => format!("Cannot assign to read only property '{key}' of object '{object}'!"),
+        },
+        _ => format!("Cannot create property '{key}' on {object}!"),
+    })
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Whether the code being run is strict mode code, i.e. the body of a class
+     * or a script or function beginning with a 'use strict' directive.
+     */
+    pub(crate) fn is_strict(&self) -> bool {
+        self.strict.get()
+    }
+
+    /**
+     * Runs code as strict or sloppy mode code, restoring the mode of the code running it afterwards.
+     */
+    pub(crate) fn run_in_mode<T>(&self, strict: bool, run: impl FnOnce() -> T) -> T {
+        let enclosing = self.strict.replace(strict);
+        let result = run();
+        self.strict.set(enclosing);
+        result
+    }
+
+    /**
+     * Reads a private member, which only objects initialized by the declaring class have -> obj.#x
+     * Unlike properties, private members are never looked up through the prototype chain.
+     */
+    fn get_private_member(&self, object: &Value, key: &PropertyKey) -> Result<Value> {
+        let member = match object {
+            Value::Object(target) => target.get_own_property(key),
+            _ => None,
+        };
+        match member {
+            Some(Property::Data(value)) => Ok(value),
+            Some(Property::Accessor { get: Some(getter), .. }) => self.call(&getter, object, &[]),
+            Some(Property::Accessor { get: None, .. }) => Err(Error::Type(format!("'{key}' was defined without a getter!"))),
+            None => Err(Error::Type(format!("Cannot read private member {key} from an object whose class did not declare it!"))),
+        }
+    }
+
+    /**
+     * Writes a private member -> obj.#x = 1
+     * Failed writes always throw, as private methods & getters can only be misused by the class itself.
+     */
+    fn set_private_member(&self, object: &Value, key: &PropertyKey, value: Value) -> Result<()> {
+        let target = match object {
+            Value::Object(target) if target.get_own_property(key).is_some() => target,
+            _ => return Err(Error::Type(format!("Cannot write private member {key} to an object whose class did not declare it!"))),
+        };
+        match target.get_own_property(key) {
+            Some(Property::Data(_)) if !target.is_writable(key) => Err(Error::Type(format!("Private method '{key}' is not writable!"))),
+            Some(Property::Accessor { set: Some(setter), .. }) => self.call(&setter, object, &[value]).map(|_| ()),
+            Some(Property::Accessor { set: None, .. }) => Err(Error::Type(format!("'{key}' was defined without a setter!"))),
+            _ => {
+                target.set_own_value(key, value);
+                Ok(())
+            },
+        }
+    }
+
+    /**
+     * Returns the prototype whose properties a primitive value exposes, if any.
+     */
+    pub(crate) fn primitive_prototype(&self, value: &Value) -> Option<ObjectRef> {
+        match value {
+            Value::Boolean(_) => self.realm.intrinsic("%Boolean.prototype%"),
+            Value::Number(_) => self.realm.intrinsic("%Number.prototype%"),
+            Value::String(_) => self.realm.intrinsic("%String.prototype%"),
+            Value::BigInt(_) => self.realm.intrinsic("%BigInt.prototype%"),
+            Value::Symbol(_) => self.realm.intrinsic("%Symbol.prototype%"),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::interpreter::tests::*;
+
+    #[test]
+    fn test_string_properties() {
+        assert_eval("'hello'.length;", Value::Number(5.0));
+        assert_eval("'hello'[1];", Value::String("e".into()));
+        assert_eval("'hello'[10];", Value::Undefined);
+        assert_eval("'😀!'.length;", Value::Number(3.0));
+    }
+
+    #[test]
+    fn test_array_length_assignment() {
+        assert_eval("let a = [1, 2, 3]; a.length = 1; a.length;", Value::Number(1.0));
+        assert_eval("let a = [1]; a[3] = 4; a.length;", Value::Number(4.0));
+        assert_eval("let a = [1, 2]; a.length = '3'; a.length;", Value::Number(3.0));
+        assert_eval("let a = [1]; a[4294967295] = 2; a.length;", Value::Number(1.0));
+
+        let error = || Error::Range("Invalid array length!".to_string());
+        assert_eval_error("let a = [1]; a.length = -1;", error());
+        assert_eval_error("let a = [1]; a.length = 1.5;", error());
+        assert_eval_error("let a = [1]; let d = new Object(); d.value = 4294967296; Object.defineProperty(a, 'length', d);", error());
+    }
+
+    #[test]
+    fn test_array_length_attributes() {
+        let content = "
+            let sealed = Object.seal([1, 2, 3]);
+            sealed.length = 1;
+            let refused = Reflect.set(sealed, 'length', 0);
+            let fixed = [1, 2];
+            Object.defineProperty(fixed, 'length', JSON.parse('{\"writable\":false}'));
+            fixed[5] = 1;
+            let pinned = [1, 2, 3, 4];
+            Object.defineProperty(pinned, '1', JSON.parse('{\"configurable\":false}'));
+            let shrunk = Reflect.set(pinned, 'length', 0);
+            '' + [
+                sealed.length, sealed[2], refused, fixed.length, fixed[5], Reflect.set(fixed, 'length', 2),
+                Reflect.set(fixed, 'length', 3), shrunk, pinned.length, pinned.join('|')
+            ];
+        ";
+        assert_eval(content, Value::String("3,3,false,2,,false,false,false,2,1|2".into()));
+        assert_eval_error(
+            "'use strict'; let a = Object.freeze([1]); a.length = 0;",
+            Error::Type("Cannot assign to read only property 'length' of object '1'!".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_array_element_descriptors() {
+        let content = "
+            let a = [1, 2, 3];
+            let getter = new Object();
+            getter.get = function() { return 7; };
+            Object.defineProperty(a, '0', getter);
+            let descriptor = Object.getOwnPropertyDescriptor(a, '0');
+            Object.defineProperty(a, '5', getter);
+            let hole = [1, 2, 3];
+            Reflect.deleteProperty(hole, '1');
+            hole[1] = 'x';
+            let gap = [1, 2, 3];
+            Reflect.deleteProperty(gap, '1');
+            '' + [
+                a[0], a[1], descriptor.get === getter.get, a.length, a.join('|'), hole.join('|'),
+                gap.hasOwnProperty(1), gap.hasOwnProperty(2), Object.keys(gap).join('|')
+            ];
+        ";
+        assert_eval(content, Value::String("7,2,true,6,7|2|3|||7,1|x|3,false,true,0|2".into()));
+    }
+
+    #[test]
+    fn test_integer_keys_order() {
+        let content = "
+            let o = new Object();
+            o.b = 1;
+            o.a = 2;
+            o[10] = 3;
+            o[1] = 4;
+            o['01'] = 5;
+            '' + [Object.keys(o).join('|'), JSON.stringify(o)];
+        ";
+        assert_eval(content, Value::String(r#"1|10|b|a|01,{"1":4,"10":3,"b":1,"a":2,"01":5}"#.into()));
+    }
+
+    #[test]
+    fn test_sparse_array_elements() {
+        assert_eval("let a = []; a[4294967294] = 1; a.length;", Value::Number(4294967295.0));
+        assert_eval("let a = [1]; a[1000000000] = 2; Object.keys(a).join();", Value::String("0,1000000000".into()));
+        assert_eval("let a = [1]; a[3] = 4; Object.keys(a).join();", Value::String("0,3".into()));
+        assert_eval("let a = [1]; a[3] = 4; a[2] = 3; a[1] = 2; a.join();", Value::String("1,2,3,4".into()));
+        assert_eval("let a = [1]; a[1000000000] = 2; a.length = 2; a[1000000000];", Value::Undefined);
+        assert_eval("let a = new Array(4294967295); a[0] = 1; a.length;", Value::Number(4294967295.0));
+    }
+
+    #[test]
+    fn test_read_property_of_undefined() {
+        let error = Error::Type("Cannot read properties of undefined (reading 'x')!".to_string());
+        assert_eval_error("let o; o.x;", error);
+    }
+}
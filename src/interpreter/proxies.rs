@@ -0,0 +1,338 @@
+use crate::prelude::*;
+
+use crate::interpreter::builtins::array::{create_array, list_from_array_like};
+use crate::interpreter::builtins::object::{from_property_descriptor, to_property_descriptor};
+use crate::interpreter::callable::Callable;
+use crate::interpreter::properties::PropertyAccessor;
+
+/**
+ * Internal methods of proxy objects. Each one calls the trap of the same name on
+ * the handler, checking the result is consistent with the target, e.g. that a
+ * non-configurable property isn't reported missing. Missing traps forward the
+ * operation to the target.
+ */
+pub trait ProxyOperations {
+    fn proxy_get_prototype_of(&self, proxy: &ProxyState) -> Result<Option<ObjectRef>>;
+    fn proxy_set_prototype_of(&self, proxy: &ProxyState, prototype: Option<ObjectRef>) -> Result<bool>;
+    fn proxy_is_extensible(&self, proxy: &ProxyState) -> Result<bool>;
+    fn proxy_prevent_extensions(&self, proxy: &ProxyState) -> Result<bool>;
+    fn proxy_get_own_property(&self, proxy: &ProxyState, key: &PropertyKey) -> Result<Option<PropertyDescriptor>>;
+    fn proxy_define_own_property(&self, proxy: &ProxyState, key: &PropertyKey, descriptor: PropertyDescriptor) -> Result<bool>;
+    fn proxy_has(&self, proxy: &ProxyState, key: &PropertyKey) -> Result<bool>;
+    fn proxy_get(&self, proxy: &ProxyState, key: &PropertyKey, receiver: &Value) -> Result<Value>;
+    fn proxy_set(&self, proxy: &ProxyState, key: &PropertyKey, value: Value, receiver: &Value) -> Result<bool>;
+    fn proxy_delete_property(&self, proxy: &ProxyState, key: &PropertyKey) -> Result<bool>;
+    fn proxy_own_keys(&self, proxy: &ProxyState) -> Result<Vec<PropertyKey>>;
+    fn proxy_call(&self, proxy: &ProxyState, this: &Value, args: &[Value]) -> Result<Value>;
+    fn proxy_construct(&self, proxy: &ProxyState, args: &[Value], new_target: &ObjectRef) -> Result<Value>;
+}
+
+impl <'a> ProxyOperations for Interpreter<'a> {
+    fn proxy_get_prototype_of(&self, proxy: &ProxyState) -> Result<Option<ObjectRef>> {
+        let (handler, trap) = match self.proxy_trap(proxy, "getPrototypeOf")? {
+            Some(trap) => trap,
+            None => return self.get_prototype_of(&proxy.target),
+        };
+        let prototype = match self.call(&trap, &handler, &[Value::Object(proxy.target.clone())])? {
+            Value::Object(prototype) => Some(prototype),
+            Value::Null => None,
+            _ => return Err(Error::Type("'getPrototypeOf' on proxy: trap returned neither object nor null!".to_string())),
+        };
+        if !self.is_extensible(&proxy.target)? && prototype != self.get_prototype_of(&proxy.target)? {
+            return Err(Error::Type(
+                "'getPrototypeOf' on proxy: proxy target is non-extensible but the trap did not return its actual prototype!".to_string()
+            ));
+        }
+        Ok(prototype)
+    }
+
+    fn proxy_set_prototype_of(&self, proxy: &ProxyState, prototype: Option<ObjectRef>) -> Result<bool> {
+        let (handler, trap) = match self.proxy_trap(proxy, "setPrototypeOf")? {
+            Some(trap) => trap,
+            None => return self.set_prototype_of(&proxy.target, prototype),
+        };
+        let value = prototype.clone().map(Value::Object).unwrap_or(Value::Null);
+        if !self.call(&trap, &handler, &[Value::Object(proxy.target.clone()), value])?.to_boolean() {
+            return Ok(false);
+        }
+        if !self.is_extensible(&proxy.target)? && prototype != self.get_prototype_of(&proxy.target)? {
+            return Err(Error::Type(
+                "'setPrototypeOf' on proxy: trap returned truish for setting a new prototype on the non-extensible proxy target!".to_string()
+            ));
+        }
+        Ok(true)
+    }
+
+    fn proxy_is_extensible(&self, proxy: &ProxyState) -> Result<bool> {
+        let (handler, trap) = match self.proxy_trap(proxy, "isExtensible")? {
+            Some(trap) => trap,
+            None => return self.is_extensible(&proxy.target),
+        };
+        let extensible = self.call(&trap, &handler, &[Value::Object(proxy.target.clone())])?.to_boolean();
+        let expected = self.is_extensible(&proxy.target)?;
+        if extensible != expected {
+            return Err(Error::Type(format!(
+                "'isExtensible' on proxy: trap result does not reflect extensibility of proxy target (which is '{expected}')!"
+            )));
+        }
+        Ok(extensible)
+    }
+
+    fn proxy_prevent_extensions(&self, proxy: &ProxyState) -> Result<bool> {
+        let (handler, trap) = match self.proxy_trap(proxy, "preventExtensions")? {
+            Some(trap) => trap,
+            None => return self.prevent_extensions(&proxy.target),
+        };
+        let prevented = self.call(&trap, &handler, &[Value::Object(proxy.target.clone())])?.to_boolean();
+        if prevented && self.is_extensible(&proxy.target)? {
+            return Err(Error::Type("'preventExtensions' on proxy: trap returned truish but the proxy target is extensible!".to_string()));
+        }
+        Ok(prevented)
+    }
+
+    fn proxy_get_own_property(&self, proxy: &ProxyState, key: &PropertyKey) -> Result<Option<PropertyDescriptor>> {
+        let (handler, trap) = match self.proxy_trap(proxy, "getOwnPropertyDescriptor")? {
+            Some(trap) => trap,
+            None => return self.get_own_property(&proxy.target, key),
+        };
+        let result = self.call(&trap, &handler, &[Value::Object(proxy.target.clone()), key.to_value()])?;
+        let target_descriptor = self.get_own_property(&proxy.target, key)?;
+        let reported = match result {
+            Value::Undefined => None,
+            Value::Object(_) => Some(to_property_descriptor(self, &result)?),
+            _ => return Err(Error::Type(format!(
+                "'getOwnPropertyDescriptor' on proxy: trap returned neither object nor undefined for property '{key}'!"
+            ))),
+        };
+
+        let mut descriptor = match reported {
+            Some(descriptor) => descriptor,
+            None => {
+                match &target_descriptor {
+                    Some(target) if target.configurable == Some(false) => return Err(Error::Type(format!(
+                        "'getOwnPropertyDescriptor' on proxy: trap returned undefined for property '{key}' which is non-configurable in the proxy target!"
+                    ))),
+                    Some(_) if !self.is_extensible(&proxy.target)? => return Err(Error::Type(format!(
+                        "'getOwnPropertyDescriptor' on proxy: trap returned undefined for property '{key}' which exists in the non-extensible proxy target!"
+                    ))),
+                    _ => return Ok(None),
+                }
+            },
+        };
+
+        // Reported descriptors are completed with the defaults of new properties
+        if descriptor.is_accessor() {
+            descriptor.get.get_or_insert(Value::Undefined);
+            descriptor.set.get_or_insert(Value::Undefined);
+        } else {
+            descriptor.value.get_or_insert(Value::Undefined);
+            descriptor.writable.get_or_insert(false);
+        }
+        descriptor.enumerable.get_or_insert(false);
+        descriptor.configurable.get_or_insert(false);
+
+        if target_descriptor.is_none() && !self.is_extensible(&proxy.target)? {
+            return Err(Error::Type(format!(
+                "'getOwnPropertyDescriptor' on proxy: trap returned descriptor for property '{key}' that is incompatible with the existing property in the proxy target!"
+            )));
+        }
+        if descriptor.configurable == Some(false) && target_descriptor.is_none_or(|target| target.configurable == Some(true)) {
+            return Err(Error::Type(format!(
+                "'getOwnPropertyDescriptor' on proxy: trap reported non-configurability for property '{key}' which is either non-existent or configurable in the proxy target!"
+            )));
+        }
+        Ok(Some(descriptor))
+    }
+
+    fn proxy_define_own_property(&self, proxy: &ProxyState, key: &PropertyKey, descriptor: PropertyDescriptor) -> Result<bool> {
+        let (handler, trap) = match self.proxy_trap(proxy, "defineProperty")? {
+            Some(trap) => trap,
+            None => return self.define_own_property(&proxy.target, key, descriptor),
+        };
+        let non_configurable = descriptor.configurable == Some(false);
+        let args = [Value::Object(proxy.target.clone()), key.to_value(), from_property_descriptor(self, descriptor)];
+        if !self.call(&trap, &handler, &args)?.to_boolean() {
+            return Ok(false);
+        }
+
+        match self.get_own_property(&proxy.target, key)? {
+            None if !self.is_extensible(&proxy.target)? => Err(Error::Type(format!(
+                "'defineProperty' on proxy: trap returned truish for adding property '{key}' to the non-extensible proxy target!"
+            ))),
+            None if non_configurable => Err(Error::Type(format!(
+                "'defineProperty' on proxy: trap returned truish for defining non-configurable property '{key}' which is either non-existent or configurable in the proxy target!"
+            ))),
+            Some(target) if non_configurable && target.configurable == Some(true) => Err(Error::Type(format!(
+                "'defineProperty' on proxy: trap returned truish for defining non-configurable property '{key}' which is either non-existent or configurable in the proxy target!"
+            ))),
+            _ => Ok(true),
+        }
+    }
+
+    fn proxy_has(&self, proxy: &ProxyState, key: &PropertyKey) -> Result<bool> {
+        let (handler, trap) = match self.proxy_trap(proxy, "has")? {
+            Some(trap) => trap,
+            None => return self.has_property(&proxy.target, key),
+        };
+        if self.call(&trap, &handler, &[Value::Object(proxy.target.clone()), key.to_value()])?.to_boolean() {
+            return Ok(true);
+        }
+
+        match self.get_own_property(&proxy.target, key)? {
+            Some(target) if target.configurable == Some(false) => Err(Error::Type(format!(
+                "'has' on proxy: trap returned falsish for property '{key}' which exists in the proxy target as non-configurable!"
+            ))),
+            Some(_) if !self.is_extensible(&proxy.target)? => Err(Error::Type(format!(
+                "'has' on proxy: trap returned falsish for property '{key}' but the proxy target is not extensible!"
+            ))),
+            _ => Ok(false),
+        }
+    }
+
+    fn proxy_get(&self, proxy: &ProxyState, key: &PropertyKey, receiver: &Value) -> Result<Value> {
+        let (handler, trap) = match self.proxy_trap(proxy, "get")? {
+            Some(trap) => trap,
+            None => return self.get_object_property(&proxy.target, key, receiver),
+        };
+        let value = self.call(&trap, &handler, &[Value::Object(proxy.target.clone()), key.to_value(), receiver.clone()])?;
+
+        // Non-configurable properties of the target must be reported as they are
+        if let Some(target) = self.get_own_property(&proxy.target, key)?.filter(|target| target.configurable == Some(false)) {
+            if let (Some(expected), Some(false)) = (&target.value, target.writable) {
+                if !value.same_value(expected) {
+                    return Err(Error::Type(format!(
+                        "'get' on proxy: property '{key}' is a read-only and non-configurable data property on the proxy target but the proxy did not return its actual value (expected '{expected}' but got '{value}')!"
+                    )));
+                }
+            }
+            if target.is_accessor() && matches!(target.get, Some(Value::Undefined)) && !matches!(value, Value::Undefined) {
+                return Err(Error::Type(format!(
+                    "'get' on proxy: property '{key}' is a non-configurable accessor property on the proxy target and does not have a getter function, but the trap did not return 'undefined' (got '{value}')!"
+                )));
+            }
+        }
+        Ok(value)
+    }
+
+    fn proxy_set(&self, proxy: &ProxyState, key: &PropertyKey, value: Value, receiver: &Value) -> Result<bool> {
+        let (handler, trap) = match self.proxy_trap(proxy, "set")? {
+            Some(trap) => trap,
+            None => return self.set_object_property(&proxy.target, key, value, receiver),
+        };
+        let args = [Value::Object(proxy.target.clone()), key.to_value(), value.clone(), receiver.clone()];
+        if !self.call(&trap, &handler, &args)?.to_boolean() {
+            return Ok(false);
+        }
+
+        if let Some(target) = self.get_own_property(&proxy.target, key)?.filter(|target| target.configurable == Some(false)) {
+            if let (Some(expected), Some(false)) = (&target.value, target.writable) {
+                if !value.same_value(expected) {
+                    return Err(Error::Type(format!(
+                        "'set' on proxy: trap returned truish for property '{key}' which exists in the proxy target as a non-configurable and non-writable data property with a different value!"
+                    )));
+                }
+            }
+            if target.is_accessor() && matches!(target.set, Some(Value::Undefined)) {
+                return Err(Error::Type(format!(
+                    "'set' on proxy: trap returned truish for property '{key}' which exists in the proxy target as a non-configurable and non-writable accessor property without a setter!"
+                )));
+            }
+        }
+        Ok(true)
+    }
+
+    fn proxy_delete_property(&self, proxy: &ProxyState, key: &PropertyKey) -> Result<bool> {
+        let (handler, trap) = match self.proxy_trap(proxy, "deleteProperty")? {
+            Some(trap) => trap,
+            None => return self.delete_property(&proxy.target, key),
+        };
+        if !self.call(&trap, &handler, &[Value::Object(proxy.target.clone()), key.to_value()])?.to_boolean() {
+            return Ok(false);
+        }
+
+        match self.get_own_property(&proxy.target, key)? {
+            Some(target) if target.configurable == Some(false) => Err(Error::Type(format!(
+                "'deleteProperty' on proxy: trap returned truish for property '{key}' which is non-configurable in the proxy target!"
+            ))),
+            Some(_) if !self.is_extensible(&proxy.target)? => Err(Error::Type(format!(
+                "'deleteProperty' on proxy: trap returned truish for property '{key}' but the proxy target is non-extensible!"
+            ))),
+            _ => Ok(true),
+        }
+    }
+
+    fn proxy_own_keys(&self, proxy: &ProxyState) -> Result<Vec<PropertyKey>> {
+        let (handler, trap) = match self.proxy_trap(proxy, "ownKeys")? {
+            Some(trap) => trap,
+            None => return self.own_property_keys(&proxy.target),
+        };
+        let result = self.call(&trap, &handler, &[Value::Object(proxy.target.clone())])?;
+        let mut keys: Vec<PropertyKey> = vec![];
+        for element in list_from_array_like(self, &result)? {
+            let key = match element {
+                Value::String(key) => PropertyKey::String(key.to_string()),
+                Value::Symbol(symbol) => PropertyKey::Symbol(symbol),
+                _ => return Err(Error::Type(format!("{element} is not a valid property name!"))),
+            };
+            if keys.contains(&key) {
+                return Err(Error::Type("'ownKeys' on proxy: trap returned duplicate entries!".to_string()));
+            }
+            keys.push(key);
+        }
+
+        // Non-configurable keys can't be hidden, nor can any key of a non-extensible target
+        let extensible = self.is_extensible(&proxy.target)?;
+        let target_keys = self.own_property_keys(&proxy.target)?;
+        for key in &target_keys {
+            let configurable = self.get_own_property(&proxy.target, key)?.is_none_or(|target| target.configurable != Some(false));
+            if !keys.contains(key) && (!configurable || !extensible) {
+                return Err(Error::Type(format!("'ownKeys' on proxy: trap result did not include '{key}'!")));
+            }
+        }
+        if !extensible && keys.len() != target_keys.len() {
+            return Err(Error::Type("'ownKeys' on proxy: trap returned extra keys but proxy target is non-extensible!".to_string()));
+        }
+        Ok(keys)
+    }
+
+    fn proxy_call(&self, proxy: &ProxyState, this: &Value, args: &[Value]) -> Result<Value> {
+        let (handler, trap) = match self.proxy_trap(proxy, "apply")? {
+            Some(trap) => trap,
+            None => return self.call(&Value::Object(proxy.target.clone()), this, args),
+        };
+        let args = Value::Object(create_array(self, args.to_vec()));
+        self.call(&trap, &handler, &[Value::Object(proxy.target.clone()), this.clone(), args])
+    }
+
+    fn proxy_construct(&self, proxy: &ProxyState, args: &[Value], new_target: &ObjectRef) -> Result<Value> {
+        let (handler, trap) = match self.proxy_trap(proxy, "construct")? {
+            Some(trap) => trap,
+            None => return self.construct(&Value::Object(proxy.target.clone()), args, new_target),
+        };
+        let args = Value::Object(create_array(self, args.to_vec()));
+        match self.call(&trap, &handler, &[Value::Object(proxy.target.clone()), args, Value::Object(new_target.clone())])? {
+            result @ Value::Object(_) => Ok(result),
+            result => Err(Error::Type(format!("'construct' on proxy: trap returned non-object ('{result}')!"))),
+        }
+    }
+}
+
+impl <'a> Interpreter<'a> {
+    /**
+     * Returns the handler of the proxy along with its trap of the name, None if the
+     * handler doesn't define it. Revoked proxies throw as they no longer have a handler.
+     */
+    fn proxy_trap(&self, proxy: &ProxyState, name: &str) -> Result<Option<(Value, Value)>> {
+        // Missing traps forward to the target, which may itself be a proxy nested arbitrarily deep
+        self.check_call_depth()?;
+        let handler = match &proxy.handler {
+            Some(handler) => Value::Object(handler.clone()),
+            None => return Err(Error::Type(format!("Cannot perform '{name}' on a proxy that has been revoked!"))),
+        };
+        match self.get_property(&handler, name)? {
+            Value::Undefined | Value::Null => Ok(None),
+            trap if trap.is_callable() => Ok(Some((handler, trap))),
+            trap => Err(Error::Type(format!("'{name}' on proxy: trap {trap} is not a function!"))),
+        }
+    }
+}
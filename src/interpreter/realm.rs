@@ -0,0 +1,104 @@
+use crate::prelude::*;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/**
+ * Intrinsic objects shared by all the code run by an interpreter,
+ * e.g. the prototypes of built-in errors. Internal failures use them
+ * to create error objects even if scripts shadow the global bindings.
+ * Clones share the same intrinsic objects.
+ */
+#[derive(Clone, Default)]
+pub struct Realm {
+    intrinsics: HashMap<String, ObjectRef>,
+    pub symbols: WellKnownSymbols,
+
+    // Symbols shared by key through `Symbol.for`
+    registry: Rc<RefCell<HashMap<String, Symbol>>>,
+}
+
+/**
+ * Symbols the language operations look up on objects, e.g. `Symbol.iterator`.
+ */
+#[derive(Clone)]
+pub struct WellKnownSymbols {
+    pub has_instance: Symbol,
+    pub iterator: Symbol,
+    pub r#match: Symbol,
+    pub match_all: Symbol,
+    pub replace: Symbol,
+    pub search: Symbol,
+    pub split: Symbol,
+    pub to_primitive: Symbol,
+    pub to_string_tag: Symbol,
+}
+
+impl Default for WellKnownSymbols {
+    fn default() -> Self {
+        let symbol = |name: &str| Symbol::new(Some(format!("Symbol.{name}")));
+        Self {
+            has_instance: symbol("hasInstance"),
+            iterator: symbol("iterator"),
+            r#match: symbol("match"),
+            match_all: symbol("matchAll"),
+            replace: symbol("replace"),
+            search: symbol("search"),
+            split: symbol("split"),
+            to_primitive: symbol("toPrimitive"),
+            to_string_tag: symbol("toStringTag"),
+        }
+    }
+}
+
+impl WellKnownSymbols {
+    /**
+     * Lists the symbols by the name of their `Symbol` property.
+     */
+    pub fn entries(&self) -> [(&str, &Symbol); 9] {
+        [
+            ("hasInstance", &self.has_instance),
+            ("iterator", &self.iterator),
+            ("match", &self.r#match),
+            ("matchAll", &self.match_all),
+            ("replace", &self.replace),
+            ("search", &self.search),
+            ("split", &self.split),
+            ("toPrimitive", &self.to_primitive),
+            ("toStringTag", &self.to_string_tag),
+        ]
+    }
+}
+
+impl Realm {
+    /**
+     * Returns the intrinsic object registered under the name, e.g. '%TypeError.prototype%'.
+     */
+    pub fn intrinsic(&self, name: &str) -> Option<ObjectRef> {
+        self.intrinsics.get(name).cloned()
+    }
+
+    pub fn define_intrinsic(&mut self, name: &str, object: ObjectRef) {
+        self.intrinsics.insert(name.to_string(), object);
+    }
+
+    /**
+     * Returns the symbol registered under the key, creating it if needed.
+     */
+    pub fn registered_symbol(&self, key: &str) -> Symbol {
+        let mut registry = self.registry.borrow_mut();
+        registry.entry(key.to_string())
+            .or_insert_with(|| Symbol::new(Some(key.to_string())))
+            .clone()
+    }
+
+    /**
+     * Returns the key the symbol is registered under, if any.
+     */
+    pub fn symbol_key(&self, symbol: &Symbol) -> Option<String> {
+        self.registry.borrow().iter()
+            .find(|(_, registered)| *registered == symbol)
+            .map(|(key, _)| key.clone())
+    }
+}
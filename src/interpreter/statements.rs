@@ -1,13 +1,27 @@
 use crate::prelude::*;
 
+use std::cell::RefCell;
+
+use crate::interpreter::callable::Callable;
+
 pub trait StatementEvalable {
     /**
      * Evaluates statement AST Tree nodes.
      * + ExpressionStatement
      * + VariableStatement
      * + VariableDeclaration
+     * + EmptyStatement
+     * + BlockStatement
+     * + FunctionDeclaration
+     * + ReturnStatement
      */
     fn eval_statement(&self, statement: &Tree, env_ref: &EnvRef) -> Result<Value>;
+
+    /**
+     * Evaluates a list of statements, returning the value of the last one.
+     * Function declarations are hoisted, i.e. defined before any statement runs.
+     */
+    fn eval_statement_list(&self, statements: &[Tree], env_ref: &EnvRef) -> Result<Value>;
 }
 
 impl <'a> StatementEvalable for Interpreter<'a> {
@@ -15,9 +29,16 @@ impl <'a> StatementEvalable for Interpreter<'a> {
         let depth = self.depth.get();
         match statement {
             Tree::ExpressionStatement { expression } => self.eval_tree(expression, env_ref),
-            Tree::VariableStatement { declarations } => {
+            Tree::VariableStatement { kind, declarations } => {
                 for declaration in declarations {
                     self.eval_tree(declaration, env_ref)?;
+
+                    // Constants are defined like variables, but can't be assigned afterwards
+                    if let (Tree::VariableDeclaration { identifier, .. }, "const") = (declaration, kind.as_str()) {
+                        if let Tree::Identifier { name } = identifier.as_ref() {
+                            env_ref.borrow_mut().mark_constant(name);
+                        }
+                    }
                 }
                 Ok(Value::Undefined)
             },
@@ -40,9 +61,65 @@ impl <'a> StatementEvalable for Interpreter<'a> {
                 };
                 Ok(Value::Undefined)
             },
+            Tree::EmptyStatement => Ok(Value::Undefined),
+            Tree::BlockStatement { body } => {
+                // Blocks create their own lexical scope
+                let mut block_env = env_ref.borrow().extend();
+                self.eval_statement_list(body, &RefCell::new(&mut block_env))
+            },
+            Tree::FunctionDeclaration { identifier, params, body, generator, is_async, strict, source } => {
+                if let Tree::Identifier { name } = identifier.as_ref() {
+                    let env = env_ref.borrow().clone();
+                    let function = self.create_function(Function {
+                        name: name.clone(),
+                        params: params.clone(),
+                        body: body.clone(),
+                        env,
+                        kind: FunctionKind::Normal,
+                        generator: *generator,
+                        is_async: *is_async,
+                        strict: *strict,
+                        home_object: None,
+                        fields: vec![],
+                        private_methods: vec![],
+                        source: source.clone(),
+                    });
+
+                    println!("{}+ {name} = {function:?}", " ".repeat(depth));
+                    let mut env = env_ref.borrow_mut();
+                    env.define(name.clone(), Value::Object(function));
+                    return Ok(Value::Undefined);
+                }
+                Err(Error::Runtime(format!("Unable to extract identifier from node: {identifier:?}")))
+            },
+            Tree::ReturnStatement { argument } => {
+                let value = match argument.as_ref() {
+                    None => Value::Undefined,
+                    Some(argument) => self.eval_tree(argument, env_ref)?,
+                };
+                Err(Error::Abrupt(Completion::Return(value)))
+            },
             _ => Err(Error::Runtime(format!("Unimplemented statement node: {statement}")))
         }
     }
+
+    fn eval_statement_list(&self, statements: &[Tree], env_ref: &EnvRef) -> Result<Value> {
+        // Hoisting function declarations
+        for statement in statements {
+            if let Tree::FunctionDeclaration { .. } = statement {
+                self.eval_tree(statement, env_ref)?;
+            }
+        }
+
+        let mut value = Value::Undefined;
+        for statement in statements {
+            if let Tree::FunctionDeclaration { .. } = statement {
+                continue;
+            }
+            value = self.eval_tree(statement, env_ref)?;
+        }
+        Ok(value)
+    }
 }
 
 #[cfg(test)]
@@ -57,6 +134,7 @@ mod tests {
         let mut env = EnvironmentBuilder::default().build().unwrap();
         let tree = Tree::ExpressionStatement {
             expression: Box::new(Tree::VariableStatement {
+                kind: String::from("let"),
                 declarations: vec![
                     Tree::VariableDeclaration {
                         identifier: Box::new(Tree::Identifier { name: "abc".to_string() }),
@@ -98,6 +176,6 @@ mod tests {
             }),
         };
 
-        assert_value_env(tree, &mut env, Value::String("20!".to_string()));
+        assert_value_env(tree, &mut env, Value::String("20!".into()));
     }
 }
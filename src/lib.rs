@@ -0,0 +1,15 @@
+mod interpreter;
+mod parser;
+mod collections;
+mod completion;
+mod environment;
+mod errors;
+mod number;
+mod object;
+mod symbol;
+mod tokenizer;
+mod tree;
+mod utf16;
+mod value;
+
+pub mod prelude;
@@ -1,26 +1,50 @@
-use crate::prelude::*;
+use std::io::Read;
+use std::process::ExitCode;
 
-mod interpreter;
-mod parser;
-mod environment;
-mod errors;
-mod prelude;
-mod tokenizer;
-mod tree;
-mod value;
+use js_interpreter::prelude::*;
 
-fn main() {
-    let content_string = "
-        let x = null, y = 'Hello', z = true;
-        x;
-    ";
+/**
+ * Runs the script at the path given as the first argument, or read from
+ * the standard input, driving its event loop until no timer is pending.
+ */
+fn main() -> ExitCode {
+    let content_string = match read_script(std::env::args().nth(1)) {
+        Ok(content_string) => content_string,
+        Err(error) => {
+            eprintln!("Unable to read script: {error}");
+            return ExitCode::FAILURE;
+        },
+    };
 
-    let mut parser = Parser::new(content_string.to_owned()).unwrap();
-    let result = parser.parse();
-    let tree = result.unwrap();
+    let tree = match Parser::new(content_string).and_then(|mut parser| parser.parse()) {
+        Ok(tree) => tree,
+        Err(error) => {
+            eprintln!("{error:?}");
+            return ExitCode::FAILURE;
+        },
+    };
 
     let mut env = EnvironmentBuilder::default().build().unwrap();
     let interpreter = Interpreter::new(&tree, &mut env);
-    let result = interpreter.eval();
+    let result = interpreter.eval().and_then(|value| {
+        interpreter.run_event_loop()?;
+        Ok(value)
+    });
     println!("{result:?}");
+
+    match result {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(_) => ExitCode::FAILURE,
+    }
+}
+
+fn read_script(path: Option<String>) -> std::io::Result<String> {
+    match path {
+        Some(path) => std::fs::read_to_string(path),
+        None => {
+            let mut content_string = String::new();
+            std::io::stdin().read_to_string(&mut content_string)?;
+            Ok(content_string)
+        },
+    }
 }
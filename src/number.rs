@@ -0,0 +1,304 @@
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Pow};
+
+/**
+ * Converts a number into its JS string representation, i.e. Number::toString.
+ * Uses the shortest digits round-tripping to the same number, switching to
+ * the exponential notation from 1e21 and below 1e-6.
+ */
+pub fn to_string(x: f64) -> String {
+    if x.is_nan() {
+        return "NaN".to_string();
+    }
+    if x == 0.0 {
+        return "0".to_string(); // Including -0
+    }
+    if x.is_infinite() {
+        return if x < 0.0 { "-Infinity" } else { "Infinity" }.to_string();
+    }
+    let sign = if x < 0.0 { "-" } else { "" };
+
+    // Rust's exponential formatting already yields the shortest round-trip digits
+    let (digits, exponent) = shortest_digits(x.abs());
+    let k = digits.len() as i32;
+    let n = exponent + 1; // Position of the decimal point
+
+    let formatted = if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat(-n as usize))
+    } else {
+        exponential(&digits, n - 1)
+    };
+    format!("{sign}{formatted}")
+}
+
+/**
+ * Converts a number into a string in the radix, i.e. Number.prototype.toString(radix).
+ * Fraction digits are emitted until they uniquely identify the number.
+ */
+pub fn to_radix_string(x: f64, radix: u32) -> String {
+    if radix == 10 || !x.is_finite() {
+        return to_string(x);
+    }
+    let sign = if x < 0.0 { "-" } else { "" };
+    let x = x.abs();
+
+    let mut integer = x.floor();
+    let mut fraction = x - integer;
+
+    // Half the distance to the next number, below which digits stop being significant
+    let mut delta = (0.5 * (x.next_up() - x)).max(0.0_f64.next_up());
+    let mut fraction_digits: Vec<u32> = vec![];
+    if fraction >= delta {
+        loop {
+            fraction *= radix as f64;
+            delta *= radix as f64;
+            let digit = fraction as u32;
+            fraction_digits.push(digit);
+            fraction -= digit as f64;
+
+            // Rounding up the last digit if the rest is past its half
+            if (fraction > 0.5 || (fraction == 0.5 && digit & 1 == 1)) && fraction + delta > 1.0 {
+                loop {
+                    match fraction_digits.pop() {
+                        None => {
+                            integer += 1.0;
+                            break;
+                        },
+                        Some(digit) if digit + 1 < radix => {
+                            fraction_digits.push(digit + 1);
+                            break;
+                        },
+                        Some(_) => continue,
+                    }
+                }
+                break;
+            }
+            if fraction < delta {
+                break;
+            }
+        }
+    }
+
+    let integer = BigInt::from_f64(integer).unwrap_or_default().to_str_radix(radix);
+    if fraction_digits.is_empty() {
+        return format!("{sign}{integer}");
+    }
+    let fraction: String = fraction_digits.iter()
+        .filter_map(|digit| char::from_digit(*digit, radix))
+        .collect();
+    format!("{sign}{integer}.{fraction}")
+}
+
+/**
+ * Formats a number with the digits after the decimal point, i.e. Number.prototype.toFixed.
+ * Expects digits between 0 and 100.
+ */
+pub fn to_fixed(x: f64, digits: usize) -> String {
+    if !x.is_finite() || x.abs() >= 1e21 {
+        return to_string(x);
+    }
+    let sign = if x < 0.0 { "-" } else { "" };
+    let n = round_scaled(x.abs(), digits as i32).to_string();
+    if digits == 0 {
+        return format!("{sign}{n}");
+    }
+
+    // Padding the integer part so that it has at least one digit
+    let n = format!("{n:0>width$}", width = digits + 1);
+    let (integer, fraction) = n.split_at(n.len() - digits);
+    format!("{sign}{integer}.{fraction}")
+}
+
+/**
+ * Formats a number in exponential notation, i.e. Number.prototype.toExponential.
+ * Uses as many fraction digits as needed if none are requested.
+ */
+pub fn to_exponential(x: f64, fraction_digits: Option<usize>) -> String {
+    if !x.is_finite() {
+        return to_string(x);
+    }
+    let sign = if x < 0.0 { "-" } else { "" };
+    let (digits, exponent) = match fraction_digits {
+        None if x == 0.0 => ("0".to_string(), 0),
+        None => shortest_digits(x.abs()),
+        Some(fraction_digits) => precise_digits(x.abs(), fraction_digits + 1),
+    };
+    format!("{sign}{}", exponential(&digits, exponent))
+}
+
+/**
+ * Formats a number with the significant digits, i.e. Number.prototype.toPrecision.
+ * Expects a precision between 1 and 100.
+ */
+pub fn to_precision(x: f64, precision: usize) -> String {
+    if !x.is_finite() {
+        return to_string(x);
+    }
+    let sign = if x < 0.0 { "-" } else { "" };
+    let (digits, exponent) = precise_digits(x.abs(), precision);
+    let p = precision as i32;
+
+    let formatted = if exponent < -6 || exponent >= p {
+        exponential(&digits, exponent)
+    } else if exponent == p - 1 {
+        digits
+    } else if exponent >= 0 {
+        let point = (exponent + 1) as usize;
+        format!("{}.{}", &digits[..point], &digits[point..])
+    } else {
+        format!("0.{}{digits}", "0".repeat((-(exponent + 1)) as usize))
+    };
+    format!("{sign}{formatted}")
+}
+
+/**
+ * Formats the digits as d.ddde+x
+ */
+fn exponential(digits: &str, exponent: i32) -> String {
+    let sign = if exponent < 0 { "-" } else { "+" };
+    let (first, rest) = digits.split_at(1);
+    match rest {
+        "" => format!("{first}e{sign}{}", exponent.abs()),
+        _ => format!("{first}.{rest}e{sign}{}", exponent.abs()),
+    }
+}
+
+/**
+ * Shortest digits of a positive number along with the exponent of its first digit.
+ */
+fn shortest_digits(x: f64) -> (String, i32) {
+    let formatted = format!("{x:e}");
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    (mantissa.replace('.', ""), exponent.parse().unwrap_or(0))
+}
+
+/**
+ * The count of digits of a positive number, rounded half up, along with the exponent of the first digit.
+ */
+fn precise_digits(x: f64, count: usize) -> (String, i32) {
+    if x == 0.0 {
+        return ("0".repeat(count), 0);
+    }
+    let count = count as i32;
+    let lower = BigInt::from(10).pow((count - 1) as u32);
+    let upper = &lower * 10;
+
+    // The estimate of the exponent can be off by one, e.g. for 999.99
+    let mut exponent = x.log10().floor() as i32;
+    loop {
+        let n = round_scaled(x, count - 1 - exponent);
+        if n >= upper {
+            exponent += 1;
+        } else if n < lower {
+            exponent -= 1;
+        } else {
+            return (n.to_string(), exponent);
+        }
+    }
+}
+
+/**
+ * Rounds x * 10^scale to the closest integer using the exact value of x,
+ * picking the larger integer when x is halfway.
+ */
+fn round_scaled(x: f64, scale: i32) -> BigInt {
+    let (mantissa, exponent) = decode(x);
+    let mut numerator = BigInt::from(mantissa);
+    let mut denominator = BigInt::from(1);
+    if exponent >= 0 {
+        numerator <<= exponent as usize;
+    } else {
+        denominator <<= (-exponent) as usize;
+    }
+    if scale >= 0 {
+        numerator *= BigInt::from(10).pow(scale as u32);
+    } else {
+        denominator *= BigInt::from(10).pow((-scale) as u32);
+    }
+
+    // floor(x + 1/2) = floor((2 * numerator + denominator) / (2 * denominator))
+    (numerator * 2 + &denominator) / (denominator * 2)
+}
+
+/**
+ * Decodes a positive finite number into mantissa * 2^exponent.
+ */
+fn decode(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+    match exponent {
+        0 => (fraction, -1074), // Subnormal numbers
+        _ => (fraction | (1 << 52), exponent - 1075),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(to_string(0.1 + 0.2), "0.30000000000000004");
+        assert_eq!(to_string(-0.0), "0");
+        assert_eq!(to_string(123.0), "123");
+        assert_eq!(to_string(-1.5), "-1.5");
+        assert_eq!(to_string(1e21), "1e+21");
+        assert_eq!(to_string(1e20 + 1.0), "100000000000000000000");
+        assert_eq!(to_string(123456789e15), "1.23456789e+23");
+        assert_eq!(to_string(0.000001), "0.000001");
+        assert_eq!(to_string(1e-7), "1e-7");
+        assert_eq!(to_string(1.5e-10), "1.5e-10");
+        assert_eq!(to_string(f64::MAX), "1.7976931348623157e+308");
+        assert_eq!(to_string(5e-324), "5e-324");
+        assert_eq!(to_string(f64::NEG_INFINITY), "-Infinity");
+    }
+
+    #[test]
+    fn test_to_radix_string() {
+        assert_eq!(to_radix_string(255.0, 16), "ff");
+        assert_eq!(to_radix_string(-255.0, 2), "-11111111");
+        assert_eq!(to_radix_string(0.5, 2), "0.1");
+        assert_eq!(to_radix_string(0.1, 2), "0.0001100110011001100110011001100110011001100110011001101");
+        assert_eq!(to_radix_string(3.75, 16), "3.c");
+        assert_eq!(to_radix_string(35.0, 36), "z");
+        assert_eq!(to_radix_string(2f64.powi(60), 16), "1000000000000000");
+    }
+
+    #[test]
+    fn test_to_fixed() {
+        assert_eq!(to_fixed(1.005, 2), "1.00"); // 1.005 is actually 1.00499999999999989...
+        assert_eq!(to_fixed(2.5, 0), "3");
+        assert_eq!(to_fixed(-2.5, 0), "-3");
+        assert_eq!(to_fixed(0.000001, 2), "0.00");
+        assert_eq!(to_fixed(-0.0000001, 2), "-0.00");
+        assert_eq!(to_fixed(123.456, 1), "123.5");
+        assert_eq!(to_fixed(0.5, 3), "0.500");
+        assert_eq!(to_fixed(1e21, 2), "1e+21");
+    }
+
+    #[test]
+    fn test_to_exponential() {
+        assert_eq!(to_exponential(123456.0, Some(2)), "1.23e+5");
+        assert_eq!(to_exponential(123456.0, None), "1.23456e+5");
+        assert_eq!(to_exponential(0.00015, Some(1)), "1.5e-4");
+        assert_eq!(to_exponential(9.99, Some(1)), "1.0e+1");
+        assert_eq!(to_exponential(0.0, Some(2)), "0.00e+0");
+        assert_eq!(to_exponential(-5.0, None), "-5e+0");
+    }
+
+    #[test]
+    fn test_to_precision() {
+        assert_eq!(to_precision(123.456, 4), "123.5");
+        assert_eq!(to_precision(123.456, 2), "1.2e+2");
+        assert_eq!(to_precision(0.000123, 2), "0.00012");
+        assert_eq!(to_precision(0.0000001234, 2), "1.2e-7");
+        assert_eq!(to_precision(999.99, 3), "1.00e+3");
+        assert_eq!(to_precision(0.0, 3), "0.00");
+        assert_eq!(to_precision(-1.5, 1), "-2");
+    }
+}
@@ -0,0 +1,1097 @@
+use crate::prelude::*;
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::{Rc, Weak};
+
+use indexmap::IndexMap;
+
+use crate::collections::{KeyedCollection, WeakCollection};
+use crate::interpreter::{GeneratorState, PromiseState, RegularExpression};
+
+/**
+ * Property slot of an object, either holding a value directly
+ * or through a getter & setter pair.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub enum Property {
+    Data(Value),
+    Accessor { get: Option<Value>, set: Option<Value> },
+}
+
+/**
+ * Fields of a property & its attributes, e.g. as passed to `Object.defineProperty`.
+ * Missing fields are left as they are on existing properties, or default to
+ * undefined & false on new ones. Accessors without a getter or setter hold undefined.
+ */
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PropertyDescriptor {
+    pub value: Option<Value>,
+    pub writable: Option<bool>,
+    pub get: Option<Value>,
+    pub set: Option<Value>,
+    pub enumerable: Option<bool>,
+    pub configurable: Option<bool>,
+}
+
+impl PropertyDescriptor {
+    /**
+     * Descriptor of a writable, enumerable & configurable data property, like the ones created by assignments.
+     */
+    pub fn data(value: Value) -> Self {
+        Self {
+            value: Some(value),
+            writable: Some(true),
+            enumerable: Some(true),
+            configurable: Some(true),
+            ..Self::default()
+        }
+    }
+
+    pub fn is_accessor(&self) -> bool {
+        self.get.is_some() || self.set.is_some()
+    }
+
+    pub fn is_data(&self) -> bool {
+        self.value.is_some() || self.writable.is_some()
+    }
+}
+
+/**
+ * Key of an object's property, either a string or a symbol.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyKey {
+    String(String),
+    Symbol(Symbol),
+
+    // Private name of a class member, e.g. `#x`, unique to the class body declaring it.
+    Private(Symbol),
+}
+
+impl PropertyKey {
+    /**
+     * Returns the key if it's a string, e.g. to look up array indices.
+     */
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            PropertyKey::String(key) => Some(key),
+            PropertyKey::Symbol(_) | PropertyKey::Private(_) => None,
+        }
+    }
+
+    /**
+     * Name given to functions defined under the key, e.g. '[Symbol.iterator]'.
+     */
+    pub fn function_name(&self) -> String {
+        match self {
+            PropertyKey::String(key) => key.clone(),
+            PropertyKey::Symbol(symbol) => match symbol.description() {
+                Some(description) => format!("[{description}]"),
+                None => String::new(),
+            },
+            PropertyKey::Private(name) => name.description().unwrap_or_default(),
+        }
+    }
+
+    /**
+     * Converts the key back into a value, e.g. for the keys of an object.
+     */
+    pub fn to_value(&self) -> Value {
+        match self {
+            PropertyKey::String(key) => Value::String(key.clone().into()),
+            PropertyKey::Symbol(symbol) | PropertyKey::Private(symbol) => Value::Symbol(symbol.clone()),
+        }
+    }
+}
+
+impl From<&str> for PropertyKey {
+    fn from(key: &str) -> Self {
+        PropertyKey::String(key.to_string())
+    }
+}
+
+impl From<String> for PropertyKey {
+    fn from(key: String) -> Self {
+        PropertyKey::String(key)
+    }
+}
+
+impl From<&String> for PropertyKey {
+    fn from(key: &String) -> Self {
+        PropertyKey::String(key.clone())
+    }
+}
+
+impl From<Symbol> for PropertyKey {
+    fn from(symbol: Symbol) -> Self {
+        PropertyKey::Symbol(symbol)
+    }
+}
+
+impl From<&Symbol> for PropertyKey {
+    fn from(symbol: &Symbol) -> Self {
+        PropertyKey::Symbol(symbol.clone())
+    }
+}
+
+impl From<&PropertyKey> for PropertyKey {
+    fn from(key: &PropertyKey) -> Self {
+        key.clone()
+    }
+}
+
+impl fmt::Display for PropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyKey::String(key) => write!(f, "{key}"),
+            PropertyKey::Symbol(symbol) => write!(f, "{symbol}"),
+            PropertyKey::Private(name) => write!(f, "{}", name.description().unwrap_or_default()),
+        }
+    }
+}
+
+/**
+ * Describes how a function object behaves when invoked.
+ */
+#[derive(Clone, Debug, PartialEq)]
+pub enum FunctionKind {
+    Normal,
+    Method,
+    ClassConstructor { derived: bool, implicit: bool },
+}
+
+/**
+ * User-defined function, closing over the environment it was created in.
+ * The params, body & source are shared with the tree the function was created from.
+ */
+pub struct Function {
+    pub name: String,
+    pub params: Rc<[Tree]>,
+    pub body: Rc<Tree>,
+    pub env: Environment,
+    pub kind: FunctionKind,
+
+    // Generator functions return a generator object instead of running their body.
+    pub generator: bool,
+
+    // Async functions run their body until the first `await`, returning a promise.
+    pub is_async: bool,
+
+    // Strict mode functions see `this` as is & throw on failed assignments.
+    pub strict: bool,
+
+    // Object whose prototype is used to resolve `super` references.
+    pub home_object: Option<ObjectRef>,
+
+    // Instance fields initialized by class constructors.
+    pub fields: Vec<FieldDefinition>,
+
+    // Private methods & accessors that class constructors add to their instances.
+    pub private_methods: Vec<(PropertyKey, Property)>,
+
+    // Source text returned by `Function.prototype.toString`, the whole class for class constructors.
+    pub source: Rc<str>,
+}
+
+/**
+ * Field of a class, whose key is computed once the class is defined.
+ */
+#[derive(Clone)]
+pub struct FieldDefinition {
+    pub key: PropertyKey,
+    pub value: Option<Tree>,
+}
+
+/**
+ * Signature of functions implemented by the interpreter itself, receiving
+ * `this`, the arguments and `new.target` when invoked as a constructor.
+ */
+pub type NativeFn = fn(&Interpreter, &Value, &[Value], Option<&ObjectRef>) -> Result<Value>;
+
+/**
+ * Native function capturing state of its own, e.g. the
+ * promise settled by the `resolve` function of an executor.
+ */
+pub type NativeClosure = Rc<dyn Fn(&Interpreter, &Value, &[Value], Option<&ObjectRef>) -> Result<Value>>;
+
+/**
+ * Built-in function, e.g. the `Error` constructor.
+ */
+#[derive(Clone)]
+pub struct NativeFunction {
+    pub name: String,
+    pub function: NativeClosure,
+    pub constructor: bool,
+}
+
+/**
+ * Function calling its target with a fixed `this` value, the bound
+ * arguments being passed before the ones of the call, e.g. `f.bind(o, 1)`.
+ */
+#[derive(Clone)]
+pub struct BoundFunction {
+    pub target: ObjectRef,
+    pub this: Value,
+    pub args: Vec<Value>,
+}
+
+/**
+ * Elements of an array. The ones from the start up to the first hole are stored
+ * inline, the others being kept as properties keyed by their index, e.g. `a[1e9] = 1`.
+ */
+pub struct ArrayElements {
+    pub dense: Vec<Value>,
+    pub length: usize,
+}
+
+impl From<Vec<Value>> for ArrayElements {
+    fn from(dense: Vec<Value>) -> Self {
+        let length = dense.len();
+        Self { dense, length }
+    }
+}
+
+pub enum ObjectKind {
+    Ordinary,
+    Array(ArrayElements),
+    Function(Rc<Function>),
+    NativeFunction(NativeFunction),
+
+    // Functions returned by `Function.prototype.bind`
+    BoundFunction(BoundFunction),
+
+    // Instances of Error & its subclasses
+    Error,
+
+    // Objects returned by generator functions
+    Generator(GeneratorState),
+    Promise(PromiseState),
+    RegExp(Rc<RegularExpression>),
+
+    // Time value of Date objects in milliseconds since the epoch, NaN for invalid dates
+    Date(f64),
+
+    // Keyed collections, the weak ones not keeping their keys alive
+    Map(KeyedCollection),
+    Set(KeyedCollection),
+    WeakMap(WeakCollection),
+    WeakSet(WeakCollection),
+
+    // Wrappers of primitive values, e.g. `new Number(1)` or `Object('abc')`
+    Primitive(Value),
+
+    // Exotic objects forwarding their internal methods to the traps of a handler
+    Proxy(ProxyState),
+}
+
+/**
+ * Target & handler of a proxy. The handler is dropped once the proxy is
+ * revoked, the target being kept as it decides whether the proxy is callable.
+ */
+#[derive(Clone)]
+pub struct ProxyState {
+    pub target: ObjectRef,
+    pub handler: Option<ObjectRef>,
+}
+
+pub struct Object {
+    pub kind: ObjectKind,
+    pub properties: IndexMap<PropertyKey, Property>,
+    pub prototype: Option<ObjectRef>,
+
+    // Keys skipped while enumerating, e.g. class methods in for-in loops
+    pub non_enumerable: HashSet<PropertyKey>,
+
+    // Data properties whose value can't be changed, & properties which can't be
+    // deleted nor redefined, e.g. once the object is frozen
+    pub non_writable: HashSet<PropertyKey>,
+    pub non_configurable: HashSet<PropertyKey>,
+
+    // Whether new properties can be added
+    pub extensible: bool,
+}
+
+/**
+ * Objects are released iteratively, as the drop glue of `Rc` would recurse as deep
+ * as the object graph, e.g. a long linked list, & overflow the stack. The objects
+ * only referenced by the dropped one are emptied into a worklist before being released,
+ * hence their own drop has nothing left to recurse into.
+ */
+impl Drop for Object {
+    fn drop(&mut self) {
+        let mut pending = vec![];
+        self.release_references(&mut pending);
+        while let Some(object) = pending.pop() {
+            // Objects still referenced elsewhere are released by their last owner
+            if Rc::strong_count(&object.0) == 1 {
+                if let Ok(mut object) = object.0.try_borrow_mut() {
+                    object.release_references(&mut pending);
+                }
+            }
+        }
+    }
+}
+
+impl Object {
+    /**
+     * Moves the objects referenced by the prototype, the properties & the elements onto the worklist.
+     */
+    fn release_references(&mut self, pending: &mut Vec<ObjectRef>) {
+        let mut values = vec![];
+        for (_, property) in std::mem::take(&mut self.properties) {
+            match property {
+                Property::Data(value) => values.push(value),
+                Property::Accessor { get, set } => values.extend(get.into_iter().chain(set)),
+            }
+        }
+        match std::mem::replace(&mut self.kind, ObjectKind::Ordinary) {
+            ObjectKind::Array(elements) => values.extend(elements.dense),
+            ObjectKind::BoundFunction(bound) => {
+                values.push(Value::Object(bound.target));
+                values.push(bound.this);
+                values.extend(bound.args);
+            },
+            ObjectKind::Primitive(value) => values.push(value),
+            ObjectKind::Proxy(proxy) => pending.extend([proxy.target].into_iter().chain(proxy.handler)),
+            _ => {},
+        }
+        pending.extend(self.prototype.take());
+        pending.extend(values.into_iter().filter_map(|value| match value {
+            Value::Object(object) => Some(object),
+            _ => None,
+        }));
+    }
+}
+
+/**
+ * Shared handle to a heap allocated object.
+ * Two handles are equal only if they point to the same object.
+ */
+#[derive(Clone)]
+pub struct ObjectRef(Rc<RefCell<Object>>);
+
+impl ObjectRef {
+    /**
+     * Allocates a new object without any own properties.
+     */
+    pub fn new(kind: ObjectKind, prototype: Option<ObjectRef>) -> Self {
+        Self(Rc::new(RefCell::new(Object {
+            kind,
+            properties: IndexMap::new(),
+            prototype,
+            non_enumerable: HashSet::new(),
+            non_writable: HashSet::new(),
+            non_configurable: HashSet::new(),
+            extensible: true,
+        })))
+    }
+
+    pub fn borrow(&self) -> Ref<'_, Object> {
+        self.0.borrow()
+    }
+
+    pub fn borrow_mut(&self) -> RefMut<'_, Object> {
+        self.0.borrow_mut()
+    }
+
+    pub fn prototype(&self) -> Option<ObjectRef> {
+        self.borrow().prototype.clone()
+    }
+
+    /**
+     * Replaces the prototype of the object, e.g. `Object.setPrototypeOf`.
+     * Returns false if the object isn't extensible or if the change would make the chain cyclic.
+     */
+    pub fn set_prototype(&self, prototype: Option<ObjectRef>) -> bool {
+        if self.prototype() == prototype {
+            return true;
+        }
+        if !self.is_extensible() {
+            return false;
+        }
+        let mut current = prototype.clone();
+        while let Some(object) = current {
+            if &object == self {
+                return false;
+            }
+            current = object.prototype();
+        }
+        self.borrow_mut().prototype = prototype;
+        true
+    }
+
+    /**
+     * Creates a handle which doesn't keep the object alive, e.g. for WeakMap keys.
+     */
+    pub fn downgrade(&self) -> WeakObjectRef {
+        WeakObjectRef(Rc::downgrade(&self.0))
+    }
+
+    /**
+     * Returns the user-defined function, if the object is one.
+     */
+    pub fn function(&self) -> Option<Rc<Function>> {
+        match &self.borrow().kind {
+            ObjectKind::Function(function) => Some(function.clone()),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns the built-in function, if the object is one.
+     */
+    pub fn native_function(&self) -> Option<NativeFunction> {
+        match &self.borrow().kind {
+            ObjectKind::NativeFunction(function) => Some(function.clone()),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns the target & bound arguments of a function created by `bind`, if the object is one.
+     */
+    pub fn bound_function(&self) -> Option<BoundFunction> {
+        match &self.borrow().kind {
+            ObjectKind::BoundFunction(function) => Some(function.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn is_callable(&self) -> bool {
+        match &self.borrow().kind {
+            ObjectKind::Function(_) | ObjectKind::NativeFunction(_) | ObjectKind::BoundFunction(_) => true,
+            ObjectKind::Proxy(proxy) => proxy.target.is_callable(),
+            _ => false,
+        }
+    }
+
+    pub fn is_constructor(&self) -> bool {
+        match &self.borrow().kind {
+            ObjectKind::Function(function) => function.kind != FunctionKind::Method && !function.generator && !function.is_async,
+            ObjectKind::NativeFunction(function) => function.constructor,
+            ObjectKind::BoundFunction(function) => function.target.is_constructor(),
+            ObjectKind::Proxy(proxy) => proxy.target.is_constructor(),
+            _ => false,
+        }
+    }
+
+    /**
+     * Returns the target & handler of a proxy, None for any other object.
+     */
+    pub fn proxy(&self) -> Option<ProxyState> {
+        match &self.borrow().kind {
+            ObjectKind::Proxy(proxy) => Some(proxy.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn is_array(&self) -> bool {
+        matches!(self.borrow().kind, ObjectKind::Array(_))
+    }
+
+    pub fn is_promise(&self) -> bool {
+        matches!(self.borrow().kind, ObjectKind::Promise(_))
+    }
+
+    /**
+     * Returns the compiled pattern, if the object is a regular expression.
+     */
+    pub fn regexp(&self) -> Option<Rc<RegularExpression>> {
+        match &self.borrow().kind {
+            ObjectKind::RegExp(regexp) => Some(regexp.clone()),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns the wrapped primitive value, if the object is a wrapper.
+     */
+    pub fn primitive_value(&self) -> Option<Value> {
+        match &self.borrow().kind {
+            ObjectKind::Primitive(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /**
+     * Returns the object's own property, without looking into its prototype.
+     */
+    pub fn get_own_property(&self, key: impl Into<PropertyKey>) -> Option<Property> {
+        let key = key.into();
+        let object = self.borrow();
+        if let (ObjectKind::Array(elements), Some(key)) = (&object.kind, key.as_string()) {
+            if key == "length" {
+                return Some(Property::Data(Value::Number(elements.length as f64)));
+            }
+            if let Some(value) = array_index(key).and_then(|index| elements.dense.get(index)) {
+                return Some(Property::Data(value.clone()));
+            }
+        }
+
+        // String wrappers expose their characters, e.g. `new String('ab')[1]`
+        if let (ObjectKind::Primitive(Value::String(string)), Some(key)) = (&object.kind, key.as_string()) {
+            if key == "length" {
+                return Some(Property::Data(Value::Number(string.length() as f64)));
+            }
+            if let Some(unit) = array_index(key).and_then(|index| string.unit_at(index)) {
+                return Some(Property::Data(Value::String(unit)));
+            }
+        }
+        object.properties.get(&key).cloned()
+    }
+
+    /**
+     * Creates or replaces an own property of the object.
+     */
+    pub fn define_property(&self, key: impl Into<PropertyKey>, property: Property) {
+        if let Property::Data(value) = property {
+            return self.set_own_value(key, value);
+        }
+        self.borrow_mut().properties.insert(key.into(), property);
+    }
+
+    /**
+     * Creates or replaces an own property which isn't enumerated, e.g. methods.
+     */
+    pub fn define_non_enumerable(&self, key: impl Into<PropertyKey>, property: Property) {
+        let key = key.into();
+        self.borrow_mut().non_enumerable.insert(key.clone());
+        self.define_property(key, property);
+    }
+
+    /**
+     * Defines the `length` of a function, i.e. the number of arguments it expects,
+     * which is read-only but configurable.
+     */
+    pub fn define_function_length(&self, length: f64) {
+        self.define_non_enumerable("length", Property::Data(Value::Number(length)));
+        self.borrow_mut().non_writable.insert("length".into());
+    }
+
+    /**
+     * Adds a private method or accessor, which unlike private fields can't be reassigned.
+     */
+    pub fn define_private_method(&self, key: PropertyKey, property: Property) {
+        let method = matches!(property, Property::Data(_));
+        self.define_non_enumerable(&key, property);
+        if method {
+            self.borrow_mut().non_writable.insert(key);
+        }
+    }
+
+    pub fn is_enumerable(&self, key: impl Into<PropertyKey>) -> bool {
+        let key = key.into();
+        !self.has_length(&key) && !self.borrow().non_enumerable.contains(&key)
+    }
+
+    pub fn is_writable(&self, key: impl Into<PropertyKey>) -> bool {
+        let key = key.into();
+        !self.has_string_unit(&key) && !self.borrow().non_writable.contains(&key)
+    }
+
+    pub fn is_configurable(&self, key: impl Into<PropertyKey>) -> bool {
+        let key = key.into();
+        !self.has_length(&key) && !self.has_string_unit(&key) && !self.borrow().non_configurable.contains(&key)
+    }
+
+    pub fn is_extensible(&self) -> bool {
+        self.borrow().extensible
+    }
+
+    /**
+     * Prevents new properties from being added to the object.
+     */
+    pub fn prevent_extensions(&self) {
+        self.borrow_mut().extensible = false;
+    }
+
+    /**
+     * Whether the key is the built-in `length` of arrays & string wrappers.
+     */
+    fn has_length(&self, key: &PropertyKey) -> bool {
+        key.as_string() == Some("length") && matches!(self.borrow().kind, ObjectKind::Array(_) | ObjectKind::Primitive(Value::String(_)))
+    }
+
+    /**
+     * Whether the key is the length or a character index of a string wrapper, which are read-only.
+     */
+    fn has_string_unit(&self, key: &PropertyKey) -> bool {
+        match (&self.borrow().kind, key.as_string()) {
+            (ObjectKind::Primitive(Value::String(string)), Some(key)) => {
+                key == "length" || array_index(key).is_some_and(|index| index < string.length())
+            },
+            _ => false,
+        }
+    }
+
+    /**
+     * Returns the own property along with its attributes, as returned by `Object.getOwnPropertyDescriptor`.
+     */
+    pub fn get_own_property_descriptor(&self, key: impl Into<PropertyKey>) -> Option<PropertyDescriptor> {
+        let key = key.into();
+        let mut descriptor = match self.get_own_property(&key)? {
+            Property::Data(value) => PropertyDescriptor {
+                value: Some(value),
+                writable: Some(self.is_writable(&key)),
+                ..PropertyDescriptor::default()
+            },
+            Property::Accessor { get, set } => PropertyDescriptor {
+                get: Some(get.unwrap_or(Value::Undefined)),
+                set: Some(set.unwrap_or(Value::Undefined)),
+                ..PropertyDescriptor::default()
+            },
+        };
+        descriptor.enumerable = Some(self.is_enumerable(&key));
+        descriptor.configurable = Some(self.is_configurable(&key));
+        Some(descriptor)
+    }
+
+    /**
+     * Creates or updates an own property according to the descriptor, e.g. `Object.defineProperty`.
+     * Returns false if the object isn't extensible, if the property is non-configurable & the
+     * descriptor changes it, or if it adds an array element past a read-only length.
+     */
+    pub fn define_own_property(&self, key: impl Into<PropertyKey>, descriptor: PropertyDescriptor) -> bool {
+        let key = key.into();
+        let element = key.as_string().and_then(array_index).filter(|_| self.is_array());
+        if let (true, Some("length"), Some(Value::Number(length))) = (self.is_array(), key.as_string(), &descriptor.value) {
+            return self.set_array_length(*length as usize, descriptor);
+        }
+        let current = self.get_own_property(&key);
+        if current.is_none() && !self.is_extensible() {
+            return false;
+        }
+        if let (None, Some(index)) = (&current, element) {
+            if index >= self.array_length() && !self.is_writable("length") {
+                return false;
+            }
+        }
+
+        // Non-configurable properties only accept descriptors that don't change them,
+        // apart from making a writable data property read-only or changing its value
+        if current.is_some() && !self.is_configurable(&key) {
+            if descriptor.configurable == Some(true) || descriptor.enumerable.is_some_and(|enumerable| enumerable != self.is_enumerable(&key)) {
+                return false;
+            }
+            let unchanged = |field: &Option<Value>, current: &Value| field.as_ref().is_none_or(|field| field.same_value(current));
+            let rejected = match &current {
+                Some(Property::Accessor { get, set }) => descriptor.is_data()
+                    || !unchanged(&descriptor.get, get.as_ref().unwrap_or(&Value::Undefined))
+                    || !unchanged(&descriptor.set, set.as_ref().unwrap_or(&Value::Undefined)),
+                Some(Property::Data(value)) => descriptor.is_accessor()
+                    || (!self.is_writable(&key) && (descriptor.writable == Some(true) || !unchanged(&descriptor.value, value))),
+                None => false,
+            };
+            if rejected {
+                return false;
+            }
+        }
+
+        let new = current.is_none();
+        let accessor = |value: Value| (!matches!(value, Value::Undefined)).then_some(value);
+        let writable = match current {
+            Some(Property::Accessor { get, set }) if !descriptor.is_data() => {
+                let get = descriptor.get.map_or(get, accessor);
+                let set = descriptor.set.map_or(set, accessor);
+                self.borrow_mut().properties.insert(key.clone(), Property::Accessor { get, set });
+                None
+            },
+            None | Some(Property::Data(_)) if descriptor.is_accessor() => {
+                let property = Property::Accessor { get: descriptor.get.and_then(accessor), set: descriptor.set.and_then(accessor) };
+                if let Some(index) = element {
+                    self.move_out_of_line(index);
+                }
+                let mut object = self.borrow_mut();
+                object.properties.insert(key.clone(), property);
+                if let (ObjectKind::Array(elements), Some(index)) = (&mut object.kind, element) {
+                    elements.length = elements.length.max(index + 1);
+                }
+                Some(true)
+            },
+
+            // Data properties replacing accessors or created anew are read-only unless told otherwise
+            None | Some(Property::Accessor { .. }) => {
+                self.set_own_value(&key, descriptor.value.unwrap_or(Value::Undefined));
+                Some(descriptor.writable.unwrap_or(false))
+            },
+            Some(Property::Data(_)) => {
+                if let Some(value) = descriptor.value {
+                    self.set_own_value(&key, value);
+                }
+                descriptor.writable
+            },
+        };
+
+        let mut object = self.borrow_mut();
+        let object = &mut *object;
+        let attributes = [
+            (&mut object.non_writable, writable),
+            (&mut object.non_enumerable, descriptor.enumerable.or(new.then_some(false))),
+            (&mut object.non_configurable, descriptor.configurable.or(new.then_some(false))),
+        ];
+        for (unset, attribute) in attributes {
+            match attribute {
+                Some(true) => unset.remove(&key),
+                Some(false) => unset.insert(key.clone()),
+                None => continue,
+            };
+        }
+        true
+    }
+
+    /**
+     * Changes the length of an array, deleting the elements past it from the last one down to
+     * the first non-configurable one, e.g. once the array is sealed. Returns false if the length
+     * is read-only or if an element couldn't be deleted, the length then ending right after it.
+     */
+    fn set_array_length(&self, length: usize, descriptor: PropertyDescriptor) -> bool {
+        let key = PropertyKey::from("length");
+        if descriptor.configurable == Some(true) || descriptor.enumerable == Some(true) || descriptor.is_accessor() {
+            return false;
+        }
+        if !self.is_writable(&key) {
+            return length == self.array_length() && descriptor.writable != Some(true);
+        }
+        let kept = self.borrow().non_configurable.iter()
+            .filter_map(|key| key.as_string().and_then(array_index))
+            .filter(|index| *index >= length)
+            .max();
+        self.set_own_value(&key, Value::Number(kept.map_or(length, |index| index + 1) as f64));
+        if descriptor.writable == Some(false) {
+            self.borrow_mut().non_writable.insert(key);
+        }
+        kept.is_none()
+    }
+
+    fn array_length(&self) -> usize {
+        match &self.borrow().kind {
+            ObjectKind::Array(elements) => elements.length,
+            _ => 0,
+        }
+    }
+
+    /**
+     * Moves the inline elements of an array from the index on into properties,
+     * e.g. before the element becomes a hole or an accessor.
+     */
+    fn move_out_of_line(&self, index: usize) {
+        let mut object = self.borrow_mut();
+        let object = &mut *object;
+        if let ObjectKind::Array(elements) = &mut object.kind {
+            if index < elements.dense.len() {
+                for (offset, value) in elements.dense.split_off(index).into_iter().enumerate() {
+                    object.properties.insert(PropertyKey::String((index + offset).to_string()), Property::Data(value));
+                }
+            }
+        }
+    }
+
+    /**
+     * Own keys along with the built-in `length` of arrays & string wrappers.
+     */
+    pub fn own_property_keys(&self) -> Vec<PropertyKey> {
+        let mut keys = self.own_keys();
+        let length = PropertyKey::from("length");
+        if self.has_length(&length) {
+            // The length comes first of the non-index keys, as if created along with the object
+            let position = keys.iter().position(|key| key.as_string().and_then(array_index).is_none()).unwrap_or(keys.len());
+            keys.insert(position, length);
+        }
+        keys
+    }
+
+    /**
+     * Returns the keys of the object's own properties, array indices first in ascending
+     * order, then strings & symbols each in insertion order, e.g. '1' before 'b' for any object.
+     */
+    pub fn own_keys(&self) -> Vec<PropertyKey> {
+        let object = self.borrow();
+        let mut keys: Vec<PropertyKey> = match &object.kind {
+            ObjectKind::Array(elements) => (0..elements.dense.len()).map(|index| PropertyKey::String(index.to_string())).collect(),
+            ObjectKind::Primitive(Value::String(string)) => (0..string.length()).map(|index| PropertyKey::String(index.to_string())).collect(),
+            _ => vec![],
+        };
+
+        // Index keys which aren't inline, e.g. elements past a hole, are listed before any other
+        let (mut indices, others): (Vec<&PropertyKey>, Vec<&PropertyKey>) = object.properties.keys()
+            .partition(|key| key.as_string().and_then(array_index).is_some());
+        indices.sort_by_key(|key| key.as_string().and_then(array_index));
+
+        // Private members aren't properties, so they are never listed
+        let (strings, symbols): (Vec<&PropertyKey>, Vec<&PropertyKey>) = others.into_iter()
+            .filter(|key| !matches!(key, PropertyKey::Private(_)))
+            .partition(|key| matches!(key, PropertyKey::String(_)));
+        keys.extend(indices.into_iter().chain(strings).chain(symbols).cloned());
+        keys
+    }
+
+    /**
+     * Creates or updates an own data property of the object. Arrays store their
+     * elements inline while they have no holes, & drop the ones past a shorter length.
+     */
+    pub fn set_own_value(&self, key: impl Into<PropertyKey>, value: Value) {
+        let key = key.into();
+        let mut object = self.borrow_mut();
+        let object = &mut *object;
+        if let (ObjectKind::Array(elements), Some(name)) = (&mut object.kind, key.as_string()) {
+            if name == "length" {
+                if let Value::Number(length) = value {
+                    let length = length as usize;
+                    elements.dense.truncate(length);
+                    if length < elements.length {
+                        let removed = |key: &PropertyKey| key.as_string().and_then(array_index).is_some_and(|index| index >= length);
+                        object.properties.retain(|key, _| !removed(key));
+                        for keys in [&mut object.non_enumerable, &mut object.non_writable, &mut object.non_configurable] {
+                            keys.retain(|key| !removed(key));
+                        }
+                    }
+                    elements.length = length;
+                }
+                return;
+            }
+            if let Some(index) = array_index(name) {
+                elements.length = elements.length.max(index + 1);
+                if let Some(element) = elements.dense.get_mut(index) {
+                    *element = value;
+                    return;
+                }
+
+                // Appending an element moves the ones following it inline, up to the next hole
+                if index == elements.dense.len() {
+                    object.properties.shift_remove(&key);
+                    elements.dense.push(value);
+                    loop {
+                        let next = PropertyKey::String(elements.dense.len().to_string());
+                        match object.properties.get(&next) {
+                            Some(Property::Data(value)) => elements.dense.push(value.clone()),
+                            _ => break,
+                        }
+                        object.properties.shift_remove(&next);
+                    }
+                    return;
+                }
+            }
+        }
+        object.properties.insert(key, Property::Data(value));
+    }
+
+    /**
+     * Removes an own property of the object, keeping the order of the others,
+     * unless it's non-configurable. Returns whether the property is gone.
+     * Removing an inline array element leaves a hole, the elements following it moving out of line.
+     */
+    pub fn delete_property(&self, key: impl Into<PropertyKey>) -> bool {
+        let key = key.into();
+        if !self.is_configurable(&key) {
+            return self.get_own_property(&key).is_none();
+        }
+        if let Some(index) = key.as_string().and_then(array_index).filter(|_| self.is_array()) {
+            self.move_out_of_line(index);
+        }
+        let mut object = self.borrow_mut();
+        object.properties.shift_remove(&key);
+        object.non_enumerable.remove(&key);
+        object.non_writable.remove(&key);
+        object.non_configurable.remove(&key);
+        true
+    }
+
+    /**
+     * Reads a data property through the prototype chain without invoking
+     * getters, e.g. the `name` & `message` of errors while formatting.
+     */
+    pub fn get_data_value(&self, key: impl Into<PropertyKey>) -> Option<Value> {
+        let key = key.into();
+        let mut current = Some(self.clone());
+        while let Some(object) = current {
+            match object.get_own_property(&key) {
+                Some(Property::Data(value)) => return Some(value),
+                Some(Property::Accessor { .. }) => return None,
+                None => current = object.prototype(),
+            }
+        }
+        None
+    }
+
+    /**
+     * Whether the property is defined on the object or its prototype chain.
+     */
+    pub fn has_property(&self, key: impl Into<PropertyKey>) -> bool {
+        let key = key.into();
+        let mut current = Some(self.clone());
+        while let Some(object) = current {
+            if object.get_own_property(&key).is_some() {
+                return true;
+            }
+            current = object.prototype();
+        }
+        false
+    }
+}
+
+/**
+ * Parses a property key as an array index, e.g. '2' but not '02',
+ * the largest index being one less than the maximum array length.
+ */
+fn array_index(key: &str) -> Option<usize> {
+    match key.parse::<usize>() {
+        Ok(index) if index < u32::MAX as usize && index.to_string() == key => Some(index),
+        _ => None,
+    }
+}
+
+impl PartialEq for ObjectRef {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ObjectRef {}
+
+impl Hash for ObjectRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
+/**
+ * Handle to an object which doesn't keep it alive. Two handles are equal
+ * only if they point to the same object, even once it has been dropped.
+ */
+#[derive(Clone)]
+pub struct WeakObjectRef(Weak<RefCell<Object>>);
+
+impl WeakObjectRef {
+    /**
+     * Returns the object, unless it has been dropped.
+     */
+    pub fn upgrade(&self) -> Option<ObjectRef> {
+        self.0.upgrade().map(ObjectRef)
+    }
+}
+
+impl PartialEq for WeakObjectRef {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for WeakObjectRef {}
+
+impl Hash for WeakObjectRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Weak::as_ptr(&self.0).hash(state);
+    }
+}
+
+impl fmt::Debug for ObjectRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.borrow().kind {
+            ObjectKind::Ordinary => write!(f, "Object"),
+            ObjectKind::Array(elements) => write!(f, "Array({})", elements.length),
+            ObjectKind::Function(function) => write!(f, "Function({})", function.name),
+            ObjectKind::NativeFunction(function) => write!(f, "NativeFunction({})", function.name),
+            ObjectKind::BoundFunction(function) => write!(f, "BoundFunction({:?})", function.target),
+            ObjectKind::Error => write!(f, "Error({self})"),
+            ObjectKind::Generator(_) => write!(f, "Generator"),
+            ObjectKind::Promise(_) => write!(f, "Promise"),
+            ObjectKind::RegExp(regexp) => write!(f, "RegExp(/{}/{})", regexp.source, regexp.flags),
+            ObjectKind::Date(time) => write!(f, "Date({time})"),
+            ObjectKind::Map(entries) => write!(f, "Map({})", entries.len()),
+            ObjectKind::Set(entries) => write!(f, "Set({})", entries.len()),
+            ObjectKind::WeakMap(_) => write!(f, "WeakMap"),
+            ObjectKind::WeakSet(_) => write!(f, "WeakSet"),
+            ObjectKind::Primitive(value) => write!(f, "Primitive({value:?})"),
+            ObjectKind::Proxy(_) => write!(f, "Proxy"),
+        }
+    }
+}
+
+impl fmt::Display for ObjectRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let object = self.borrow();
+        match &object.kind {
+            ObjectKind::Ordinary => write!(f, "[object Object]"),
+            ObjectKind::Array(elements) => {
+                // Arrays containing themselves are displayed as the empty string where they're nested, as by `join`
+                thread_local! {
+                    static DISPLAYING: RefCell<Vec<*const RefCell<Object>>> = const { RefCell::new(vec![]) };
+                }
+                let pointer = Rc::as_ptr(&self.0);
+                if DISPLAYING.with_borrow(|displaying| displaying.contains(&pointer)) {
+                    return Ok(());
+                }
+                DISPLAYING.with_borrow_mut(|displaying| displaying.push(pointer));
+                let elements: Vec<String> = (0..elements.length)
+                    .map(|index| match elements.dense.get(index) {
+                        Some(element) => Some(element.clone()),
+                        None => object.properties.get(&PropertyKey::String(index.to_string())).cloned().map(|property| match property {
+                            Property::Data(value) => value,
+                            Property::Accessor { .. } => Value::Undefined,
+                        }),
+                    })
+                    .map(|element| match element {
+                        None | Some(Value::Undefined | Value::Null) => String::new(),
+                        Some(element) => element.to_string(),
+                    })
+                    .collect();
+                DISPLAYING.with_borrow_mut(|displaying| displaying.pop());
+                write!(f, "{}", elements.join(","))
+            },
+            ObjectKind::Function(function) if function.generator => write!(f, "function* {}() {{ [code] }}", function.name),
+            ObjectKind::Function(function) if function.is_async => write!(f, "async function {}() {{ [code] }}", function.name),
+            ObjectKind::Function(function) => write!(f, "function {}() {{ [code] }}", function.name),
+            ObjectKind::NativeFunction(function) => write!(f, "function {}() {{ [native code] }}", function.name),
+            ObjectKind::BoundFunction(_) => write!(f, "function () {{ [native code] }}"),
+            ObjectKind::Generator(_) => write!(f, "[object Generator]"),
+            ObjectKind::Promise(_) => write!(f, "[object Promise]"),
+            ObjectKind::RegExp(regexp) => write!(f, "/{}/{}", regexp.source, regexp.flags),
+            ObjectKind::Date(_) => write!(f, "[object Date]"),
+            ObjectKind::Map(_) => write!(f, "[object Map]"),
+            ObjectKind::Set(_) => write!(f, "[object Set]"),
+            ObjectKind::WeakMap(_) => write!(f, "[object WeakMap]"),
+            ObjectKind::WeakSet(_) => write!(f, "[object WeakSet]"),
+            ObjectKind::Primitive(value) => write!(f, "{value}"),
+            ObjectKind::Proxy(proxy) if proxy.target.is_callable() => write!(f, "function () {{ [native code] }}"),
+            ObjectKind::Proxy(_) => write!(f, "[object Object]"),
+            ObjectKind::Error => {
+                // Mirrors Error.prototype.toString
+                let name = self.get_data_value("name").unwrap_or(Value::String("Error".into()));
+                let message = self.get_data_value("message").unwrap_or(Value::String(JsString::default()));
+                match (name.to_string(), message.to_string()) {
+                    (name, message) if message.is_empty() => write!(f, "{name}"),
+                    (name, message) if name.is_empty() => write!(f, "{message}"),
+                    (name, message) => write!(f, "{name}: {message}"),
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_drop_deep_object_graph() {
+        // Linked list, nested arrays & prototype chain, each deeper than the stack allows recursing
+        let mut list = Value::Null;
+        let mut nested = ObjectRef::new(ObjectKind::Array(ArrayElements::from(vec![])), None);
+        let mut chain = ObjectRef::new(ObjectKind::Ordinary, None);
+        for _ in 0..200_000 {
+            let node = ObjectRef::new(ObjectKind::Ordinary, None);
+            node.borrow_mut().properties.insert(PropertyKey::from("next"), Property::Data(list));
+            list = Value::Object(node);
+            nested = ObjectRef::new(ObjectKind::Array(ArrayElements::from(vec![Value::Object(nested)])), None);
+            chain = ObjectRef::new(ObjectKind::Ordinary, Some(chain));
+        }
+
+        // Objects referenced elsewhere outlive the dropped graph
+        let shared = chain.prototype().expect("Prototype of the chain");
+        drop((list, nested, chain));
+        assert!(shared.prototype().is_some());
+    }
+}
@@ -20,6 +20,7 @@ impl Eatable for Parser {
         
         // Advance to the next token.
         let token = self.lookahead.clone();
+        self.previous_end = token.end;
         self.lookahead = self.tokenizer.get_next_token()?;
         Ok(token)
     }
@@ -58,6 +58,7 @@ mod tests {
                     }),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "3 - 2 + 1;");
     }
@@ -0,0 +1,160 @@
+use crate::prelude::*;
+
+use super::assignment::AssignmentExpressionParsable;
+use super::spread::SpreadElementParsable;
+
+pub trait ArrayExpressionParsable {
+    /**
+     * ArrayExpression
+     *  : '[' OptElementList ']'
+     *  ;
+     */
+    fn array_expression(&mut self) -> Result<Tree>;
+
+    /**
+     * ElementList
+     *  : Element
+     *  | ElementList ',' Element
+     *  ;
+     */
+    fn element_list(&mut self) -> Result<Vec<Tree>>;
+
+    /**
+     * Element
+     *  : AssignmentExpression
+     *  | SpreadElement
+     *  ;
+     */
+    fn element(&mut self) -> Result<Tree>;
+}
+
+impl ArrayExpressionParsable for Parser {
+    fn array_expression(&mut self) -> Result<Tree> {
+        self.eat(TokenType::SquareBracketOpen)?;
+
+        // OptElementList
+        let elements = match self.lookahead.token_type {
+            TokenType::SquareBracketClose => vec![],
+            _ => self.element_list()?,
+        };
+
+        self.eat(TokenType::SquareBracketClose)?;
+
+        Ok(Tree::ArrayExpression { elements })
+    }
+
+    fn element_list(&mut self) -> Result<Vec<Tree>> {
+        let mut elements = vec![self.element()?];
+
+        while self.lookahead.token_type == TokenType::Comma {
+            self.eat(TokenType::Comma)?;
+
+            // Allowing trailing comma -> [1, 2,]
+            if self.lookahead.token_type == TokenType::SquareBracketClose {
+                break;
+            }
+            elements.push(self.element()?);
+        }
+
+        Ok(elements)
+    }
+
+    fn element(&mut self) -> Result<Tree> {
+        match self.lookahead.token_type {
+            TokenType::Spread => self.spread_element(),
+            _ => self.assignment_expression(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_empty_array_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::ArrayExpression { elements: vec![] }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "[];");
+    }
+
+    #[test]
+    fn test_parse_array_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::ArrayExpression {
+                        elements: vec![
+                            Tree::NumericLiteral { value: 1.0 },
+                            Tree::BinaryExpression {
+                                operator: String::from("+"),
+                                left: Box::new(Tree::Identifier { name: String::from("x") }),
+                                right: Box::new(Tree::NumericLiteral { value: 2.0 }),
+                            },
+                            Tree::ArrayExpression { elements: vec![] },
+                        ],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "[1, x + 2, [],];");
+    }
+
+    #[test]
+    fn test_parse_spread_array_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::ArrayExpression {
+                        elements: vec![
+                            Tree::SpreadElement {
+                                argument: Box::new(Tree::Identifier { name: String::from("a") }),
+                            },
+                            Tree::NumericLiteral { value: 1.0 },
+                            Tree::SpreadElement {
+                                argument: Box::new(Tree::Identifier { name: String::from("b") }),
+                            },
+                        ],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "[...a, 1, ...b];");
+    }
+
+    #[test]
+    fn test_parse_computed_member_of_array_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::MemberExpression {
+                        object: Box::new(Tree::ArrayExpression {
+                            elements: vec![
+                                Tree::NumericLiteral { value: 1.0 },
+                            ],
+                        }),
+                        property: Box::new(Tree::NumericLiteral { value: 0.0 }),
+                        computed: true,
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "[1][0];");
+    }
+
+    #[test]
+    fn test_parse_unclosed_array_expression() {
+        let expected = Error::Syntax("Unexpected token SemiColon, expected SquareBracketClose!".to_string());
+        assert_syntax_error(expected, "[1, 2;");
+    }
+}
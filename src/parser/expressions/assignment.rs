@@ -1,11 +1,14 @@
 use crate::prelude::*;
 
+use super::generator::YieldExpressionParsable;
+use super::identifier::IdentifierParsable;
 use super::logical::LogicalExpressionParsable;
 
 pub trait AssignmentExpressionParsable {
     /**
      * AssignmentExpression
-     *  : LogicalOrExpression
+     *  : YieldExpression
+     *  | LogicalOrExpression
      *  | LeftHandSideExpression ASSIGNMENT_OPERATOR AssignmentExpression
      *  ;
      */
@@ -32,6 +35,11 @@ pub trait AssignmentExpressionParsable {
 
 impl AssignmentExpressionParsable for Parser {
     fn assignment_expression(&mut self) -> Result<Tree> {
+        // `yield` is a regular identifier outside of generators
+        if self.generator && self.is_contextual_keyword("yield") {
+            return self.yield_expression();
+        }
+
         let mut left = self.logical_or_expression()?;
 
         // Checking if the lookahead token is not of assignment type
@@ -93,6 +101,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "num = 42;");
     }
@@ -109,6 +118,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "str = 'Hello, World!';");
     }
@@ -129,6 +139,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "xyz = 2 + 3;");
     }
@@ -149,6 +160,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x = y = 42;");
     }
@@ -165,6 +177,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "num += 42;");
     }
@@ -181,6 +194,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "num -= 42;");
     }
@@ -197,6 +211,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "num *= 42;");
     }
@@ -213,6 +228,7 @@ mod tests {
                     }), 
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "num /= 42;");
     }
@@ -230,6 +246,7 @@ mod tests {
         let expected = Tree::Program {
             body: vec![
                 Tree::VariableStatement {
+                    kind: String::from("let"),
                     declarations: vec![
                         Tree::VariableDeclaration {
                             identifier: Box::new(Tree::Identifier { name: String::from("isSomething") }),
@@ -254,6 +271,7 @@ mod tests {
                     ],
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "let isSomething = 50 < value + 5 * 2 == true;");
     }
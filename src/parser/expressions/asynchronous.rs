@@ -0,0 +1,162 @@
+use crate::prelude::*;
+
+use super::unary::UnaryExpressionParsable;
+
+pub trait AwaitExpressionParsable {
+    /**
+     * AwaitExpression
+     *  : 'await' UnaryExpression
+     *  ;
+     */
+    fn await_expression(&mut self) -> Result<Tree>;
+}
+
+impl AwaitExpressionParsable for Parser {
+    fn await_expression(&mut self) -> Result<Tree> {
+        self.eat(TokenType::Identifier)?;
+
+        Ok(Tree::AwaitExpression {
+            argument: Box::new(self.unary_expression()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_async_function_declaration() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::FunctionDeclaration {
+                    identifier: Box::new(Tree::Identifier { name: String::from("load") }),
+                    params: Rc::from([]),
+                    body: Rc::new(Tree::BlockStatement {
+                        body: vec![
+                            Tree::ReturnStatement {
+                                argument: Box::new(Some(Tree::BinaryExpression {
+                                    operator: String::from("+"),
+                                    left: Box::new(Tree::AwaitExpression {
+                                        argument: Box::new(Tree::CallExpression {
+                                            callee: Box::new(Tree::Identifier { name: String::from("fetch") }),
+                                            arguments: vec![],
+                                        }),
+                                    }),
+                                    right: Box::new(Tree::NumericLiteral { value: 1.0 }),
+                                })),
+                            },
+                        ],
+                    }),
+                    generator: false,
+                    is_async: true,
+                    strict: false,
+                    source: Rc::from("async function load() { return await fetch() + 1; }"),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "async function load() { return await fetch() + 1; }");
+    }
+
+    #[test]
+    fn test_parse_async_function_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::FunctionExpression {
+                        identifier: Box::new(None),
+                        params: Rc::from([]),
+                        body: Rc::new(Tree::BlockStatement { body: vec![] }),
+                        generator: false,
+                        is_async: true,
+                        strict: false,
+                        source: Rc::from("async function() {}"),
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "(async function() {});");
+    }
+
+    #[test]
+    fn test_parse_async_methods() {
+        let method = |name: &str, is_async: bool, source: &str| Tree::MethodDefinition {
+            key: Box::new(Tree::Identifier { name: String::from(name) }),
+            computed: false,
+            is_static: false,
+            value: Box::new(Tree::FunctionExpression {
+                identifier: Box::new(None),
+                params: Rc::from([]),
+                body: Rc::new(Tree::BlockStatement { body: vec![] }),
+                generator: false,
+                is_async,
+                strict: true,
+                source: Rc::from(source),
+            }),
+        };
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ClassDeclaration {
+                    idenifier: Box::new(Tree::Identifier { name: String::from("Api") }),
+                    super_class: Box::new(None),
+                    body: Box::new(Tree::ClassBody {
+                        body: vec![method("load", true, "async load() {}"), method("async", false, "async() {}")],
+                    }),
+                    source: Rc::from("class Api { async load() {} async() {} }"),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "class Api { async load() {} async() {} }");
+    }
+
+    #[test]
+    fn test_parse_await_outside_async_function() {
+        let expected = || Error::Syntax("Unexpected token Identifier, expected SemiColon!".to_string());
+        assert_syntax_error(expected(), "await x;");
+        assert_syntax_error(expected(), "function f() { await x; }");
+        assert_syntax_error(expected(), "async function f() { function g() { await x; } }");
+    }
+
+    #[test]
+    fn test_parse_async_and_await_as_identifiers() {
+        let declaration = |name: &str, value: f64| Tree::VariableStatement {
+            kind: String::from("let"),
+            declarations: vec![
+                Tree::VariableDeclaration {
+                    identifier: Box::new(Tree::Identifier { name: String::from(name) }),
+                    init: Box::new(Some(Tree::NumericLiteral { value })),
+                },
+            ],
+        };
+        let expected = Tree::Program {
+            body: vec![
+                declaration("async", 1.0),
+                declaration("await", 2.0),
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::BinaryExpression {
+                        operator: String::from("+"),
+                        left: Box::new(Tree::Identifier { name: String::from("async") }),
+                        right: Box::new(Tree::Identifier { name: String::from("await") }),
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "let async = 1; let await = 2; async + await;");
+
+        let expected = Error::Syntax("Unexpected reserved word 'await'!".to_string());
+        assert_syntax_error(expected, "async function f() { let await = 1; }");
+    }
+
+    #[test]
+    fn test_parse_async_generator() {
+        let expected = Error::Syntax("Async generator functions are not supported!".to_string());
+        assert_syntax_error(expected, "async function* gen() {}");
+    }
+}
@@ -2,6 +2,7 @@ use crate::prelude::*;
 
 use super::assignment::AssignmentExpressionParsable;
 use super::member::MemberExpressionParsable;
+use super::spread::SpreadElementParsable;
 
 pub trait CallExpressionParsable {
     /**
@@ -15,6 +16,8 @@ pub trait CallExpressionParsable {
     /**
      * CallExpression
      *  : Callee Arguments
+     *  | CallExpression '.' PropertyName
+     *  | CallExpression '[' Expression ']'
      *  ;
      * 
      * Callee
@@ -33,11 +36,19 @@ pub trait CallExpressionParsable {
 
     /**
      * ArgumentList
-     *  : AssignmentExpression
-     *  | ArgumentList ',' AssignmentExpression
+     *  : Argument
+     *  | ArgumentList ',' Argument
      *  ;
      */
     fn argument_list(&mut self) -> Result<Vec<Tree>>;
+
+    /**
+     * Argument
+     *  : AssignmentExpression
+     *  | SpreadElement
+     *  ;
+     */
+    fn argument(&mut self) -> Result<Tree>;
 }
 
 impl CallExpressionParsable for Parser {
@@ -55,14 +66,17 @@ impl CallExpressionParsable for Parser {
     }
 
     fn call_expression(&mut self, callee: Tree) -> Result<Tree> {
-        let mut call_expression = Tree::CallExpression {
+        let call_expression = Tree::CallExpression {
             callee: Box::new(callee),
             arguments: self.arguments()?,
         };
 
+        // Accessing properties of the result -> callback().x
+        let call_expression = self.member_properties(call_expression)?;
+
         // Recursively checking if chained functions are called -> callback()();
         if self.lookahead.token_type == TokenType::CircleBracketOpen {
-            call_expression = self.call_expression(call_expression)?;
+            return self.call_expression(call_expression);
         }
 
         Ok(call_expression)
@@ -86,7 +100,7 @@ impl CallExpressionParsable for Parser {
 
         // Consuming arguments until we hit the ')' token
         while self.lookahead.token_type != TokenType::CircleBracketClose {
-            arguments.push(self.assignment_expression()?);
+            arguments.push(self.argument()?);
 
             // Consuming Commas
             if self.lookahead.token_type == TokenType::Comma {
@@ -96,6 +110,13 @@ impl CallExpressionParsable for Parser {
 
         Ok(arguments)
     }
+
+    fn argument(&mut self) -> Result<Tree> {
+        match self.lookahead.token_type {
+            TokenType::Spread => self.spread_element(),
+            _ => self.assignment_expression(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -116,6 +137,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "foo(x);");
     }
@@ -136,6 +158,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "foo(x)();");
     }
@@ -166,7 +189,59 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "console.log(x > 42, y = true);");
     }
+
+    #[test]
+    fn test_parse_spread_call_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::CallExpression {
+                        callee: Box::new(Tree::Identifier { name: String::from("foo") }),
+                        arguments: vec![
+                            Tree::Identifier { name: String::from("x") },
+                            Tree::SpreadElement {
+                                argument: Box::new(Tree::Identifier { name: String::from("args") }),
+                            },
+                            Tree::SpreadElement {
+                                argument: Box::new(Tree::ArrayExpression {
+                                    elements: vec![
+                                        Tree::NumericLiteral { value: 1.0 },
+                                    ],
+                                }),
+                            },
+                        ],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "foo(x, ...args, ...[1]);");
+    }
+
+    #[test]
+    fn test_parse_member_of_call_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::CallExpression {
+                        callee: Box::new(Tree::MemberExpression {
+                            object: Box::new(Tree::CallExpression {
+                                callee: Box::new(Tree::Identifier { name: String::from("a") }),
+                                arguments: vec![],
+                            }),
+                            property: Box::new(Tree::Identifier { name: String::from("b") }),
+                            computed: false,
+                        }),
+                        arguments: vec![],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "a().b();");
+    }
 }
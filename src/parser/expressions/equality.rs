@@ -58,6 +58,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x > 0 == true;");
     }
@@ -78,6 +79,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x <= 0 != false;");
     }
@@ -94,6 +96,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "true != false;");
     }
@@ -0,0 +1,84 @@
+use crate::prelude::*;
+
+use super::identifier::IdentifierParsable;
+use super::unary::UnaryExpressionParsable;
+
+pub trait ExponentiationExpressionParsable {
+    /**
+     * ExponentiationExpression
+     *  : UnaryExpression
+     *  | LeftHandSideExpression EXPONENTIATION_OPERATOR ExponentiationExpression
+     *  ;
+     * 
+     * NOTE: Unlike other binary operators, `**` is right associative, i.e. `2 ** 3 ** 2`
+     * is `2 ** (3 ** 2)`. A unary expression such as `-2` can't be its left operand
+     * without parentheses, as it would be unclear which operator applies first.
+     */
+    fn exponentiation_expression(&mut self) -> Result<Tree>;
+}
+
+impl ExponentiationExpressionParsable for Parser {
+    fn exponentiation_expression(&mut self) -> Result<Tree> {
+        let unary = matches!(self.lookahead.token_type, TokenType::AdditiveOperator | TokenType::LogicalNotOperator)
+            || (self.is_async && self.is_contextual_keyword("await"));
+        let left = self.unary_expression()?;
+        if self.lookahead.token_type != TokenType::ExponentiationOperator {
+            return Ok(left);
+        }
+        if unary {
+            return Err(Error::Syntax(
+                "Unary operator used immediately before exponentiation expression, parentheses must be used to disambiguate operator precedence!".to_string()
+            ));
+        }
+
+        let operator = self.eat(TokenType::ExponentiationOperator)?.value;
+
+        // Enforcing right associativity
+        let right = self.exponentiation_expression()?;
+        Ok(Tree::BinaryExpression {
+            operator,
+            left: Box::new(left),
+            right: Box::new(right),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_exponentiation_expressions() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::BinaryExpression {
+                        operator: String::from("%"),
+                        left: Box::new(Tree::BinaryExpression {
+                            operator: String::from("**"),
+                            left: Box::new(Tree::NumericLiteral { value: 2.0 }),
+                            right: Box::new(Tree::BinaryExpression {
+                                operator: String::from("**"),
+                                left: Box::new(Tree::NumericLiteral { value: 3.0 }),
+                                right: Box::new(Tree::NumericLiteral { value: 2.0 }),
+                            }),
+                        }),
+                        right: Box::new(Tree::NumericLiteral { value: 5.0 }),
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "2 ** 3 ** 2 % 5;");
+    }
+
+    #[test]
+    fn test_parse_unary_exponentiation_base() {
+        let expected = Error::Syntax(
+            "Unary operator used immediately before exponentiation expression, parentheses must be used to disambiguate operator precedence!".to_string()
+        );
+        assert_syntax_error(expected, "-2 ** 2;");
+        assert!(Parser::new("(-2) ** 2;".to_string()).unwrap().parse().is_ok());
+    }
+}
@@ -1,13 +1,14 @@
 use crate::prelude::*;
 
+use std::rc::Rc;
+
 use super::identifier::IdentifierParsable;
-use super::statements::block::BlockStatementParsable;
-use super::statements::function::FunctionDeclarationParsable;
+use super::statements::function::{check_function_modifiers, FunctionDeclarationParsable};
 
 pub trait FunctionExpressionParsable {
     /**
      * FunctionExpression
-     *  : 'function' OptIdentifier '(' OptFormalParameterList ')' BlockStatement
+     *  : OptAsync 'function' OptGenerator OptIdentifier '(' OptFormalParameterList ')' BlockStatement
      *  ;
      */
     fn function_expression(&mut self) -> Result<Tree>;
@@ -15,7 +16,11 @@ pub trait FunctionExpressionParsable {
 
 impl FunctionExpressionParsable for Parser {
     fn function_expression(&mut self) -> Result<Tree> {
+        let start = self.lookahead.start;
+        let is_async = self.async_modifier()?;
         self.eat(TokenType::FunctionKeyword)?;
+        let generator = self.generator()?;
+        check_function_modifiers(generator, is_async)?;
 
         let identifier = match self.lookahead.token_type {
             TokenType::CircleBracketOpen => None,
@@ -29,18 +34,24 @@ impl FunctionExpressionParsable for Parser {
 
         self.eat(TokenType::CircleBracketClose)?;
 
-        let body = self.block_statement()?;
+        let (body, strict) = self.function_body(&params, generator, is_async)?;
 
         Ok(Tree::FunctionExpression {
             identifier: Box::new(identifier),
-            params,
-            body: Box::new(body),
+            params: Rc::from(params),
+            body: Rc::new(body),
+            generator,
+            is_async,
+            strict,
+            source: self.source_since(start),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
     use crate::prelude::*;
     use crate::parser::tests::*;
 
@@ -54,10 +65,10 @@ mod tests {
                         left: Box::new(Tree::Identifier { name: String::from("square") }),
                         right: Box::new(Tree::FunctionExpression {
                             identifier: Box::new(None),
-                            params: vec![
+                            params: Rc::from([
                                 Tree::Identifier { name: String::from("x") },
-                            ],
-                            body: Box::new(Tree::BlockStatement {
+                            ]),
+                            body: Rc::new(Tree::BlockStatement {
                                 body: vec![
                                     Tree::ReturnStatement {
                                         argument: Box::new(Some(Tree::BinaryExpression {
@@ -68,10 +79,15 @@ mod tests {
                                     },
                                 ],
                             }),
+                            generator: false,
+                            is_async: false,
+                            strict: false,
+                            source: Rc::from("function (x) { return x * x; }"),
                         }),
                     }),
                 }
             ],
+            strict: false,
         };
         assert_tree(expected, "square = function (x) { return x * x; };");
     }
@@ -86,14 +102,19 @@ mod tests {
                         left: Box::new(Tree::Identifier { name: String::from("x") }),
                         right: Box::new(Tree::FunctionExpression {
                             identifier: Box::new(Some(Tree::Identifier { name: String::from("y") })),
-                            params: vec![],
-                            body: Box::new(Tree::BlockStatement {
+                            params: Rc::from([]),
+                            body: Rc::new(Tree::BlockStatement {
                                 body: vec![],
                             }),
+                            generator: false,
+                            is_async: false,
+                            strict: false,
+                            source: Rc::from("function y() {}"),
                         }),
                     }),
                 }
             ],
+            strict: false,
         };
         assert_tree(expected, "x = function y() {};");
     }
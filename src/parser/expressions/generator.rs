@@ -0,0 +1,195 @@
+use crate::prelude::*;
+
+use super::assignment::AssignmentExpressionParsable;
+
+pub trait YieldExpressionParsable {
+    /**
+     * YieldExpression
+     *  : 'yield'
+     *  | 'yield' AssignmentExpression
+     *  | 'yield' '*' AssignmentExpression
+     *  ;
+     */
+    fn yield_expression(&mut self) -> Result<Tree>;
+}
+
+impl YieldExpressionParsable for Parser {
+    fn yield_expression(&mut self) -> Result<Tree> {
+        self.eat(TokenType::Identifier)?;
+
+        // Delegating to another iterable, i.e. `yield* iterable`
+        let delegate = self.lookahead.token_type == TokenType::MultiplicativeOperator && self.lookahead.value == "*";
+        if delegate {
+            self.eat(TokenType::MultiplicativeOperator)?;
+        }
+
+        // The argument is optional unless delegating, e.g. `let x = yield;`
+        let argument = match self.lookahead.token_type {
+            TokenType::SemiColon
+            | TokenType::Colon
+            | TokenType::Comma
+            | TokenType::CircleBracketClose
+            | TokenType::SquareBracketClose
+            | TokenType::CurlyBracketClose
+            | TokenType::Eof if !delegate => None,
+            _ => Some(self.assignment_expression()?),
+        };
+
+        Ok(Tree::YieldExpression {
+            argument: Box::new(argument),
+            delegate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    fn generator_declaration(body: Vec<Tree>, source: &str) -> Tree {
+        Tree::Program {
+            body: vec![
+                Tree::FunctionDeclaration {
+                    identifier: Box::new(Tree::Identifier { name: String::from("gen") }),
+                    params: Rc::from([]),
+                    body: Rc::new(Tree::BlockStatement { body }),
+                    generator: true,
+                    is_async: false,
+                    strict: false,
+                    source: Rc::from(source),
+                },
+            ],
+            strict: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_yield_expression() {
+        let expected = generator_declaration(vec![
+            Tree::ExpressionStatement {
+                expression: Box::new(Tree::YieldExpression {
+                    argument: Box::new(Some(Tree::BinaryExpression {
+                        operator: String::from("+"),
+                        left: Box::new(Tree::NumericLiteral { value: 1.0 }),
+                        right: Box::new(Tree::NumericLiteral { value: 2.0 }),
+                    })),
+                    delegate: false,
+                }),
+            },
+        ], "function* gen() { yield 1 + 2; }");
+        assert_tree(expected, "function* gen() { yield 1 + 2; }");
+    }
+
+    #[test]
+    fn test_parse_yield_without_argument() {
+        let expected = generator_declaration(vec![
+            Tree::VariableStatement {
+                kind: String::from("let"),
+                declarations: vec![
+                    Tree::VariableDeclaration {
+                        identifier: Box::new(Tree::Identifier { name: String::from("x") }),
+                        init: Box::new(Some(Tree::YieldExpression {
+                            argument: Box::new(None),
+                            delegate: false,
+                        })),
+                    },
+                ],
+            },
+        ], "function *gen() { let x = yield; }");
+        assert_tree(expected, "function *gen() { let x = yield; }");
+    }
+
+    #[test]
+    fn test_parse_delegating_yield() {
+        let expected = generator_declaration(vec![
+            Tree::ExpressionStatement {
+                expression: Box::new(Tree::YieldExpression {
+                    argument: Box::new(Some(Tree::Identifier { name: String::from("other") })),
+                    delegate: true,
+                }),
+            },
+        ], "function*gen() { yield* other; }");
+        assert_tree(expected, "function*gen() { yield* other; }");
+    }
+
+    #[test]
+    fn test_parse_generator_method() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ClassDeclaration {
+                    idenifier: Box::new(Tree::Identifier { name: String::from("Range") }),
+                    super_class: Box::new(None),
+                    body: Box::new(Tree::ClassBody {
+                        body: vec![
+                            Tree::MethodDefinition {
+                                key: Box::new(Tree::Identifier { name: String::from("values") }),
+                                computed: false,
+                                is_static: false,
+                                value: Box::new(Tree::FunctionExpression {
+                                    identifier: Box::new(None),
+                                    params: Rc::from([]),
+                                    body: Rc::new(Tree::BlockStatement {
+                                        body: vec![
+                                            Tree::ExpressionStatement {
+                                                expression: Box::new(Tree::YieldExpression {
+                                                    argument: Box::new(None),
+                                                    delegate: false,
+                                                }),
+                                            },
+                                        ],
+                                    }),
+                                    generator: true,
+                                    is_async: false,
+                                    strict: true,
+                                    source: Rc::from("*values() { yield; }"),
+                                }),
+                            },
+                        ],
+                    }),
+                    source: Rc::from("class Range { *values() { yield; } }"),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "class Range { *values() { yield; } }");
+    }
+
+    #[test]
+    fn test_parse_yield_outside_generator() {
+        let expected = || Error::Syntax("Unexpected token Number, expected SemiColon!".to_string());
+        assert_syntax_error(expected(), "yield 1;");
+        assert_syntax_error(expected(), "function f() { yield 1; }");
+        assert_syntax_error(expected(), "function* gen() { function f() { yield 1; } }");
+    }
+
+    #[test]
+    fn test_parse_yield_as_identifier() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::FunctionDeclaration {
+                    identifier: Box::new(Tree::Identifier { name: String::from("f") }),
+                    params: Rc::from([Tree::Identifier { name: String::from("yield") }]),
+                    body: Rc::new(Tree::BlockStatement {
+                        body: vec![
+                            Tree::ReturnStatement {
+                                argument: Box::new(Some(Tree::Identifier { name: String::from("yield") })),
+                            },
+                        ],
+                    }),
+                    generator: false,
+                    is_async: false,
+                    strict: false,
+                    source: Rc::from("function f(yield) { return yield; }"),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "function f(yield) { return yield; }");
+
+        let expected = Error::Syntax("Unexpected reserved word 'yield'!".to_string());
+        assert_syntax_error(expected, "function* gen() { let yield = 1; }");
+    }
+}
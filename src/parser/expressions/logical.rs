@@ -85,6 +85,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x && y;");
     }
@@ -101,6 +102,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x || y;");
     }
@@ -133,6 +135,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x > 0 || y < 0 && z == null;");
     }
@@ -8,26 +8,42 @@ pub trait MemberExpressionParsable {
     /**
      * MemberExpression
      *  : PrimaryExpression
-     *  | MemberExpression '.' Identifier
+     *  | MemberExpression '.' PropertyName
+     *  | MemberExpression '.' PrivateIdentifier
      *  | MemberExpression '[' Expression ']'
      *  ;
      */
     fn member_expression(&mut self) -> Result<Tree>;
+
+    /**
+     * Applies the chained property accesses to an object,
+     * e.g. `.y[z]` of `x().y[z]`.
+     */
+    fn member_properties(&mut self, object: Tree) -> Result<Tree>;
 }
 
 impl MemberExpressionParsable for Parser {
     fn member_expression(&mut self) -> Result<Tree> {
-        let mut object = self.primary_expression()?;
+        let object = self.primary_expression()?;
+
+        // Either PrimaryExpression or MemberExpression
+        self.member_properties(object)
+    }
 
+    fn member_properties(&mut self, mut object: Tree) -> Result<Tree> {
         while self.lookahead.token_type == TokenType::Dot || self.lookahead.token_type == TokenType::SquareBracketOpen {
             match self.lookahead.token_type {
 
-                // Non-computed member expression '.' -> x.y
+                // Non-computed member expression '.' -> x.y or x.#y
                 TokenType::Dot => {
                     self.eat(TokenType::Dot)?;
+                    let property = match self.lookahead.token_type {
+                        TokenType::PrivateIdentifier => self.private_identifier()?,
+                        _ => self.property_name()?,
+                    };
                     object = Tree::MemberExpression {
                         object: Box::new(object),
-                        property: Box::new(self.identifier()?),
+                        property: Box::new(property),
                         computed: false,
                     };
                 },
@@ -45,7 +61,6 @@ impl MemberExpressionParsable for Parser {
             }
         }
 
-        // Either PrimaryExpression or MemberExpression
         Ok(object)
     }
 }
@@ -67,6 +82,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "str.length;");
     }
@@ -87,6 +103,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x.y = 1;");
     }
@@ -107,6 +124,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "arr[0] = 1;");
     }
@@ -131,6 +149,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "a.b.c['d'];");
     }
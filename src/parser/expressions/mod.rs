@@ -1,10 +1,14 @@
 use super::*;
 
 mod additive;
+mod array;
+mod asynchronous;
 pub mod assignment;
 mod call;
 mod equality;
+mod exponentiation;
 pub mod function;
+mod generator;
 mod lhs;
 mod logical;
 mod member;
@@ -13,4 +17,5 @@ mod new;
 mod paranthesized;
 mod primary;
 mod relational;
+mod spread;
 mod unary;
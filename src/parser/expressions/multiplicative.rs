@@ -1,30 +1,30 @@
 use crate::prelude::*;
 
-use super::unary::UnaryExpressionParsable;
+use super::exponentiation::ExponentiationExpressionParsable;
 
 pub trait MultiplicativeExpressionParsable {
     /**
      * MultiplicativeExpression
-     *  : UnaryExpression
-     *  | MultiplicativeExpression MULTIPLICATIVE_OPERATOR UnaryExpression
+     *  : ExponentiationExpression
+     *  | MultiplicativeExpression MULTIPLICATIVE_OPERATOR ExponentiationExpression
      *  ;
      * 
-     * NOTE: Since UnaryExpression has higher presidence over MultiplicativeExpression
-     * the left and right sub-tree of MultiplicativeExpression looks for a UnaryExpression.
+     * NOTE: Since ExponentiationExpression has higher presidence over MultiplicativeExpression
+     * the left and right sub-tree of MultiplicativeExpression looks for an ExponentiationExpression.
      */
     fn multiplicative_expression(&mut self) -> Result<Tree>;
 }
 
 impl MultiplicativeExpressionParsable for Parser {
     fn multiplicative_expression(&mut self) -> Result<Tree> {
-        let mut left = self.unary_expression()?;
+        let mut left = self.exponentiation_expression()?;
 
         while self.lookahead.token_type == TokenType::MultiplicativeOperator {
-            // Operator: *, /
+            // Operator: *, /, %
             let operator = self.eat(TokenType::MultiplicativeOperator)?.value;
 
             // Extracting the right literal
-            let right = self.unary_expression()?;
+            let right = self.exponentiation_expression()?;
             
             // Enforcing left associativity
             left = Tree::BinaryExpression { 
@@ -58,6 +58,7 @@ mod tests {
                     }),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "3 / 2 * 1;");
     }
@@ -78,6 +79,7 @@ mod tests {
                     }),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "3 + 2 / 1;");
     }
@@ -41,6 +41,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "new Point(x, y);");
     }
@@ -60,7 +61,28 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "new MyNamespace.MyClass();");
     }
+
+    #[test]
+    fn test_parse_spread_new_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::NewExpression {
+                        callee: Box::new(Tree::Identifier { name: String::from("Point") }),
+                        arguments: vec![
+                            Tree::SpreadElement {
+                                argument: Box::new(Tree::Identifier { name: String::from("xs") }),
+                            },
+                        ],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "new Point(...xs);");
+    }
 }
@@ -41,6 +41,7 @@ mod tests {
                     }),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "(3 + 2) / 1;");
     }
@@ -1,6 +1,8 @@
 use crate::prelude::*;
 
+use super::array::ArrayExpressionParsable;
 use super::function::FunctionExpressionParsable;
+use super::statements::function::FunctionDeclarationParsable;
 use super::identifier::IdentifierParsable;
 use super::literal::LiteralParsable;
 use super::new::NewExpressionParsable;
@@ -12,6 +14,7 @@ pub trait PrimaryExpressionParsable {
      *  : ParanthesizedExpression
      *  | FunctionExpression
      *  | NewExpression
+     *  | ArrayExpression
      *  | Literal
      *  | Identifier
      *  ;
@@ -24,14 +27,20 @@ impl PrimaryExpressionParsable for Parser {
         match self.lookahead.token_type {
             TokenType::CircleBracketOpen => self.paranthesized_expression(),
             TokenType::FunctionKeyword => self.function_expression(),
+            TokenType::Identifier if self.is_async_function() => self.function_expression(),
             TokenType::NewKeyword => self.new_expression(),
+            TokenType::SquareBracketOpen => self.array_expression(),
             TokenType::Number 
+            | TokenType::BigInt
             | TokenType::String 
+            | TokenType::RegularExpression
             | TokenType::TrueKeyword 
             | TokenType::FalseKeyword 
             | TokenType::NullKeyword 
             | TokenType::ThisKeyword 
             | TokenType::SuperKeyword => self.literal(),
+            TokenType::MultiplicativeOperator
+            | TokenType::ComplexAssignmentOperator if self.lookahead.value.starts_with('/') => self.literal(),
             _ => self.identifier(),
         }
     }
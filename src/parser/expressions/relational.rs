@@ -1,12 +1,14 @@
 use crate::prelude::*;
 
 use super::additive::AdditiveExpressionParsable;
+use super::identifier::IdentifierParsable;
 
 pub trait RelationalExpressionParsable {
     /**
      * RelationalExpression
      *  : AdditiveExpression
      *  | AdditiveExpression RELATIONAL_OPERATOR RelationalExpression
+     *  | PrivateIdentifier 'in' AdditiveExpression
      *  ;
      * 
      * NOTE: Since AdditiveExpression has higher presidence over RelationalExpression
@@ -17,10 +19,22 @@ pub trait RelationalExpressionParsable {
 
 impl RelationalExpressionParsable for Parser {
     fn relational_expression(&mut self) -> Result<Tree> {
-        let mut left = self.additive_expression()?;
+        let mut left = match self.lookahead.token_type {
+            // Checking whether an object has a private member -> #x in obj
+            TokenType::PrivateIdentifier => {
+                let name = self.private_identifier()?;
+                let operator = self.eat(TokenType::InKeyword)?.value;
+                Tree::BinaryExpression {
+                    operator,
+                    left: Box::new(name),
+                    right: Box::new(self.additive_expression()?),
+                }
+            },
+            _ => self.additive_expression()?,
+        };
 
         while self.lookahead.token_type == TokenType::RelationalOperator {
-            // Operator: <, >, <=, >=
+            // Operator: <, >, <=, >=, instanceof
             let operator = self.eat(TokenType::RelationalOperator)?.value;
 
             // Extracting the right literal
@@ -54,6 +68,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "x >= 42;");
     }
@@ -82,6 +97,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "y = (x + 10) * 3 > 100;");
     }
@@ -100,6 +116,7 @@ mod tests {
                     alternate: Box::new(None),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "if (x < 42) {}");
     }
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+
+use super::assignment::AssignmentExpressionParsable;
+
+pub trait SpreadElementParsable {
+    /**
+     * SpreadElement
+     *  : '...' AssignmentExpression
+     *  ;
+     */
+    fn spread_element(&mut self) -> Result<Tree>;
+}
+
+impl SpreadElementParsable for Parser {
+    fn spread_element(&mut self) -> Result<Tree> {
+        self.eat(TokenType::Spread)?;
+        Ok(Tree::SpreadElement {
+            argument: Box::new(self.assignment_expression()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_spread_element_with_expression() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::CallExpression {
+                        callee: Box::new(Tree::Identifier { name: String::from("foo") }),
+                        arguments: vec![
+                            Tree::SpreadElement {
+                                argument: Box::new(Tree::AssignmentExpression {
+                                    operator: String::from("="),
+                                    left: Box::new(Tree::Identifier { name: String::from("x") }),
+                                    right: Box::new(Tree::Identifier { name: String::from("y") }),
+                                }),
+                            },
+                        ],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "foo(...x = y);");
+    }
+
+    #[test]
+    fn test_parse_spread_element_outside_list() {
+        let expected = Error::Syntax("Unexpected token Spread, expected Identifier!".to_string());
+        assert_syntax_error(expected, "...x;");
+    }
+}
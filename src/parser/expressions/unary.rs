@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use super::asynchronous::AwaitExpressionParsable;
+use super::identifier::IdentifierParsable;
 use super::lhs::LeftHandSideExpressionParsable;
 
 pub trait UnaryExpressionParsable {
@@ -8,6 +10,7 @@ pub trait UnaryExpressionParsable {
      *  : LeftHandSideExpression
      *  | ADDITIVE_OPERATOR UnaryExpression
      *  | LOGICAL_NOT UnaryExpression
+     *  | AwaitExpression
      *  ;
      */
     fn unary_expression(&mut self) -> Result<Tree>;
@@ -15,6 +18,10 @@ pub trait UnaryExpressionParsable {
 
 impl UnaryExpressionParsable for Parser {
     fn unary_expression(&mut self) -> Result<Tree> {
+        // `await` is a regular identifier outside of async functions
+        if self.is_async && self.is_contextual_keyword("await") {
+            return self.await_expression();
+        }
         let operator = match self.lookahead.token_type {
             TokenType::AdditiveOperator => Some(self.eat(TokenType::AdditiveOperator)?),
             TokenType::LogicalNotOperator => Some(self.eat(TokenType::LogicalNotOperator)?),
@@ -46,6 +53,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "!x;");
     }
@@ -61,6 +69,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "+x;");
     }
@@ -76,6 +85,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "-x;");
     }
@@ -115,6 +125,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "y = !(a > 20 || b != null) && c > 0;");
     }
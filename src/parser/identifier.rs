@@ -7,11 +7,65 @@ pub trait IdentifierParsable {
      *  ;
      */
     fn identifier(&mut self) -> Result<Tree>;
+
+    /**
+     * PropertyName
+     *  : IDENTIFIER
+     *  | KEYWORD
+     *  ;
+     * 
+     * Keywords are valid property names, e.g. `iterator.return()`.
+     */
+    fn property_name(&mut self) -> Result<Tree>;
+
+    /**
+     * PrivateIdentifier
+     *  : PRIVATE_IDENTIFIER
+     *  ;
+     * 
+     * References a private name, which an enclosing class must declare.
+     */
+    fn private_identifier(&mut self) -> Result<Tree>;
+
+    /**
+     * Whether the lookahead is an identifier acting as a keyword in this context,
+     * e.g. `static` within a class body, which is a regular name elsewhere.
+     */
+    fn is_contextual_keyword(&self, keyword: &str) -> bool;
 }
 
 impl IdentifierParsable for Parser {
     fn identifier(&mut self) -> Result<Tree> {
+        // `yield` & `await` can't be bound within the functions giving them meaning
+        if (self.generator && self.is_contextual_keyword("yield")) || (self.is_async && self.is_contextual_keyword("await")) {
+            return Err(Error::Syntax(format!("Unexpected reserved word '{}'!", self.lookahead.value)));
+        }
         let name = self.eat(TokenType::Identifier)?.value;
         Ok(Tree::Identifier { name })
     }
+
+    fn property_name(&mut self) -> Result<Tree> {
+        let is_word = !matches!(self.lookahead.token_type, TokenType::Number | TokenType::BigInt)
+            && !self.lookahead.value.is_empty()
+            && self.lookahead.value.chars().all(|char| char.is_alphanumeric() || char == '_');
+        if !is_word {
+            return self.identifier();
+        }
+
+        let name = self.eat(self.lookahead.token_type.clone())?.value;
+        Ok(Tree::Identifier { name })
+    }
+
+    fn private_identifier(&mut self) -> Result<Tree> {
+        let name = self.eat(TokenType::PrivateIdentifier)?.value;
+        match self.private_scopes.last_mut() {
+            Some(scope) => scope.referenced.push(name.clone()),
+            None => return Err(Error::Syntax(format!("Private field '{name}' must be declared in an enclosing class!"))),
+        }
+        Ok(Tree::PrivateIdentifier { name })
+    }
+
+    fn is_contextual_keyword(&self, keyword: &str) -> bool {
+        self.lookahead.token_type == TokenType::Identifier && self.lookahead.value == keyword
+    }
 }
\ No newline at end of file
@@ -1,10 +1,14 @@
 use crate::prelude::*;
 
+use crate::interpreter::RegularExpression;
+
 pub trait LiteralParsable {
     /**
      * Literal
      *  : NumericLiteral
+     *  | BigIntLiteral
      *  | StringLiteral
+     *  | RegExpLiteral
      *  | BooleanLiteral
      *  | NullLiteral
      *  | ThisLiteral
@@ -20,6 +24,13 @@ pub trait LiteralParsable {
      */
     fn numeric_literal(&mut self) -> Result<Tree>;
 
+    /**
+     * BigIntLiteral
+     *  : BIGINT
+     *  ;
+     */
+    fn bigint_literal(&mut self) -> Result<Tree>;
+
     /**
      * StringLiteral
      *  : STRING
@@ -27,6 +38,13 @@ pub trait LiteralParsable {
      */
     fn string_literal(&mut self) -> Result<Tree>;
 
+    /**
+     * RegExpLiteral
+     *  : REGULAR_EXPRESSION
+     *  ;
+     */
+    fn regexp_literal(&mut self) -> Result<Tree>;
+
     /**
      * BooleanLiteral
      *  : 'true'
@@ -61,7 +79,11 @@ impl LiteralParsable for Parser {
     fn literal(&mut self) -> Result<Tree> {
         match self.lookahead.token_type {
             TokenType::Number => self.numeric_literal(),
+            TokenType::BigInt => self.bigint_literal(),
             TokenType::String => self.string_literal(),
+            TokenType::RegularExpression
+            | TokenType::MultiplicativeOperator
+            | TokenType::ComplexAssignmentOperator => self.regexp_literal(),
             TokenType::TrueKeyword | TokenType::FalseKeyword => self.boolean_literal(),
             TokenType::NullKeyword => self.null_literal(),
             TokenType::ThisKeyword => self.this_literal(),
@@ -72,12 +94,35 @@ impl LiteralParsable for Parser {
 
     fn numeric_literal(&mut self) -> Result<Tree> {
         let token = self.eat(TokenType::Number)?;
+
+        // Legacy octal literals, e.g. `010` -> 8, unless a digit isn't octal as in `019`
+        let legacy = token.value.len() > 1 && token.value.starts_with('0') && token.value.chars().all(|char| char.is_ascii_digit());
+        if legacy {
+            let octal = token.value.chars().all(|char| char < '8');
+            return match (self.strict, octal) {
+                (true, true) => Err(Error::Syntax("Octal literals are not allowed in strict mode!".to_string())),
+                (true, false) => Err(Error::Syntax("Decimals with leading zeros are not allowed in strict mode!".to_string())),
+                (false, true) => Ok(Tree::NumericLiteral {
+                    value: token.value.chars().fold(0.0, |value, digit| value * 8.0 + digit.to_digit(8).unwrap_or_default() as f64),
+                }),
+                (false, false) => Ok(Tree::NumericLiteral { value: token.value.parse().unwrap_or_default() }),
+            };
+        }
+
         match token.value.parse() {
             Err(_) => Err(Error::Syntax("Expected a parsable numeric value!".to_string())),
             Ok(parsed) => Ok(Tree::NumericLiteral { value: parsed })
         }
     }
 
+    fn bigint_literal(&mut self) -> Result<Tree> {
+        let token = self.eat(TokenType::BigInt)?;
+
+        // Removing the 'n' suffix, keeping the digits as is since they may exceed f64 precision
+        let value = String::from(&token.value[..(token.value.len()-1)]);
+        Ok(Tree::BigIntLiteral { value })
+    }
+
     fn string_literal(&mut self) -> Result<Tree> {
         let token = self.eat(TokenType::String)?;
 
@@ -86,6 +131,26 @@ impl LiteralParsable for Parser {
         Ok(Tree::StringLiteral { value })
     }
 
+    fn regexp_literal(&mut self) -> Result<Tree> {
+        // A slash starting an expression can only begin a regular expression
+        if self.lookahead.value.starts_with('/') {
+            self.lookahead = self.tokenizer.read_regular_expression(&self.lookahead)?;
+        }
+        let token = self.eat(TokenType::RegularExpression)?;
+
+        // Splitting the pattern from the flags at the closing slash
+        let end = token.value.rfind('/').unwrap_or_default();
+        let (pattern, flags) = (&token.value[1..end], &token.value[end + 1..]);
+
+        // Invalid patterns & flags are early errors, rather than thrown once the literal is evaluated
+        RegularExpression::new(pattern, flags)?;
+
+        Ok(Tree::RegExpLiteral {
+            pattern: pattern.to_string(),
+            flags: flags.to_string(),
+        })
+    }
+
     fn boolean_literal(&mut self) -> Result<Tree> {
         let token = match self.lookahead.token_type {
             TokenType::FalseKeyword => self.eat(TokenType::FalseKeyword)?,
@@ -127,10 +192,44 @@ mod tests {
                     expression: Box::new(Tree::NumericLiteral { value: 42.0 } ),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "42;");
     }
 
+    #[test]
+    fn test_parse_literal_decimal() {
+        let expected = Tree::Program { 
+            body: vec![
+                Tree::ExpressionStatement { 
+                    expression: Box::new(Tree::NumericLiteral { value: 1500.25 } ),
+                }
+            ], 
+            strict: false,
+        };
+        assert_tree(expected, "1.50025e3;");
+    }
+
+    #[test]
+    fn test_parse_literal_bigint() {
+        let expected = Tree::Program { 
+            body: vec![
+                Tree::ExpressionStatement { 
+                    expression: Box::new(Tree::BigIntLiteral { value: String::from("9007199254740993") } ),
+                }
+            ], 
+            strict: false,
+        };
+        assert_tree(expected, "9007199254740993n;");
+    }
+
+    #[test]
+    fn test_parse_bigint_with_leading_zero() {
+        let expected = || Error::Syntax("Unexpected token Identifier, expected SemiColon!".to_string());
+        assert_syntax_error(expected(), "01n;");
+        assert_syntax_error(expected(), "007n;");
+    }
+
     #[test]
     fn test_parse_literal_string() {
         let expected = Tree::Program { 
@@ -139,6 +238,7 @@ mod tests {
                     expression: Box::new(Tree::StringLiteral { value: "Hello".to_owned() } ),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "\"Hello\";");
     }
@@ -157,6 +257,7 @@ mod tests {
                     expression: Box::new(Tree::NumericLiteral { value: 42.0 } ),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "    42;");
     }
@@ -169,10 +270,55 @@ mod tests {
                     expression: Box::new(Tree::StringLiteral { value: "  Hello, World!  ".to_owned() } ),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "  \"  Hello, World!  \";  ");
     }
 
+    #[test]
+    fn test_parse_regexp_literal() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::AssignmentExpression {
+                        operator: "=".to_string(),
+                        left: Box::new(Tree::Identifier { name: "re".to_string() }),
+                        right: Box::new(Tree::RegExpLiteral { pattern: r"=[/\]]\/".to_string(), flags: "gi".to_string() }),
+                    }),
+                },
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::BinaryExpression {
+                        operator: "/".to_string(),
+                        left: Box::new(Tree::BinaryExpression {
+                            operator: "/".to_string(),
+                            left: Box::new(Tree::Identifier { name: "a".to_string() }),
+                            right: Box::new(Tree::Identifier { name: "b".to_string() }),
+                        }),
+                        right: Box::new(Tree::Identifier { name: "c".to_string() }),
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, r"re = /=[/\]]\//gi; a / b / c;");
+    }
+
+    #[test]
+    fn test_parse_unterminated_regexp_literal() {
+        for content in ["let re = /ab\n/;", "let re = /[/;"] {
+            assert_syntax_error(Error::Syntax("Invalid regular expression: missing /!".to_string()), content);
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_regexp_literal() {
+        let expected = Error::Syntax(
+            "Invalid regular expression: /(/: Parsing error at position 1: Opening parenthesis without closing parenthesis!".to_string(),
+        );
+        assert_syntax_error(expected, "function f() { return /(/; }");
+        assert_syntax_error(Error::Syntax("Invalid regular expression flags 'z'!".to_string()), "/a/z;");
+    }
+
     #[test]
     fn test_parse_true_literal() {
         let expected = Tree::Program {
@@ -181,6 +327,7 @@ mod tests {
                     expression: Box::new(Tree::BooleanLiteral { value: true }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "true;");
     }
@@ -193,6 +340,7 @@ mod tests {
                     expression: Box::new(Tree::BooleanLiteral { value: false }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "false;");
     }
@@ -205,6 +353,7 @@ mod tests {
                     expression: Box::new(Tree::NullLiteral),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "null;");
     }
@@ -217,6 +366,7 @@ mod tests {
                     expression: Box::new(Tree::ThisLiteral),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "this;");
     }
@@ -229,7 +379,30 @@ mod tests {
                     expression: Box::new(Tree::SuperLiteral),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "super;");
     }
+
+    #[test]
+    fn test_parse_legacy_octal_literals() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::BinaryExpression {
+                        operator: String::from("+"),
+                        left: Box::new(Tree::NumericLiteral { value: 8.0 }),
+                        right: Box::new(Tree::NumericLiteral { value: 19.0 }),
+                    }),
+                }
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "010 + 019;");
+
+        let expected = Error::Syntax("Octal literals are not allowed in strict mode!".to_string());
+        assert_syntax_error(expected, "'use strict'; 010;");
+        let expected = Error::Syntax("Decimals with leading zeros are not allowed in strict mode!".to_string());
+        assert_syntax_error(expected, "function f() { 'use strict'; return 019; }");
+    }
 }
\ No newline at end of file
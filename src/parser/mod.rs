@@ -1,5 +1,8 @@
 use crate::prelude::*;
 
+use std::collections::HashMap;
+use std::rc::Rc;
+
 pub use eatable::Eatable;
 pub use parsable::Parsable;
 
@@ -17,6 +20,62 @@ mod program;
 pub struct Parser {
     tokenizer: Tokenizer,
     lookahead: Token,
+    jump_context: JumpContext,
+
+    // Whether `yield` expressions are allowed, i.e. parsing a generator body
+    generator: bool,
+
+    // Whether `await` expressions are allowed, i.e. parsing an async function body
+    is_async: bool,
+
+    // End of the last eaten token, closing the source text of functions & classes
+    previous_end: usize,
+
+    // Private names of the class bodies enclosing the code being parsed, innermost last
+    private_scopes: Vec<PrivateScope>,
+
+    // Whether strict mode code is being parsed, i.e. after a 'use strict' directive or within a class
+    strict: bool,
+}
+
+/**
+ * Statements enclosing the one being parsed, used to validate
+ * the targets of `break` & `continue` statements.
+ */
+#[derive(Default)]
+struct JumpContext {
+    labels: Vec<Label>,
+
+    // Number of innermost labels applying to the upcoming statement, e.g. `a: b: for (;;)`
+    label_set: usize,
+    iterations: usize,
+    breakables: usize,
+}
+
+struct Label {
+    name: String,
+    iteration: bool,
+}
+
+/**
+ * Private names declared by a class body, along with the ones referenced within it.
+ * References are resolved once the body ends, as they may precede the declaration.
+ */
+#[derive(Default)]
+struct PrivateScope {
+    declared: HashMap<String, (PrivateKind, bool)>,
+    referenced: Vec<String>,
+}
+
+/**
+ * How a private name is declared, as a getter & a setter may share the same name.
+ */
+#[derive(Clone, Copy, PartialEq)]
+enum PrivateKind {
+    Field,
+    Method,
+    Getter,
+    Setter,
 }
 
 impl Parser {
@@ -34,8 +93,21 @@ impl Parser {
         Ok(Self {
             tokenizer,
             lookahead,
+            jump_context: JumpContext::default(),
+            generator: false,
+            is_async: false,
+            previous_end: 0,
+            private_scopes: vec![],
+            strict: false,
         })
     }
+
+    /**
+     * Returns the source text from the offset up to the last eaten token, e.g. `function f() {}`.
+     */
+    fn source_since(&self, start: usize) -> Rc<str> {
+        Rc::from(self.tokenizer.source(start, self.previous_end))
+    }
 }
 
 #[cfg(test)]
@@ -5,7 +5,7 @@ use super::statements::list::StatementListParsable;
 pub trait ProgramParsable {
     /**
      * Program
-     *  : StatementList
+     *  : OptDirectivePrologue StatementList
      *  ;
      */
     fn program(&mut self) -> Result<Tree>;
@@ -13,8 +13,9 @@ pub trait ProgramParsable {
 
 impl ProgramParsable for Parser {
     fn program(&mut self) -> Result<Tree> {
-        let body = self.statement_list(TokenType::Eof)?;
-        Ok(Tree::Program { body })
+        let mut body = self.directive_prologue()?;
+        body.extend(self.statement_list(TokenType::Eof)?);
+        Ok(Tree::Program { body, strict: self.strict })
     }
 }
 
@@ -31,6 +32,7 @@ mod tests {
                     expression: Box::new(Tree::NumericLiteral { value: 42.0 } ),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "// Comment \n 42;");
     }
@@ -43,6 +45,7 @@ mod tests {
                     expression: Box::new(Tree::StringLiteral { value: "Hello".to_owned() } ),
                 }
             ], 
+            strict: false,
         };
         let content_string = "\
         /* Multi-line comment \n\
@@ -54,7 +57,40 @@ mod tests {
 
     #[test]
     fn test_parse_empty_content() {
-        let expected = Tree::Program { body: vec![] };
+        let expected = Tree::Program { body: vec![], strict: false };
         assert_tree(expected, "");
     }
+
+    #[test]
+    fn test_parse_directive_prologue() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::StringLiteral { value: String::from("use asm") }),
+                },
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::StringLiteral { value: String::from("use strict") }),
+                },
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::NumericLiteral { value: 0.0 }),
+                },
+            ],
+            strict: true,
+        };
+        assert_tree(expected, "'use asm'; \"use strict\"; 0;");
+
+        // Only the leading string literals are directives, parenthesized ones being expressions
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ExpressionStatement {
+                    expression: Box::new(Tree::StringLiteral { value: String::from("use strict") }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "(\"use strict\");");
+        assert!(Parser::new("0; 'use strict'; 010;".to_string()).unwrap().parse().is_ok());
+        assert!(Parser::new("'use strict' + 1; 010;".to_string()).unwrap().parse().is_ok());
+        assert!(Parser::new("'use\\x20strict'; 010;".to_string()).unwrap().parse().is_ok());
+    }
 }
\ No newline at end of file
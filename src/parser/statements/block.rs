@@ -34,6 +34,7 @@ mod tests {
             body: vec![
                 Tree::BlockStatement { body: vec![] }
             ], 
+            strict: false,
         };
         assert_tree(expected, "{}");
     }
@@ -56,6 +57,7 @@ mod tests {
                     expression: Box::new(Tree::StringLiteral { value: "Hello".to_owned() } ),
                 }
             ], 
+            strict: false,
         };
         assert_tree(expected, "42; { //Commenting 42 -> 42;\n 'Hello'; } \"Hello\";");
     }
@@ -86,6 +88,7 @@ mod tests {
                     ]
                 },
             ], 
+            strict: false,
         };
         assert_tree(expected, "{{ 42; { 'Hello'; } }{}}");
     }
@@ -1,9 +1,11 @@
 use crate::prelude::*;
 
-use super::block::BlockStatementParsable;
+use std::rc::Rc;
+
 use super::expressions::assignment::AssignmentExpressionParsable;
-use super::function::FunctionDeclarationParsable;
+use super::function::{check_function_modifiers, FunctionDeclarationParsable};
 use super::identifier::IdentifierParsable;
+use crate::parser::{PrivateKind, PrivateScope};
 
 pub trait ClassDeclarationParsable {
     /**
@@ -35,10 +37,19 @@ pub trait ClassDeclarationParsable {
     /**
      * ClassStatement
      *  : ConstructorDefinition
-     *  | GetterDefinition
-     *  | SetterDefinition
-     *  | MethodDefinition
-     *  | PropertyDefinition
+     *  | OptStatic GetterDefinition
+     *  | OptStatic SetterDefinition
+     *  | OptStatic MethodDefinition
+     *  | OptStatic PropertyDefinition
+     *  | StaticBlock
+     *  ;
+     * 
+     * Static
+     *  : 'static'
+     *  ;
+     * 
+     * StaticBlock
+     *  : 'static' BlockStatement
      *  ;
      */
     fn class_statement(&mut self) -> Result<Tree>;
@@ -52,31 +63,33 @@ pub trait ClassDeclarationParsable {
 
     /**
      * GetterDefinition
-     *  : 'get' Identifier '(' ')' BlockStatement
+     *  : 'get' ClassElementName '(' ')' BlockStatement
      *  ;
      */
-    fn getter_definition(&mut self) -> Result<Tree>;
+    fn getter_definition(&mut self, start: usize, is_static: bool) -> Result<Tree>;
     
     /**
      * SetterDefinition
-     *  : 'set' Identifier '(' Identifier ')' BlockStatement 
+     *  : 'set' ClassElementName '(' Identifier ')' BlockStatement 
      *  ;
      */
-    fn setter_definition(&mut self) -> Result<Tree>;
+    fn setter_definition(&mut self, start: usize, is_static: bool) -> Result<Tree>;
 
     /**
      * MethodDefinition
-     *  : Identifier '(' OptFormalParameterList ')' BlockStatement
+     *  : OptAsync OptGenerator ClassElementName '(' OptFormalParameterList ')' BlockStatement
      *  ;
+     * 
+     * The source text of the method begins at `start`, i.e. before its key & its `async` & `*` modifiers.
      */
-    fn method_definition(&mut self, identifier: Tree) -> Result<Tree>;
+    fn method_definition(&mut self, start: usize, identifier: Tree, computed: bool, is_static: bool, generator: bool, is_async: bool) -> Result<Tree>;
 
     /**
      * PropertyDefinition
-     *  : Identifier OptPropertyInitializer ';'
+     *  : ClassElementName OptPropertyInitializer ';'
      *  ;
      */
-    fn property_definition(&mut self) -> Result<Tree>;
+    fn property_definition(&mut self, start: usize, is_static: bool) -> Result<Tree>;
 
     /**
      * PropertyInitializer
@@ -84,10 +97,22 @@ pub trait ClassDeclarationParsable {
      *  ;
      */
     fn property_initializer(&mut self) -> Result<Tree>;
+
+    /**
+     * ClassElementName
+     *  : PropertyName
+     *  | PrivateIdentifier
+     *  | '[' AssignmentExpression ']'
+     *  ;
+     * 
+     * Returns the key along with whether it's computed.
+     */
+    fn class_element_name(&mut self) -> Result<(Tree, bool)>;
 }
 
 impl ClassDeclarationParsable for Parser {
     fn class_delaration(&mut self) -> Result<Tree> {
+        let start = self.lookahead.start;
         self.eat(TokenType::ClassKeyword)?;
         let identifier = self.identifier()?;
 
@@ -106,19 +131,27 @@ impl ClassDeclarationParsable for Parser {
             idenifier: Box::new(identifier),
             body: Box::new(body),
             super_class: Box::new(super_class),
+            source: self.source_since(start),
         })
     }
 
     fn class_body(&mut self) -> Result<Tree> {
         self.eat(TokenType::CurlyBracketOpen)?;
+        self.private_scopes.push(PrivateScope::default());
+
+        // Class bodies are strict mode code
+        let strict = std::mem::replace(&mut self.strict, true);
 
         // OptClassStatementList
         let statements = match self.lookahead.token_type {
-            TokenType::CircleBracketClose => vec![],
-            _ => self.class_statement_list()?,
+            TokenType::CircleBracketClose => Ok(vec![]),
+            _ => self.class_statement_list(),
         };
+        self.strict = strict;
+        let statements = statements?;
 
         self.eat(TokenType::CurlyBracketClose)?;
+        self.resolve_private_names()?;
 
         Ok(Tree::ClassBody { body: statements })
     }
@@ -127,22 +160,49 @@ impl ClassDeclarationParsable for Parser {
         let mut statements = vec![];
 
         while self.lookahead.token_type != TokenType::CurlyBracketClose {
-            statements.push(self.class_statement()?);
+            let statement = self.class_statement()?;
+            let is_constructor = matches!(statement, Tree::ConstructorDefinition { .. });
+            if is_constructor && statements.iter().any(|statement| matches!(statement, Tree::ConstructorDefinition { .. })) {
+                return Err(Error::Syntax(String::from("A class may only have one constructor!")));
+            }
+            statements.push(statement);
         }
 
         Ok(statements)
     }
 
     fn class_statement(&mut self) -> Result<Tree> {
+        // `static` is either a modifier, the start of a block or the name of the element, e.g. `static() {}`
+        let mut start = self.lookahead.start;
+        let mut is_static = false;
+        if self.is_contextual_keyword("static") {
+            let name = self.eat(TokenType::Identifier)?.value;
+            match self.lookahead.token_type {
+                TokenType::CircleBracketOpen
+                | TokenType::SimpleAssignmentOperator
+                | TokenType::SemiColon => return self.class_element(start, Tree::Identifier { name }, false, false, false, false),
+                TokenType::CurlyBracketOpen => {
+                    let (body, _) = self.function_body(&[], false, false)?;
+                    return Ok(Tree::StaticBlock { body: Rc::new(body) });
+                },
+                // The source text of static elements begins after the modifier, e.g. at `m` in `static m() {}`
+                _ => {
+                    is_static = true;
+                    start = self.lookahead.start;
+                },
+            }
+        }
+
         match self.lookahead.token_type {
-            TokenType::ConstructorKeyword => self.constructor_definition(),
-            TokenType::GetKeyword => self.getter_definition(),
-            TokenType::SetKeyword => self.setter_definition(),
-            _ => self.property_definition(),
+            TokenType::ConstructorKeyword if !is_static => self.constructor_definition(),
+            TokenType::GetKeyword => self.getter_definition(start, is_static),
+            TokenType::SetKeyword => self.setter_definition(start, is_static),
+            _ => self.property_definition(start, is_static),
         }
     }
 
     fn constructor_definition(&mut self) -> Result<Tree> {
+        let start = self.lookahead.start;
         self.eat(TokenType::ConstructorKeyword)?;
         self.eat(TokenType::CircleBracketOpen)?;
 
@@ -154,20 +214,26 @@ impl ClassDeclarationParsable for Parser {
 
         self.eat(TokenType::CircleBracketClose)?;
 
-        let body = self.block_statement()?;
+        let (body, strict) = self.function_body(&params, false, false)?;
 
         Ok(Tree::ConstructorDefinition {
             value: Box::new(Tree::FunctionExpression {
                 identifier: Box::new(None),
-                params,
-                body: Box::new(body),
+                params: Rc::from(params),
+                body: Rc::new(body),
+                generator: false,
+                is_async: false,
+                strict,
+                source: self.source_since(start),
             }),
         })
     }
 
-    fn getter_definition(&mut self) -> Result<Tree> {
+    fn getter_definition(&mut self, start: usize, is_static: bool) -> Result<Tree> {
         self.eat(TokenType::GetKeyword)?;
-        let identifier = self.identifier()?;
+        let (identifier, computed) = self.class_element_name()?;
+        check_element_name(&identifier, computed, is_static, Some("an accessor"))?;
+        self.declare_private_name(&identifier, PrivateKind::Getter, is_static)?;
 
         self.eat(TokenType::CircleBracketOpen)?;
 
@@ -175,21 +241,29 @@ impl ClassDeclarationParsable for Parser {
 
         self.eat(TokenType::CircleBracketClose)?;
 
-        let body = self.block_statement()?;
+        let (body, strict) = self.function_body(&[], false, false)?;
 
         Ok(Tree::GetterDefinition {
             key: Box::new(identifier),
             value: Box::new(Tree::FunctionExpression {
                 identifier: Box::new(None),
-                params: vec![],
-                body: Box::new(body),
+                params: Rc::from([]),
+                body: Rc::new(body),
+                generator: false,
+                is_async: false,
+                strict,
+                source: self.source_since(start),
             }),
+            computed,
+            is_static,
         })
     }
 
-    fn setter_definition(&mut self) -> Result<Tree> {
+    fn setter_definition(&mut self, start: usize, is_static: bool) -> Result<Tree> {
         self.eat(TokenType::SetKeyword)?;
-        let identifier = self.identifier()?;
+        let (identifier, computed) = self.class_element_name()?;
+        check_element_name(&identifier, computed, is_static, Some("an accessor"))?;
+        self.declare_private_name(&identifier, PrivateKind::Setter, is_static)?;
 
         self.eat(TokenType::CircleBracketOpen)?;
 
@@ -198,19 +272,26 @@ impl ClassDeclarationParsable for Parser {
 
         self.eat(TokenType::CircleBracketClose)?;
 
-        let body = self.block_statement()?;
+        let (body, strict) = self.function_body(std::slice::from_ref(&param), false, false)?;
 
         Ok(Tree::SetterDefinition {
             key: Box::new(identifier),
             value: Box::new(Tree::FunctionExpression {
                 identifier: Box::new(None),
-                params: vec![param],
-                body: Box::new(body),
+                params: Rc::from([param]),
+                body: Rc::new(body),
+                generator: false,
+                is_async: false,
+                strict,
+                source: self.source_since(start),
             }),
+            computed,
+            is_static,
         })
     }
 
-    fn method_definition(&mut self, identifier: Tree) -> Result<Tree> {
+    fn method_definition(&mut self, start: usize, identifier: Tree, computed: bool, is_static: bool, generator: bool, is_async: bool) -> Result<Tree> {
+        self.declare_private_name(&identifier, PrivateKind::Method, is_static)?;
         self.eat(TokenType::CircleBracketOpen)?;
 
         // OptFormalParameterList
@@ -221,26 +302,102 @@ impl ClassDeclarationParsable for Parser {
 
         self.eat(TokenType::CircleBracketClose)?;
 
-        let body = self.block_statement()?;
+        let (body, strict) = self.function_body(&params, generator, is_async)?;
 
         Ok(Tree::MethodDefinition {
             key: Box::new(identifier),
             value: Box::new(Tree::FunctionExpression {
                 identifier: Box::new(None),
-                params,
-                body: Box::new(body),
+                params: Rc::from(params),
+                body: Rc::new(body),
+                generator,
+                is_async,
+                strict,
+                source: self.source_since(start),
             }),
+            computed,
+            is_static,
         })
     }
 
-    fn property_definition(&mut self) -> Result<Tree> {
-        let identifier = self.identifier()?;
+    fn property_definition(&mut self, start: usize, is_static: bool) -> Result<Tree> {
+        // `async` is either a modifier or the name of the element, e.g. `async() {}`
+        let mut is_async = false;
+        if self.is_contextual_keyword("async") {
+            let name = self.eat(TokenType::Identifier)?.value;
+            match self.lookahead.token_type {
+                TokenType::CircleBracketOpen
+                | TokenType::SimpleAssignmentOperator
+                | TokenType::SemiColon => return self.class_element(start, Tree::Identifier { name }, false, is_static, false, false),
+                _ => is_async = true,
+            }
+        }
+
+        let generator = self.generator()?;
+        check_function_modifiers(generator, is_async)?;
+        let (identifier, computed) = self.class_element_name()?;
+        self.class_element(start, identifier, computed, is_static, generator, is_async)
+    }
+
+    fn property_initializer(&mut self) -> Result<Tree> {
+        self.eat(TokenType::SimpleAssignmentOperator)?;
+        self.assignment_expression()
+    }
+
+    fn class_element_name(&mut self) -> Result<(Tree, bool)> {
+        match self.lookahead.token_type {
+            TokenType::SquareBracketOpen => {
+                self.eat(TokenType::SquareBracketOpen)?;
+                let key = self.assignment_expression()?;
+                self.eat(TokenType::SquareBracketClose)?;
+                Ok((key, true))
+            },
+            TokenType::PrivateIdentifier => {
+                let name = self.eat(TokenType::PrivateIdentifier)?.value;
+                Ok((Tree::PrivateIdentifier { name }, false))
+            },
+            _ => Ok((self.property_name()?, false)),
+        }
+    }
+}
+
+/**
+ * Static elements may not be named `prototype`, which would replace the prototype of the class.
+ * Other elements named `constructor` must be plain methods, described by `special` otherwise,
+ * e.g. "an accessor". Computed names can't be checked while parsing, hence defining a computed
+ * `prototype` fails once the class is evaluated, as that property of classes is read-only.
+ */
+fn check_element_name(key: &Tree, computed: bool, is_static: bool, special: Option<&str>) -> Result<()> {
+    let name = match key {
+        Tree::Identifier { name } if !computed => name.as_str(),
+        _ => return Ok(()),
+    };
+    match (name, is_static, special) {
+        ("prototype", true, _) => Err(Error::Syntax(String::from("Classes may not have a static property named 'prototype'!"))),
+        ("constructor", false, Some(special)) => Err(Error::Syntax(format!("Class constructor may not be {special}!"))),
+        _ => Ok(()),
+    }
+}
+
+impl Parser {
+    /**
+     * Parses the rest of a MethodDefinition or a PropertyDefinition, once its key is known.
+     */
+    fn class_element(&mut self, start: usize, identifier: Tree, computed: bool, is_static: bool, generator: bool, is_async: bool) -> Result<Tree> {
+        let special = match (generator, is_async) {
+            (true, _) => Some("a generator"),
+            (_, true) => Some("an async method"),
+            _ => None,
+        };
+        check_element_name(&identifier, computed, is_static, special)?;
 
         // Checking if production is MethodDefinition
-        if self.lookahead.token_type == TokenType::CircleBracketOpen {
-            return self.method_definition(identifier);
+        if generator || is_async || self.lookahead.token_type == TokenType::CircleBracketOpen {
+            return self.method_definition(start, identifier, computed, is_static, generator, is_async);
         }
-        
+
+        self.declare_private_name(&identifier, PrivateKind::Field, is_static)?;
+
         // OptPropertyInitializer
         let value = match self.lookahead.token_type {
             TokenType::SimpleAssignmentOperator => Some(self.property_initializer()?),
@@ -252,17 +409,59 @@ impl ClassDeclarationParsable for Parser {
         Ok(Tree::PropertyDefinition {
             key: Box::new(identifier),
             value: Box::new(value),
+            computed,
+            is_static,
         })
     }
 
-    fn property_initializer(&mut self) -> Result<Tree> {
-        self.eat(TokenType::SimpleAssignmentOperator)?;
-        self.assignment_expression()
+    /**
+     * Declares the private name of a class element within the innermost class body.
+     * Names may only be declared once, except for a getter & setter pair with the same placement.
+     */
+    fn declare_private_name(&mut self, key: &Tree, kind: PrivateKind, is_static: bool) -> Result<()> {
+        let Tree::PrivateIdentifier { name } = key else {
+            return Ok(());
+        };
+        if name == "#constructor" {
+            return Err(Error::Syntax(String::from("Classes may not have a private field named '#constructor'!")));
+        }
+
+        let scope = self.private_scopes.last_mut().expect("Private names are declared within a class body");
+        let accessor_pair = match scope.declared.get(name) {
+            None => false,
+            Some(&(declared, declared_static)) => declared_static == is_static && matches!(
+                (declared, kind),
+                (PrivateKind::Getter, PrivateKind::Setter) | (PrivateKind::Setter, PrivateKind::Getter)
+            ),
+        };
+        if scope.declared.contains_key(name) && !accessor_pair {
+            return Err(Error::Syntax(format!("Identifier '{name}' has already been declared!")));
+        }
+
+        scope.declared.insert(name.clone(), (kind, is_static));
+        Ok(())
+    }
+
+    /**
+     * Closes the innermost class body, handing the private names it doesn't declare to the enclosing one.
+     */
+    fn resolve_private_names(&mut self) -> Result<()> {
+        let scope = self.private_scopes.pop().expect("Private scope of the class body");
+        let unresolved = scope.referenced.into_iter().filter(|name| !scope.declared.contains_key(name));
+        for name in unresolved {
+            match self.private_scopes.last_mut() {
+                Some(outer) => outer.referenced.push(name),
+                None => return Err(Error::Syntax(format!("Private field '{name}' must be declared in an enclosing class!"))),
+            }
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
     use crate::prelude::*;
     use crate::parser::tests::*;
 
@@ -276,8 +475,10 @@ mod tests {
                         body: vec![],
                     }),
                     super_class: Box::new(None),
+                    source: Rc::from("class Point{}"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "class Point{}");
     }
@@ -292,8 +493,10 @@ mod tests {
                         body: vec![],
                     }),
                     super_class: Box::new(Some(Tree::Identifier { name: String::from("Point") })),
+                    source: Rc::from("class Point3D extends Point{}"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "class Point3D extends Point{}");
     }
@@ -308,17 +511,23 @@ mod tests {
                         body: vec![
                             Tree::PropertyDefinition {
                                 key: Box::new(Tree::Identifier { name: String::from("x") }),
+                                computed: false,
+                                is_static: false,
                                 value: Box::new(Some(Tree::NumericLiteral { value: 10.0 })),
                             },
                             Tree::PropertyDefinition {
                                 key: Box::new(Tree::Identifier { name: String::from("y") }),
+                                computed: false,
+                                is_static: false,
                                 value: Box::new(None),
                             },
                         ],
                     }),
                     super_class: Box::new(None),
+                    source: Rc::from("class Point {\n                x = 10;\n                y;\n            }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "
             class Point {
@@ -339,11 +548,11 @@ mod tests {
                             Tree::ConstructorDefinition {
                                 value: Box::new(Tree::FunctionExpression {
                                     identifier: Box::new(None),
-                                    params: vec![
+                                    params: Rc::from([
                                         Tree::Identifier { name: String::from("x") },
                                         Tree::Identifier { name: String::from("y") },
-                                    ],
-                                    body: Box::new(Tree::BlockStatement {
+                                    ]),
+                                    body: Rc::new(Tree::BlockStatement {
                                         body: vec![
                                             Tree::ExpressionStatement { 
                                                 expression: Box::new(Tree::AssignmentExpression {
@@ -369,13 +578,19 @@ mod tests {
                                             },
                                         ],
                                     }),
+                                    generator: false,
+                                    is_async: false,
+                                    strict: true,
+                                    source: Rc::from("constructor(x, y) {\n                    this.x = x;\n                    this.y = y;\n                }"),
                                 }),
                             },
                         ],
                     }),
                     super_class: Box::new(None),
+                    source: Rc::from("class Point {\n                constructor(x, y) {\n                    this.x = x;\n                    this.y = y;\n                }\n            }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "
             class Point {
@@ -397,23 +612,31 @@ mod tests {
                         body: vec![
                             Tree::GetterDefinition {
                                 key: Box::new(Tree::Identifier { name: String::from("x") }),
+                                computed: false,
+                                is_static: false,
                                 value: Box::new(Tree::FunctionExpression {
                                     identifier: Box::new(None),
-                                    params: vec![],
-                                    body: Box::new(Tree::BlockStatement {
+                                    params: Rc::from([]),
+                                    body: Rc::new(Tree::BlockStatement {
                                         body: vec![
                                             Tree::ReturnStatement {
                                                 argument: Box::new(Some(Tree::Identifier { name: String::from("x") })),
                                             },
                                         ],
                                     }),
+                                    generator: false,
+                                    is_async: false,
+                                    strict: true,
+                                    source: Rc::from("get x() { return x; }"),
                                 }),
                             },
                         ],
                     }),
                     super_class: Box::new(None),
+                    source: Rc::from("class Point {\n                get x() { return x; }\n            }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "
             class Point {
@@ -442,12 +665,14 @@ mod tests {
                         body: vec![
                             Tree::SetterDefinition {
                                 key: Box::new(Tree::Identifier { name: String::from("x") }),
+                                computed: false,
+                                is_static: false,
                                 value: Box::new(Tree::FunctionExpression {
                                     identifier: Box::new(None),
-                                    params: vec![
+                                    params: Rc::from([
                                         Tree::Identifier { name: String::from("y") },
-                                    ],
-                                    body: Box::new(Tree::BlockStatement {
+                                    ]),
+                                    body: Rc::new(Tree::BlockStatement {
                                         body: vec![
                                             Tree::ExpressionStatement {
                                                 expression: Box::new(Tree::AssignmentExpression {
@@ -462,13 +687,19 @@ mod tests {
                                             },
                                         ],
                                     }),
+                                    generator: false,
+                                    is_async: false,
+                                    strict: true,
+                                    source: Rc::from("set x(y) { this.x = y; }"),
                                 }),
                             },
                         ],
                     }),
                     super_class: Box::new(None),
+                    source: Rc::from("class Point {\n                set x(y) { this.x = y; }\n            }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "
             class Point {
@@ -507,12 +738,14 @@ mod tests {
                         body: vec![
                             Tree::MethodDefinition {
                                 key: Box::new(Tree::Identifier { name: String::from("hello") }),
+                                computed: false,
+                                is_static: false,
                                 value: Box::new(Tree::FunctionExpression {
                                     identifier: Box::new(None),
-                                    params: vec![
+                                    params: Rc::from([
                                         Tree::Identifier { name: String::from("name") },
-                                    ],
-                                    body: Box::new(Tree::BlockStatement {
+                                    ]),
+                                    body: Rc::new(Tree::BlockStatement {
                                         body: vec![
                                             Tree::ReturnStatement {
                                                 argument: Box::new(Some(Tree::BinaryExpression {
@@ -523,13 +756,19 @@ mod tests {
                                             },
                                         ],
                                     }),
+                                    generator: false,
+                                    is_async: false,
+                                    strict: true,
+                                    source: Rc::from("hello(name) {\n                    return 'hello, ' + name;\n                }"),
                                 }),
                             },
                         ],
                     }),
                     super_class: Box::new(None),
+                    source: Rc::from("class Point {\n                hello(name) {\n                    return 'hello, ' + name;\n                }\n            }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "
             class Point {
@@ -539,4 +778,209 @@ mod tests {
             }
         ");
     }
+
+    #[test]
+    fn test_parse_computed_class_element_names() {
+        let symbol = || Tree::MemberExpression {
+            object: Box::new(Tree::Identifier { name: String::from("Symbol") }),
+            property: Box::new(Tree::Identifier { name: String::from("iterator") }),
+            computed: false,
+        };
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ClassDeclaration {
+                    idenifier: Box::new(Tree::Identifier { name: String::from("Range") }),
+                    body: Box::new(Tree::ClassBody {
+                        body: vec![
+                            Tree::PropertyDefinition {
+                                key: Box::new(Tree::StringLiteral { value: String::from("end") }),
+                                computed: true,
+                                is_static: false,
+                                value: Box::new(None),
+                            },
+                            Tree::MethodDefinition {
+                                key: Box::new(symbol()),
+                                computed: true,
+                                is_static: false,
+                                value: Box::new(Tree::FunctionExpression {
+                                    identifier: Box::new(None),
+                                    params: Rc::from([]),
+                                    body: Rc::new(Tree::BlockStatement { body: vec![] }),
+                                    generator: true,
+                                    is_async: false,
+                                    strict: true,
+                                    source: Rc::from("*[Symbol.iterator]() {}"),
+                                }),
+                            },
+                        ],
+                    }),
+                    super_class: Box::new(None),
+                    source: Rc::from("class Range {\n                ['end'];\n                *[Symbol.iterator]() {}\n            }"),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "
+            class Range {
+                ['end'];
+                *[Symbol.iterator]() {}
+            }
+        ");
+    }
+    #[test]
+    fn test_parse_static_and_private_class_elements() {
+        let private = |name: &str| Box::new(Tree::PrivateIdentifier { name: String::from(name) });
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ClassDeclaration {
+                    idenifier: Box::new(Tree::Identifier { name: String::from("Counter") }),
+                    body: Box::new(Tree::ClassBody {
+                        body: vec![
+                            Tree::PropertyDefinition {
+                                key: private("#count"),
+                                computed: false,
+                                is_static: true,
+                                value: Box::new(Some(Tree::NumericLiteral { value: 0.0 })),
+                            },
+                            Tree::StaticBlock {
+                                body: Rc::new(Tree::BlockStatement { body: vec![] }),
+                            },
+                            Tree::MethodDefinition {
+                                key: Box::new(Tree::Identifier { name: String::from("static") }),
+                                computed: false,
+                                is_static: false,
+                                value: Box::new(Tree::FunctionExpression {
+                                    identifier: Box::new(None),
+                                    params: Rc::from([]),
+                                    body: Rc::new(Tree::BlockStatement {
+                                        body: vec![
+                                            Tree::ReturnStatement {
+                                                argument: Box::new(Some(Tree::BinaryExpression {
+                                                    operator: String::from("in"),
+                                                    left: private("#count"),
+                                                    right: Box::new(Tree::ThisLiteral),
+                                                })),
+                                            },
+                                        ],
+                                    }),
+                                    generator: false,
+                                    is_async: false,
+                                    strict: true,
+                                    source: Rc::from("static() { return #count in this; }"),
+                                }),
+                            },
+                        ],
+                    }),
+                    super_class: Box::new(None),
+                    source: Rc::from("class Counter {\n                static #count = 0;\n                static {}\n                static() { return #count in this; }\n            }"),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "
+            class Counter {
+                static #count = 0;
+                static {}
+                static() { return #count in this; }
+            }
+        ");
+    }
+
+    #[test]
+    fn test_parse_static_as_identifier() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::VariableStatement {
+                    kind: String::from("let"),
+                    declarations: vec![
+                        Tree::VariableDeclaration {
+                            identifier: Box::new(Tree::Identifier { name: String::from("static") }),
+                            init: Box::new(Some(Tree::NumericLiteral { value: 0.0 })),
+                        },
+                    ],
+                },
+                Tree::ClassDeclaration {
+                    idenifier: Box::new(Tree::Identifier { name: String::from("Counter") }),
+                    body: Box::new(Tree::ClassBody {
+                        body: vec![
+                            Tree::PropertyDefinition {
+                                key: Box::new(Tree::Identifier { name: String::from("count") }),
+                                computed: false,
+                                is_static: true,
+                                value: Box::new(Some(Tree::Identifier { name: String::from("static") })),
+                            },
+                        ],
+                    }),
+                    super_class: Box::new(None),
+                    source: Rc::from("class Counter { static count = static; }"),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "let static = 0; class Counter { static count = static; }");
+    }
+
+    #[test]
+    fn test_parse_undeclared_private_name() {
+        let expected = || Error::Syntax("Private field '#y' must be declared in an enclosing class!".to_string());
+        assert_syntax_error(expected(), "
+            class Point {
+                #x;
+                sum() { return this.#x + this.#y; }
+            }
+        ");
+        assert_syntax_error(expected(), "this.#y;");
+    }
+
+    #[test]
+    fn test_parse_duplicate_private_name() {
+        let expected = || Error::Syntax("Identifier '#x' has already been declared!".to_string());
+        assert_syntax_error(expected(), "
+            class Point {
+                #x;
+                #x() {}
+            }
+        ");
+        assert_syntax_error(expected(), "
+            class Point {
+                get #x() {}
+                static set #x(value) {}
+            }
+        ");
+    }
+
+    #[test]
+    fn test_parse_static_prototype_element() {
+        let expected = || Error::Syntax("Classes may not have a static property named 'prototype'!".to_string());
+        assert_syntax_error(expected(), "class Point { static prototype = 1; }");
+        assert_syntax_error(expected(), "class Point { static prototype() {} }");
+        assert_syntax_error(expected(), "class Point { static *prototype() {} }");
+        assert_syntax_error(expected(), "class Point { static get prototype() {} }");
+        assert_syntax_error(expected(), "class Point { static set prototype(value) {} }");
+    }
+
+    #[test]
+    fn test_parse_special_constructor_method() {
+        assert_syntax_error(Error::Syntax("Class constructor may not be an accessor!".to_string()), "class Point { get constructor() {} }");
+        assert_syntax_error(Error::Syntax("Class constructor may not be an accessor!".to_string()), "class Point { set constructor(value) {} }");
+        assert_syntax_error(Error::Syntax("Class constructor may not be a generator!".to_string()), "class Point { *constructor() {} }");
+        assert_syntax_error(Error::Syntax("Class constructor may not be an async method!".to_string()), "class Point { async constructor() {} }");
+
+        // Static elements & computed keys aren't the class constructor
+        let mut parser = Parser::new("class Point { static get constructor() {} ['constructor']() {} }".to_string()).unwrap();
+        assert!(parser.parse().is_ok());
+    }
+
+    #[test]
+    fn test_parse_duplicate_constructor() {
+        let expected = Error::Syntax("A class may only have one constructor!".to_string());
+        assert_syntax_error(expected, "
+            class Point {
+                constructor() {}
+                static constructor() {}
+                prototype() {}
+                constructor(x) {}
+            }
+        ");
+    }
 }
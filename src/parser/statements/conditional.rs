@@ -53,6 +53,7 @@ mod tests {
                     alternate: Box::new(None),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "if (x) {}");
     }
@@ -79,6 +80,7 @@ mod tests {
                     })),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "if (x) x += 1; else x = 42;");
     }
@@ -115,6 +117,7 @@ mod tests {
                     })),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "if (x) if (y) x += y; else y = 42; else x = 10;");
     }
@@ -162,6 +165,7 @@ mod tests {
                     })),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "if (x) x += 42; else if (y) y += 42; else { x = 42; y = 10; }");
     }
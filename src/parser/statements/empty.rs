@@ -27,6 +27,7 @@ mod tests {
             body: vec![
                 Tree::EmptyStatement,
             ],
+            strict: false,
         };
         assert_tree(expected, ";");
     }
@@ -44,6 +45,7 @@ mod tests {
                     expression: Box::new(Tree::StringLiteral { value: "Hello".to_owned() } ),
                 }
             ],
+            strict: false,
         };
         assert_tree(expected, ";\n42;\n;\n'Hello';");
     }
@@ -0,0 +1,157 @@
+use crate::prelude::*;
+
+use super::block::BlockStatementParsable;
+use super::expression::ExpressionStatementParsable;
+use super::identifier::IdentifierParsable;
+
+pub trait ExceptionStatementParsable {
+    /**
+     * ThrowStatement
+     *  : 'throw' Expression ';'
+     *  ;
+     */
+    fn throw_statement(&mut self) -> Result<Tree>;
+
+    /**
+     * TryStatement
+     *  : 'try' BlockStatement Catch
+     *  | 'try' BlockStatement Finally
+     *  | 'try' BlockStatement Catch Finally
+     *  ;
+     *
+     * Finally
+     *  : 'finally' BlockStatement
+     *  ;
+     */
+    fn try_statement(&mut self) -> Result<Tree>;
+
+    /**
+     * Catch
+     *  : 'catch' '(' Identifier ')' BlockStatement
+     *  | 'catch' BlockStatement
+     *  ;
+     */
+    fn catch_clause(&mut self) -> Result<Tree>;
+}
+
+impl ExceptionStatementParsable for Parser {
+    fn throw_statement(&mut self) -> Result<Tree> {
+        self.eat(TokenType::ThrowKeyword)?;
+        let argument = self.expression()?;
+        self.eat(TokenType::SemiColon)?;
+
+        Ok(Tree::ThrowStatement {
+            argument: Box::new(argument),
+        })
+    }
+
+    fn try_statement(&mut self) -> Result<Tree> {
+        self.eat(TokenType::TryKeyword)?;
+        self.jump_context.label_set = 0;
+        let block = self.block_statement()?;
+
+        let handler = match self.lookahead.token_type {
+            TokenType::CatchKeyword => Some(self.catch_clause()?),
+            _ => None,
+        };
+        let finalizer = match self.lookahead.token_type {
+            TokenType::FinallyKeyword => {
+                self.eat(TokenType::FinallyKeyword)?;
+                Some(self.block_statement()?)
+            },
+            _ => None,
+        };
+
+        if handler.is_none() && finalizer.is_none() {
+            return Err(Error::Syntax("Missing catch or finally after try!".to_string()));
+        }
+
+        Ok(Tree::TryStatement {
+            block: Box::new(block),
+            handler: Box::new(handler),
+            finalizer: Box::new(finalizer),
+        })
+    }
+
+    fn catch_clause(&mut self) -> Result<Tree> {
+        self.eat(TokenType::CatchKeyword)?;
+
+        // Optional catch binding, i.e. `catch { ... }`
+        let param = match self.lookahead.token_type {
+            TokenType::CircleBracketOpen => {
+                self.eat(TokenType::CircleBracketOpen)?;
+                let param = self.identifier()?;
+                self.eat(TokenType::CircleBracketClose)?;
+                Some(param)
+            },
+            _ => None,
+        };
+        let body = self.block_statement()?;
+
+        Ok(Tree::CatchClause {
+            param: Box::new(param),
+            body: Box::new(body),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_throw_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ThrowStatement {
+                    argument: Box::new(Tree::StringLiteral { value: String::from("oops") }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "throw 'oops';");
+    }
+
+    #[test]
+    fn test_parse_try_catch_finally() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::TryStatement {
+                    block: Box::new(Tree::BlockStatement { body: vec![] }),
+                    handler: Box::new(Some(Tree::CatchClause {
+                        param: Box::new(Some(Tree::Identifier { name: String::from("e") })),
+                        body: Box::new(Tree::BlockStatement { body: vec![] }),
+                    })),
+                    finalizer: Box::new(Some(Tree::BlockStatement { body: vec![] })),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "try {} catch (e) {} finally {}");
+    }
+
+    #[test]
+    fn test_parse_optional_catch_binding() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::TryStatement {
+                    block: Box::new(Tree::BlockStatement { body: vec![] }),
+                    handler: Box::new(Some(Tree::CatchClause {
+                        param: Box::new(None),
+                        body: Box::new(Tree::BlockStatement { body: vec![] }),
+                    })),
+                    finalizer: Box::new(None),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "try {} catch {}");
+    }
+
+    #[test]
+    fn test_parse_try_without_handler() {
+        let expected = Error::Syntax("Missing catch or finally after try!".to_string());
+        assert_syntax_error(expected, "try {}");
+    }
+}
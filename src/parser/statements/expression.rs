@@ -1,12 +1,15 @@
 use crate::prelude::*;
 
 use super::expressions::assignment::AssignmentExpressionParsable;
+use super::labeled::LabeledStatementParsable;
 
 pub trait ExpressionStatementParsable {
     /**
      * ExpressionStatement
      *  : Expression ';'
      *  ;
+     * 
+     * An identifier followed by ':' is parsed as LabeledStatement.
      */
     fn expression_statement(&mut self) -> Result<Tree>;
 
@@ -21,6 +24,9 @@ pub trait ExpressionStatementParsable {
 impl ExpressionStatementParsable for Parser {
     fn expression_statement(&mut self) -> Result<Tree> {
         let expression = self.expression()?;
+        if let (Tree::Identifier { .. }, TokenType::Colon) = (&expression, &self.lookahead.token_type) {
+            return self.labeled_statement(expression);
+        }
         self.eat(TokenType::SemiColon)?;
         Ok(Tree::ExpressionStatement { expression: Box::new(expression) })
     }
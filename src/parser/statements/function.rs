@@ -1,17 +1,39 @@
 use crate::prelude::*;
 
-use super::block::BlockStatementParsable;
+use std::rc::Rc;
+
 use super::expression::ExpressionStatementParsable;
 use super::identifier::IdentifierParsable;
+use super::list::StatementListParsable;
 
 pub trait FunctionDeclarationParsable {
     /**
      * FunctionDeclaration
-     *  : 'function' Identifier '(' OptFormalParameterList ')' BlockStatement
+     *  : OptAsync 'function' OptGenerator Identifier '(' OptFormalParameterList ')' BlockStatement
      *  ;
      */
     fn function_declaration(&mut self) -> Result<Tree>;
 
+    /**
+     * Async
+     *  : 'async'
+     *  ;
+     */
+    fn async_modifier(&mut self) -> Result<bool>;
+
+    /**
+     * Whether the lookahead starts an async function, as `async` is
+     * otherwise a regular identifier, e.g. `let async = 1;`.
+     */
+    fn is_async_function(&self) -> bool;
+
+    /**
+     * Generator
+     *  : '*'
+     *  ;
+     */
+    fn generator(&mut self) -> Result<bool>;
+
     /**
      * FormalParameterList
      *  : Identifier
@@ -20,6 +42,20 @@ pub trait FunctionDeclarationParsable {
      */
     fn formal_parameter_list(&mut self) -> Result<Vec<Tree>>;
 
+    /**
+     * FunctionBody
+     *  : '{' OptDirectivePrologue OptStatementList '}'
+     *  ;
+     * 
+     * Labels & loops don't cross function boundaries, hence the
+     * body is parsed with a fresh jump context. `yield` & `await` are
+     * only allowed directly inside the body of a generator & an async
+     * function respectively. The params are checked once the body
+     * tells whether the function is strict mode code, which is returned
+     * along with the body.
+     */
+    fn function_body(&mut self, params: &[Tree], generator: bool, is_async: bool) -> Result<(Tree, bool)>;
+
     /**
      * ReturnStatement
      *  : 'return' OptExpression ';'
@@ -30,7 +66,11 @@ pub trait FunctionDeclarationParsable {
 
 impl FunctionDeclarationParsable for Parser {
     fn function_declaration(&mut self) -> Result<Tree> {
+        let start = self.lookahead.start;
+        let is_async = self.async_modifier()?;
         self.eat(TokenType::FunctionKeyword)?;
+        let generator = self.generator()?;
+        check_function_modifiers(generator, is_async)?;
         let identifier = self.identifier()?;
 
         self.eat(TokenType::CircleBracketOpen)?;
@@ -43,14 +83,39 @@ impl FunctionDeclarationParsable for Parser {
 
         self.eat(TokenType::CircleBracketClose)?;
 
-        let body = self.block_statement()?;
+        let (body, strict) = self.function_body(&params, generator, is_async)?;
 
         Ok(Tree::FunctionDeclaration {
             identifier: Box::new(identifier),
-            params,
-            body: Box::new(body),
+            params: Rc::from(params),
+            body: Rc::new(body),
+            generator,
+            is_async,
+            strict,
+            source: self.source_since(start),
         })
     }
+
+    fn async_modifier(&mut self) -> Result<bool> {
+        if !self.is_async_function() {
+            return Ok(false);
+        }
+        self.eat(TokenType::Identifier)?;
+        Ok(true)
+    }
+
+    fn is_async_function(&self) -> bool {
+        self.is_contextual_keyword("async")
+            && matches!(self.tokenizer.peek_token(), Ok(Token { token_type: TokenType::FunctionKeyword, .. }))
+    }
+
+    fn generator(&mut self) -> Result<bool> {
+        if self.lookahead.token_type != TokenType::MultiplicativeOperator || self.lookahead.value != "*" {
+            return Ok(false);
+        }
+        self.eat(TokenType::MultiplicativeOperator)?;
+        Ok(true)
+    }
     
     fn formal_parameter_list(&mut self) -> Result<Vec<Tree>> {
         let mut params = vec![];
@@ -67,6 +132,27 @@ impl FunctionDeclarationParsable for Parser {
         Ok(params)
     }
 
+    fn function_body(&mut self, params: &[Tree], generator: bool, is_async: bool) -> Result<(Tree, bool)> {
+        let context = std::mem::take(&mut self.jump_context);
+        let enclosing = (
+            std::mem::replace(&mut self.generator, generator),
+            std::mem::replace(&mut self.is_async, is_async),
+            self.strict,
+        );
+        let body = self.function_statements();
+        let strict = self.strict;
+        self.jump_context = context;
+        (self.generator, self.is_async, self.strict) = enclosing;
+        let body = body?;
+
+        // Strict mode functions can't have two params of the same name
+        let duplicate = params.iter().enumerate().any(|(index, param)| params[..index].contains(param));
+        if strict && duplicate {
+            return Err(Error::Syntax("Duplicate parameter name not allowed in this context!".to_string()));
+        }
+        Ok((body, strict))
+    }
+
     fn return_statement(&mut self) -> Result<Tree> {
         self.eat(TokenType::ReturnKeyword)?;
         
@@ -82,8 +168,35 @@ impl FunctionDeclarationParsable for Parser {
     }
 }
 
+impl Parser {
+    /**
+     * Parses the braces & statements of a function body, starting with its directives.
+     */
+    fn function_statements(&mut self) -> Result<Tree> {
+        self.eat(TokenType::CurlyBracketOpen)?;
+        let mut body = self.directive_prologue()?;
+        if self.lookahead.token_type != TokenType::CurlyBracketClose {
+            body.extend(self.statement_list(TokenType::CurlyBracketClose)?);
+        }
+        self.eat(TokenType::CurlyBracketClose)?;
+        Ok(Tree::BlockStatement { body })
+    }
+}
+
+/**
+ * Async generators aren't supported, since both `yield` & `await` suspend the same body.
+ */
+pub fn check_function_modifiers(generator: bool, is_async: bool) -> Result<()> {
+    match generator && is_async {
+        true => Err(Error::Syntax("Async generator functions are not supported!".to_string())),
+        false => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
     use crate::prelude::*;
     use crate::parser::tests::*;
 
@@ -93,10 +206,15 @@ mod tests {
             body: vec![
                 Tree::FunctionDeclaration {
                     identifier: Box::new(Tree::Identifier { name: String::from("hello") }),
-                    params: vec![],
-                    body: Box::new(Tree::BlockStatement { body: vec![] }),
+                    params: Rc::from([]),
+                    body: Rc::new(Tree::BlockStatement { body: vec![] }),
+                    generator: false,
+                    is_async: false,
+                    strict: false,
+                    source: Rc::from("function hello() {}"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "function hello() {}");
     }
@@ -107,11 +225,11 @@ mod tests {
             body: vec![
                 Tree::FunctionDeclaration {
                     identifier: Box::new(Tree::Identifier { name: String::from("multiply") }),
-                    params: vec![
+                    params: Rc::from([
                         Tree::Identifier { name: String::from("x") },
                         Tree::Identifier { name: String::from("y") },
-                    ],
-                    body: Box::new(Tree::BlockStatement {
+                    ]),
+                    body: Rc::new(Tree::BlockStatement {
                         body: vec![
                             Tree::ReturnStatement {
                                 argument: Box::new(Some(Tree::BinaryExpression {
@@ -122,8 +240,13 @@ mod tests {
                             },
                         ],
                     }),
+                    generator: false,
+                    is_async: false,
+                    strict: false,
+                    source: Rc::from("function multiply(x, y) { return x * y; }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "function multiply(x, y) { return x * y; }");
     }
@@ -134,10 +257,11 @@ mod tests {
             body: vec![
                 Tree::FunctionDeclaration {
                     identifier: Box::new(Tree::Identifier { name: String::from("test") }),
-                    params: vec![],
-                    body: Box::new(Tree::BlockStatement {
+                    params: Rc::from([]),
+                    body: Rc::new(Tree::BlockStatement {
                         body: vec![
                             Tree::VariableStatement {
+                                kind: String::from("let"),
                                 declarations: vec![
                                     Tree::VariableDeclaration {
                                         identifier: Box::new(Tree::Identifier { name: String::from("x") }),
@@ -147,8 +271,13 @@ mod tests {
                             },
                         ],
                     }),
+                    generator: false,
+                    is_async: false,
+                    strict: false,
+                    source: Rc::from("function test() { let x = 10; }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "function test() { let x = 10; }");
     }
@@ -159,16 +288,21 @@ mod tests {
             body: vec![
                 Tree::FunctionDeclaration {
                     identifier: Box::new(Tree::Identifier { name: String::from("test") }),
-                    params: vec![],
-                    body: Box::new(Tree::BlockStatement {
+                    params: Rc::from([]),
+                    body: Rc::new(Tree::BlockStatement {
                         body: vec![
                             Tree::ReturnStatement {
                                 argument: Box::new(None),
                             },
                         ],
                     }),
+                    generator: false,
+                    is_async: false,
+                    strict: false,
+                    source: Rc::from("function test() { return; }"),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "function test() { return; }");
     }
@@ -178,4 +312,14 @@ mod tests {
         let expected = Error::Syntax("Unexpected token SemiColon, expected CurlyBracketOpen!".to_string());
         assert_syntax_error(expected, "function why();");
     }
+
+    #[test]
+    fn test_parse_duplicate_params_in_strict_mode() {
+        assert!(Parser::new("function f(a, a) {}".to_string()).unwrap().parse().is_ok());
+
+        let expected = || Error::Syntax("Duplicate parameter name not allowed in this context!".to_string());
+        assert_syntax_error(expected(), "function f(a, a) { 'use strict'; }");
+        assert_syntax_error(expected(), "'use strict'; let f = function(a, b, a) {};");
+        assert_syntax_error(expected(), "class A { m(a, a) {} }");
+    }
 }
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 use super::expression::ExpressionStatementParsable;
+use super::expressions::assignment::AssignmentExpressionParsable;
 use super::list::StatementListParsable;
 use super::variable::VariableStatementParsable;
 
@@ -10,6 +11,8 @@ pub trait IterationStatementParsable {
      *  : WhileStatement
      *  | DoWhileStatement
      *  | ForStatement
+     *  | ForOfStatement
+     *  | ForInStatement
      *  ;
      */
     fn iteration_statement(&mut self) -> Result<Tree>;
@@ -42,15 +45,48 @@ pub trait IterationStatementParsable {
      *  ;
      */
     fn for_statement_init(&mut self) -> Result<Tree>;
+
+    /**
+     * ForOfStatement
+     *  : 'for' '(' ForDeclaration 'of' AssignmentExpression ')' Statement
+     *  ;
+     *
+     * ForInStatement
+     *  : 'for' '(' ForDeclaration 'in' Expression ')' Statement
+     *  ;
+     *
+     * ForDeclaration
+     *  : VariableKind Identifier
+     *  | LeftHandSideExpression
+     *  ;
+     *
+     * The ForDeclaration is already consumed by the caller, since it's
+     * only distinguishable from ForStatementInit by the succeeding token.
+     */
+    fn for_in_of_statement(&mut self, left: Tree) -> Result<Tree>;
 }
 
 impl IterationStatementParsable for Parser {
     fn iteration_statement(&mut self) -> Result<Tree> {
-        match self.lookahead.token_type {
+        // Labels directly applied to the loop become valid `continue` targets
+        let context = &mut self.jump_context;
+        let start = context.labels.len() - context.label_set;
+        for label in &mut context.labels[start..] {
+            label.iteration = true;
+        }
+        context.label_set = 0;
+
+        context.iterations += 1;
+        context.breakables += 1;
+        let statement = match self.lookahead.token_type {
             TokenType::DoKeyword => self.do_while_statement(),
             TokenType::ForKeyword => self.for_statement(),
             _ => self.while_statement()
-        }
+        };
+        self.jump_context.iterations -= 1;
+        self.jump_context.breakables -= 1;
+
+        statement
     }
 
     fn while_statement(&mut self) -> Result<Tree> {
@@ -94,6 +130,66 @@ impl IterationStatementParsable for Parser {
             TokenType::SemiColon => None,
             _ => Some(self.for_statement_init()?),
         };
+
+        // Switching to for-in/of loops -> for (let x of y)
+        match (init, &self.lookahead.token_type) {
+            (Some(left), TokenType::InKeyword) => self.for_in_of_statement(left),
+            (Some(left), TokenType::Identifier) if self.lookahead.value == "of" => self.for_in_of_statement(left),
+            (init, _) => self.for_statement_rest(init),
+        }
+    }
+
+    fn for_statement_init(&mut self) -> Result<Tree> {
+        match self.lookahead.token_type {
+            TokenType::LetKeyword | TokenType::ConstKeyword => self.variable_statement_init(),
+            _ => self.expression(),
+        }
+    }
+
+    fn for_in_of_statement(&mut self, left: Tree) -> Result<Tree> {
+        let of = self.lookahead.token_type == TokenType::Identifier;
+        let statement = if of { "for-of" } else { "for-in" };
+
+        // Loops bind a single variable without initializer, or assign to an identifier or member
+        let valid = match &left {
+            Tree::VariableStatement { declarations, .. } => matches!(
+                declarations.as_slice(),
+                [Tree::VariableDeclaration { init, .. }] if init.is_none()
+            ),
+            Tree::Identifier { .. } | Tree::MemberExpression { .. } => true,
+            _ => false,
+        };
+        if !valid {
+            return Err(Error::Syntax(format!("Invalid left-hand side in {statement} loop!")));
+        }
+
+        let right = match of {
+            true => {
+                self.eat(TokenType::Identifier)?;
+                self.assignment_expression()?
+            },
+            false => {
+                self.eat(TokenType::InKeyword)?;
+                self.expression()?
+            },
+        };
+        self.eat(TokenType::CircleBracketClose)?;
+
+        let body = self.statement()?;
+
+        let (left, right, body) = (Box::new(left), Box::new(right), Box::new(body));
+        Ok(match of {
+            true => Tree::ForOfStatement { left, right, body },
+            false => Tree::ForInStatement { left, right, body },
+        })
+    }
+}
+
+impl Parser {
+    /**
+     * Parses the rest of a C-style for loop, after its initializer.
+     */
+    fn for_statement_rest(&mut self, init: Option<Tree>) -> Result<Tree> {
         self.eat(TokenType::SemiColon)?;
 
         let test = match self.lookahead.token_type {
@@ -117,13 +213,6 @@ impl IterationStatementParsable for Parser {
             body: Box::new(body),
         })
     }
-
-    fn for_statement_init(&mut self) -> Result<Tree> {
-        match self.lookahead.token_type {
-            TokenType::LetKeyword => self.variable_statement_init(),
-            _ => self.expression(),
-        }
-    }
 }
 
 #[cfg(test)]
@@ -140,6 +229,7 @@ mod tests {
                     body: Box::new(Tree::EmptyStatement),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "while (true);");
     }
@@ -167,6 +257,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "while (x > 42) { x -= 1; }");
     }
@@ -190,6 +281,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "do x += 1; while (x < 42);");
     }
@@ -205,6 +297,7 @@ mod tests {
                     body: Box::new(Tree::EmptyStatement),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "for (;;);");
     }
@@ -215,6 +308,7 @@ mod tests {
             body: vec![
                 Tree::ForStatement {
                     init: Box::new(Some(Tree::VariableStatement {
+                        kind: String::from("let"),
                         declarations: vec![
                             Tree::VariableDeclaration {
                                 identifier: Box::new(Tree::Identifier { name: String::from("i") }),
@@ -241,6 +335,7 @@ mod tests {
                     }),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "for (let i=0, x=y; i<10; i+=1) {}");
     }
@@ -260,7 +355,56 @@ mod tests {
                     body: Box::new(Tree::EmptyStatement),
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "for (x = 2;;);");
     }
+
+    #[test]
+    fn test_parse_for_of_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ForOfStatement {
+                    left: Box::new(Tree::VariableStatement {
+                        kind: String::from("const"),
+                        declarations: vec![
+                            Tree::VariableDeclaration {
+                                identifier: Box::new(Tree::Identifier { name: String::from("x") }),
+                                init: Box::new(None),
+                            },
+                        ],
+                    }),
+                    right: Box::new(Tree::Identifier { name: String::from("xs") }),
+                    body: Box::new(Tree::EmptyStatement),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "for (const x of xs);");
+    }
+
+    #[test]
+    fn test_parse_for_in_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ForInStatement {
+                    left: Box::new(Tree::MemberExpression {
+                        object: Box::new(Tree::Identifier { name: String::from("o") }),
+                        property: Box::new(Tree::Identifier { name: String::from("key") }),
+                        computed: false,
+                    }),
+                    right: Box::new(Tree::Identifier { name: String::from("obj") }),
+                    body: Box::new(Tree::BlockStatement { body: vec![] }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "for (o.key in obj) {}");
+    }
+
+    #[test]
+    fn test_parse_invalid_for_of_declaration() {
+        let expected = Error::Syntax("Invalid left-hand side in for-of loop!".to_string());
+        assert_syntax_error(expected, "for (let x = 1, y of xs);");
+    }
 }
@@ -0,0 +1,136 @@
+use crate::prelude::*;
+
+use super::identifier::IdentifierParsable;
+
+pub trait JumpStatementParsable {
+    /**
+     * BreakStatement
+     *  : 'break' OptIdentifier ';'
+     *  ;
+     */
+    fn break_statement(&mut self) -> Result<Tree>;
+
+    /**
+     * ContinueStatement
+     *  : 'continue' OptIdentifier ';'
+     *  ;
+     */
+    fn continue_statement(&mut self) -> Result<Tree>;
+}
+
+impl JumpStatementParsable for Parser {
+    fn break_statement(&mut self) -> Result<Tree> {
+        self.eat(TokenType::BreakKeyword)?;
+
+        let label = match self.lookahead.token_type {
+            TokenType::Identifier => Some(self.identifier()?),
+            _ => None,
+        };
+        self.eat(TokenType::SemiColon)?;
+
+        // Unlabeled breaks must be enclosed by a loop or switch
+        match &label {
+            Some(Tree::Identifier { name }) => if !self.jump_context.labels.iter().any(|label| &label.name == name) {
+                return Err(Error::Syntax(format!("Undefined label '{name}'!")));
+            },
+            _ => if self.jump_context.breakables == 0 {
+                return Err(Error::Syntax("Illegal break statement!".to_string()));
+            },
+        }
+
+        Ok(Tree::BreakStatement {
+            label: Box::new(label),
+        })
+    }
+
+    fn continue_statement(&mut self) -> Result<Tree> {
+        self.eat(TokenType::ContinueKeyword)?;
+
+        let label = match self.lookahead.token_type {
+            TokenType::Identifier => Some(self.identifier()?),
+            _ => None,
+        };
+        self.eat(TokenType::SemiColon)?;
+
+        // Continues must target an enclosing loop
+        if self.jump_context.iterations == 0 {
+            return Err(Error::Syntax(
+                "Illegal continue statement: no surrounding iteration statement!".to_string()
+            ));
+        }
+        if let Some(Tree::Identifier { name }) = &label {
+            match self.jump_context.labels.iter().rev().find(|label| &label.name == name) {
+                None => return Err(Error::Syntax(format!("Undefined label '{name}'!"))),
+                Some(label) if !label.iteration => return Err(Error::Syntax(
+                    format!("Illegal continue statement: '{name}' does not denote an iteration statement!")
+                )),
+                _ => (),
+            }
+        }
+
+        Ok(Tree::ContinueStatement {
+            label: Box::new(label),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_break_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::WhileStatement {
+                    test: Box::new(Tree::BooleanLiteral { value: true }),
+                    body: Box::new(Tree::BreakStatement { label: Box::new(None) }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "while (true) break;");
+    }
+
+    #[test]
+    fn test_parse_continue_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::ForStatement {
+                    init: Box::new(None),
+                    test: Box::new(None),
+                    update: Box::new(None),
+                    body: Box::new(Tree::BlockStatement {
+                        body: vec![
+                            Tree::ContinueStatement { label: Box::new(None) },
+                        ],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "for (;;) { continue; }");
+    }
+
+    #[test]
+    fn test_parse_illegal_break_statement() {
+        let expected = Error::Syntax("Illegal break statement!".to_string());
+        assert_syntax_error(expected, "if (x) break;");
+    }
+
+    #[test]
+    fn test_parse_illegal_continue_statement() {
+        let expected = Error::Syntax("Illegal continue statement: no surrounding iteration statement!".to_string());
+        assert_syntax_error(expected, "{ continue; }");
+    }
+
+    #[test]
+    fn test_parse_jump_across_function_boundary() {
+        let expected = Error::Syntax("Illegal break statement!".to_string());
+        assert_syntax_error(expected, "while (true) { function f() { break; } }");
+
+        let expected = Error::Syntax("Undefined label 'outer'!".to_string());
+        assert_syntax_error(expected, "outer: while (true) { function f() { while (true) continue outer; } }");
+    }
+}
@@ -0,0 +1,120 @@
+use crate::prelude::*;
+
+use super::list::StatementListParsable;
+use crate::parser::Label;
+
+pub trait LabeledStatementParsable {
+    /**
+     * LabeledStatement
+     *  : Identifier ':' Statement
+     *  ;
+     *
+     * The identifier is already consumed by the caller, since a
+     * label is only distinguishable from an expression by the ':'.
+     */
+    fn labeled_statement(&mut self, label: Tree) -> Result<Tree>;
+}
+
+impl LabeledStatementParsable for Parser {
+    fn labeled_statement(&mut self, label: Tree) -> Result<Tree> {
+        let name = match &label {
+            Tree::Identifier { name } => name.clone(),
+            _ => return Err(Error::Syntax(format!("Invalid label: {label}!"))),
+        };
+        self.eat(TokenType::Colon)?;
+
+        if self.jump_context.labels.iter().any(|label| label.name == name) {
+            return Err(Error::Syntax(format!("Label '{name}' has already been declared!")));
+        }
+        self.jump_context.labels.push(Label { name, iteration: false });
+
+        // Only labels & loops keep extending the current label set
+        self.jump_context.label_set = match self.lookahead.token_type {
+            TokenType::Identifier
+            | TokenType::WhileKeyword
+            | TokenType::DoKeyword
+            | TokenType::ForKeyword => self.jump_context.label_set + 1,
+            _ => 0,
+        };
+
+        let body = self.statement();
+        self.jump_context.labels.pop();
+        self.jump_context.label_set = 0;
+
+        Ok(Tree::LabeledStatement {
+            label: Box::new(label),
+            body: Box::new(body?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_labeled_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::LabeledStatement {
+                    label: Box::new(Tree::Identifier { name: String::from("outer") }),
+                    body: Box::new(Tree::WhileStatement {
+                        test: Box::new(Tree::BooleanLiteral { value: true }),
+                        body: Box::new(Tree::ContinueStatement {
+                            label: Box::new(Some(Tree::Identifier { name: String::from("outer") })),
+                        }),
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "outer: while (true) continue outer;");
+    }
+
+    #[test]
+    fn test_parse_labeled_block_break() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::LabeledStatement {
+                    label: Box::new(Tree::Identifier { name: String::from("block") }),
+                    body: Box::new(Tree::BlockStatement {
+                        body: vec![
+                            Tree::BreakStatement {
+                                label: Box::new(Some(Tree::Identifier { name: String::from("block") })),
+                            },
+                        ],
+                    }),
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "block: { break block; }");
+    }
+
+    #[test]
+    fn test_parse_nested_label_set() {
+        let content_string = "a: b: for (;;) { continue a; }";
+        assert!(Parser::new(content_string.to_string()).unwrap().parse().is_ok());
+    }
+
+    #[test]
+    fn test_parse_undefined_label() {
+        let expected = Error::Syntax("Undefined label 'missing'!".to_string());
+        assert_syntax_error(expected, "while (true) { break missing; }");
+    }
+
+    #[test]
+    fn test_parse_duplicate_label() {
+        let expected = Error::Syntax("Label 'a' has already been declared!".to_string());
+        assert_syntax_error(expected, "a: { a: ; }");
+    }
+
+    #[test]
+    fn test_parse_continue_non_iteration_label() {
+        let expected = Error::Syntax(
+            "Illegal continue statement: 'a' does not denote an iteration statement!".to_string()
+        );
+        assert_syntax_error(expected, "a: { for (;;) { continue a; } }");
+    }
+}
@@ -3,11 +3,15 @@ use crate::prelude::*;
 use super::block::BlockStatementParsable;
 use super::class::ClassDeclarationParsable;
 use super::empty::EmptyStatementParsable;
+use super::exception::ExceptionStatementParsable;
 use super::expression::ExpressionStatementParsable;
 use super::conditional::IfStatementParsable;
 use super::function::FunctionDeclarationParsable;
 use super::iteration::IterationStatementParsable;
+use super::jump::JumpStatementParsable;
+use super::switch::SwitchStatementParsable;
 use super::variable::VariableStatementParsable;
+use super::with::WithStatementParsable;
 
 pub trait StatementListParsable {
     /**
@@ -18,16 +22,35 @@ pub trait StatementListParsable {
      */
     fn statement_list(&mut self, stop_lookahead_type: TokenType) -> Result<Vec<Tree>>;
 
+    /**
+     * DirectivePrologue
+     *  : ExpressionStatement
+     *  | DirectivePrologue ExpressionStatement
+     *  ;
+     * 
+     * Parses the string literal statements beginning a program or a function body.
+     * Strict mode applies as soon as a 'use strict' directive is found, which may
+     * not contain escapes, hence the raw token is checked.
+     */
+    fn directive_prologue(&mut self) -> Result<Vec<Tree>>;
+
     /**
      * Statement
      *  : IterationStatement
      *  | FunctionDeclaration
      *  | ReturnKeyword
+     *  | BreakStatement
+     *  | ContinueStatement
      *  | EmptyStatement
      *  | BlockStatement
      *  | VariableStatement
      *  | IfStatement
+     *  | SwitchStatement
+     *  | ThrowStatement
+     *  | TryStatement
      *  | ClassDeclaration
+     *  | LabeledStatement
+     *  | WithStatement
      *  | ExpressionStatement
      *  ;
      * 
@@ -35,6 +58,8 @@ pub trait StatementListParsable {
      *  : WhileStatement
      *  | DoWhileStatement
      *  | ForStatement
+     *  | ForOfStatement
+     *  | ForInStatement
      *  ;
      */
     fn statement(&mut self) -> Result<Tree>;
@@ -51,16 +76,47 @@ impl StatementListParsable for Parser {
         Ok(statement_list)
     }
 
+    fn directive_prologue(&mut self) -> Result<Vec<Tree>> {
+        let mut directives = vec![];
+
+        while self.lookahead.token_type == TokenType::String {
+            let raw = self.lookahead.value.clone();
+            let statement = self.statement()?;
+
+            // A string literal followed by more of an expression ends the prologue, e.g. `'a' + b;`
+            let is_directive = match &statement {
+                Tree::ExpressionStatement { expression } => matches!(expression.as_ref(), Tree::StringLiteral { .. }),
+                _ => false,
+            };
+            directives.push(statement);
+            if !is_directive {
+                break;
+            }
+            if raw == "'use strict'" || raw == "\"use strict\"" {
+                self.strict = true;
+            }
+        }
+
+        Ok(directives)
+    }
+
     fn statement(&mut self) -> Result<Tree> {
         match self.lookahead.token_type {
             TokenType::WhileKeyword | TokenType::DoKeyword | TokenType::ForKeyword => self.iteration_statement(),
             TokenType::FunctionKeyword => self.function_declaration(),
+            TokenType::Identifier if self.is_async_function() => self.function_declaration(),
             TokenType::ReturnKeyword => self.return_statement(),
+            TokenType::BreakKeyword => self.break_statement(),
+            TokenType::ContinueKeyword => self.continue_statement(),
             TokenType::SemiColon => self.empty_statement(),
             TokenType::CurlyBracketOpen => self.block_statement(),
-            TokenType::LetKeyword => self.variable_statement(),
+            TokenType::LetKeyword | TokenType::ConstKeyword => self.variable_statement(),
             TokenType::IfKeyword => self.if_statement(),
+            TokenType::SwitchKeyword => self.switch_statement(),
+            TokenType::ThrowKeyword => self.throw_statement(),
+            TokenType::TryKeyword => self.try_statement(),
             TokenType::ClassKeyword => self.class_delaration(),
+            TokenType::WithKeyword => self.with_statement(),
             _ => self.expression_statement(),
         }
     }
@@ -82,6 +138,7 @@ mod tests {
                     expression: Box::new(Tree::StringLiteral { value: "Hello".to_owned() } ),
                 }
             ],
+            strict: false,
         };
         assert_tree(expected, "42;\"Hello\";");
     }
@@ -4,8 +4,13 @@ pub mod block;
 mod class;
 mod conditional;
 mod empty;
+mod exception;
 pub mod expression;
 pub mod function;
 mod iteration;
+mod jump;
+mod labeled;
+mod switch;
 pub mod list;
 mod variable;
+mod with;
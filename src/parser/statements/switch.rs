@@ -0,0 +1,166 @@
+use crate::prelude::*;
+
+use super::expression::ExpressionStatementParsable;
+use super::list::StatementListParsable;
+
+pub trait SwitchStatementParsable {
+    /**
+     * SwitchStatement
+     *  : 'switch' '(' Expression ')' CaseBlock
+     *  ;
+     *
+     * CaseBlock
+     *  : '{' OptCaseClauses '}'
+     *  ;
+     *
+     * CaseClauses
+     *  : SwitchCase
+     *  | CaseClauses SwitchCase
+     *  ;
+     */
+    fn switch_statement(&mut self) -> Result<Tree>;
+
+    /**
+     * SwitchCase
+     *  : 'case' Expression ':' OptStatementList
+     *  | 'default' ':' OptStatementList
+     *  ;
+     */
+    fn switch_case(&mut self) -> Result<Tree>;
+}
+
+impl SwitchStatementParsable for Parser {
+    fn switch_statement(&mut self) -> Result<Tree> {
+        self.eat(TokenType::SwitchKeyword)?;
+
+        self.eat(TokenType::CircleBracketOpen)?;
+        let discriminant = self.expression()?;
+        self.eat(TokenType::CircleBracketClose)?;
+
+        // Unlabeled breaks inside the cases target the switch
+        self.jump_context.label_set = 0;
+        self.jump_context.breakables += 1;
+        let cases = self.case_block();
+        self.jump_context.breakables -= 1;
+
+        Ok(Tree::SwitchStatement {
+            discriminant: Box::new(discriminant),
+            cases: cases?,
+        })
+    }
+
+    fn switch_case(&mut self) -> Result<Tree> {
+        let test = match self.lookahead.token_type {
+            TokenType::DefaultKeyword => {
+                self.eat(TokenType::DefaultKeyword)?;
+                None
+            },
+            _ => {
+                self.eat(TokenType::CaseKeyword)?;
+                Some(self.expression()?)
+            },
+        };
+        self.eat(TokenType::Colon)?;
+
+        let mut consequent = vec![];
+        while !matches!(
+            self.lookahead.token_type,
+            TokenType::CaseKeyword | TokenType::DefaultKeyword | TokenType::CurlyBracketClose,
+        ) {
+            consequent.push(self.statement()?);
+        }
+
+        Ok(Tree::SwitchCase {
+            test: Box::new(test),
+            consequent,
+        })
+    }
+}
+
+impl Parser {
+    /**
+     * Parses the case clauses of a switch, allowing at most one default clause.
+     */
+    fn case_block(&mut self) -> Result<Vec<Tree>> {
+        self.eat(TokenType::CurlyBracketOpen)?;
+
+        let mut cases = vec![];
+        let mut has_default = false;
+        while self.lookahead.token_type != TokenType::CurlyBracketClose {
+            if self.lookahead.token_type == TokenType::DefaultKeyword {
+                if has_default {
+                    return Err(Error::Syntax("More than one default clause in switch statement!".to_string()));
+                }
+                has_default = true;
+            }
+            cases.push(self.switch_case()?);
+        }
+
+        self.eat(TokenType::CurlyBracketClose)?;
+        Ok(cases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_switch_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::SwitchStatement {
+                    discriminant: Box::new(Tree::Identifier { name: String::from("x") }),
+                    cases: vec![
+                        Tree::SwitchCase {
+                            test: Box::new(Some(Tree::NumericLiteral { value: 1.0 })),
+                            consequent: vec![],
+                        },
+                        Tree::SwitchCase {
+                            test: Box::new(Some(Tree::NumericLiteral { value: 2.0 })),
+                            consequent: vec![
+                                Tree::ExpressionStatement {
+                                    expression: Box::new(Tree::Identifier { name: String::from("y") }),
+                                },
+                                Tree::BreakStatement { label: Box::new(None) },
+                            ],
+                        },
+                        Tree::SwitchCase {
+                            test: Box::new(None),
+                            consequent: vec![Tree::EmptyStatement],
+                        },
+                    ],
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "switch (x) { case 1: case 2: y; break; default: ; }");
+    }
+
+    #[test]
+    fn test_parse_empty_switch_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::SwitchStatement {
+                    discriminant: Box::new(Tree::NumericLiteral { value: 1.0 }),
+                    cases: vec![],
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "switch (1) {}");
+    }
+
+    #[test]
+    fn test_parse_multiple_default_clauses() {
+        let expected = Error::Syntax("More than one default clause in switch statement!".to_string());
+        assert_syntax_error(expected, "switch (x) { default: default: }");
+    }
+
+    #[test]
+    fn test_parse_continue_inside_switch() {
+        let expected = Error::Syntax("Illegal continue statement: no surrounding iteration statement!".to_string());
+        assert_syntax_error(expected, "switch (x) { case 1: continue; }");
+    }
+}
@@ -13,7 +13,12 @@ pub trait VariableStatementParsable {
 
     /**
      * VariableStatementInit
-     *  : 'let' VariableDeclarationList
+     *  : VariableKind VariableDeclarationList
+     *  ;
+     * 
+     * VariableKind
+     *  : 'let'
+     *  | 'const'
      *  ;
      */
     fn variable_statement_init(&mut self) -> Result<Tree>;
@@ -45,13 +50,26 @@ impl VariableStatementParsable for Parser {
     fn variable_statement(&mut self) -> Result<Tree> {
         let statement = self.variable_statement_init()?;
         self.eat(TokenType::SemiColon)?;
+
+        // Constants can't be assigned later on, except for the bindings of for-in/of loops
+        if let Tree::VariableStatement { kind, declarations } = &statement {
+            let uninitialized = declarations.iter().any(|declaration| {
+                matches!(declaration, Tree::VariableDeclaration { init, .. } if init.is_none())
+            });
+            if kind == "const" && uninitialized {
+                return Err(Error::Syntax("Missing initializer in const declaration!".to_string()));
+            }
+        }
         Ok(statement)
     }
 
     fn variable_statement_init(&mut self) -> Result<Tree> {
-        self.eat(TokenType::LetKeyword)?;
+        let kind = match self.lookahead.token_type {
+            TokenType::ConstKeyword => self.eat(TokenType::ConstKeyword)?.value,
+            _ => self.eat(TokenType::LetKeyword)?.value,
+        };
         let declarations = self.variable_declaration_list()?;
-        Ok(Tree::VariableStatement { declarations })
+        Ok(Tree::VariableStatement { kind, declarations })
     }
 
     fn variable_declaration_list(&mut self) -> Result<Vec<Tree>> {
@@ -68,9 +86,10 @@ impl VariableStatementParsable for Parser {
     fn variable_declaration(&mut self) -> Result<Tree> {
         let identifier = self.identifier()?;
 
-        // OptVariableInitializer
+        // OptVariableInitializer, missing in for-in/of heads as well -> for (let x of y)
         let init = match self.lookahead.token_type {
-            TokenType::Comma | TokenType::SemiColon => None,
+            TokenType::Comma | TokenType::SemiColon | TokenType::InKeyword => None,
+            TokenType::Identifier if self.lookahead.value == "of" => None,
             _ => Some(self.variable_initializer()?),
         };
 
@@ -96,7 +115,8 @@ mod tests {
     fn test_parse_simple_no_init_variable_statement() {
         let expected = Tree::Program { 
             body: vec![
-                Tree::VariableStatement { 
+                Tree::VariableStatement {
+                    kind: String::from("let"),
                     declarations: vec![
                         Tree::VariableDeclaration {
                             identifier: Box::new(Tree::Identifier { name: String::from("y") }),
@@ -105,6 +125,7 @@ mod tests {
                     ],
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "let y;");
     }
@@ -113,7 +134,8 @@ mod tests {
     fn test_parse_simple_variable_statement() {
         let expected = Tree::Program { 
             body: vec![
-                Tree::VariableStatement { 
+                Tree::VariableStatement {
+                    kind: String::from("let"),
                     declarations: vec![
                         Tree::VariableDeclaration {
                             identifier: Box::new(Tree::Identifier { name: String::from("str") }),
@@ -122,6 +144,7 @@ mod tests {
                     ],
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "let str = 'Hello';");
     }
@@ -130,7 +153,8 @@ mod tests {
     fn test_parse_multiple_no_init_variable_statement() {
         let expected = Tree::Program { 
             body: vec![
-                Tree::VariableStatement { 
+                Tree::VariableStatement {
+                    kind: String::from("let"),
                     declarations: vec![
                         Tree::VariableDeclaration {
                             identifier: Box::new(Tree::Identifier { name: String::from("a") }),
@@ -143,6 +167,7 @@ mod tests {
                     ],
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "let a, b;");
     }
@@ -151,7 +176,8 @@ mod tests {
     fn test_parse_multiple_variable_statement() {
         let expected = Tree::Program { 
             body: vec![
-                Tree::VariableStatement { 
+                Tree::VariableStatement {
+                    kind: String::from("let"),
                     declarations: vec![
                         Tree::VariableDeclaration {
                             identifier: Box::new(Tree::Identifier { name: String::from("c") }),
@@ -164,6 +190,7 @@ mod tests {
                     ],
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "let c, d = 42;");
     }
@@ -172,7 +199,8 @@ mod tests {
     fn test_parse_chained_variable_statement_1() {
         let expected = Tree::Program { 
             body: vec![
-                Tree::VariableStatement { 
+                Tree::VariableStatement {
+                    kind: String::from("let"),
                     declarations: vec![
                         Tree::VariableDeclaration {
                             identifier: Box::new(Tree::Identifier { name: String::from("x") }),
@@ -185,6 +213,7 @@ mod tests {
                     ],
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "let x = y = 42;");
     }
@@ -193,7 +222,8 @@ mod tests {
     fn test_parse_chained_variable_statement_2() {
         let expected = Tree::Program { 
             body: vec![
-                Tree::VariableStatement { 
+                Tree::VariableStatement {
+                    kind: String::from("let"),
                     declarations: vec![
                         Tree::VariableDeclaration {
                             identifier: Box::new(Tree::Identifier { name: String::from("x") }),
@@ -206,6 +236,7 @@ mod tests {
                     ],
                 },
             ],
+            strict: false,
         };
         assert_tree(expected, "let x = y -= 42;");
     }
@@ -225,4 +256,29 @@ mod tests {
         );
         assert_syntax_error(expected, "let 42 = 42;");
     }
+
+    #[test]
+    fn test_parse_const_variable_statement() {
+        let expected = Tree::Program {
+            body: vec![
+                Tree::VariableStatement {
+                    kind: String::from("const"),
+                    declarations: vec![
+                        Tree::VariableDeclaration {
+                            identifier: Box::new(Tree::Identifier { name: String::from("x") }),
+                            init: Box::new(Some(Tree::NumericLiteral { value: 1.0 })),
+                        },
+                    ],
+                },
+            ],
+            strict: false,
+        };
+        assert_tree(expected, "const x = 1;");
+    }
+
+    #[test]
+    fn test_parse_const_without_initializer() {
+        let expected = Error::Syntax("Missing initializer in const declaration!".to_string());
+        assert_syntax_error(expected, "const x;");
+    }
 }
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+use super::expression::ExpressionStatementParsable;
+use super::list::StatementListParsable;
+
+pub trait WithStatementParsable {
+    /**
+     * WithStatement
+     *  : 'with' '(' Expression ')' Statement
+     *  ;
+     * 
+     * Not allowed in strict mode code, as the variables of the body
+     * can't be resolved before knowing the properties of the object.
+     * Sloppy mode code is parsed but rejected as well, since environments
+     * only hold declared variables & can't be backed by an object.
+     */
+    fn with_statement(&mut self) -> Result<Tree>;
+}
+
+impl WithStatementParsable for Parser {
+    fn with_statement(&mut self) -> Result<Tree> {
+        if self.strict {
+            return Err(Error::Syntax("Strict mode code may not include a with statement!".to_string()));
+        }
+        self.eat(TokenType::WithKeyword)?;
+
+        self.eat(TokenType::CircleBracketOpen)?;
+        self.expression()?;
+        self.eat(TokenType::CircleBracketClose)?;
+
+        self.statement()?;
+
+        Err(Error::Syntax("With statements are not supported!".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::parser::tests::*;
+
+    #[test]
+    fn test_parse_with_statement() {
+        let expected = || Error::Syntax("With statements are not supported!".to_string());
+        assert_syntax_error(expected(), "with (Math) { PI; }");
+        assert_syntax_error(expected(), "function f() { with (Math) return PI; }");
+
+        // The body is still parsed, reporting its own syntax errors first
+        let expected = Error::Syntax("Unexpected token Eof, expected Identifier!".to_string());
+        assert_syntax_error(expected, "with (Math) {");
+    }
+
+    #[test]
+    fn test_parse_with_statement_in_strict_mode() {
+        let expected = || Error::Syntax("Strict mode code may not include a with statement!".to_string());
+        assert_syntax_error(expected(), "'use strict'; with (Math) {}");
+        assert_syntax_error(expected(), "function f() { 'use strict'; with (Math) {} }");
+        assert_syntax_error(expected(), "class A { m() { with (Math) {} } }");
+    }
+}
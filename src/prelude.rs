@@ -1,10 +1,15 @@
-pub use crate::interpreter::{Evalable, Interpreter};
+pub use crate::interpreter::{Clock, Evalable, Interpreter, SystemClock, VirtualClock};
 pub use crate::parser::{Eatable, Parsable, Parser};
+pub use crate::completion::Completion;
 pub use crate::environment::{Environment, EnvironmentBuilder};
 pub use crate::errors::Error;
+pub use crate::object::{ArrayElements, BoundFunction, FieldDefinition, Function, FunctionKind, NativeClosure, NativeFn, NativeFunction, Object, ObjectKind, ObjectRef, Property, PropertyDescriptor, PropertyKey, ProxyState};
+pub use crate::symbol::Symbol;
 pub use crate::tokenizer::{Token, TokenType, Tokenizer};
 pub use crate::tree::Tree;
+pub use crate::utf16::JsString;
 pub use crate::value::Value;
+pub use num_bigint::BigInt;
 
 pub type Result<T> = core::result::Result<T, Error>;
 pub type EnvRef<'a> = std::cell::RefCell<&'a mut Environment>;
@@ -0,0 +1,95 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::{Rc, Weak};
+
+/**
+ * Unique primitive value, e.g. created by `Symbol('description')`.
+ * Two handles are equal only if they point to the same symbol.
+ */
+#[derive(Clone)]
+pub struct Symbol(Rc<Option<String>>);
+
+impl Symbol {
+    pub fn new(description: Option<String>) -> Self {
+        Self(Rc::new(description))
+    }
+
+    pub fn description(&self) -> Option<String> {
+        self.0.as_ref().clone()
+    }
+
+    /**
+     * Returns a handle which doesn't keep the symbol alive.
+     */
+    pub fn downgrade(&self) -> WeakSymbol {
+        WeakSymbol(Rc::downgrade(&self.0))
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol({})", self.0.as_deref().unwrap_or(""))
+    }
+}
+
+/**
+ * Symbol which may have been dropped, e.g. the key of a WeakMap entry.
+ */
+pub struct WeakSymbol(Weak<Option<String>>);
+
+impl WeakSymbol {
+    /**
+     * Returns the symbol, unless it has been dropped.
+     */
+    pub fn upgrade(&self) -> Option<Symbol> {
+        self.0.upgrade().map(Symbol)
+    }
+}
+
+impl PartialEq for WeakSymbol {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for WeakSymbol {}
+
+impl Hash for WeakSymbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Weak::as_ptr(&self.0).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_identity() {
+        let symbol = Symbol::new(Some("id".to_string()));
+        assert_eq!(symbol, symbol.clone());
+        assert_ne!(symbol, Symbol::new(Some("id".to_string())));
+        assert_eq!(symbol.to_string(), "Symbol(id)");
+        assert_eq!(Symbol::new(None).to_string(), "Symbol()");
+    }
+}
@@ -1,5 +1,6 @@
 use crate::prelude::{Error, Result};
 use std::fmt::format;
+use std::sync::LazyLock;
 
 use regex::Regex;
 
@@ -10,15 +11,19 @@ pub enum TokenType {
 
     // ----- LITERALS -----
     Number,
+    BigInt,
     String,
+    RegularExpression,
 
     // ----- SYMBOLS & DELIMITERS -----
     SemiColon,
+    Colon,
     CurlyBracketOpen,
     CurlyBracketClose,
     CircleBracketOpen,
     CircleBracketClose,
     Comma,
+    Spread,
     Dot,
     SquareBracketOpen,
     SquareBracketClose,
@@ -26,6 +31,7 @@ pub enum TokenType {
     // ----- OPERATORS -----
     AdditiveOperator,
     MultiplicativeOperator,
+    ExponentiationOperator,
     RelationalOperator,
     SimpleAssignmentOperator,
     ComplexAssignmentOperator,
@@ -36,9 +42,11 @@ pub enum TokenType {
 
     // ----- IDENTIFIERS -----
     Identifier,
+    PrivateIdentifier,
 
     // ----- KEYWORDS -----
     LetKeyword,
+    ConstKeyword,
     IfKeyword,
     ElseKeyword,
     TrueKeyword,
@@ -47,8 +55,19 @@ pub enum TokenType {
     WhileKeyword,
     DoKeyword,
     ForKeyword,
+    InKeyword,
     FunctionKeyword,
     ReturnKeyword,
+    BreakKeyword,
+    ContinueKeyword,
+    SwitchKeyword,
+    CaseKeyword,
+    DefaultKeyword,
+    ThrowKeyword,
+    TryKeyword,
+    CatchKeyword,
+    FinallyKeyword,
+    WithKeyword,
     ClassKeyword,
     ExtendsKeyword,
     ConstructorKeyword,
@@ -63,7 +82,7 @@ impl TokenType {
     /**
      * Tokenizer spec.
      */
-    const SPEC: [(Option<TokenType>, &str); 44] = [
+    const SPEC: [(Option<TokenType>, &str); 62] = [
         // ----- WHITESPACES -----
         (None, r"^\s+"),
 
@@ -71,21 +90,24 @@ impl TokenType {
         (None, r"^(//.*)"),
 
         // ----- MULTI-LINE COMMENTS -----
-        (None, r"^(/*[\s\S]*?\*/)"),
+        (None, r"^(/\*[\s\S]*?\*/)"),
 
         // ----- SYMBOLS & DELIMITERS -----
         (Some(Self::SemiColon), r"^(;)"),
+        (Some(Self::Colon), r"^(:)"),
         (Some(Self::CurlyBracketOpen), r"^(\{)"),
         (Some(Self::CurlyBracketClose), r"^(\})"),
         (Some(Self::CircleBracketOpen), r"^(\()"),
         (Some(Self::CircleBracketClose), r"^(\))"),
         (Some(Self::Comma), r"^(\,)"),
+        (Some(Self::Spread), r"^(\.\.\.)"),
         (Some(Self::Dot), r"^(\.)"),
         (Some(Self::SquareBracketOpen), r"^(\[)"),
         (Some(Self::SquareBracketClose), r"^(\])"),
 
         // ----- KEYWORDS -----
         (Some(Self::LetKeyword), r"^(\blet\b)"),
+        (Some(Self::ConstKeyword), r"^(\bconst\b)"),
         (Some(Self::IfKeyword), r"^(\bif\b)"),
         (Some(Self::ElseKeyword), r"^(\belse\b)"),
         (Some(Self::TrueKeyword), r"^(\btrue\b)"),
@@ -94,8 +116,20 @@ impl TokenType {
         (Some(Self::WhileKeyword), r"^(\bwhile\b)"),
         (Some(Self::DoKeyword), r"^(\bdo\b)"),
         (Some(Self::ForKeyword), r"^(\bfor\b)"),
+        (Some(Self::InKeyword), r"^(\bin\b)"),
         (Some(Self::FunctionKeyword), r"^(\bfunction\b)"),
         (Some(Self::ReturnKeyword), r"^(\breturn\b)"),
+        (Some(Self::BreakKeyword), r"^(\bbreak\b)"),
+        (Some(Self::ContinueKeyword), r"^(\bcontinue\b)"),
+        (Some(Self::SwitchKeyword), r"^(\bswitch\b)"),
+        (Some(Self::CaseKeyword), r"^(\bcase\b)"),
+        (Some(Self::DefaultKeyword), r"^(\bdefault\b)"),
+        (Some(Self::ThrowKeyword), r"^(\bthrow\b)"),
+        (Some(Self::TryKeyword), r"^(\btry\b)"),
+        (Some(Self::CatchKeyword), r"^(\bcatch\b)"),
+        (Some(Self::FinallyKeyword), r"^(\bfinally\b)"),
+        (Some(Self::WithKeyword), r"^(\bwith\b)"),
+        (Some(Self::RelationalOperator), r"^(\binstanceof\b)"),
         (Some(Self::ClassKeyword), r"^(\bclass\b)"),
         (Some(Self::ExtendsKeyword), r"^(\bextends\b)"),
         (Some(Self::ConstructorKeyword), r"^(\bconstructor\b)"),
@@ -106,7 +140,8 @@ impl TokenType {
         (Some(Self::NewKeyword), r"^(\bnew\b)"),
 
         // ----- NUMBERS -----
-        (Some(Self::Number), r"^(\d+)"),
+        (Some(Self::BigInt), r"^((?:0|[1-9]\d*)n\b)"),
+        (Some(Self::Number), r"^(\d+(?:\.\d*)?(?:[eE][+-]?\d+)?)"),
 
         // ----- STRINGS -----
         (Some(Self::String), r#"^(".*?")"#),
@@ -114,9 +149,10 @@ impl TokenType {
 
         // ----- IDENTIFIERS -----
         (Some(Self::Identifier), r"^(\w+)"),
+        (Some(Self::PrivateIdentifier), r"^(#\w+)"),
 
         // ----- EQUALITY OPERATORS -----
-        (Some(Self::EqualityOperator), r"^([=|!]=)"),
+        (Some(Self::EqualityOperator), r"^([=!]==?)"),
 
         // ----- LOGICAL OPERATORS -----
         (Some(Self::LogicalAndOperator), r"^(&&)"),
@@ -125,21 +161,38 @@ impl TokenType {
 
         // ----- ASSIGNMENT OPERATORS -----
         (Some(Self::SimpleAssignmentOperator), r"^(=)"),
-        (Some(Self::ComplexAssignmentOperator), r"^([\+|\-|\*|/]=)"),
+        (Some(Self::ComplexAssignmentOperator), r"^(\*\*=|[\+|\-|\*|/|%]=)"),
 
         // ----- MATH OPERATORS -----
         (Some(Self::AdditiveOperator), r"^(\+|-)"),
-        (Some(Self::MultiplicativeOperator), r"^(\*|/)"),
+        (Some(Self::ExponentiationOperator), r"^(\*\*)"),
+        (Some(Self::MultiplicativeOperator), r"^(\*|/|%)"),
 
         // ----- RELATIONAL OPERATORS -----
         (Some(Self::RelationalOperator), r"^([><]=?)"),
     ];
+
+    /**
+     * Compiled tokenizer spec, built once on first use.
+     */
+    fn compiled_spec() -> &'static [(Option<TokenType>, Regex)] {
+        static COMPILED_SPEC: LazyLock<Vec<(Option<TokenType>, Regex)>> = LazyLock::new(|| {
+            TokenType::SPEC.iter()
+                .map(|(token_type, regex)| (token_type.clone(), Regex::new(regex).unwrap()))
+                .collect()
+        });
+        &COMPILED_SPEC
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Token {
     pub token_type: TokenType,
     pub value: String,
+
+    // Byte offsets of the token in the source text
+    pub start: usize,
+    pub end: usize,
 }
 
 /**
@@ -162,42 +215,104 @@ impl Tokenizer {
     }
 
     /**
-     * Whether we still have more tokens.
+     * Obtains next token.
      */
-    fn has_tokens(&self) -> bool {
-        self.cursor < self.content_string.len()
+    pub fn get_next_token(&mut self) -> Result<Token> {
+        let token = self.read_token(self.cursor)?;
+        self.cursor = token.end;
+        Ok(token)
     }
 
     /**
-     * Obtains next token.
+     * Reads the token following the current one without consuming it,
+     * e.g. to tell `async function` apart from an identifier named `async`.
      */
-    pub fn get_next_token(&mut self) -> Result<Token> {
-        if !self.has_tokens() {
-            return Ok(Token {
-                token_type: TokenType::Eof,
-                value: String::new(),
-            });
-        }
+    pub fn peek_token(&self) -> Result<Token> {
+        self.read_token(self.cursor)
+    }
+
+    /**
+     * Reads the token starting at the cursor, skipping whitespaces & comments.
+     */
+    fn read_token(&self, mut cursor: usize) -> Result<Token> {
+        'tokens: while cursor < self.content_string.len() {
+            for (token_type, re) in TokenType::compiled_spec() {
+                if let Some(caps) = re.captures(&self.content_string[cursor..]) {
+                    let cap = &caps[0];
+                    cursor += cap.len();
 
-        for (token_type, regex) in TokenType::SPEC {
-            let re = Regex::new(regex).unwrap();
-            if let Some(caps) = re.captures(&self.content_string[self.cursor..]) {
-                let cap = &caps[0];
-                self.cursor += cap.len();
-
-                // Should skip token, e.g. whitespaces
-                return match token_type {
-                    None => self.get_next_token(),
-                    Some(token_type) => Ok(Token {
-                        token_type,
-                        value: cap.to_string(),
-                    })
-                };
+                    // Should skip token, e.g. whitespaces
+                    match token_type {
+                        None => continue 'tokens,
+                        Some(token_type) => return Ok(Token {
+                            token_type: token_type.clone(),
+                            value: cap.to_string(),
+                            start: cursor - cap.len(),
+                            end: cursor,
+                        }),
+                    }
+                }
             }
+
+            return Err(Error::Syntax(
+                format(format_args!("Unexpected token: {}", self.content_string[cursor..].chars().next().unwrap()))
+            ));
         }
 
-        Err(Error::Syntax(
-            format(format_args!("Unexpected token: {}", self.content_string.chars().nth(self.cursor).unwrap()))
-        ))
+        Ok(Token {
+            token_type: TokenType::Eof,
+            value: String::new(),
+            start: cursor,
+            end: cursor,
+        })
+    }
+
+    /**
+     * Re-reads a '/' or '/=' token as a regular expression literal, e.g. `/a\/b/gi`.
+     * The tokenizer can't tell them apart on its own, hence the parser
+     * calls this once it expects an expression instead of an operator.
+     */
+    pub fn read_regular_expression(&mut self, slash: &Token) -> Result<Token> {
+        let start = self.cursor - slash.value.len();
+        let mut chars = self.content_string[start..].char_indices().skip(1);
+        let mut class = false;
+        let end = loop {
+            match chars.next() {
+                None | Some((_, '\n' | '\r')) => {
+                    return Err(Error::Syntax("Invalid regular expression: missing /!".to_string()));
+                },
+                Some((_, '\\')) => {
+                    if let Some((_, '\n' | '\r')) | None = chars.next() {
+                        return Err(Error::Syntax("Invalid regular expression: missing /!".to_string()));
+                    }
+                },
+
+                // Slashes don't end the literal within a class, e.g. `/[/]/`
+                Some((_, '[')) => class = true,
+                Some((_, ']')) => class = false,
+                Some((index, '/')) if !class => break index + 1,
+                Some(_) => {},
+            }
+        };
+
+        // Flags follow the closing slash, e.g. 'gi'
+        let flags = self.content_string[start + end..].chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .map(char::len_utf8)
+            .sum::<usize>();
+        self.cursor = start + end + flags;
+        Ok(Token {
+            token_type: TokenType::RegularExpression,
+            value: self.content_string[start..self.cursor].to_string(),
+            start,
+            end: self.cursor,
+        })
+    }
+
+    /**
+     * Returns the source text between the byte offsets, e.g. of a function for `toString`.
+     */
+    pub fn source(&self, start: usize, end: usize) -> &str {
+        &self.content_string[start..end]
     }
 }
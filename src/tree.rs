@@ -1,12 +1,22 @@
+use std::rc::Rc;
+
 use strum_macros::Display;
 
-#[derive(Debug, PartialEq, Display)]
+#[derive(Clone, Debug, PartialEq, Display)]
 pub enum Tree {
     /**
      * Program
-     *  : StatementList
+     *  : OptDirectivePrologue StatementList
+     *  ;
+     * 
+     * DirectivePrologue
+     *  : ExpressionStatement
+     *  | DirectivePrologue ExpressionStatement
      *  ;
      * 
+     * A directive is a statement made of a string literal only, e.g. 'use strict'.
+     * Scripts & functions whose directives include 'use strict' are strict mode code.
+     * 
      * StatementList
      *  : Statement
      *  | StatementList Statement
@@ -16,11 +26,17 @@ pub enum Tree {
      *  : IterationStatement
      *  | FunctionDeclaration
      *  | ReturnKeyword
+     *  | BreakStatement
+     *  | ContinueStatement
      *  | EmptyStatement
      *  | BlockStatement
      *  | VariableStatement
      *  | IfStatement
+     *  | SwitchStatement
+     *  | ThrowStatement
+     *  | TryStatement
      *  | ClassDeclaration
+     *  | LabeledStatement
      *  | ExpressionStatement
      *  ;
      * 
@@ -28,9 +44,11 @@ pub enum Tree {
      *  : WhileStatement
      *  | DoWhileStatement
      *  | ForStatement
+     *  | ForOfStatement
+     *  | ForInStatement
      *  ;
      */
-    Program{ body: Vec<Tree> },
+    Program{ body: Vec<Tree>, strict: bool },
 
     /**
      * WhileStatement
@@ -58,17 +76,48 @@ pub enum Tree {
      */
     ForStatement{ init: Box<Option<Tree>>, test: Box<Option<Tree>>, update: Box<Option<Tree>>, body: Box<Tree> },
 
+    /**
+     * ForOfStatement
+     *  : 'for' '(' ForDeclaration 'of' AssignmentExpression ')' Statement
+     *  ;
+     * 
+     * ForDeclaration
+     *  : VariableKind Identifier
+     *  | LeftHandSideExpression
+     *  ;
+     */
+    ForOfStatement{ left: Box<Tree>, right: Box<Tree>, body: Box<Tree> },
+
+    /**
+     * ForInStatement
+     *  : 'for' '(' ForDeclaration 'in' Expression ')' Statement
+     *  ;
+     */
+    ForInStatement{ left: Box<Tree>, right: Box<Tree>, body: Box<Tree> },
+
     /**
      * FunctionDeclaration
-     *  : 'function' Identifier '(' OptFormalParameterList ')' BlockStatement
+     *  : OptAsync 'function' OptGenerator Identifier '(' OptFormalParameterList ')' BlockStatement
+     *  ;
+     * 
+     * Async
+     *  : 'async'
+     *  ;
+     * 
+     * Generator
+     *  : '*'
      *  ;
      * 
      * FormalParameterList
      *  : Identifier
      *  | FormalParameterList ',' Identifier
      *  ;
+     * 
+     * The source text is kept for `Function.prototype.toString`. The params, body & source
+     * are shared with the functions created out of the declaration. Functions nested in
+     * strict mode code or beginning with a 'use strict' directive are strict.
      */
-    FunctionDeclaration{ identifier: Box<Tree>, params: Vec<Tree>, body: Box<Tree> },
+    FunctionDeclaration{ identifier: Box<Tree>, params: Rc<[Tree]>, body: Rc<Tree>, generator: bool, is_async: bool, strict: bool, source: Rc<str> },
 
     /**
      * ReturnStatement
@@ -76,7 +125,28 @@ pub enum Tree {
      *  ;
      */
     ReturnStatement{ argument: Box<Option<Tree>> },
-    
+
+    /**
+     * BreakStatement
+     *  : 'break' OptIdentifier ';'
+     *  ;
+     */
+    BreakStatement{ label: Box<Option<Tree>> },
+
+    /**
+     * ContinueStatement
+     *  : 'continue' OptIdentifier ';'
+     *  ;
+     */
+    ContinueStatement{ label: Box<Option<Tree>> },
+
+    /**
+     * LabeledStatement
+     *  : Identifier ':' Statement
+     *  ;
+     */
+    LabeledStatement{ label: Box<Tree>, body: Box<Tree> },
+
     /**
      * EmptyStatement
      *  : ';'
@@ -97,7 +167,12 @@ pub enum Tree {
      *  ;
      * 
      * VariableStatementInit
-     *  : 'let' VariableDeclarationList
+     *  : VariableKind VariableDeclarationList
+     *  ;
+     * 
+     * VariableKind
+     *  : 'let'
+     *  | 'const'
      *  ;
      * 
      * VariableDeclarationList
@@ -105,7 +180,7 @@ pub enum Tree {
      *  | VariableDeclarationList ',' VariableDeclaration
      *  ;
      */
-    VariableStatement{ declarations: Vec<Tree> },
+    VariableStatement{ kind: String, declarations: Vec<Tree> },
 
     /**
      * VariableDeclaration
@@ -126,6 +201,58 @@ pub enum Tree {
      */
     IfStatement{ test: Box<Tree>, consequent: Box<Tree>, alternate: Box<Option<Tree>> },
 
+    /**
+     * SwitchStatement
+     *  : 'switch' '(' Expression ')' CaseBlock
+     *  ;
+     * 
+     * CaseBlock
+     *  : '{' OptCaseClauses '}'
+     *  ;
+     * 
+     * CaseClauses
+     *  : SwitchCase
+     *  | CaseClauses SwitchCase
+     *  ;
+     */
+    SwitchStatement{ discriminant: Box<Tree>, cases: Vec<Tree> },
+
+    /**
+     * SwitchCase
+     *  : 'case' Expression ':' OptStatementList
+     *  | 'default' ':' OptStatementList
+     *  ;
+     */
+    SwitchCase{ test: Box<Option<Tree>>, consequent: Vec<Tree> },
+
+    /**
+     * ThrowStatement
+     *  : 'throw' Expression ';'
+     *  ;
+     */
+    ThrowStatement{ argument: Box<Tree> },
+
+    /**
+     * TryStatement
+     *  : 'try' BlockStatement Catch
+     *  | 'try' BlockStatement Finally
+     *  | 'try' BlockStatement Catch Finally
+     *  ;
+     * 
+     * Finally
+     *  : 'finally' BlockStatement
+     *  ;
+     */
+    TryStatement{ block: Box<Tree>, handler: Box<Option<Tree>>, finalizer: Box<Option<Tree>> },
+
+    /**
+     * Catch
+     *  : 'catch' '(' Identifier ')' BlockStatement
+     *  | 'catch' BlockStatement
+     *  ;
+     */
+    CatchClause{ param: Box<Option<Tree>>, body: Box<Tree> },
+
     /**
      * ClassDeclaration
      *  : 'class' Identifier OptClassExtends ClassBody
@@ -134,8 +261,10 @@ pub enum Tree {
      * ClassExtends
      *  : 'extends' Identifier
      *  ;
+     * 
+     * The source text is kept for `Function.prototype.toString`.
      */
-    ClassDeclaration{ idenifier: Box<Tree>, body: Box<Tree>, super_class: Box<Option<Tree>> },
+    ClassDeclaration{ idenifier: Box<Tree>, body: Box<Tree>, super_class: Box<Option<Tree>>, source: Rc<str> },
 
     /**
      * ClassBody
@@ -149,10 +278,15 @@ pub enum Tree {
      * 
      * ClassStatement
      *  : ConstructorDefinition
-     *  | GetterDefinition
-     *  | SetterDefinition
-     *  | MethodDefinition
-     *  | PropertyDefinition
+     *  | OptStatic GetterDefinition
+     *  | OptStatic SetterDefinition
+     *  | OptStatic MethodDefinition
+     *  | OptStatic PropertyDefinition
+     *  | StaticBlock
+     *  ;
+     * 
+     * Static
+     *  : 'static'
      *  ;
      */
     ClassBody{ body: Vec<Tree> },
@@ -166,35 +300,48 @@ pub enum Tree {
 
     /**
      * GetterDefinition
-     *  : 'get' Identifier '(' ')' BlockStatement
+     *  : 'get' ClassElementName '(' ')' BlockStatement
      *  ;
      */
-    GetterDefinition{ key: Box<Tree>, value: Box<Tree> },
+    GetterDefinition{ key: Box<Tree>, value: Box<Tree>, computed: bool, is_static: bool },
 
     /**
      * SetterDefinition
-     *  : 'set' Identifier '(' Identifier ')' BlockStatement 
+     *  : 'set' ClassElementName '(' Identifier ')' BlockStatement 
      *  ;
      */
-    SetterDefinition{ key: Box<Tree>, value: Box<Tree> },
+    SetterDefinition{ key: Box<Tree>, value: Box<Tree>, computed: bool, is_static: bool },
 
     /**
      * MethodDefinition
-     *  : Identifier '(' OptFormalParameterList ')' BlockStatement
+     *  : OptAsync OptGenerator ClassElementName '(' OptFormalParameterList ')' BlockStatement
      *  ;
      */
-    MethodDefinition{ key: Box<Tree>, value: Box<Tree> },
+    MethodDefinition{ key: Box<Tree>, value: Box<Tree>, computed: bool, is_static: bool },
 
     /**
      * PropertyDefinition
-     *  : Identifier OptPropertyInitializer ';'
+     *  : ClassElementName OptPropertyInitializer ';'
      *  ;
      * 
      * PropertyInitializer
      *  : SIMPLE_ASSIGNMENT_OPERATOR AssignmentExpression
      *  ;
+     * 
+     * ClassElementName
+     *  : PropertyName
+     *  | PrivateIdentifier
+     *  | '[' AssignmentExpression ']'
+     *  ;
      */
-    PropertyDefinition{ key: Box<Tree>, value: Box<Option<Tree>> },
+    PropertyDefinition{ key: Box<Tree>, value: Box<Option<Tree>>, computed: bool, is_static: bool },
+
+    /**
+     * StaticBlock
+     *  : 'static' BlockStatement
+     *  ;
+     */
+    StaticBlock{ body: Rc<Tree> },
 
     /**
      * ExpressionStatement
@@ -209,12 +356,22 @@ pub enum Tree {
 
     /**
      * AssignmentExpression
-     *  : LogicalOrExpression
+     *  : YieldExpression
+     *  | LogicalOrExpression
      *  | LeftHandSideExpression ASSIGNMENT_OPERATOR AssignmentExpression
      *  ;
      */
     AssignmentExpression{ operator: String, left: Box<Tree>, right: Box<Tree> },
 
+    /**
+     * YieldExpression
+     *  : 'yield'
+     *  | 'yield' AssignmentExpression
+     *  | 'yield' '*' AssignmentExpression
+     *  ;
+     */
+    YieldExpression{ argument: Box<Option<Tree>>, delegate: bool },
+
     /**
      * LogicalOrExpression
      *  : LogicalAndExpression
@@ -237,6 +394,7 @@ pub enum Tree {
      * RelationalExpression
      *  : AdditiveExpression
      *  | AdditiveExpression RELATIONAL_OPERATOR RelationalExpression
+     *  | PrivateIdentifier 'in' AdditiveExpression
      *  ;
      * 
      * AdditiveExpression
@@ -256,6 +414,7 @@ pub enum Tree {
      *  : LeftHandSideExpression
      *  | ADDITIVE_OPERATOR UnaryExpression
      *  | LOGICAL_NOT UnaryExpression
+     *  | AwaitExpression
      *  ;
      * 
      * LeftHandSideExpression
@@ -264,6 +423,13 @@ pub enum Tree {
      */
     UnaryExpression{ operator: String, argument: Box<Tree> },
 
+    /**
+     * AwaitExpression
+     *  : 'await' UnaryExpression
+     *  ;
+     */
+    AwaitExpression{ argument: Box<Tree> },
+
     /**
      * CallMemberExpression
      *  : MemberExpression
@@ -272,6 +438,8 @@ pub enum Tree {
      * 
      * CallExpression
      *  : Callee Arguments
+     *  | CallExpression '.' PropertyName
+     *  | CallExpression '[' Expression ']'
      *  ;
      * 
      * Callee
@@ -284,8 +452,13 @@ pub enum Tree {
      *  ;
      * 
      * ArgumentList
+     *  : Argument
+     *  | ArgumentList ',' Argument
+     *  ;
+     * 
+     * Argument
      *  : AssignmentExpression
-     *  | ArgumentList ',' AssignmentExpression
+     *  | SpreadElement
      *  ;
      */
     CallExpression{ callee: Box<Tree>, arguments: Vec<Tree> },
@@ -293,7 +466,8 @@ pub enum Tree {
     /**
      * MemberExpression
      *  : PrimaryExpression
-     *  | MemberExpression '.' Identifier
+     *  | MemberExpression '.' PropertyName
+     *  | MemberExpression '.' PrivateIdentifier
      *  | MemberExpression '[' Expression ']'
      *  ;
      * 
@@ -301,6 +475,7 @@ pub enum Tree {
      *  : ParanthesizedExpression
      *  | FunctionExpression
      *  | NewExpression
+     *  | ArrayExpression
      *  | Literal
      *  | Identifier
      *  ;
@@ -311,7 +486,9 @@ pub enum Tree {
      * 
      * Literal
      *  : NumericLiteral
+     *  | BigIntLiteral
      *  | StringLiteral
+     *  | RegExpLiteral
      *  | BooleanLiteral
      *  | NullLiteral
      *  | ThisLiteral
@@ -322,10 +499,12 @@ pub enum Tree {
 
     /**
      * FunctionExpression
-     *  : 'function' OptIdentifier '(' OptFormalParameterList ')' BlockStatement
+     *  : OptAsync 'function' OptGenerator OptIdentifier '(' OptFormalParameterList ')' BlockStatement
      *  ;
+     * 
+     * The source text is kept for `Function.prototype.toString`, methods keeping theirs, e.g. `get x() {}`.
      */
-    FunctionExpression{ identifier: Box<Option<Tree>>, params: Vec<Tree>, body: Box<Tree> },
+    FunctionExpression{ identifier: Box<Option<Tree>>, params: Rc<[Tree]>, body: Rc<Tree>, generator: bool, is_async: bool, strict: bool, source: Rc<str> },
 
     /**
      * NewExpression
@@ -334,6 +513,30 @@ pub enum Tree {
      */
     NewExpression{ callee: Box<Tree>, arguments: Vec<Tree> },
 
+    /**
+     * ArrayExpression
+     *  : '[' OptElementList ']'
+     *  ;
+     * 
+     * ElementList
+     *  : Element
+     *  | ElementList ',' Element
+     *  ;
+     * 
+     * Element
+     *  : AssignmentExpression
+     *  | SpreadElement
+     *  ;
+     */
+    ArrayExpression{ elements: Vec<Tree> },
+
+    /**
+     * SpreadElement
+     *  : '...' AssignmentExpression
+     *  ;
+     */
+    SpreadElement{ argument: Box<Tree> },
+
     /**
      * NumericLiteral
      *  : NUMBER
@@ -341,6 +544,13 @@ pub enum Tree {
      */
     NumericLiteral{ value: f64 },
 
+    /**
+     * BigIntLiteral
+     *  : BIGINT
+     *  ;
+     */
+    BigIntLiteral{ value: String },
+
     /**
      * StringLiteral
      *  : STRING
@@ -348,6 +558,13 @@ pub enum Tree {
      */
     StringLiteral{ value: String },
 
+    /**
+     * RegExpLiteral
+     *  : REGULAR_EXPRESSION
+     *  ;
+     */
+    RegExpLiteral{ pattern: String, flags: String },
+
     /**
      * BooleanLiteral
      *  : 'true'
@@ -383,4 +600,13 @@ pub enum Tree {
      *  ;
      */
     Identifier{ name: String },
+
+    /**
+     * PrivateIdentifier
+     *  : PRIVATE_IDENTIFIER
+     *  ;
+     * 
+     * Names a private class member, e.g. `#count`, the name including the '#'.
+     */
+    PrivateIdentifier{ name: String },
 }
@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+
+/**
+ * JS strings are sequences of UTF-16 code units while Rust strings are UTF-8,
+ * hence string indices & lengths go through this type, e.g. '😀'.length is 2.
+ * The text is kept as UTF-8 for Rust code to work with, along with the code
+ * units whenever they hold lone surrogates, e.g. '😀'[0], which UTF-8 can't
+ * encode & the text replaces by U+FFFD.
+ */
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct JsString {
+    text: String,
+    units: Option<Rc<[u16]>>,
+}
+
+impl JsString {
+    /**
+     * Creates a string out of code units, which may hold lone surrogates.
+     */
+    pub fn from_units(units: &[u16]) -> Self {
+        match String::from_utf16(units) {
+            Ok(text) => Self { text, units: None },
+            Err(_) => Self { text: String::from_utf16_lossy(units), units: Some(Rc::from(units)) },
+        }
+    }
+
+    /**
+     * Returns the UTF-16 code units of the string.
+     */
+    pub fn units(&self) -> Cow<'_, [u16]> {
+        match &self.units {
+            Some(units) => Cow::Borrowed(units),
+            None => Cow::Owned(self.text.encode_utf16().collect()),
+        }
+    }
+
+    /**
+     * Count of UTF-16 code units of the string, i.e. `String.prototype.length`.
+     */
+    pub fn length(&self) -> usize {
+        match &self.units {
+            Some(units) => units.len(),
+            None => self.text.encode_utf16().count(),
+        }
+    }
+
+    /**
+     * Returns the code unit at the index as a string, e.g. `'abc'[1]`.
+     */
+    pub fn unit_at(&self, index: usize) -> Option<JsString> {
+        let unit = match &self.units {
+            Some(units) => units.get(index).copied(),
+            None => self.text.encode_utf16().nth(index),
+        };
+        unit.map(|unit| Self::from_units(&[unit]))
+    }
+
+    /**
+     * Returns the code units between the indices as a string, the end being excluded.
+     * Indices past the end of the string are clamped.
+     */
+    pub fn slice(&self, start: usize, end: usize) -> JsString {
+        let units = self.units();
+        let end = end.min(units.len());
+        Self::from_units(&units[start.min(end)..end])
+    }
+
+    /**
+     * Appends another string, e.g. `'\uD83D' + '\uDE00'` which pairs the surrogates into '😀'.
+     */
+    pub fn concat(&self, other: &JsString) -> JsString {
+        match (&self.units, &other.units) {
+            (None, None) => Self::from(format!("{}{}", self.text, other.text)),
+            _ => Self::from_units(&[self.units(), other.units()].concat()),
+        }
+    }
+
+    /**
+     * Whether the string holds no lone surrogates.
+     */
+    pub fn is_well_formed(&self) -> bool {
+        self.units.is_none()
+    }
+}
+
+impl Deref for JsString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<String> for JsString {
+    fn from(text: String) -> Self {
+        Self { text, units: None }
+    }
+}
+
+impl From<&str> for JsString {
+    fn from(text: &str) -> Self {
+        Self::from(text.to_string())
+    }
+}
+
+impl PartialEq<str> for JsString {
+    fn eq(&self, other: &str) -> bool {
+        self.is_well_formed() && self.text == other
+    }
+}
+
+impl PartialEq<&str> for JsString {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/**
+ * Strings are sorted by their code units, e.g. '｡' > '😀' unlike their code points.
+ */
+impl Ord for JsString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.units, &other.units) {
+            (None, None) => self.text.encode_utf16().cmp(other.text.encode_utf16()),
+            _ => self.units().cmp(&other.units()),
+        }
+    }
+}
+
+impl PartialOrd for JsString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+impl fmt::Debug for JsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.text)
+    }
+}
+
+/**
+ * Index of the first occurrence of the searched units at or after the index.
+ */
+pub fn index_of(units: &[u16], search: &[u16], from: usize) -> Option<usize> {
+    if from > units.len() || search.len() > units.len() - from {
+        return None;
+    }
+    (from..=units.len() - search.len()).find(|index| units[*index..].starts_with(search))
+}
+
+/**
+ * Index of the last occurrence of the searched units at or before the index.
+ */
+pub fn last_index_of(units: &[u16], search: &[u16], from: usize) -> Option<usize> {
+    if search.len() > units.len() {
+        return None;
+    }
+    (0..=from.min(units.len() - search.len())).rev().find(|index| units[*index..].starts_with(search))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn units(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn test_code_units() {
+        assert_eq!(JsString::from("héllo").length(), 5);
+        assert_eq!(JsString::from("😀").length(), 2);
+        assert_eq!(JsString::from("a😀").unit_at(0), Some(JsString::from("a")));
+        assert_eq!(JsString::from("a😀").unit_at(1), Some(JsString::from_units(&[0xD83D]))); // Lone high surrogate
+        assert_eq!(JsString::from("a😀").unit_at(3), None);
+        assert_eq!(JsString::from("a😀b").slice(1, 3), "😀");
+        assert_eq!(JsString::from("abc").slice(2, 10), "c");
+        assert_eq!(JsString::from("abc").slice(5, 10), "");
+    }
+
+    #[test]
+    fn test_lone_surrogates() {
+        let high = JsString::from_units(&[0xD83D]);
+        let low = JsString::from_units(&[0xDE00]);
+        assert!(!high.is_well_formed());
+        assert_eq!(&*high, "\u{FFFD}");
+        assert_ne!(high, JsString::from("\u{FFFD}"));
+        assert_ne!(high, low);
+        assert_eq!(high.length(), 1);
+        assert_eq!(high.concat(&low), JsString::from("😀"));
+        assert!(high.concat(&low).is_well_formed());
+        assert_eq!(low.concat(&high).units(), units("😀").into_iter().rev().collect::<Vec<u16>>());
+        assert_eq!(JsString::from("\u{FF61}").cmp(&"😀".into()), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_search() {
+        let haystack = units("😀abcabc");
+        assert_eq!(index_of(&haystack, &units("bc"), 0), Some(3));
+        assert_eq!(index_of(&haystack, &units("bc"), 4), Some(6));
+        assert_eq!(index_of(&haystack, &units(""), 8), Some(8));
+        assert_eq!(index_of(&haystack, &units("x"), 0), None);
+        assert_eq!(last_index_of(&haystack, &units("bc"), 8), Some(6));
+        assert_eq!(last_index_of(&haystack, &units("bc"), 5), Some(3));
+        assert_eq!(last_index_of(&haystack, &units(""), 20), Some(8));
+    }
+}
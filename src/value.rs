@@ -1,390 +1,327 @@
+use crate::prelude::*;
+
+use std::cmp::Ordering;
+use std::fmt;
 use std::ops;
 
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+
+use crate::number;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Value {
     Number(f64),
-    String(String),
+    BigInt(BigInt),
+    String(JsString),
     Undefined,
-    Nan,
-    Infinity(bool), // +/- Infinity; true = -ve
     Null,
     Boolean(bool),
+    Symbol(Symbol),
+    Object(ObjectRef),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", number::to_string(*n)),
+            Value::BigInt(n) => write!(f, "{n}"),
+            Value::String(s) => write!(f, "{s}"),
+            Value::Undefined => write!(f, "undefined"),
+            Value::Null => write!(f, "null"),
+            Value::Boolean(b) => write!(f, "{b}"),
+            Value::Symbol(symbol) => write!(f, "{symbol}"),
+            Value::Object(object) => write!(f, "{object}"),
+        }
+    }
+}
+
+impl Value {
+    /**
+     * Whether the value is a function object, be it user-defined or built-in.
+     */
+    pub fn is_callable(&self) -> bool {
+        matches!(self, Value::Object(object) if object.is_callable())
+    }
+
+    /**
+     * Converts the value to a boolean, i.e. JS truthiness.
+     */
+    pub fn to_boolean(&self) -> bool {
+        match self {
+            Value::Number(n) => *n != 0.0 && !n.is_nan(),
+            Value::BigInt(n) => !n.is_zero(),
+            Value::String(s) => !s.is_empty(),
+            Value::Undefined | Value::Null => false,
+            Value::Symbol(_) | Value::Object(_) => true,
+            Value::Boolean(b) => *b,
+        }
+    }
+
+    /**
+     * Converts the value to a number, e.g. '42' -> 42 and undefined -> NaN.
+     */
+    pub fn to_number(&self) -> f64 {
+        match self {
+            Value::Number(n) => *n,
+            Value::BigInt(n) => n.to_f64().unwrap_or(f64::NAN),
+            Value::String(s) => {
+                let s = s.trim();
+                match s {
+                    "" => 0.0,
+                    "Infinity" | "+Infinity" => f64::INFINITY,
+                    "-Infinity" => f64::NEG_INFINITY,
+
+                    // Integers in other radixes can't be signed, e.g. '0x1f' but not '-0x1f'
+                    _ if matches!(s.get(..2), Some("0x" | "0X" | "0o" | "0O" | "0b" | "0B")) => {
+                        Value::string_to_bigint(s).and_then(|n| n.to_f64()).unwrap_or(f64::NAN)
+                    },
+
+                    // Rust also accepts 'inf' & 'nan' which aren't JS numerals
+                    _ if s.chars().any(|c| c.is_ascii_alphabetic() && c != 'e' && c != 'E') => f64::NAN,
+                    _ => s.parse::<f64>().unwrap_or(f64::NAN),
+                }
+            },
+            Value::Undefined | Value::Symbol(_) => f64::NAN,
+            Value::Null => 0.0,
+            Value::Boolean(b) => if *b { 1.0 } else { 0.0 },
+            Value::Object(object) => Value::String(object.to_string().into()).to_number(),
+        }
+    }
+
+    /**
+     * Strict equality comparison, i.e. `a === b`.
+     * NaN isn't equal to itself while 0 & -0 are equal.
+     */
+    pub fn strict_equals(&self, other: &Value) -> bool {
+        self == other
+    }
+
+    /**
+     * SameValue comparison, i.e. `Object.is(a, b)`.
+     * Unlike strict equality, NaN is equal to itself while 0 & -0 differ.
+     */
+    pub fn same_value(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) if a.is_nan() && b.is_nan() => true,
+            (Value::Number(a), Value::Number(b)) => a == b && a.is_sign_negative() == b.is_sign_negative(),
+            _ => self == other,
+        }
+    }
+
+    /**
+     * SameValueZero comparison, e.g. `[NaN].includes(NaN)`.
+     * NaN is equal to itself while 0 & -0 are equal.
+     */
+    pub fn same_value_zero(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) if a.is_nan() && b.is_nan() => true,
+            _ => self == other,
+        }
+    }
+
+    /**
+     * Loose equality comparison with type coercion, i.e. `a == b`.
+     */
+    pub fn loose_equals(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Undefined | Value::Null, Value::Undefined | Value::Null) => true,
+            (Value::Undefined | Value::Null, _) | (_, Value::Undefined | Value::Null) => false,
+            (Value::Object(_), Value::Object(_)) => self == other,
+            (Value::Symbol(_), _) | (_, Value::Symbol(_)) => self == other,
+
+            // Objects are compared as their string representation
+            (Value::Object(a), b) => Value::String(a.to_string().into()).loose_equals(b),
+            (a, Value::Object(b)) => a.loose_equals(&Value::String(b.to_string().into())),
+
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Boolean(a), Value::Boolean(b)) => a == b,
+
+            // BigInts are compared with the mathematical value of the other operand
+            (Value::BigInt(a), Value::String(b)) | (Value::String(b), Value::BigInt(a)) => {
+                Value::string_to_bigint(b).is_some_and(|b| *a == b)
+            },
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => self.compare_numeric(other) == Some(Ordering::Equal),
+
+            _ => self.to_number() == other.to_number(),
+        }
+    }
+
+    /**
+     * Compares numeric values, i.e. numbers & BigInts, by their mathematical value.
+     * Returns None if either is NaN.
+     */
+    pub fn compare_numeric(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::BigInt(a), Value::BigInt(b)) => Some(a.cmp(b)),
+            (Value::BigInt(a), b) => compare_bigint_number(a, b.to_number()),
+            (a, Value::BigInt(b)) => compare_bigint_number(b, a.to_number()).map(Ordering::reverse),
+            (a, b) => a.to_number().partial_cmp(&b.to_number()),
+        }
+    }
+
+    /**
+     * Parses a string into a BigInt, e.g. ' 42 ' -> 42n and '0xff' -> 255n.
+     * Returns None if the string isn't an integer.
+     */
+    pub fn string_to_bigint(s: &str) -> Option<BigInt> {
+        let s = s.trim();
+        let (digits, radix) = match s.get(..2) {
+            Some("0x" | "0X") => (&s[2..], 16),
+            Some("0o" | "0O") => (&s[2..], 8),
+            Some("0b" | "0B") => (&s[2..], 2),
+            _ => (s, 10),
+        };
+        if s.is_empty() {
+            return Some(BigInt::zero());
+        }
+
+        // Only decimal integers can be signed, e.g. '-12'
+        let unsigned = match radix {
+            10 => digits.strip_prefix(['+', '-']).unwrap_or(digits),
+            _ => digits,
+        };
+        if unsigned.is_empty() || !unsigned.chars().all(|c| c.is_digit(radix)) {
+            return None;
+        }
+        BigInt::parse_bytes(digits.trim_start_matches('+').as_bytes(), radix)
+    }
+}
+
+/**
+ * Compares a BigInt with a number without losing the precision of either.
+ */
+fn compare_bigint_number(a: &BigInt, b: f64) -> Option<Ordering> {
+    if b.is_nan() {
+        return None;
+    }
+    if b.is_infinite() {
+        return Some(if b > 0.0 { Ordering::Less } else { Ordering::Greater });
+    }
+    let floor = BigInt::from_f64(b.floor())?;
+    match a.cmp(&floor) {
+        // 2n < 2.5 as the fraction is dropped by the floor
+        Ordering::Equal if b.fract() != 0.0 => Some(Ordering::Less),
+        ordering => Some(ordering),
+    }
+}
+
+/**
+ * Size in bits past which BigInt results are rejected instead of exhausting memory.
+ */
+const MAX_BIGINT_BITS: u64 = 1 << 30;
+
+fn mixed_bigint_error() -> Error {
+    Error::Type("Cannot mix BigInt and other types, use explicit conversions!".to_string())
 }
 
 impl ops::Add for Value {
-    type Output = Value;
+    type Output = Result<Value>;
 
     fn add(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
-            (Value::Number(a), Value::Number(b)) => Value::Number(a + b),
-            (Value::Number(a), Value::String(b)) => Value::String(format!("{a}{b}")),
-            (Value::Number(_), Value::Undefined) => Value::Nan,
-            (Value::Number(_), Value::Nan) => Value::Nan,
-            (Value::Number(_), Value::Infinity(neg)) => Value::Infinity(neg),
-            (Value::Number(a), Value::Null) => Value::Number(a),
-            (Value::Number(a), Value::Boolean(b)) => Value::Number(a + if b { 1.0 } else { 0.0 }),
- 
-            (Value::String(a), Value::Number(b)) => Value::String(format!("{a}{b}")),
-            (Value::String(a), Value::String(b)) => Value::String(format!("{a}{b}")),
-            (Value::String(a), Value::Undefined) => Value::String(format!("{a}undefined")),
-            (Value::String(a), Value::Nan) => Value::String(format!("{a}NaN")),
-            (Value::String(a), Value::Infinity(neg)) => Value::String(format!("{a}{}Infinity", if neg { "-" } else { "" })),
-            (Value::String(a), Value::Null) => Value::String(format!("{a}null")),
-            (Value::String(a), Value::Boolean(b)) => Value::String(format!("{a}{b}")),
- 
-            (Value::Undefined, Value::Number(_)) => Value::Nan,
-            (Value::Undefined, Value::String(b)) => Value::String(format!("undefined{b}")),
-            (Value::Undefined, Value::Undefined) => Value::Nan,
-            (Value::Undefined, Value::Nan) => Value::Nan,
-            (Value::Undefined, Value::Infinity(_)) => Value::Nan,
-            (Value::Undefined, Value::Null) => Value::Nan,
-            (Value::Undefined, Value::Boolean(_)) => Value::Nan,
- 
-            (Value::Nan, Value::Number(_)) => Value::Nan,
-            (Value::Nan, Value::String(b)) => Value::String(format!("NaN{b}")),
-            (Value::Nan, Value::Undefined) => Value::Nan,
-            (Value::Nan, Value::Nan) => Value::Nan,
-            (Value::Nan, Value::Infinity(_)) => Value::Nan,
-            (Value::Nan, Value::Null) => Value::Nan,
-            (Value::Nan, Value::Boolean(_)) => Value::Nan,
-
-            (Value::Infinity(neg), Value::Number(_)) => Value::Infinity(neg),
-            (Value::Infinity(neg), Value::String(b)) => Value::String(format!("{}Infinity{b}", if neg { "-" } else { "" })),
-            (Value::Infinity(_), Value::Undefined) => Value::Nan,
-            (Value::Infinity(_), Value::Nan) => Value::Nan,
-            (Value::Infinity(a), Value::Infinity(b)) => if a == b { Value::Infinity(a) } else { Value::Nan },
-            (Value::Infinity(neg), Value::Null) => Value::Infinity(neg),
-            (Value::Infinity(neg), Value::Boolean(_)) => Value::Infinity(neg),
-
-            (Value::Null, Value::Number(b)) => Value::Number(b),
-            (Value::Null, Value::String(b)) => Value::String(format!("null{b}")),
-            (Value::Null, Value::Undefined) => Value::Nan,
-            (Value::Null, Value::Nan) => Value::Nan,
-            (Value::Null, Value::Infinity(neg)) => Value::Infinity(neg),
-            (Value::Null, Value::Null) => Value::Number(0.0),
-            (Value::Null, Value::Boolean(b)) => Value::Number(0.0) + Value::Boolean(b),
-
-            (Value::Boolean(a), Value::Number(b)) => Value::Number(b) + Value::Boolean(a),
-            (Value::Boolean(a), Value::String(b)) => Value::String(format!("{a}{b}")),
-            (Value::Boolean(_), Value::Undefined) => Value::Nan,
-            (Value::Boolean(_), Value::Nan) => Value::Nan,
-            (Value::Boolean(_), Value::Infinity(neg)) => Value::Infinity(neg),
-            (Value::Boolean(a), Value::Null) => Value::Boolean(a) + Value::Number(0.0),
-            (Value::Boolean(a), Value::Boolean(b)) => Value::Number(if a { 1.0 } else { 0.0 }) +  Value::Boolean(b),
+            // Objects take part in arithmetic as their string representation
+            (Value::Object(a), b) => Value::String(a.to_string().into()) + b,
+            (a, Value::Object(b)) => a + Value::String(b.to_string().into()),
+
+            // Concatenating if either operand is a string, e.g. 1 + '2' = '12'
+            // Surrogates split across the operands pair up again, e.g. '\uD83D' + '\uDE00' = '😀'
+            (Value::String(a), Value::String(b)) => Ok(Value::String(a.concat(&b))),
+            (Value::String(a), b) => Ok(Value::String(a.concat(&b.to_string().into()))),
+            (a, Value::String(b)) => Ok(Value::String(JsString::from(a.to_string()).concat(&b))),
+
+            (Value::BigInt(a), Value::BigInt(b)) => Ok(Value::BigInt(a + b)),
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => Err(mixed_bigint_error()),
+            (a, b) => Ok(Value::Number(a.to_number() + b.to_number())),
         }
     }
 }
 
 impl ops::Sub for Value {
-    type Output = Value;
+    type Output = Result<Value>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
-            (Value::Number(a), Value::Number(b)) => Value::Number(a - b),
-            (Value::Number(a), Value::String(b)) => {
-                if b.is_empty() {
-                    return Value::Number(a); // 2 - '' = 2
-                }
-                match b.parse::<f64>() {
-                    Err(_) => Value::Nan, // 9 - 'a' = NaN
-                    Ok(parsed) => Value::Number(a - parsed), // 9 - '3' = 6
-                }
-            },
-            (Value::Number(_), Value::Undefined) => Value::Nan,
-            (Value::Number(_), Value::Nan) => Value::Nan,
-            (Value::Number(_), Value::Infinity(neg)) => Value::Infinity(!neg),
-            (Value::Number(a), Value::Null) => Value::Number(a),
-            (Value::Number(a), Value::Boolean(b)) => Value::Number(a - if b { 1.0 } else { 0.0 }),
- 
-            (Value::String(a), Value::Number(b)) => {
-                if a.is_empty() {
-                    return Value::Number(-b); // '' - 2 = -2
-                }
-                match a.parse::<f64>() {
-                    Err(_) => Value::Nan, // 'a' - 1 = NaN
-                    Ok(parsed) => Value::Number(parsed - b), // '9' - 3 = 6
-                }
-            },
-            (Value::String(a), Value::String(b)) => {
-                let parse_a_result = a.parse::<f64>();
-                let parse_b_result = b.parse::<f64>();
-                if a.is_empty() && b.is_empty() {
-                    Value::Number(0.0) // '' - '' = 0
-                } else if a.is_empty() && parse_b_result.is_ok() {
-                    let parse_b = parse_b_result.unwrap();
-                    Value::Number(-parse_b) // '' - '2' = -2
-                } else if b.is_empty() && parse_a_result.is_ok() {
-                    let parse_a = parse_a_result.unwrap();
-                    Value::Number(parse_a) // '2' - '' = 2
-                } else if parse_a_result.is_ok() && parse_b_result.is_ok() {
-                    let parse_a = parse_a_result.unwrap();
-                    let parse_b = parse_b_result.unwrap();
-                    Value::Number(parse_a - parse_b) // '12' - '3' = 9
-                } else {
-                    Value::Nan // 'a' - '1' = NaN
-                }
-            },
-            (Value::String(_), Value::Undefined) => Value::Nan,
-            (Value::String(_), Value::Nan) => Value::Nan,
-            (Value::String(a), Value::Infinity(neg)) => {
-                if a.is_empty() {
-                    return Value::Infinity(!neg); // '' - Infinity = -Infinity;
-                }
-                match a.parse::<f64>() {
-                    Err(_) => Value::Nan, // 'a' - Infinity; 'a' - -Infinity;
-                    Ok(_) => Value::Infinity(!neg), // '1' - Infinity = -Infinity; '1' - -Infinity = Infinity;
-                }
-            },
-            (Value::String(a), Value::Null) => Value::String(a) - Value::Number(0.0), // Considering null as 0
-            (Value::String(a), Value::Boolean(b)) => Value::String(a) - Value::Number(if b { 1.0 } else { 0.0 }),
- 
-            (Value::Undefined, _) => Value::Nan,
-            (Value::Nan, _) => Value::Nan,
-
-            (Value::Infinity(neg), Value::Number(_)) => Value::Infinity(neg),
-            (Value::Infinity(neg), Value::String(b)) => {
-                if b.is_empty() {
-                    return Value::Infinity(neg) // Infinity - '' = Infinity;
-                }
-                match b.parse::<f64>() {
-                    Err(_) => Value::Nan, // Infinity - 'a' = NaN
-                    Ok(_) => Value::Infinity(neg), // -Infinity - '1' = -Infinity
-                }
-            },
-            (Value::Infinity(_), Value::Undefined) => Value::Nan,
-            (Value::Infinity(_), Value::Nan) => Value::Nan,
-            (Value::Infinity(a), Value::Infinity(b)) => {
-                if a != b {
-                    return Value::Infinity(a); // -Infinity - Infinity = -Infinity; Infinity - -Infinity = -Infinity;
-                }
-                Value::Nan // Infinity - Infinity = NaN; -Infinity - -Infinity = NaN;
-            },
-            (Value::Infinity(neg), Value::Null) => Value::Infinity(neg),
-            (Value::Infinity(neg), Value::Boolean(_)) => Value::Infinity(neg),
-
-            (Value::Null, Value::Number(b)) => Value::Number(-b),
-            (Value::Null, Value::String(b)) => Value::Number(0.0) - Value::String(b),
-            (Value::Null, Value::Undefined) => Value::Nan,
-            (Value::Null, Value::Nan) => Value::Nan,
-            (Value::Null, Value::Infinity(neg)) => Value::Infinity(!neg),
-            (Value::Null, Value::Null) => Value::Number(0.0),
-            (Value::Null, Value::Boolean(b)) => Value::Number(0.0) - Value::Boolean(b),
-
-            (Value::Boolean(a), Value::Number(b)) => Value::Number(if a { 1.0 } else { 0.0 } - b),
-            (Value::Boolean(a), Value::String(b)) => Value::Number(if a { 1.0 } else { 0.0 }) - Value::String(b),
-            (Value::Boolean(_), Value::Undefined) => Value::Nan,
-            (Value::Boolean(_), Value::Nan) => Value::Nan,
-            (Value::Boolean(_), Value::Infinity(neg)) => Value::Infinity(!neg),
-            (Value::Boolean(a), Value::Null) => Value::Boolean(a) - Value::Number(0.0),
-            (Value::Boolean(a), Value::Boolean(b)) => Value::Number(if a { 1.0 } else { 0.0 }) -  Value::Boolean(b),
+            (Value::BigInt(a), Value::BigInt(b)) => Ok(Value::BigInt(a - b)),
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => Err(mixed_bigint_error()),
+            (a, b) => Ok(Value::Number(a.to_number() - b.to_number())),
         }
     }
 }
 
 impl ops::Mul for Value {
-    type Output = Value;
+    type Output = Result<Value>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
-            (Value::Number(a), Value::Number(b)) => Value::Number(a * b),
-            (Value::Number(a), Value::String(b)) => {
-                if b.is_empty() {
-                    return Value::Number(0.0); // '' * 2 = 0
-                }
-                match b.parse::<f64>() {
-                    Err(_) => Value::Nan, // 2 * 'a' = NaN
-                    Ok(parsed) => Value::Number(a * parsed), // 2 * '5' = 10
-                }
-            },
-            (Value::Number(_), Value::Undefined) => Value::Nan,
-            (Value::Number(_), Value::Nan) => Value::Nan,
-            (Value::Number(a), Value::Infinity(neg)) => {
-                if a == 0.0 {
-                    return Value::Nan; // 0 * Infinity = NaN; 0 * -Infinity = NaN;
-                } else if a < 0.0 {
-                    return Value::Infinity(!neg); // -2 * Infinity = -Infinity; -2 * -Infinity = Infinity;
-                }
-                Value::Infinity(neg) // 2 * Infinity = Infinity; 2 * -Infinity = -Infinity;
-            },
-            (Value::Number(_), Value::Null) => Value::Number(0.0),
-            (Value::Number(a), Value::Boolean(b)) => Value::Number(a * if b { 1.0 } else { 0.0 }),
-
-            (Value::String(a), Value::Number(b)) => {
-                if a.is_empty() {
-                    return Value::Number(0.0); // 2 * '' = 0
-                }
-                match a.parse::<f64>() {
-                    Err(_) => Value::Nan, // 'a' * 2 = NaN
-                    Ok(parsed) => Value::Number(parsed * b), // '5' * 2 = 10
-                }
-            },
-            (Value::String(a), Value::String(b)) => {
-                let parse_a_result = a.parse::<f64>();
-                let parse_b_result = b.parse::<f64>();
-                if (a.is_empty() && b.is_empty()) || (a.is_empty() && parse_b_result.is_ok()) || (b.is_empty() && parse_a_result.is_ok()) {
-                    // '' * '' = 0
-                    // '' * '2' = 0
-                    // '2' * '' = 0
-                    Value::Number(0.0) 
-                } else if parse_a_result.is_ok() && parse_b_result.is_ok() {
-                    let parse_a = parse_a_result.unwrap();
-                    let parse_b = parse_b_result.unwrap();
-                    Value::Number(parse_a * parse_b) // '2' * '3' = 6
-                } else {
-                    Value::Nan // 'a' * '1' = NaN
-                }
-            },
-            (Value::String(_), Value::Undefined) => Value::Nan,
-            (Value::String(_), Value::Nan) => Value::Nan,
-            (Value::String(a), Value::Infinity(neg)) => {
-                if a.is_empty() {
-                    return Value::Nan;
-                }
-                match a.parse::<f64>() {
-                    Err(_) => Value::Nan, // 'a' * Infinity = NaN; 'a' * -Infinity = NaN;
-                    Ok(parsed) => Value::Number(parsed) * Value::Infinity(neg), // Considering parsed value as number!
-                }
-            },
-            (Value::String(a), Value::Null) => Value::String(a) * Value::Number(0.0), // Considering 'null' as 0
-            (Value::String(a), Value::Boolean(b)) => Value::String(a) * Value::Number(if b { 1.0 } else { 0.0 }),
- 
-            (Value::Undefined, _) => Value::Nan,
-            (Value::Nan, _) => Value::Nan,
-
-            (Value::Infinity(neg), Value::Number(b)) => Value::Number(b) * Value::Infinity(neg),
-            (Value::Infinity(neg), Value::String(b)) => Value::String(b) * Value::Infinity(neg),
-            (Value::Infinity(_), Value::Undefined) => Value::Nan,
-            (Value::Infinity(_), Value:: Nan) => Value::Nan,
-            (Value::Infinity(a), Value::Infinity(b)) => Value::Infinity(a ^ b),
-            (Value::Infinity(_), Value::Null) => Value::Nan,
-            (Value::Infinity(neg), Value::Boolean(b)) => Value::Infinity(neg) * Value::Number(if b { 1.0 } else { 0.0 }),
-
-            (Value::Null, Value::Number(_)) => Value::Number(0.0),
-            (Value::Null, Value::String(b)) => Value::String(b) * Value::Null,
-            (Value::Null, Value::Undefined) => Value::Nan,
-            (Value::Null, Value::Nan) => Value::Nan,
-            (Value::Null, Value::Infinity(_)) => Value::Nan,
-            (Value::Null, Value::Null) => Value::Number(0.0),
-            (Value::Null, Value::Boolean(_)) => Value::Number(0.0),
-
-            (Value::Boolean(a), Value::Number(b)) => Value::Number(b) * Value::Boolean(a),
-            (Value::Boolean(a), Value::String(b)) => Value::String(b) * Value::Boolean(a),
-            (Value::Boolean(_), Value::Undefined) => Value::Nan,
-            (Value::Boolean(_), Value::Nan) => Value::Nan,
-            (Value::Boolean(a), Value::Infinity(b)) => Value::Infinity(b) * Value::Boolean(a),
-            (Value::Boolean(_), Value::Null) => Value::Number(0.0),
-            (Value::Boolean(a), Value::Boolean(b)) => Value::Number(if a && b { 1.0 } else { 0.0 }),
+            (Value::BigInt(a), Value::BigInt(b)) => Ok(Value::BigInt(a * b)),
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => Err(mixed_bigint_error()),
+            (a, b) => Ok(Value::Number(a.to_number() * b.to_number())),
         }
     }
 }
 
 impl ops::Div for Value {
-    type Output = Value;
+    type Output = Result<Value>;
 
     fn div(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
-            (Value::Number(a), Value::Number(b)) => {
-                if a == 0.0 && b == 0.0 {
-                    return Value::Nan; // 0 / 0 = NaN
-                } else if b == 0.0 {
-                    return Value::Infinity(a < 0.0); // 1 / 0 = Infinity; -1 / 0 = -Infinity;
-                }
-                Value::Number(a / b)
-            },
-            (Value::Number(a), Value::String(b)) => {
-                if b.is_empty() && a != 0.0 {
-                    return Value::Infinity(a < 0.0); // 1 / '' = Infinity; -1 / '' = -Infinity;
-                } else if b.is_empty() {
-                    return Value::Nan; // 0 / '' = NaN
-                }
-                match b.parse::<f64>() {
-                    Err(_) => Value::Nan, // 9 / 'a' = NaN
-                    Ok(parsed) => Value::Number(a) / Value::Number(parsed), // 9 / '3' = 3
+            (Value::BigInt(a), Value::BigInt(b)) => {
+                if b.is_zero() {
+                    return Err(Error::Range("Division by zero!".to_string()));
                 }
+                Ok(Value::BigInt(a / b)) // Rounding towards zero, i.e. 7n / 2n = 3n
             },
-            (Value::Number(_), Value::Undefined) => Value::Nan,
-            (Value::Number(_), Value::Nan) => Value::Nan,
-            (Value::Number(_), Value::Infinity(_)) => Value::Number(0.0),
-            (Value::Number(a), Value::Null) => Value::Number(a) / Value::Number(0.0),
-            (Value::Number(a), Value::Boolean(b)) => Value::Number(a) / Value::Number(if b { 1.0 } else { 0.0 }),
- 
-            (Value::String(a), Value::Number(b)) => {
-                let parse_a_result = a.parse::<f64>();
-                if a.is_empty() && b != 0.0 {
-                    return Value::Number(0.0); // '' / 2 = 0
-                } else if parse_a_result.is_err() {
-                    return Value::Nan; // '' / 0; 'a' / 2;
-                }
-                let parse_a = parse_a_result.unwrap();
-                Value::Number(parse_a) / Value::Number(b) // Considering parsed value as number!
-            },
-            (Value::String(a), Value::String(b)) => {
-                let parse_a_result = a.parse::<f64>();
-                let parse_b_result = b.parse::<f64>();
-                if a.is_empty() && b.is_empty() {
-                    Value::Nan // '' / '' = NaN
-                } else if parse_a_result.is_ok() && parse_b_result.is_ok() {
-                    let parse_a = parse_a_result.unwrap();
-                    let parse_b = parse_b_result.unwrap();
-                    Value::Number(parse_a) / Value::Number(parse_b) // Considering parsed value as number!
-                } else if parse_b_result.is_ok() {
-                    let parse_b = parse_b_result.unwrap();
-                    Value::String(a) / Value::Number(parse_b) // Considering parsed value as number!
-                } else if parse_a_result.is_ok() {
-                    let parse_a = parse_a_result.unwrap();
-                    Value::Number(parse_a) / Value::String(b) // Considering parsed value as number!
-                } else {
-                    Value::Nan // 'a' / 'b' = NaN
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => Err(mixed_bigint_error()),
+            (a, b) => Ok(Value::Number(a.to_number() / b.to_number())),
+        }
+    }
+}
+
+impl ops::Rem for Value {
+    type Output = Result<Value>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Value::BigInt(a), Value::BigInt(b)) => {
+                if b.is_zero() {
+                    return Err(Error::Range("Division by zero!".to_string()));
                 }
+                Ok(Value::BigInt(a % b)) // Taking the sign of the dividend, i.e. -7n % 2n = -1n
             },
-            (Value::String(_), Value::Undefined) => Value::Nan,
-            (Value::String(_), Value::Nan) => Value::Nan,
-            (Value::String(a), Value::Infinity(_)) => {
-                if a.is_empty() {
-                    return Value::Number(0.0); // '' / Infinity = 0; '' / -Infinity = 0;
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => Err(mixed_bigint_error()),
+            (a, b) => Ok(Value::Number(a.to_number() % b.to_number())),
+        }
+    }
+}
+
+impl Value {
+    /**
+     * Raises the value to the power of another, i.e. `a ** b`.
+     */
+    pub fn pow(self, rhs: Self) -> Result<Value> {
+        match (self, rhs) {
+            (Value::BigInt(a), Value::BigInt(b)) => {
+                if b.is_negative() {
+                    return Err(Error::Range("Exponent must be non-negative!".to_string()));
                 }
-                match a.parse::<f64>() {
-                    Err(_) => Value::Nan, // 'a' / Infinity = NaN
-                    Ok(_) => Value::Number(0.0), // '123' / Infinity = 0
+                // Only 0n, 1n & -1n keep a reasonable size when raised to a huge power
+                match b.to_u32().filter(|b| a.bits().saturating_mul(u64::from(*b)) <= MAX_BIGINT_BITS) {
+                    Some(b) => Ok(Value::BigInt(a.pow(b))),
+                    None if a.is_zero() || a.is_one() => Ok(Value::BigInt(a)),
+                    None if a == -BigInt::one() => Ok(Value::BigInt(if (&b % 2u32).is_zero() { BigInt::one() } else { a })),
+                    None => Err(Error::Range("Maximum BigInt size exceeded!".to_string())),
                 }
             },
-            (Value::String(a), Value::Null) => Value::String(a) / Value::Number(0.0),
-            (Value::String(a), Value::Boolean(b)) => Value::String(a) / Value::Number(if b { 1.0 } else { 0.0 }),
- 
-            (Value::Undefined, _) => Value::Nan,
-            (Value::Nan, _) => Value::Nan,
-
-            (Value::Infinity(neg), Value::Number(b)) => Value::Infinity(neg ^ (b < 0.0)),
-            (Value::Infinity(neg), Value::String(b)) => {
-                if b.is_empty() {
-                    return Value::Infinity(neg); // Infinity / '' = Infinity;
-                }
-                match b.parse::<f64>() {
-                    Err(_) => Value::Nan,
-                    Ok(parsed) => Value::Infinity(neg) / Value::Number(parsed), // Considering parsed value as number!
+            (Value::BigInt(_), _) | (_, Value::BigInt(_)) => Err(mixed_bigint_error()),
+            (a, b) => {
+                let (a, b) = (a.to_number(), b.to_number());
+
+                // Unlike powf, 1 ** NaN & 1 ** Infinity are NaN
+                if b.is_nan() || (a.abs() == 1.0 && b.is_infinite()) {
+                    return Ok(Value::Number(f64::NAN));
                 }
+                Ok(Value::Number(a.powf(b)))
             },
-            (Value::Infinity(_), Value::Undefined) => Value::Nan,
-            (Value::Infinity(_), Value::Nan) => Value::Nan,
-            (Value::Infinity(_), Value::Infinity(_)) => Value::Nan,
-            (Value::Infinity(neg), Value::Null) => Value::Infinity(neg),
-            (Value::Infinity(neg), Value::Boolean(_)) => Value::Infinity(neg),
-
-            (Value::Null, Value::Number(b)) => Value::Number(0.0) / Value::Number(b),
-            (Value::Null, Value::String(b)) => Value::Number(0.0) / Value::String(b),
-            (Value::Null, Value::Undefined) => Value::Nan,
-            (Value::Null, Value::Nan) => Value::Nan,
-            (Value::Null, Value::Infinity(_)) => Value::Number(0.0),
-            (Value::Null, Value::Null) => Value::Nan,
-            (Value::Null, Value::Boolean(b)) => Value::Number(0.0) / Value::Boolean(b),
-
-            (Value::Boolean(a), Value::Number(b)) => Value::Number(if a { 1.0 } else { 0.0 }) / Value::Number(b),
-            (Value::Boolean(a), Value::String(b)) => Value::Number(if a { 1.0 } else { 0.0 }) / Value::String(b),
-            (Value::Boolean(_), Value::Undefined) => Value::Nan,
-            (Value::Boolean(_), Value::Nan) => Value::Nan,
-            (Value::Boolean(_), Value::Infinity(_)) => Value::Number(0.0),
-            (Value::Boolean(a), Value::Null) => Value::Boolean(a) / Value::Number(0.0),
-            (Value::Boolean(a), Value::Boolean(b)) => Value::Number(if a { 1.0 } else { 0.0 }) / Value::Boolean(b),
         }
     }
 }
@@ -393,407 +330,511 @@ impl ops::Div for Value {
 mod tests {
     use super::*;
 
+    #[track_caller]
+    fn assert_same(result: Result<Value>, expected: Value) {
+        let value = result.unwrap();
+        assert!(value.same_value(&expected), "expected {expected:?}, got {value:?}");
+    }
+
     #[test]
     fn test_add_values() {
-        assert_eq!(Value::Number(1.0) + Value::Number(2.0), Value::Number(3.0));
-        assert_eq!(Value::Number(1.0) + Value::String("a".to_string()), Value::String("1a".to_string()));
-        assert_eq!(Value::Number(1.0) + Value::Undefined, Value::Nan);
-        assert_eq!(Value::Number(1.0) + Value::Nan, Value::Nan);
-        assert_eq!(Value::Number(1.0) + Value::Infinity(false), Value::Infinity(false));
-        assert_eq!(Value::Number(1.0) + Value::Infinity(true), Value::Infinity(true));
-        assert_eq!(Value::Number(1.0) + Value::Null, Value::Number(1.0));
-        assert_eq!(Value::Number(3.0) + Value::Boolean(true), Value::Number(4.0));
-        assert_eq!(Value::Number(2.0) + Value::Boolean(false), Value::Number(2.0));
-
-        assert_eq!(Value::String("a".to_string()) + Value::Number(1.0), Value::String("a1".to_string()));
-        assert_eq!(Value::String("a".to_string()) + Value::String("b".to_string()), Value::String("ab".to_string()));
-        assert_eq!(Value::String("a".to_string()) + Value::Undefined, Value::String("aundefined".to_string()));
-        assert_eq!(Value::String("a".to_string()) + Value::Nan, Value::String("aNaN".to_string()));
-        assert_eq!(Value::String("a".to_string()) + Value::Infinity(false), Value::String("aInfinity".to_string()));
-        assert_eq!(Value::String("a".to_string()) + Value::Infinity(true), Value::String("a-Infinity".to_string()));
-        assert_eq!(Value::String("a".to_string()) + Value::Null, Value::String("anull".to_string()));
-        assert_eq!(Value::String("1".to_string()) + Value::Boolean(true), Value::String("1true".to_string()));
-        assert_eq!(Value::String("!".to_string()) + Value::Boolean(false), Value::String("!false".to_string()));
-
-        assert_eq!(Value::Undefined + Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Undefined + Value::String("a".to_string()), Value::String("undefineda".to_string()));
-        assert_eq!(Value::Undefined + Value::Undefined, Value::Nan);
-        assert_eq!(Value::Undefined + Value::Nan, Value::Nan);
-        assert_eq!(Value::Undefined + Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Undefined + Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Undefined + Value::Null, Value::Nan);
-        assert_eq!(Value::Undefined + Value::Boolean(true), Value::Nan);
-        assert_eq!(Value::Undefined + Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::Nan + Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Nan + Value::String("a".to_string()), Value::String("NaNa".to_string()));
-        assert_eq!(Value::Nan + Value::Undefined, Value::Nan);
-        assert_eq!(Value::Nan + Value::Nan, Value::Nan);
-        assert_eq!(Value::Nan + Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Nan + Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Nan + Value::Null, Value::Nan);
-        assert_eq!(Value::Nan + Value::Boolean(true), Value::Nan);
-        assert_eq!(Value::Nan + Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::Infinity(false) + Value::Number(2.0), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) + Value::Number(2.0), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) + Value::String("a".to_string()), Value::String("Infinitya".to_string()));
-        assert_eq!(Value::Infinity(true) + Value::String("a".to_string()), Value::String("-Infinitya".to_string()));
-        assert_eq!(Value::Infinity(false) + Value::Undefined, Value::Nan);
-        assert_eq!(Value::Infinity(true) + Value::Undefined, Value::Nan);
-        assert_eq!(Value::Infinity(false) + Value::Nan, Value::Nan);
-        assert_eq!(Value::Infinity(true) + Value::Nan, Value::Nan);
-        assert_eq!(Value::Infinity(false) + Value::Infinity(false), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) + Value::Infinity(true), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) + Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Infinity(false) + Value::Null, Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) + Value::Boolean(true), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) + Value::Boolean(false), Value::Infinity(true));
-
-        assert_eq!(Value::Null + Value::Number(-2.0), Value::Number(-2.0));
-        assert_eq!(Value::Null + Value::String("!".to_string()), Value::String("null!".to_string()));
-        assert_eq!(Value::Null + Value::Undefined, Value::Nan);
-        assert_eq!(Value::Null + Value::Nan, Value::Nan);
-        assert_eq!(Value::Null + Value::Infinity(true), Value::Infinity(true));
-        assert_eq!(Value::Null + Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Null + Value::Boolean(true), Value::Number(1.0));
-        assert_eq!(Value::Null + Value::Boolean(false), Value::Number(0.0));
-
-        assert_eq!(Value::Boolean(true) + Value::Number(2.0), Value::Number(3.0));
-        assert_eq!(Value::Boolean(false) + Value::Number(2.0), Value::Number(2.0));
-        assert_eq!(Value::Boolean(true) + Value::String("!".to_string()), Value::String("true!".to_string()));
-        assert_eq!(Value::Boolean(false) + Value::String("2".to_string()), Value::String("false2".to_string()));
-        assert_eq!(Value::Boolean(false) + Value::Undefined, Value::Nan);
-        assert_eq!(Value::Boolean(true) + Value::Nan, Value::Nan);
-        assert_eq!(Value::Boolean(true) + Value::Infinity(true), Value::Infinity(true));
-        assert_eq!(Value::Boolean(false) + Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) + Value::Null, Value::Number(1.0));
-        assert_eq!(Value::Boolean(false) + Value::Boolean(false), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) + Value::Boolean(false), Value::Number(1.0));
-        assert_eq!(Value::Boolean(true) + Value::Boolean(true), Value::Number(2.0));
+        assert_same(Value::Number(1.0) + Value::Number(2.0), Value::Number(3.0));
+        assert_same(Value::Number(1.0) + Value::String("a".into()), Value::String("1a".into()));
+        assert_same(Value::Number(1.0) + Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(1.0) + Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(1.0) + Value::Number(f64::INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(1.0) + Value::Number(f64::NEG_INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(1.0) + Value::Null, Value::Number(1.0));
+        assert_same(Value::Number(3.0) + Value::Boolean(true), Value::Number(4.0));
+        assert_same(Value::Number(2.0) + Value::Boolean(false), Value::Number(2.0));
+
+        assert_same(Value::String("a".into()) + Value::Number(1.0), Value::String("a1".into()));
+        assert_same(Value::String("a".into()) + Value::String("b".into()), Value::String("ab".into()));
+        assert_same(Value::String("a".into()) + Value::Undefined, Value::String("aundefined".into()));
+        assert_same(Value::String("a".into()) + Value::Number(f64::NAN), Value::String("aNaN".into()));
+        assert_same(Value::String("a".into()) + Value::Number(f64::INFINITY), Value::String("aInfinity".into()));
+        assert_same(Value::String("a".into()) + Value::Number(f64::NEG_INFINITY), Value::String("a-Infinity".into()));
+        assert_same(Value::String("a".into()) + Value::Null, Value::String("anull".into()));
+        assert_same(Value::String("1".into()) + Value::Boolean(true), Value::String("1true".into()));
+        assert_same(Value::String("!".into()) + Value::Boolean(false), Value::String("!false".into()));
+
+        assert_same(Value::Undefined + Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Undefined + Value::String("a".into()), Value::String("undefineda".into()));
+        assert_same(Value::Undefined + Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Undefined + Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Undefined + Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined + Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined + Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Undefined + Value::Boolean(true), Value::Number(f64::NAN));
+        assert_same(Value::Undefined + Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::NAN) + Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) + Value::String("a".into()), Value::String("NaNa".into()));
+        assert_same(Value::Number(f64::NAN) + Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) + Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) + Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) + Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) + Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) + Value::Boolean(true), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) + Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::INFINITY) + Value::Number(2.0), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) + Value::Number(2.0), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) + Value::String("a".into()), Value::String("Infinitya".into()));
+        assert_same(Value::Number(f64::NEG_INFINITY) + Value::String("a".into()), Value::String("-Infinitya".into()));
+        assert_same(Value::Number(f64::INFINITY) + Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) + Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) + Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) + Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) + Value::Number(f64::INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) + Value::Number(f64::NEG_INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) + Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) + Value::Null, Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) + Value::Boolean(true), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) + Value::Boolean(false), Value::Number(f64::NEG_INFINITY));
+
+        assert_same(Value::Null + Value::Number(-2.0), Value::Number(-2.0));
+        assert_same(Value::Null + Value::String("!".into()), Value::String("null!".into()));
+        assert_same(Value::Null + Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Null + Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Null + Value::Number(f64::NEG_INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Null + Value::Null, Value::Number(0.0));
+        assert_same(Value::Null + Value::Boolean(true), Value::Number(1.0));
+        assert_same(Value::Null + Value::Boolean(false), Value::Number(0.0));
+
+        assert_same(Value::Boolean(true) + Value::Number(2.0), Value::Number(3.0));
+        assert_same(Value::Boolean(false) + Value::Number(2.0), Value::Number(2.0));
+        assert_same(Value::Boolean(true) + Value::String("!".into()), Value::String("true!".into()));
+        assert_same(Value::Boolean(false) + Value::String("2".into()), Value::String("false2".into()));
+        assert_same(Value::Boolean(false) + Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) + Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) + Value::Number(f64::NEG_INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Boolean(false) + Value::Null, Value::Number(0.0));
+        assert_same(Value::Boolean(true) + Value::Null, Value::Number(1.0));
+        assert_same(Value::Boolean(false) + Value::Boolean(false), Value::Number(0.0));
+        assert_same(Value::Boolean(true) + Value::Boolean(false), Value::Number(1.0));
+        assert_same(Value::Boolean(true) + Value::Boolean(true), Value::Number(2.0));
     }
 
     #[test]
     fn test_sub_values() {
-        assert_eq!(Value::Number(1.0) - Value::Number(2.0), Value::Number(-1.0));
-        assert_eq!(Value::Number(1.0) - Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Number(1.0) - Value::String("2".to_string()), Value::Number(-1.0));
-        assert_eq!(Value::Number(1.0) - Value::String("".to_string()), Value::Number(1.0));
-        assert_eq!(Value::Number(1.0) - Value::Undefined, Value::Nan);
-        assert_eq!(Value::Number(1.0) - Value::Nan, Value::Nan);
-        assert_eq!(Value::Number(1.0) - Value::Infinity(false), Value::Infinity(true));
-        assert_eq!(Value::Number(1.0) - Value::Infinity(true), Value::Infinity(false));
-        assert_eq!(Value::Number(2.0) - Value::Null, Value::Number(2.0));
-        assert_eq!(Value::Number(3.0) - Value::Boolean(true), Value::Number(2.0));
-        assert_eq!(Value::Number(3.0) - Value::Boolean(false), Value::Number(3.0));
-
-        assert_eq!(Value::String("a".to_string()) - Value::Number(1.0), Value::Nan);
-        assert_eq!(Value::String("2".to_string()) - Value::Number(1.0), Value::Number(1.0));
-        assert_eq!(Value::String("".to_string()) - Value::Number(2.0), Value::Number(-2.0));
-        assert_eq!(Value::String("a".to_string()) - Value::String("2".to_string()), Value::Nan);
-        assert_eq!(Value::String("1".to_string()) - Value::String("2".to_string()), Value::Number(-1.0));
-        assert_eq!(Value::String("".to_string()) - Value::String("".to_string()), Value::Number(0.0));
-        assert_eq!(Value::String("a".to_string()) - Value::Undefined, Value::Nan);
-        assert_eq!(Value::String("a".to_string()) - Value::Nan, Value::Nan);
-        assert_eq!(Value::String("".to_string()) - Value::Infinity(false), Value::Infinity(true));
-        assert_eq!(Value::String("2".to_string()) - Value::Infinity(true), Value::Infinity(false));
-        assert_eq!(Value::String("a".to_string()) - Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::String("a".to_string()) - Value::Null, Value::Nan);
-        assert_eq!(Value::String("".to_string()) - Value::Null, Value::Number(0.0));
-        assert_eq!(Value::String("3".to_string()) - Value::Null, Value::Number(3.0));
-        assert_eq!(Value::String("a".to_string()) - Value::Boolean(true), Value::Nan);
-        assert_eq!(Value::String("2".to_string()) - Value::Boolean(false), Value::Number(2.0));
-        assert_eq!(Value::String("".to_string()) - Value::Boolean(true), Value::Number(-1.0));
-
-        assert_eq!(Value::Undefined - Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Undefined - Value::String("1".to_string()), Value::Nan);
-        assert_eq!(Value::Undefined - Value::Undefined, Value::Nan);
-        assert_eq!(Value::Undefined - Value::Nan, Value::Nan);
-        assert_eq!(Value::Undefined - Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Undefined - Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Undefined - Value::Null, Value::Nan);
-        assert_eq!(Value::Undefined - Value::Boolean(false), Value::Nan);
-        assert_eq!(Value::Undefined - Value::Boolean(true), Value::Nan);
-
-        assert_eq!(Value::Nan - Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Nan - Value::String("1".to_string()), Value::Nan);
-        assert_eq!(Value::Nan - Value::Undefined, Value::Nan);
-        assert_eq!(Value::Nan - Value::Nan, Value::Nan);
-        assert_eq!(Value::Nan - Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Nan - Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Nan - Value::Null, Value::Nan);
-        assert_eq!(Value::Nan - Value::Boolean(true), Value::Nan);
-        assert_eq!(Value::Nan - Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::Infinity(false) - Value::Number(1.0), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) - Value::Number(1.0), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) - Value::String("".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) - Value::String("".to_string()), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) - Value::String("2".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) - Value::String("2".to_string()), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) - Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(true) - Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(false) - Value::Undefined, Value::Nan);
-        assert_eq!(Value::Infinity(true) - Value::Undefined, Value::Nan);
-        assert_eq!(Value::Infinity(false) - Value::Nan, Value::Nan);
-        assert_eq!(Value::Infinity(true) - Value::Nan, Value::Nan);
-        assert_eq!(Value::Infinity(false) - Value::Infinity(true), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) - Value::Infinity(false), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) - Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Infinity(true) - Value::Null, Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) - Value::Null, Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) - Value::Boolean(false), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) - Value::Boolean(true), Value::Infinity(false));
-
-        assert_eq!(Value::Null - Value::Number(2.0), Value::Number(-2.0));
-        assert_eq!(Value::Null - Value::String("".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Null - Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Null - Value::String("2".to_string()), Value::Number(-2.0));
-        assert_eq!(Value::Null - Value::Undefined, Value::Nan);
-        assert_eq!(Value::Null - Value::Nan, Value::Nan);
-        assert_eq!(Value::Null - Value::Infinity(false), Value::Infinity(true));
-        assert_eq!(Value::Null - Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Null - Value::Boolean(true), Value::Number(-1.0));
-
-        assert_eq!(Value::Boolean(true) - Value::Number(2.0), Value::Number(-1.0));
-        assert_eq!(Value::Boolean(false) - Value::Number(2.0), Value::Number(-2.0));
-        assert_eq!(Value::Boolean(true) - Value::String("!".to_string()), Value::Nan);
-        assert_eq!(Value::Boolean(false) - Value::String("2".to_string()), Value::Number(-2.0));
-        assert_eq!(Value::Boolean(false) - Value::Undefined, Value::Nan);
-        assert_eq!(Value::Boolean(true) - Value::Nan, Value::Nan);
-        assert_eq!(Value::Boolean(true) - Value::Infinity(true), Value::Infinity(false));
-        assert_eq!(Value::Boolean(false) - Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) - Value::Null, Value::Number(1.0));
-        assert_eq!(Value::Boolean(false) - Value::Boolean(false), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) - Value::Boolean(false), Value::Number(1.0));
-        assert_eq!(Value::Boolean(true) - Value::Boolean(true), Value::Number(0.0));
+        assert_same(Value::Number(1.0) - Value::Number(2.0), Value::Number(-1.0));
+        assert_same(Value::Number(1.0) - Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(1.0) - Value::String("2".into()), Value::Number(-1.0));
+        assert_same(Value::Number(1.0) - Value::String("".into()), Value::Number(1.0));
+        assert_same(Value::Number(1.0) - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(1.0) - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(1.0) - Value::Number(f64::INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(1.0) - Value::Number(f64::NEG_INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(2.0) - Value::Null, Value::Number(2.0));
+        assert_same(Value::Number(3.0) - Value::Boolean(true), Value::Number(2.0));
+        assert_same(Value::Number(3.0) - Value::Boolean(false), Value::Number(3.0));
+
+        assert_same(Value::String("a".into()) - Value::Number(1.0), Value::Number(f64::NAN));
+        assert_same(Value::String("2".into()) - Value::Number(1.0), Value::Number(1.0));
+        assert_same(Value::String("".into()) - Value::Number(2.0), Value::Number(-2.0));
+        assert_same(Value::String("a".into()) - Value::String("2".into()), Value::Number(f64::NAN));
+        assert_same(Value::String("1".into()) - Value::String("2".into()), Value::Number(-1.0));
+        assert_same(Value::String("".into()) - Value::String("".into()), Value::Number(0.0));
+        assert_same(Value::String("a".into()) - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::String("".into()) - Value::Number(f64::INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::String("2".into()) - Value::Number(f64::NEG_INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::String("a".into()) - Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) - Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::String("".into()) - Value::Null, Value::Number(0.0));
+        assert_same(Value::String("3".into()) - Value::Null, Value::Number(3.0));
+        assert_same(Value::String("a".into()) - Value::Boolean(true), Value::Number(f64::NAN));
+        assert_same(Value::String("2".into()) - Value::Boolean(false), Value::Number(2.0));
+        assert_same(Value::String("".into()) - Value::Boolean(true), Value::Number(-1.0));
+
+        assert_same(Value::Undefined - Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::String("1".into()), Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::Boolean(false), Value::Number(f64::NAN));
+        assert_same(Value::Undefined - Value::Boolean(true), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::NAN) - Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::String("1".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::Boolean(true), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) - Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::INFINITY) - Value::Number(1.0), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::Number(1.0), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) - Value::String("".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::String("".into()), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) - Value::String("2".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::String("2".into()), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) - Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) - Value::Number(f64::NEG_INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::Number(f64::INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) - Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::Null, Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) - Value::Null, Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) - Value::Boolean(false), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) - Value::Boolean(true), Value::Number(f64::INFINITY));
+
+        assert_same(Value::Null - Value::Number(2.0), Value::Number(-2.0));
+        assert_same(Value::Null - Value::String("".into()), Value::Number(0.0));
+        assert_same(Value::Null - Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Null - Value::String("2".into()), Value::Number(-2.0));
+        assert_same(Value::Null - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Null - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Null - Value::Number(f64::INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Null - Value::Null, Value::Number(0.0));
+        assert_same(Value::Null - Value::Boolean(true), Value::Number(-1.0));
+
+        assert_same(Value::Boolean(true) - Value::Number(2.0), Value::Number(-1.0));
+        assert_same(Value::Boolean(false) - Value::Number(2.0), Value::Number(-2.0));
+        assert_same(Value::Boolean(true) - Value::String("!".into()), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(false) - Value::String("2".into()), Value::Number(-2.0));
+        assert_same(Value::Boolean(false) - Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) - Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) - Value::Number(f64::NEG_INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::Boolean(false) - Value::Null, Value::Number(0.0));
+        assert_same(Value::Boolean(true) - Value::Null, Value::Number(1.0));
+        assert_same(Value::Boolean(false) - Value::Boolean(false), Value::Number(0.0));
+        assert_same(Value::Boolean(true) - Value::Boolean(false), Value::Number(1.0));
+        assert_same(Value::Boolean(true) - Value::Boolean(true), Value::Number(0.0));
     }
 
     #[test]
     fn test_mul_values() {
-        assert_eq!(Value::Number(3.0) * Value::Number(2.0), Value::Number(6.0));
-        assert_eq!(Value::Number(3.0) * Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Number(3.0) * Value::String("-2".to_string()), Value::Number(-6.0));
-        assert_eq!(Value::Number(1.0) * Value::String("".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Number(1.0) * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Number(1.0) * Value::Nan, Value::Nan);
-        assert_eq!(Value::Number(0.0) * Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Number(0.0) * Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Number(-2.0) * Value::Infinity(false), Value::Infinity(true));
-        assert_eq!(Value::Number(2.0) * Value::Infinity(true), Value::Infinity(true));
-        assert_eq!(Value::Number(2.0) * Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Number(2.0) * Value::Boolean(true), Value::Number(2.0));
-        assert_eq!(Value::Number(2.0) * Value::Boolean(false), Value::Number(0.0));
-
-        assert_eq!(Value::String("a".to_string()) * Value::Number(1.0), Value::Nan);
-        assert_eq!(Value::String("2".to_string()) * Value::Number(2.0), Value::Number(4.0));
-        assert_eq!(Value::String("".to_string()) * Value::Number(2.0), Value::Number(0.0));
-        assert_eq!(Value::String("a".to_string()) * Value::String("2".to_string()), Value::Nan);
-        assert_eq!(Value::String("-2".to_string()) * Value::String("2".to_string()), Value::Number(-4.0));
-        assert_eq!(Value::String("".to_string()) * Value::String("".to_string()), Value::Number(0.0));
-        assert_eq!(Value::String("a".to_string()) * Value::Undefined, Value::Nan);
-        assert_eq!(Value::String("a".to_string()) * Value::Nan, Value::Nan);
-        assert_eq!(Value::String("a".to_string()) * Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::String("a".to_string()) * Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::String("".to_string()) * Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::String("".to_string()) * Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::String("0".to_string()) * Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::String("0".to_string()) * Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::String("2".to_string()) * Value::Infinity(false), Value::Infinity(false));
-        assert_eq!(Value::String("-2".to_string()) * Value::Infinity(false), Value::Infinity(true));
-        assert_eq!(Value::String("a".to_string()) * Value::Null, Value::Nan);
-        assert_eq!(Value::String("2".to_string()) * Value::Null, Value::Number(0.0));
-        assert_eq!(Value::String("".to_string()) * Value::Null, Value::Number(0.0));
-        assert_eq!(Value::String("a".to_string()) * Value::Boolean(false), Value::Nan);
-        assert_eq!(Value::String("2".to_string()) * Value::Boolean(true), Value::Number(2.0));
-        assert_eq!(Value::String("".to_string()) * Value::Boolean(true), Value::Number(0.0));
-
-        assert_eq!(Value::Undefined * Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Undefined * Value::String("1".to_string()), Value::Nan);
-        assert_eq!(Value::Undefined * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Undefined * Value::Nan, Value::Nan);
-        assert_eq!(Value::Undefined * Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Undefined * Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Undefined * Value::Null, Value::Nan);
-        assert_eq!(Value::Undefined * Value::Boolean(true), Value::Nan);
-        assert_eq!(Value::Undefined * Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::Nan * Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Nan * Value::String("1".to_string()), Value::Nan);
-        assert_eq!(Value::Nan * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Nan * Value::Nan, Value::Nan);
-        assert_eq!(Value::Nan * Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Nan * Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Nan * Value::Null, Value::Nan);
-        assert_eq!(Value::Nan * Value::Boolean(true), Value::Nan);
-        assert_eq!(Value::Nan * Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::Infinity(false) * Value::Number(0.0), Value::Nan);
-        assert_eq!(Value::Infinity(true) * Value::Number(0.0), Value::Nan);
-        assert_eq!(Value::Infinity(false) * Value::Number(2.0), Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) * Value::Number(-2.0), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) * Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(true) * Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(false) * Value::String("".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(true) * Value::String("".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(false) * Value::String("0".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(true) * Value::String("0".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(false) * Value::String("2".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) * Value::String("-2".to_string()), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Infinity(true) * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Infinity(false) * Value::Nan, Value::Nan);
-        assert_eq!(Value::Infinity(true) * Value::Nan, Value::Nan);
-        assert_eq!(Value::Infinity(false) * Value::Infinity(false), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) * Value::Infinity(false), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) * Value::Infinity(true), Value::Infinity(true));
-        assert_eq!(Value::Infinity(true) * Value::Infinity(true), Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) * Value::Boolean(true), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) * Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::Null * Value::Number(2.0), Value::Number(0.0));
-        assert_eq!(Value::Null * Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Null * Value::String("2".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Null * Value::String("".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Null * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Null * Value::Nan, Value::Nan);
-        assert_eq!(Value::Null * Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Null * Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Null * Value::Boolean(true), Value::Number(0.0));
-        assert_eq!(Value::Null * Value::Boolean(false), Value::Number(0.0));
-
-        assert_eq!(Value::Boolean(true) * Value::Number(2.0), Value::Number(2.0));
-        assert_eq!(Value::Boolean(false) * Value::Number(2.0), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) * Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Boolean(false) * Value::String("2".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) * Value::String("".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Boolean(false) * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Boolean(true) * Value::Undefined, Value::Nan);
-        assert_eq!(Value::Boolean(false) * Value::Nan, Value::Nan);
-        assert_eq!(Value::Boolean(true) * Value::Nan, Value::Nan);
-        assert_eq!(Value::Boolean(false) * Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Boolean(true) * Value::Infinity(true), Value::Infinity(true));
-        assert_eq!(Value::Boolean(false) * Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) * Value::Null, Value::Number(0.0));
-        assert_eq!(Value::Boolean(false) * Value::Boolean(false), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) * Value::Boolean(false), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) * Value::Boolean(true), Value::Number(1.0));
+        assert_same(Value::Number(3.0) * Value::Number(2.0), Value::Number(6.0));
+        assert_same(Value::Number(3.0) * Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(3.0) * Value::String("-2".into()), Value::Number(-6.0));
+        assert_same(Value::Number(1.0) * Value::String("".into()), Value::Number(0.0));
+        assert_same(Value::Number(1.0) * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(1.0) * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(0.0) * Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(0.0) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(-2.0) * Value::Number(f64::INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(2.0) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(2.0) * Value::Null, Value::Number(0.0));
+        assert_same(Value::Number(2.0) * Value::Boolean(true), Value::Number(2.0));
+        assert_same(Value::Number(2.0) * Value::Boolean(false), Value::Number(0.0));
+
+        assert_same(Value::String("a".into()) * Value::Number(1.0), Value::Number(f64::NAN));
+        assert_same(Value::String("2".into()) * Value::Number(2.0), Value::Number(4.0));
+        assert_same(Value::String("".into()) * Value::Number(2.0), Value::Number(0.0));
+        assert_same(Value::String("a".into()) * Value::String("2".into()), Value::Number(f64::NAN));
+        assert_same(Value::String("-2".into()) * Value::String("2".into()), Value::Number(-4.0));
+        assert_same(Value::String("".into()) * Value::String("".into()), Value::Number(0.0));
+        assert_same(Value::String("a".into()) * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) * Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("".into()) * Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("".into()) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("0".into()) * Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("0".into()) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("2".into()) * Value::Number(f64::INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::String("-2".into()) * Value::Number(f64::INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::String("a".into()) * Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::String("2".into()) * Value::Null, Value::Number(0.0));
+        assert_same(Value::String("".into()) * Value::Null, Value::Number(0.0));
+        assert_same(Value::String("a".into()) * Value::Boolean(false), Value::Number(f64::NAN));
+        assert_same(Value::String("2".into()) * Value::Boolean(true), Value::Number(2.0));
+        assert_same(Value::String("".into()) * Value::Boolean(true), Value::Number(0.0));
+
+        assert_same(Value::Undefined * Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::String("1".into()), Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::Boolean(true), Value::Number(f64::NAN));
+        assert_same(Value::Undefined * Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::NAN) * Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::String("1".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::Boolean(true), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) * Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::INFINITY) * Value::Number(0.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::Number(0.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) * Value::Number(2.0), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) * Value::Number(-2.0), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) * Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) * Value::String("".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::String("".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) * Value::String("0".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::String("0".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) * Value::String("2".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) * Value::String("-2".into()), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) * Value::Number(f64::INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::Number(f64::INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) * Value::Boolean(true), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) * Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::Null * Value::Number(2.0), Value::Number(0.0));
+        assert_same(Value::Null * Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Null * Value::String("2".into()), Value::Number(0.0));
+        assert_same(Value::Null * Value::String("".into()), Value::Number(0.0));
+        assert_same(Value::Null * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Null * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Null * Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Null * Value::Null, Value::Number(0.0));
+        assert_same(Value::Null * Value::Boolean(true), Value::Number(0.0));
+        assert_same(Value::Null * Value::Boolean(false), Value::Number(0.0));
+
+        assert_same(Value::Boolean(true) * Value::Number(2.0), Value::Number(2.0));
+        assert_same(Value::Boolean(false) * Value::Number(2.0), Value::Number(0.0));
+        assert_same(Value::Boolean(true) * Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(false) * Value::String("2".into()), Value::Number(0.0));
+        assert_same(Value::Boolean(true) * Value::String("".into()), Value::Number(0.0));
+        assert_same(Value::Boolean(false) * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) * Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Boolean(false) * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) * Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(false) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) * Value::Number(f64::NEG_INFINITY), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Boolean(false) * Value::Null, Value::Number(0.0));
+        assert_same(Value::Boolean(true) * Value::Null, Value::Number(0.0));
+        assert_same(Value::Boolean(false) * Value::Boolean(false), Value::Number(0.0));
+        assert_same(Value::Boolean(true) * Value::Boolean(false), Value::Number(0.0));
+        assert_same(Value::Boolean(true) * Value::Boolean(true), Value::Number(1.0));
     }
 
     #[test]
     fn test_div_values() {
-        assert_eq!(Value::Number(3.0) / Value::Number(4.0), Value::Number(0.75));
-        assert_eq!(Value::Number(0.0) / Value::Number(0.0), Value::Nan);
-        assert_eq!(Value::Number(-3.0) / Value::Number(0.0), Value::Infinity(true));
-        assert_eq!(Value::Number(0.0) / Value::String("".to_string()), Value::Nan);
-        assert_eq!(Value::Number(3.0) / Value::String("".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Number(9.0) / Value::String("-3.0".to_string()), Value::Number(-3.0));
-        assert_eq!(Value::Number(3.0) / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Number(3.0) / Value::Undefined, Value::Nan);
-        assert_eq!(Value::Number(3.0) / Value::Nan, Value::Nan);
-        assert_eq!(Value::Number(3.0) / Value::Infinity(false), Value::Number(0.0));
-        assert_eq!(Value::Number(3.0) / Value::Infinity(true), Value::Number(0.0));
-        assert_eq!(Value::Number(3.0) / Value::Null, Value::Infinity(false));
-        assert_eq!(Value::Number(0.0) / Value::Null, Value::Nan);
-        assert_eq!(Value::Number(2.0) / Value::Boolean(true), Value::Number(2.0));
-        assert_eq!(Value::Number(0.0) / Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::String("".to_string()) / Value::Number(2.0), Value::Number(0.0));
-        assert_eq!(Value::String("".to_string()) / Value::Number(0.0), Value::Nan);
-        assert_eq!(Value::String("a".to_string()) / Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::String("-2".to_string()) / Value::Number(2.0), Value::Number(-1.0));
-        assert_eq!(Value::String("".to_string()) / Value::String("".to_string()), Value::Nan);
-        assert_eq!(Value::String("".to_string()) / Value::String("2.0".to_string()), Value::Number(0.0));
-        assert_eq!(Value::String("".to_string()) / Value::String("0.0".to_string()), Value::Nan);
-        assert_eq!(Value::String("a".to_string()) / Value::String("2.0".to_string()), Value::Nan);
-        assert_eq!(Value::String("-2".to_string()) / Value::String("2.0".to_string()), Value::Number(-1.0));
-        assert_eq!(Value::String("3.0".to_string()) / Value::String("".to_string()), Value::Infinity(false));
-        assert_eq!(Value::String("9.0".to_string()) / Value::String("-3.0".to_string()), Value::Number(-3.0));
-        assert_eq!(Value::String("3.0".to_string()) / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::String("b".to_string()) / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::String("3".to_string()) / Value::Undefined, Value::Nan);
-        assert_eq!(Value::String("3".to_string()) / Value::Nan, Value::Nan);
-        assert_eq!(Value::String("".to_string()) / Value::Infinity(false), Value::Number(0.0));
-        assert_eq!(Value::String("".to_string()) / Value::Infinity(true), Value::Number(0.0));
-        assert_eq!(Value::String("a".to_string()) / Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::String("3.0".to_string()) / Value::Infinity(true), Value::Number(0.0));
-        assert_eq!(Value::String("".to_string()) / Value::Null, Value::Nan);
-        assert_eq!(Value::String("a".to_string()) / Value::Null, Value::Nan);
-        assert_eq!(Value::String("-2".to_string()) / Value::Null, Value::Infinity(true));
-        assert_eq!(Value::String("".to_string()) / Value::Boolean(false), Value::Nan);
-        assert_eq!(Value::String("a".to_string()) / Value::Boolean(true), Value::Nan);
-        assert_eq!(Value::String("-2".to_string()) / Value::Boolean(false), Value::Infinity(true));
-
-        assert_eq!(Value::Undefined / Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Undefined / Value::String("".to_string()), Value::Nan);
-        assert_eq!(Value::Undefined / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Undefined / Value::String("2".to_string()), Value::Nan);
-        assert_eq!(Value::Undefined / Value::Undefined, Value::Nan);
-        assert_eq!(Value::Undefined / Value::Nan, Value::Nan);
-        assert_eq!(Value::Undefined / Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Undefined / Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Undefined / Value::Null, Value::Nan);
-        assert_eq!(Value::Undefined / Value::Boolean(true), Value::Nan);
-
-        assert_eq!(Value::Nan / Value::Number(2.0), Value::Nan);
-        assert_eq!(Value::Nan / Value::String("".to_string()), Value::Nan);
-        assert_eq!(Value::Nan / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Nan / Value::String("2".to_string()), Value::Nan);
-        assert_eq!(Value::Nan / Value::Undefined, Value::Nan);
-        assert_eq!(Value::Nan / Value::Nan, Value::Nan);
-        assert_eq!(Value::Nan / Value::Infinity(false), Value::Nan);
-        assert_eq!(Value::Nan / Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Nan / Value::Null, Value::Nan);
-        assert_eq!(Value::Nan / Value::Boolean(true), Value::Nan);
-
-        assert_eq!(Value::Infinity(false) / Value::Number(2.0), Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) / Value::Number(-2.0), Value::Infinity(true));
-        assert_eq!(Value::Infinity(true) / Value::Number(2.0), Value::Infinity(true));
-        assert_eq!(Value::Infinity(true) / Value::Number(-2.0), Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) / Value::String("".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) / Value::String("".to_string()), Value::Infinity(true));
-        assert_eq!(Value::Infinity(true) / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Infinity(false) / Value::String("2.0".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) / Value::String("-2.0".to_string()), Value::Infinity(true));
-        assert_eq!(Value::Infinity(true) / Value::String("2.0".to_string()), Value::Infinity(true));
-        assert_eq!(Value::Infinity(true) / Value::String("-2.0".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Infinity(false) / Value::Undefined, Value::Nan);
-        assert_eq!(Value::Infinity(true) / Value::Nan, Value::Nan);
-        assert_eq!(Value::Infinity(false) / Value::Infinity(true), Value::Nan);
-        assert_eq!(Value::Infinity(true) / Value::Null, Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) / Value::Null, Value::Infinity(false));
-        assert_eq!(Value::Infinity(true) / Value::Boolean(false), Value::Infinity(true));
-        assert_eq!(Value::Infinity(false) / Value::Boolean(true), Value::Infinity(false));
-
-        assert_eq!(Value::Null / Value::Number(2.0), Value::Number(0.0));
-        assert_eq!(Value::Null / Value::Number(0.0), Value::Nan);
-        assert_eq!(Value::Null / Value::String("".to_string()), Value::Nan);
-        assert_eq!(Value::Null / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Null / Value::String("2".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Null / Value::Undefined, Value::Nan);
-        assert_eq!(Value::Null / Value::Nan, Value::Nan);
-        assert_eq!(Value::Null / Value::Infinity(false), Value::Number(0.0));
-        assert_eq!(Value::Null / Value::Infinity(true), Value::Number(0.0));
-        assert_eq!(Value::Null / Value::Null, Value::Nan);
-        assert_eq!(Value::Null / Value::Boolean(true), Value::Number(0.0));
-        assert_eq!(Value::Null / Value::Boolean(false), Value::Nan);
-
-        assert_eq!(Value::Boolean(true) / Value::Number(2.0), Value::Number(0.5));
-        assert_eq!(Value::Boolean(false) / Value::Number(2.0), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) / Value::String("a".to_string()), Value::Nan);
-        assert_eq!(Value::Boolean(false) / Value::String("2".to_string()), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) / Value::String("".to_string()), Value::Infinity(false));
-        assert_eq!(Value::Boolean(false) / Value::Undefined, Value::Nan);
-        assert_eq!(Value::Boolean(true) / Value::Undefined, Value::Nan);
-        assert_eq!(Value::Boolean(false) / Value::Nan, Value::Nan);
-        assert_eq!(Value::Boolean(true) / Value::Nan, Value::Nan);
-        assert_eq!(Value::Boolean(false) / Value::Infinity(true), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) / Value::Infinity(true), Value::Number(0.0));
-        assert_eq!(Value::Boolean(false) / Value::Null, Value::Nan);
-        assert_eq!(Value::Boolean(true) / Value::Null, Value::Infinity(false));
-        assert_eq!(Value::Boolean(false) / Value::Boolean(false), Value::Nan);
-        assert_eq!(Value::Boolean(true) / Value::Boolean(false), Value::Infinity(false));
-        assert_eq!(Value::Boolean(false) / Value::Boolean(true), Value::Number(0.0));
-        assert_eq!(Value::Boolean(true) / Value::Boolean(true), Value::Number(1.0));
+        assert_same(Value::Number(3.0) / Value::Number(4.0), Value::Number(0.75));
+        assert_same(Value::Number(0.0) / Value::Number(0.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(-3.0) / Value::Number(0.0), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(0.0) / Value::String("".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(3.0) / Value::String("".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(9.0) / Value::String("-3.0".into()), Value::Number(-3.0));
+        assert_same(Value::Number(3.0) / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(3.0) / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(3.0) / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(3.0) / Value::Number(f64::INFINITY), Value::Number(0.0));
+        assert_same(Value::Number(3.0) / Value::Number(f64::NEG_INFINITY), Value::Number(-0.0));
+        assert_same(Value::Number(3.0) / Value::Null, Value::Number(f64::INFINITY));
+        assert_same(Value::Number(0.0) / Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Number(2.0) / Value::Boolean(true), Value::Number(2.0));
+        assert_same(Value::Number(0.0) / Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::String("".into()) / Value::Number(2.0), Value::Number(0.0));
+        assert_same(Value::String("".into()) / Value::Number(0.0), Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) / Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::String("-2".into()) / Value::Number(2.0), Value::Number(-1.0));
+        assert_same(Value::String("".into()) / Value::String("".into()), Value::Number(f64::NAN));
+        assert_same(Value::String("".into()) / Value::String("2.0".into()), Value::Number(0.0));
+        assert_same(Value::String("".into()) / Value::String("0.0".into()), Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) / Value::String("2.0".into()), Value::Number(f64::NAN));
+        assert_same(Value::String("-2".into()) / Value::String("2.0".into()), Value::Number(-1.0));
+        assert_same(Value::String("3.0".into()) / Value::String("".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::String("9.0".into()) / Value::String("-3.0".into()), Value::Number(-3.0));
+        assert_same(Value::String("3.0".into()) / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::String("b".into()) / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::String("3".into()) / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::String("3".into()) / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::String("".into()) / Value::Number(f64::INFINITY), Value::Number(0.0));
+        assert_same(Value::String("".into()) / Value::Number(f64::NEG_INFINITY), Value::Number(-0.0));
+        assert_same(Value::String("a".into()) / Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::String("3.0".into()) / Value::Number(f64::NEG_INFINITY), Value::Number(-0.0));
+        assert_same(Value::String("".into()) / Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) / Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::String("-2".into()) / Value::Null, Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::String("".into()) / Value::Boolean(false), Value::Number(f64::NAN));
+        assert_same(Value::String("a".into()) / Value::Boolean(true), Value::Number(f64::NAN));
+        assert_same(Value::String("-2".into()) / Value::Boolean(false), Value::Number(f64::NEG_INFINITY));
+
+        assert_same(Value::Undefined / Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::String("".into()), Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::String("2".into()), Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Undefined / Value::Boolean(true), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::NAN) / Value::Number(2.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::String("".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::String("2".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::Number(f64::INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NAN) / Value::Boolean(true), Value::Number(f64::NAN));
+
+        assert_same(Value::Number(f64::INFINITY) / Value::Number(2.0), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) / Value::Number(-2.0), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::Number(2.0), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::Number(-2.0), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) / Value::String("".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::String("".into()), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) / Value::String("2.0".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) / Value::String("-2.0".into()), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::String("2.0".into()), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::String("-2.0".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::INFINITY) / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::INFINITY) / Value::Number(f64::NEG_INFINITY), Value::Number(f64::NAN));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::Null, Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) / Value::Null, Value::Number(f64::INFINITY));
+        assert_same(Value::Number(f64::NEG_INFINITY) / Value::Boolean(false), Value::Number(f64::NEG_INFINITY));
+        assert_same(Value::Number(f64::INFINITY) / Value::Boolean(true), Value::Number(f64::INFINITY));
+
+        assert_same(Value::Null / Value::Number(2.0), Value::Number(0.0));
+        assert_same(Value::Null / Value::Number(0.0), Value::Number(f64::NAN));
+        assert_same(Value::Null / Value::String("".into()), Value::Number(f64::NAN));
+        assert_same(Value::Null / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Null / Value::String("2".into()), Value::Number(0.0));
+        assert_same(Value::Null / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Null / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Null / Value::Number(f64::INFINITY), Value::Number(0.0));
+        assert_same(Value::Null / Value::Number(f64::NEG_INFINITY), Value::Number(-0.0));
+        assert_same(Value::Null / Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Null / Value::Boolean(true), Value::Number(0.0));
+        assert_same(Value::Null / Value::Boolean(false), Value::Number(f64::NAN));
+
+        assert_same(Value::Boolean(true) / Value::Number(2.0), Value::Number(0.5));
+        assert_same(Value::Boolean(false) / Value::Number(2.0), Value::Number(0.0));
+        assert_same(Value::Boolean(true) / Value::String("a".into()), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(false) / Value::String("2".into()), Value::Number(0.0));
+        assert_same(Value::Boolean(true) / Value::String("".into()), Value::Number(f64::INFINITY));
+        assert_same(Value::Boolean(false) / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) / Value::Undefined, Value::Number(f64::NAN));
+        assert_same(Value::Boolean(false) / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) / Value::Number(f64::NAN), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(false) / Value::Number(f64::NEG_INFINITY), Value::Number(-0.0));
+        assert_same(Value::Boolean(true) / Value::Number(f64::NEG_INFINITY), Value::Number(-0.0));
+        assert_same(Value::Boolean(false) / Value::Null, Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) / Value::Null, Value::Number(f64::INFINITY));
+        assert_same(Value::Boolean(false) / Value::Boolean(false), Value::Number(f64::NAN));
+        assert_same(Value::Boolean(true) / Value::Boolean(false), Value::Number(f64::INFINITY));
+        assert_same(Value::Boolean(false) / Value::Boolean(true), Value::Number(0.0));
+        assert_same(Value::Boolean(true) / Value::Boolean(true), Value::Number(1.0));
+    }
+
+    #[test]
+    fn test_rem_and_pow_values() {
+        assert_same(Value::Number(7.0) % Value::Number(3.0), Value::Number(1.0));
+        assert_same(Value::Number(-7.0) % Value::Number(3.0), Value::Number(-1.0));
+        assert_same(Value::Number(5.5) % Value::String("2".into()), Value::Number(1.5));
+        assert_same(Value::Number(7.0) % Value::Number(0.0), Value::Number(f64::NAN));
+        assert_same(Value::Number(-0.0) % Value::Number(3.0), Value::Number(-0.0));
+        assert_same(Value::Number(7.0) % Value::Number(f64::INFINITY), Value::Number(7.0));
+
+        assert_same(Value::Number(2.0).pow(Value::Number(10.0)), Value::Number(1024.0));
+        assert_same(Value::Number(4.0).pow(Value::Number(-0.5)), Value::Number(0.5));
+        assert_same(Value::Number(f64::NAN).pow(Value::Number(0.0)), Value::Number(1.0));
+        assert_same(Value::Number(1.0).pow(Value::Number(f64::NAN)), Value::Number(f64::NAN));
+        assert_same(Value::Number(-1.0).pow(Value::Number(f64::INFINITY)), Value::Number(f64::NAN));
+        assert_same(Value::Number(-8.0).pow(Value::Number(1.0 / 3.0)), Value::Number(f64::NAN));
+    }
+
+    #[test]
+    fn test_bigint_values() {
+        let big = |n: i64| Value::BigInt(BigInt::from(n));
+        let mixed = || Err(Error::Type("Cannot mix BigInt and other types, use explicit conversions!".to_string()));
+
+        assert_eq!(big(2) + big(3), Ok(big(5)));
+        assert_eq!(big(2) + Value::String("a".into()), Ok(Value::String("2a".into())));
+        assert_eq!(big(2) + Value::Number(3.0), mixed());
+        assert_eq!(big(2) - big(3), Ok(big(-1)));
+        assert_eq!(Value::Null - big(3), mixed());
+        assert_eq!(big(2) * big(3), Ok(big(6)));
+        assert_eq!(big(2) * Value::Boolean(true), mixed());
+        assert_eq!(big(-7) / big(2), Ok(big(-3)));
+        assert_eq!(big(7) / Value::Undefined, mixed());
+        assert_eq!(big(7) / big(0), Err(Error::Range("Division by zero!".to_string())));
+        assert_eq!(big(-7) % big(2), Ok(big(-1)));
+        assert_eq!(big(7) % big(0), Err(Error::Range("Division by zero!".to_string())));
+        assert_eq!(big(-2).pow(big(3)), Ok(big(-8)));
+        assert_eq!(big(2).pow(big(-1)), Err(Error::Range("Exponent must be non-negative!".to_string())));
+        assert_eq!(big(-1).pow(Value::BigInt(BigInt::from(u64::MAX))), Ok(big(-1)));
+        assert_eq!(big(2).pow(Value::BigInt(BigInt::from(u64::MAX))), Err(Error::Range("Maximum BigInt size exceeded!".to_string())));
+        assert_eq!(big(2).pow(Value::Number(2.0)), mixed());
+
+        assert_eq!(big(2).compare_numeric(&Value::Number(2.5)), Some(Ordering::Less));
+        assert_eq!(Value::Number(-2.5).compare_numeric(&big(-3)), Some(Ordering::Greater));
+        assert_eq!(big(2).compare_numeric(&Value::Number(f64::NEG_INFINITY)), Some(Ordering::Greater));
+        assert_eq!(big(2).compare_numeric(&Value::Number(f64::NAN)), None);
+        assert_eq!(Value::string_to_bigint(" -12 "), Some(BigInt::from(-12)));
+        assert_eq!(Value::string_to_bigint("0b101"), Some(BigInt::from(5)));
+        assert_eq!(Value::string_to_bigint("-0x1"), None);
+        assert_eq!(Value::string_to_bigint("1_000"), None);
+    }
+
+    #[test]
+    fn test_ieee_754_conformance() {
+        let n = Value::Number;
+
+        // NaN propagates through every operation
+        assert_same(n(f64::NAN) + n(1.0), n(f64::NAN));
+        assert_same(n(f64::NAN) * n(0.0), n(f64::NAN));
+        assert_same(n(0.0) / n(0.0), n(f64::NAN));
+
+        // Infinity
+        assert_same(n(f64::INFINITY) - n(f64::INFINITY), n(f64::NAN));
+        assert_same(n(f64::INFINITY) + n(f64::INFINITY), n(f64::INFINITY));
+        assert_same(n(f64::INFINITY) * n(0.0), n(f64::NAN));
+        assert_same(n(f64::INFINITY) * n(-2.0), n(f64::NEG_INFINITY));
+        assert_same(n(f64::INFINITY) / n(f64::INFINITY), n(f64::NAN));
+        assert_same(n(f64::NEG_INFINITY) / n(2.0), n(f64::NEG_INFINITY));
+        assert_same(n(f64::MAX) * n(2.0), n(f64::INFINITY));
+
+        // Signed zeros
+        assert_same(n(1.0) / n(-0.0), n(f64::NEG_INFINITY));
+        assert_same(n(-1.0) / n(0.0), n(f64::NEG_INFINITY));
+        assert_same(n(-0.0) + n(-0.0), n(-0.0));
+        assert_same(n(-0.0) + n(0.0), n(0.0));
+        assert_same(n(0.0) - n(0.0), n(0.0));
+        assert_same(n(-0.0) - n(0.0), n(-0.0));
+        assert_same(n(-2.0) * n(0.0), n(-0.0));
+        assert_same(n(0.0) / n(-5.0), n(-0.0));
+
+        // Precision
+        assert_same(n(0.1) + n(0.2), n(0.30000000000000004));
+        assert_same(n(9007199254740992.0) + n(1.0), n(9007199254740992.0));
+
+        // Equality & truthiness
+        assert!(!n(f64::NAN).strict_equals(&n(f64::NAN)));
+        assert!(n(f64::NAN).same_value(&n(f64::NAN)));
+        assert!(n(0.0).strict_equals(&n(-0.0)));
+        assert!(!n(0.0).same_value(&n(-0.0)));
+        assert!(!n(f64::NAN).loose_equals(&n(f64::NAN)));
+        assert!(n(f64::INFINITY).loose_equals(&Value::String("Infinity".into())));
+        assert!(!n(f64::NAN).to_boolean());
+        assert!(!n(-0.0).to_boolean());
+        assert!(n(f64::NEG_INFINITY).to_boolean());
+
+        assert_eq!(n(f64::NAN).to_string(), "NaN");
+        assert_eq!(n(f64::NEG_INFINITY).to_string(), "-Infinity");
+        assert_eq!(n(-0.0).to_string(), "0");
     }
 }